@@ -380,6 +380,10 @@ impl<S: Stream<Item = PolarsResult<EncodedData>> + Unpin> FlightstreamConsumer<S
         })
     }
 
+    pub fn schema(&self) -> &ArrowSchema {
+        self.inner.schema()
+    }
+
     pub async fn next_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
         while let Some(msg) = self.stream.next().await {
             let msg = msg?;
@@ -53,6 +53,73 @@ pub fn read_stream_metadata(reader: &mut dyn std::io::Read) -> PolarsResult<Stre
     deserialize_stream_metadata(&buffer)
 }
 
+/// Read the row count of a stream by summing the lengths of its record batches.
+///
+/// Only the message headers are decoded, the message bodies are skipped.
+pub fn get_stream_row_count<R: Read>(reader: &mut R) -> PolarsResult<i64> {
+    read_stream_metadata(reader)?;
+
+    let mut message_buffer = vec![];
+    let mut num_rows = 0;
+
+    loop {
+        let mut meta_length: [u8; 4] = [0; 4];
+        match reader.read_exact(&mut meta_length) {
+            Ok(()) => {},
+            // A stream may end without the end-of-stream marker.
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(num_rows),
+            Err(e) => return Err(e.into()),
+        }
+        if meta_length == CONTINUATION_MARKER {
+            reader.read_exact(&mut meta_length)?;
+        }
+
+        let meta_length: usize = i32::from_le_bytes(meta_length)
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::NegativeFooterLength))?;
+        if meta_length == 0 {
+            return Ok(num_rows);
+        }
+
+        message_buffer.clear();
+        message_buffer.try_reserve(meta_length)?;
+        reader
+            .by_ref()
+            .take(meta_length as u64)
+            .read_to_end(&mut message_buffer)?;
+
+        let message = arrow_format::ipc::MessageRef::read_as_root(message_buffer.as_ref())
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+        let header = message
+            .header()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferHeader(err)))?
+            .ok_or_else(|| polars_err!(oos = OutOfSpecKind::MissingMessageHeader))?;
+        let body_length: u64 = message
+            .body_length()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))?;
+
+        match header {
+            arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
+                num_rows += batch.length()?;
+            },
+            arrow_format::ipc::MessageHeaderRef::DictionaryBatch(_) => {},
+            _ => polars_bail!(oos = OutOfSpecKind::UnexpectedMessageType),
+        }
+
+        let skipped = std::io::copy(&mut reader.by_ref().take(body_length), &mut std::io::sink())?;
+        if skipped != body_length {
+            polars_bail!(
+                oos = OutOfSpecKind::InvalidBuffersLength {
+                    buffers_size: body_length,
+                    file_size: skipped,
+                }
+            );
+        }
+    }
+}
+
 /// Encodes the stream's status after each read.
 ///
 /// A stream is an iterator, and an iterator returns `Option<Item>`. The `Item`
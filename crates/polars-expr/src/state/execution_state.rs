@@ -6,6 +6,7 @@ use std::time::Duration;
 use bitflags::bitflags;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
+use polars_io::metrics::IOMetrics;
 use polars_ops::prelude::ChunkJoinOptIds;
 use polars_utils::unique_id::UniqueId;
 
@@ -145,6 +146,27 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
+    /// Same as [`ExecutionState::finish_timer`], but also returns the I/O metrics recorded with
+    /// [`ExecutionState::record_io_metrics`].
+    pub fn finish_timer_with_io_metrics(self) -> PolarsResult<(DataFrame, DataFrame)> {
+        let node_timer = self.node_timer.unwrap();
+        let io_metrics = node_timer.finish_io_metrics()?;
+        Ok((node_timer.finish()?, io_metrics))
+    }
+
+    /// Registers the I/O counters of a scan, to be reported by the profile. The counters are read
+    /// when the profile is finished.
+    pub fn record_io_metrics(
+        &self,
+        node: PlSmallStr,
+        sources: PlSmallStr,
+        io_metrics: Arc<IOMetrics>,
+    ) {
+        if let Some(timer) = &self.node_timer {
+            timer.store_io_metrics(node, sources, io_metrics)
+        }
+    }
+
     // Timings should be a list of (start, end, name) where the start
     // and end are raw durations since the query start as nanoseconds.
     pub fn record_raw_timings(&self, timings: &[(u64, u64, String)]) {
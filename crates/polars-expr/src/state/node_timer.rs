@@ -3,17 +3,21 @@ use std::time::{Duration, Instant};
 
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
+use polars_io::metrics::IOMetrics;
 
 type StartInstant = Instant;
 type EndInstant = Instant;
 
 type Nodes = Vec<String>;
 type Ticks = Vec<(Duration, Duration)>;
+/// `(node, sources, counters)` of every scan.
+type ScanIOMetrics = Vec<(PlSmallStr, PlSmallStr, Arc<IOMetrics>)>;
 
 #[derive(Clone)]
 pub(super) struct NodeTimer {
     query_start: Instant,
     data: Arc<Mutex<(Nodes, Ticks)>>,
+    io_metrics: Arc<Mutex<ScanIOMetrics>>,
 }
 
 impl NodeTimer {
@@ -21,9 +25,52 @@ impl NodeTimer {
         Self {
             query_start,
             data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+            io_metrics: Default::default(),
         }
     }
 
+    pub(super) fn store_io_metrics(
+        &self,
+        node: PlSmallStr,
+        sources: PlSmallStr,
+        io_metrics: Arc<IOMetrics>,
+    ) {
+        self.io_metrics
+            .lock()
+            .unwrap()
+            .push((node, sources, io_metrics))
+    }
+
+    /// One row per scan, with a column for every I/O counter.
+    pub(super) fn finish_io_metrics(&self) -> PolarsResult<DataFrame> {
+        let io_metrics = self.io_metrics.lock().unwrap();
+
+        let mut columns = vec![
+            Column::new(
+                PlSmallStr::from_static("node"),
+                io_metrics.iter().map(|x| x.0.as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                PlSmallStr::from_static("sources"),
+                io_metrics.iter().map(|x| x.1.as_str()).collect::<Vec<_>>(),
+            ),
+        ];
+
+        let counters = io_metrics
+            .iter()
+            .map(|x| x.2.counters())
+            .collect::<Vec<_>>();
+
+        for (i, (name, _)) in IOMetrics::default().counters().into_iter().enumerate() {
+            let values: NoNull<UInt64Chunked> = counters.iter().map(|x| x[i].1).collect();
+            let mut values = values.into_inner();
+            values.rename(PlSmallStr::from_static(name));
+            columns.push(values.into_column());
+        }
+
+        DataFrame::new_with_height(io_metrics.len(), columns)
+    }
+
     pub(super) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
         self.store_duration(
             start.duration_since(self.query_start),
@@ -0,0 +1,128 @@
+use arrow::io::ipc::read::FlightstreamConsumer;
+use arrow_format::flight::data::{Criteria, FlightInfo, Ticket};
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use futures::StreamExt;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use tonic::transport::Channel;
+
+use crate::FlightQuery;
+use crate::ipc::{decapsulate_schema, from_flight_data};
+
+/// A client for Arrow Flight servers, such as a [`FlightServer`](crate::FlightServer).
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_flight::{FlightClient, FlightQuery};
+///
+/// async fn example() -> PolarsResult<DataFrame> {
+///     let mut client = FlightClient::connect("http://127.0.0.1:50051").await?;
+///     client.fetch(&FlightQuery::Name("species".into())).await
+/// }
+/// ```
+pub struct FlightClient {
+    inner: FlightServiceClient<Channel>,
+}
+
+impl FlightClient {
+    /// Connect to the server at `uri`, e.g. `http://localhost:50051`.
+    pub async fn connect(uri: impl Into<String>) -> PolarsResult<Self> {
+        let inner = FlightServiceClient::connect(uri.into())
+            .await
+            .map_err(to_compute_err)?;
+        Ok(Self { inner })
+    }
+
+    /// List the flights of the server.
+    pub async fn list_flights(&mut self) -> PolarsResult<Vec<FlightInfo>> {
+        let mut stream = self
+            .inner
+            .list_flights(Criteria::default())
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+
+        let mut infos = vec![];
+        while let Some(info) = stream.next().await {
+            infos.push(info.map_err(to_compute_err)?);
+        }
+        Ok(infos)
+    }
+
+    /// Get the information on how to retrieve the result of `query`.
+    pub async fn get_flight_info(&mut self, query: &FlightQuery) -> PolarsResult<FlightInfo> {
+        let info = self
+            .inner
+            .get_flight_info(query.to_descriptor())
+            .await
+            .map_err(to_compute_err)?;
+        Ok(info.into_inner())
+    }
+
+    /// Get the schema of the result of `query`.
+    pub async fn get_schema(&mut self, query: &FlightQuery) -> PolarsResult<Schema> {
+        let result = self
+            .inner
+            .get_schema(query.to_descriptor())
+            .await
+            .map_err(to_compute_err)?;
+        let schema = decapsulate_schema(&result.into_inner().schema)?;
+        Ok(Schema::from_arrow_schema(&schema))
+    }
+
+    /// Retrieve the data of a ticket from this server.
+    pub async fn do_get(&mut self, ticket: Ticket) -> PolarsResult<DataFrame> {
+        let stream = self
+            .inner
+            .do_get(ticket)
+            .await
+            .map_err(to_compute_err)?
+            .into_inner()
+            .map(|data| data.map(from_flight_data).map_err(to_compute_err));
+
+        let mut consumer = FlightstreamConsumer::new(stream).await?;
+        let mut dfs = vec![];
+        while let Some(batch) = consumer.next_batch().await? {
+            dfs.push(DataFrame::from(batch));
+        }
+
+        if dfs.is_empty() {
+            let schema = Schema::from_arrow_schema(consumer.schema());
+            Ok(DataFrame::empty_with_schema(&schema))
+        } else {
+            Ok(accumulate_dataframes_vertical_unchecked(dfs))
+        }
+    }
+
+    /// Retrieve the result of `query` from all the endpoints of its flight.
+    ///
+    /// Only endpoints served by this server, i.e. without locations, are supported.
+    pub async fn fetch(&mut self, query: &FlightQuery) -> PolarsResult<DataFrame> {
+        let info = self.get_flight_info(query).await?;
+        let mut dfs = Vec::with_capacity(info.endpoint.len());
+        for endpoint in info.endpoint {
+            polars_ensure!(
+                endpoint.location.is_empty(),
+                ComputeError: "fetching flight endpoints from other locations is not supported"
+            );
+            let ticket = endpoint
+                .ticket
+                .ok_or_else(|| polars_err!(ComputeError: "flight endpoint has no ticket"))?;
+            dfs.push(self.do_get(ticket).await?);
+        }
+
+        match dfs.len() {
+            0 => {
+                let schema = decapsulate_schema(&info.schema)?;
+                Ok(DataFrame::empty_with_schema(&Schema::from_arrow_schema(
+                    &schema,
+                )))
+            },
+            1 => Ok(dfs.pop().unwrap()),
+            _ => Ok(accumulate_dataframes_vertical_unchecked(dfs)),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Conversions between batches and the Arrow IPC messages carried by flight messages.
+use arrow::datatypes::ArrowSchema;
+use arrow::io::ipc::IpcField;
+use arrow::io::ipc::read::deserialize_schema;
+use arrow::io::ipc::write::{
+    DictionaryTracker, EncodedData, WriteOptions, default_ipc_fields, encode_new_dictionaries,
+    encode_record_batch, schema_to_bytes,
+};
+use arrow_format::flight::data::FlightData;
+use polars_core::prelude::*;
+
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Serialize a schema in the encapsulated IPC form used by `FlightInfo` and `SchemaResult`: a
+/// continuation marker, the length of the message and the padded message.
+pub(crate) fn encapsulate_schema(schema: &ArrowSchema) -> Vec<u8> {
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+    let message = schema_to_bytes(schema, &ipc_fields, None);
+    let padding = (8 - message.len() % 8) % 8;
+
+    let mut out = Vec::with_capacity(8 + message.len() + padding);
+    out.extend_from_slice(&CONTINUATION_MARKER);
+    out.extend_from_slice(&((message.len() + padding) as i32).to_le_bytes());
+    out.extend_from_slice(&message);
+    out.resize(out.len() + padding, 0);
+    out
+}
+
+/// Deserialize a schema serialized by [`encapsulate_schema`].
+pub(crate) fn decapsulate_schema(bytes: &[u8]) -> PolarsResult<ArrowSchema> {
+    let bytes = bytes.strip_prefix(&CONTINUATION_MARKER).unwrap_or(bytes);
+    polars_ensure!(bytes.len() >= 4, ComputeError: "invalid flight schema: too short");
+    let (length, message) = bytes.split_at(4);
+    let length = i32::from_le_bytes(length.try_into().unwrap());
+    let message = usize::try_from(length)
+        .ok()
+        .and_then(|length| message.get(..length))
+        .ok_or_else(|| polars_err!(ComputeError: "invalid flight schema: bad message length"))?;
+    Ok(deserialize_schema(message)?.0)
+}
+
+pub(crate) fn to_flight_data(encoded: EncodedData) -> FlightData {
+    FlightData {
+        flight_descriptor: None,
+        data_header: encoded.ipc_message,
+        app_metadata: vec![],
+        data_body: encoded.arrow_data,
+    }
+}
+
+pub(crate) fn from_flight_data(data: FlightData) -> EncodedData {
+    EncodedData {
+        ipc_message: data.data_header,
+        arrow_data: data.data_body,
+    }
+}
+
+/// Encodes the batches of a result as the messages of a `DoGet` stream.
+pub(crate) struct FlightDataEncoder {
+    schema: ArrowSchema,
+    ipc_fields: Vec<IpcField>,
+    dictionary_tracker: DictionaryTracker,
+    options: WriteOptions,
+}
+
+impl FlightDataEncoder {
+    pub(crate) fn new(schema: &Schema) -> Self {
+        let schema = schema.to_arrow(CompatLevel::newest());
+        let ipc_fields = default_ipc_fields(schema.iter_values());
+        Self {
+            schema,
+            ipc_fields,
+            dictionary_tracker: DictionaryTracker {
+                dictionaries: Default::default(),
+                cannot_replace: false,
+            },
+            options: WriteOptions::default(),
+        }
+    }
+
+    /// The first message of the stream.
+    pub(crate) fn schema(&self) -> FlightData {
+        to_flight_data(EncodedData {
+            ipc_message: schema_to_bytes(&self.schema, &self.ipc_fields, None),
+            arrow_data: vec![],
+        })
+    }
+
+    /// The messages of a batch: the dictionaries it introduces followed by the record batch.
+    pub(crate) fn encode(&mut self, df: DataFrame) -> PolarsResult<Vec<FlightData>> {
+        let batch = df.rechunk_to_record_batch(CompatLevel::newest());
+
+        let mut encoded = vec![];
+        for (field, array) in self.ipc_fields.iter().zip(batch.arrays()) {
+            encode_new_dictionaries(
+                field,
+                array.as_ref(),
+                &self.options,
+                &mut self.dictionary_tracker,
+                &mut encoded,
+            )?;
+        }
+        let mut message = EncodedData::default();
+        encode_record_batch(&batch, &self.options, &mut message);
+        encoded.push(message);
+
+        Ok(encoded.into_iter().map(to_flight_data).collect())
+    }
+}
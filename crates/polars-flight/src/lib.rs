@@ -0,0 +1,21 @@
+//! Polars Flight
+//! This crate serves the results of Polars queries with the
+//! [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) protocol.
+//!
+//! A [`FlightServer`] serves the `LazyFrame`s of a [`FlightRegistry`]. Clients select a
+//! `LazyFrame` with a `PATH` descriptor holding its name or, with the `sql` feature and if the
+//! server enables it, run a query against the registered `LazyFrame`s with a `CMD` descriptor
+//! holding the SQL text. Results are computed by the streaming engine and sent batch by batch.
+#![deny(missing_docs)]
+mod client;
+mod ipc;
+mod query;
+mod registry;
+mod server;
+mod tickets;
+
+pub use arrow_format::flight::data::{FlightDescriptor, FlightInfo, Ticket};
+pub use client::FlightClient;
+pub use query::FlightQuery;
+pub use registry::FlightRegistry;
+pub use server::FlightServer;
@@ -0,0 +1,51 @@
+use arrow_format::flight::data::FlightDescriptor;
+use arrow_format::flight::data::flight_descriptor::DescriptorType;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+/// A query that can be served by a [`FlightServer`](crate::FlightServer).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FlightQuery {
+    /// The `LazyFrame` registered under this name, described by a `PATH` descriptor with a single
+    /// element.
+    Name(PlSmallStr),
+    /// A SQL query over the registered `LazyFrame`s, described by a `CMD` descriptor. Only served
+    /// by servers that enable SQL commands, see `FlightServer::with_sql_commands`.
+    Sql(String),
+}
+
+impl FlightQuery {
+    /// Parse the query from a flight descriptor.
+    pub fn from_descriptor(descriptor: &FlightDescriptor) -> PolarsResult<Self> {
+        match DescriptorType::from_i32(descriptor.r#type) {
+            Some(DescriptorType::Path) => match descriptor.path.as_slice() {
+                [name] => Ok(Self::Name(name.into())),
+                path => polars_bail!(
+                    ComputeError: "expected a flight path with a single element, got {:?}", path
+                ),
+            },
+            Some(DescriptorType::Cmd) => String::from_utf8(descriptor.cmd.clone())
+                .map(Self::Sql)
+                .map_err(|_| polars_err!(ComputeError: "flight command is not valid utf-8")),
+            _ => polars_bail!(
+                ComputeError: "unsupported flight descriptor type: {}", descriptor.r#type
+            ),
+        }
+    }
+
+    /// The flight descriptor that describes this query.
+    pub fn to_descriptor(&self) -> FlightDescriptor {
+        match self {
+            Self::Name(name) => FlightDescriptor {
+                r#type: DescriptorType::Path as i32,
+                cmd: vec![],
+                path: vec![name.to_string()],
+            },
+            Self::Sql(query) => FlightDescriptor {
+                r#type: DescriptorType::Cmd as i32,
+                cmd: query.as_bytes().to_vec(),
+                path: vec![],
+            },
+        }
+    }
+}
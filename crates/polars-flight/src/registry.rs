@@ -0,0 +1,72 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+use crate::FlightQuery;
+
+/// The named `LazyFrame`s served by a [`FlightServer`](crate::FlightServer).
+#[derive(Clone, Default)]
+pub struct FlightRegistry {
+    frames: PlIndexMap<PlSmallStr, LazyFrame>,
+}
+
+impl FlightRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a `LazyFrame` under `name`, replacing the `LazyFrame` previously registered
+    /// under that name.
+    pub fn register(&mut self, name: impl Into<PlSmallStr>, lf: LazyFrame) {
+        self.frames.insert(name.into(), lf);
+    }
+
+    /// Register the result of a SQL query over the registered `LazyFrame`s under `name`. Table
+    /// functions that read files are not available in the query.
+    #[cfg(feature = "sql")]
+    pub fn register_sql(&mut self, name: impl Into<PlSmallStr>, query: &str) -> PolarsResult<()> {
+        let lf = self.sql(query)?;
+        self.register(name, lf);
+        Ok(())
+    }
+
+    /// Remove the `LazyFrame` registered under `name`.
+    pub fn unregister(&mut self, name: &str) -> Option<LazyFrame> {
+        self.frames.shift_remove(name)
+    }
+
+    /// Get the `LazyFrame` registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&LazyFrame> {
+        self.frames.get(name)
+    }
+
+    /// The names of the registered `LazyFrame`s in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &PlSmallStr> {
+        self.frames.keys()
+    }
+
+    /// The `LazyFrame` that computes the result of `query`. SQL queries can only read the
+    /// registered `LazyFrame`s.
+    pub fn resolve(&self, query: &FlightQuery) -> PolarsResult<LazyFrame> {
+        match query {
+            FlightQuery::Name(name) => self.get(name).cloned().ok_or_else(
+                || polars_err!(ComputeError: "no flight named '{}' is registered", name),
+            ),
+            #[cfg(feature = "sql")]
+            FlightQuery::Sql(query) => self.sql(query),
+            #[cfg(not(feature = "sql"))]
+            FlightQuery::Sql(_) => {
+                polars_bail!(InvalidOperation: "SQL flight commands require the 'sql' feature")
+            },
+        }
+    }
+
+    #[cfg(feature = "sql")]
+    fn sql(&self, query: &str) -> PolarsResult<LazyFrame> {
+        let mut ctx = polars_sql::SQLContext::new().with_table_functions(false);
+        for (name, lf) in &self.frames {
+            ctx.register(name, lf.clone());
+        }
+        ctx.execute(query)
+    }
+}
@@ -0,0 +1,282 @@
+// The helpers return the `Status` that is sent to the client, large as it is.
+#![allow(clippy::result_large_err)]
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use arrow_format::flight::data::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, Result as ActionResult, SchemaResult, Ticket,
+};
+use arrow_format::flight::service::flight_service_server::{FlightService, FlightServiceServer};
+use futures::{Stream, stream};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use tokio::net::TcpListener;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::ipc::{FlightDataEncoder, encapsulate_schema};
+use crate::tickets::TicketStore;
+use crate::{FlightQuery, FlightRegistry};
+
+type BoxStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+/// Number of encoded messages that are buffered ahead of a `DoGet` client.
+const SEND_BUFFER_SIZE: usize = 4;
+
+/// An Arrow Flight server that serves the `LazyFrame`s of a [`FlightRegistry`].
+///
+/// The server implements `ListFlights`, `GetFlightInfo`, `GetSchema` and `DoGet`. Every flight
+/// has a single endpoint whose ticket is retrieved from this server. Tickets are opaque ids that
+/// are only valid for the server that issued them. Results are computed with the streaming engine
+/// while they are sent, so a `DoGet` only holds a few batches in memory.
+///
+/// SQL commands are rejected unless they are enabled with
+/// [`FlightServer::with_sql_commands`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_flight::{FlightRegistry, FlightServer};
+/// use polars_lazy::prelude::*;
+///
+/// async fn example(df: DataFrame) -> PolarsResult<()> {
+///     let mut registry = FlightRegistry::new();
+///     registry.register("species", df.lazy());
+///     FlightServer::new(registry)
+///         .serve("127.0.0.1:50051".parse().unwrap())
+///         .await
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FlightServer {
+    registry: Arc<FlightRegistry>,
+    tickets: Arc<TicketStore>,
+    sql_commands: bool,
+}
+
+impl FlightServer {
+    /// Create a server for the `LazyFrame`s of `registry`.
+    pub fn new(registry: FlightRegistry) -> Self {
+        Self {
+            tickets: Arc::new(TicketStore::new(registry.names())),
+            registry: Arc::new(registry),
+            sql_commands: false,
+        }
+    }
+
+    /// Serve SQL queries sent as `CMD` descriptors. Queries can only read the registered
+    /// `LazyFrame`s, table functions that read files are disabled. Disabled by default.
+    #[cfg(feature = "sql")]
+    pub fn with_sql_commands(mut self, enabled: bool) -> Self {
+        self.sql_commands = enabled;
+        self
+    }
+
+    /// The gRPC service of this server, to be added to a `tonic` server.
+    pub fn into_service(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    /// Serve on `addr` until the returned future is dropped or the server fails.
+    pub async fn serve(self, addr: SocketAddr) -> PolarsResult<()> {
+        Server::builder()
+            .add_service(self.into_service())
+            .serve(addr)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    /// Serve the connections accepted by `listener` until the returned future is dropped or the
+    /// server fails.
+    pub async fn serve_with_listener(self, listener: TcpListener) -> PolarsResult<()> {
+        let incoming = stream::unfold(listener, |listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
+        Server::builder()
+            .add_service(self.into_service())
+            .serve_with_incoming(incoming)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    fn resolve(&self, query: &FlightQuery) -> Result<LazyFrame, Status> {
+        match query {
+            FlightQuery::Name(name) if self.registry.get(name).is_none() => {
+                return Err(Status::not_found(format!(
+                    "no flight named '{name}' is registered"
+                )));
+            },
+            FlightQuery::Sql(_) if !self.sql_commands => {
+                return Err(Status::permission_denied(
+                    "SQL commands are not enabled on this flight server",
+                ));
+            },
+            _ => {},
+        }
+        self.registry.resolve(query).map_err(to_status)
+    }
+
+    fn schema(&self, query: &FlightQuery) -> Result<Vec<u8>, Status> {
+        let schema = self.resolve(query)?.collect_schema().map_err(to_status)?;
+        Ok(encapsulate_schema(&schema.to_arrow(CompatLevel::newest())))
+    }
+
+    fn flight_info(&self, query: &FlightQuery) -> Result<FlightInfo, Status> {
+        let schema = self.schema(query)?;
+        Ok(FlightInfo {
+            schema,
+            flight_descriptor: Some(query.to_descriptor()),
+            // An endpoint without locations is retrieved from this server.
+            endpoint: vec![FlightEndpoint {
+                ticket: self.tickets.issue(query),
+                location: vec![],
+            }],
+            // Unknown until the query has run.
+            total_records: -1,
+            total_bytes: -1,
+        })
+    }
+}
+
+fn to_status(err: PolarsError) -> Status {
+    match err {
+        PolarsError::ColumnNotFound(_)
+        | PolarsError::InvalidOperation(_)
+        | PolarsError::SchemaFieldNotFound(_)
+        | PolarsError::SQLInterface(_)
+        | PolarsError::SQLSyntax(_) => Status::invalid_argument(err.to_string()),
+        _ => Status::internal(err.to_string()),
+    }
+}
+
+fn parse_descriptor(descriptor: &FlightDescriptor) -> Result<FlightQuery, Status> {
+    FlightQuery::from_descriptor(descriptor)
+        .map_err(|err| Status::invalid_argument(err.to_string()))
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightServer {
+    type HandshakeStream = BoxStream<HandshakeResponse>;
+    type ListFlightsStream = BoxStream<FlightInfo>;
+    type DoGetStream = BoxStream<FlightData>;
+    type DoPutStream = BoxStream<PutResult>;
+    type DoExchangeStream = BoxStream<FlightData>;
+    type DoActionStream = BoxStream<ActionResult>;
+    type ListActionsStream = BoxStream<ActionType>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        let infos = self
+            .registry
+            .names()
+            .map(|name| self.flight_info(&FlightQuery::Name(name.clone())))
+            .collect::<Vec<_>>();
+        Ok(Response::new(Box::pin(stream::iter(infos))))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let query = parse_descriptor(request.get_ref())?;
+        self.flight_info(&query).map(Response::new)
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let query = parse_descriptor(request.get_ref())?;
+        let schema = self.schema(&query)?;
+        Ok(Response::new(SchemaResult { schema }))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let query = self
+            .tickets
+            .redeem(request.get_ref())
+            .ok_or_else(|| Status::not_found("unknown or expired flight ticket"))?;
+        let lf = self.resolve(&query)?;
+
+        let batches = tokio::task::spawn_blocking(move || lf.collect_batches())
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .map_err(to_status)?;
+
+        // Batches are pulled from the streaming engine on a blocking thread and sent through a
+        // bounded channel, so the query is paused while the client is not reading.
+        let (tx, rx) = tokio::sync::mpsc::channel(SEND_BUFFER_SIZE);
+        tokio::task::spawn_blocking(move || {
+            let mut encoder = FlightDataEncoder::new(batches.schema());
+            if tx.blocking_send(Ok(encoder.schema())).is_err() {
+                return;
+            }
+            for df in batches {
+                let messages = df.and_then(|df| encoder.encode(df));
+                let messages = match messages {
+                    Ok(messages) => messages,
+                    Err(err) => {
+                        _ = tx.blocking_send(Err(to_status(err)));
+                        return;
+                    },
+                };
+                for message in messages {
+                    // The client went away, dropping the batches stops the query.
+                    if tx.blocking_send(Ok(message)).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        let stream = stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|message| (message, rx))
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("do_put is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("do_action is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+}
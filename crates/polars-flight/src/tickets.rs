@@ -0,0 +1,79 @@
+use std::sync::Mutex;
+
+use arrow_format::flight::data::Ticket;
+use polars_core::prelude::{PlHashMap, PlIndexMap};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::FlightQuery;
+
+/// Maximum number of SQL tickets that are kept. The oldest ticket is forgotten when a new one is
+/// issued beyond this limit.
+const MAX_SQL_TICKETS: usize = 1024;
+
+/// The tickets issued by a server.
+///
+/// Tickets are random ids that are resolved on the server, so a client can only retrieve the
+/// flights that the server has described to it. Registered `LazyFrame`s get a fixed ticket when
+/// the server is created, SQL queries get a new ticket every time they are described.
+pub(crate) struct TicketStore {
+    names: PlHashMap<String, PlSmallStr>,
+    name_tickets: PlHashMap<PlSmallStr, String>,
+    sql: Mutex<PlIndexMap<String, String>>,
+}
+
+fn new_ticket_id() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+impl TicketStore {
+    pub(crate) fn new<'a>(names: impl IntoIterator<Item = &'a PlSmallStr>) -> Self {
+        let name_tickets = names
+            .into_iter()
+            .map(|name| (name.clone(), new_ticket_id()))
+            .collect::<PlHashMap<_, _>>();
+        let names = name_tickets
+            .iter()
+            .map(|(name, id)| (id.clone(), name.clone()))
+            .collect();
+
+        Self {
+            names,
+            name_tickets,
+            sql: Default::default(),
+        }
+    }
+
+    /// The ticket with which the result of `query` is retrieved. Returns `None` for names that
+    /// are not registered.
+    pub(crate) fn issue(&self, query: &FlightQuery) -> Option<Ticket> {
+        let id = match query {
+            FlightQuery::Name(name) => self.name_tickets.get(name)?.clone(),
+            FlightQuery::Sql(sql) => {
+                let id = new_ticket_id();
+                let mut tickets = self.sql.lock().unwrap();
+                if tickets.len() >= MAX_SQL_TICKETS {
+                    tickets.shift_remove_index(0);
+                }
+                tickets.insert(id.clone(), sql.clone());
+                id
+            },
+        };
+
+        Some(Ticket {
+            ticket: id.into_bytes(),
+        })
+    }
+
+    /// The query of a ticket issued by this store.
+    pub(crate) fn redeem(&self, ticket: &Ticket) -> Option<FlightQuery> {
+        let id = std::str::from_utf8(&ticket.ticket).ok()?;
+        if let Some(name) = self.names.get(id) {
+            return Some(FlightQuery::Name(name.clone()));
+        }
+        self.sql
+            .lock()
+            .unwrap()
+            .get(id)
+            .map(|sql| FlightQuery::Sql(sql.clone()))
+    }
+}
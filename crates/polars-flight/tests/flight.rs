@@ -0,0 +1,171 @@
+use polars_core::prelude::*;
+use polars_flight::*;
+use polars_lazy::prelude::*;
+use tokio::net::TcpListener;
+
+fn species() -> DataFrame {
+    let n = 250_000;
+    df!(
+        "id" => (0..n).collect::<Vec<i64>>(),
+        "name" => (0..n).map(|i| (i % 7 != 0).then(|| format!("name-{i}"))).collect::<Vec<_>>(),
+        "weight" => (0..n).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+    )
+    .unwrap()
+}
+
+/// Serve `registry` on a loopback port and return a client connected to it.
+async fn serve(registry: FlightRegistry) -> FlightClient {
+    serve_with(FlightServer::new(registry)).await
+}
+
+async fn serve_with(server: FlightServer) -> FlightClient {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(server.serve_with_listener(listener));
+    FlightClient::connect(format!("http://{addr}"))
+        .await
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_get() -> PolarsResult<()> {
+    let df = species();
+    let mut registry = FlightRegistry::new();
+    registry.register("species", df.clone().lazy());
+    registry.register(
+        "heavy",
+        df.clone()
+            .lazy()
+            .filter(col("weight").gt(lit(1000.0)))
+            .select([col("name"), (col("weight") * lit(2.0)).alias("double")]),
+    );
+    let mut client = serve(registry).await;
+
+    let infos = client.list_flights().await?;
+    let descriptors = infos
+        .iter()
+        .map(|info| FlightQuery::from_descriptor(info.flight_descriptor.as_ref().unwrap()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(
+        descriptors,
+        [
+            FlightQuery::Name("species".into()),
+            FlightQuery::Name("heavy".into())
+        ]
+    );
+
+    let query = FlightQuery::Name("heavy".into());
+    assert_eq!(
+        client.get_schema(&query).await?,
+        Schema::from_iter([
+            Field::new("name".into(), DataType::String),
+            Field::new("double".into(), DataType::Float64),
+        ])
+    );
+
+    let info = client.get_flight_info(&query).await?;
+    assert_eq!(info.endpoint.len(), 1);
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let expected = df
+        .clone()
+        .lazy()
+        .filter(col("weight").gt(lit(1000.0)))
+        .select([col("name"), (col("weight") * lit(2.0)).alias("double")])
+        .collect()?;
+    assert!(client.do_get(ticket).await?.equals_missing(&expected));
+
+    let out = client.fetch(&FlightQuery::Name("species".into())).await?;
+    assert!(out.equals_missing(&df));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_errors() -> PolarsResult<()> {
+    let mut registry = FlightRegistry::new();
+    registry.register(
+        "invalid",
+        df!("a" => ["1", "x"])?
+            .lazy()
+            .select([col("a").strict_cast(DataType::Int64)]),
+    );
+    let mut client = serve(registry).await;
+
+    let err = client
+        .get_schema(&FlightQuery::Name("missing".into()))
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("no flight named 'missing'"),
+        "{err}"
+    );
+
+    // Tickets are issued by the server, a ticket naming a flight is not accepted.
+    let err = client
+        .do_get(Ticket {
+            ticket: b"name:invalid".to_vec(),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown or expired"), "{err}");
+
+    // SQL commands are only served if the server enables them.
+    let err = client
+        .get_schema(&FlightQuery::Sql("SELECT * FROM invalid".into()))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not enabled"), "{err}");
+
+    // The error is raised while the result is streamed.
+    let err = client
+        .fetch(&FlightQuery::Name("invalid".into()))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("conversion"), "{err}");
+
+    Ok(())
+}
+
+#[cfg(feature = "sql")]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_sql() -> PolarsResult<()> {
+    let df = species();
+    let mut registry = FlightRegistry::new();
+    registry.register("species", df.clone().lazy());
+    registry.register_sql("light", "SELECT id, name FROM species WHERE weight < 10")?;
+    let mut client = serve_with(FlightServer::new(registry).with_sql_commands(true)).await;
+
+    let expected = df
+        .clone()
+        .lazy()
+        .filter(col("weight").lt(lit(10.0)))
+        .select([col("id"), col("name")])
+        .collect()?;
+    let out = client.fetch(&FlightQuery::Name("light".into())).await?;
+    assert!(out.equals_missing(&expected));
+
+    let query = FlightQuery::Sql("SELECT name, id FROM species WHERE id >= 249990".into());
+    let expected = df
+        .lazy()
+        .filter(col("id").gt_eq(lit(249990)))
+        .select([col("name"), col("id")])
+        .collect()?;
+    assert_eq!(client.get_schema(&query).await?, **expected.schema());
+    assert!(client.fetch(&query).await?.equals_missing(&expected));
+
+    // The ticket of a SQL query is an id, not the query text.
+    let info = client.get_flight_info(&query).await?;
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    assert!(!String::from_utf8_lossy(&ticket.ticket).contains("SELECT"));
+    assert!(client.do_get(ticket).await?.equals_missing(&expected));
+
+    // Queries can only read the registered frames.
+    let query = FlightQuery::Sql("SELECT * FROM read_csv('/etc/passwd')".into());
+    let err = client.fetch(&query).await.unwrap_err();
+    assert!(
+        err.to_string().contains("table functions are disabled"),
+        "{err}"
+    );
+
+    Ok(())
+}
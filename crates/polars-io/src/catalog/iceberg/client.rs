@@ -0,0 +1,283 @@
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use polars_core::prelude::PlHashMap;
+use polars_error::{PolarsResult, polars_bail, to_compute_err};
+use reqwest::RequestBuilder;
+
+use super::models::{CatalogConfig, LoadTableResult, NamespaceInfo, TableIdentifier};
+use crate::catalog::unity::utils::do_request;
+use crate::utils::decode_json_response;
+
+/// Separator for the levels of a multi-level namespace in request paths.
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
+
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Iceberg REST catalog client.
+pub struct IcebergCatalogClient {
+    /// `{uri}/v1[/{prefix}]`
+    base_url: String,
+    config: CatalogConfig,
+    http_client: reqwest::Client,
+}
+
+impl IcebergCatalogClient {
+    /// Configuration returned by the catalog when the client was built.
+    pub fn config(&self) -> &CatalogConfig {
+        &self.config
+    }
+
+    pub async fn list_namespaces(&self, parent: Option<&[&str]>) -> PolarsResult<Vec<Vec<String>>> {
+        let request = self
+            .http_client
+            .get(format!("{}/namespaces", &self.base_url));
+
+        let request = if let Some(parent) = parent {
+            request.query(&[("parent", parent.join(NAMESPACE_SEPARATOR))])
+        } else {
+            request
+        };
+
+        read_all_pages(request).await
+    }
+
+    pub async fn get_namespace(&self, namespace: &[&str]) -> PolarsResult<NamespaceInfo> {
+        let bytes = do_request(self.http_client.get(self.namespace_url(namespace)?)).await?;
+
+        decode_json_response(&bytes)
+    }
+
+    pub async fn create_namespace(
+        &self,
+        namespace: &[&str],
+        properties: &PlHashMap<String, String>,
+    ) -> PolarsResult<NamespaceInfo> {
+        let bytes = do_request(
+            self.http_client
+                .post(format!("{}/namespaces", &self.base_url))
+                .json(&Body {
+                    namespace,
+                    properties,
+                }),
+        )
+        .await?;
+
+        return decode_json_response(&bytes);
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            namespace: &'a [&'a str],
+            properties: &'a PlHashMap<String, String>,
+        }
+    }
+
+    pub async fn delete_namespace(&self, namespace: &[&str]) -> PolarsResult<()> {
+        do_request(self.http_client.delete(self.namespace_url(namespace)?)).await?;
+
+        Ok(())
+    }
+
+    pub async fn list_tables(&self, namespace: &[&str]) -> PolarsResult<Vec<TableIdentifier>> {
+        read_all_pages(
+            self.http_client
+                .get(format!("{}/tables", self.namespace_url(namespace)?)),
+        )
+        .await
+    }
+
+    /// Load the metadata of a table. The catalog is asked to vend credentials for accessing the
+    /// table storage, these are returned as part of the result if the catalog supports it.
+    pub async fn load_table(
+        &self,
+        namespace: &[&str],
+        table_name: &str,
+    ) -> PolarsResult<LoadTableResult> {
+        let bytes = do_request(
+            self.http_client
+                .get(format!(
+                    "{}/tables/{}",
+                    self.namespace_url(namespace)?,
+                    utf8_percent_encode(table_name, PATH_SEGMENT)
+                ))
+                .header("X-Iceberg-Access-Delegation", "vended-credentials"),
+        )
+        .await?;
+
+        decode_json_response(&bytes)
+    }
+
+    fn namespace_url(&self, namespace: &[&str]) -> PolarsResult<String> {
+        if namespace.is_empty() {
+            polars_bail!(ComputeError: "namespace must have at least one level")
+        }
+
+        Ok(format!(
+            "{}/namespaces/{}",
+            &self.base_url,
+            utf8_percent_encode(&namespace.join(NAMESPACE_SEPARATOR), PATH_SEGMENT)
+        ))
+    }
+}
+
+/// Reads a paginated listing, these look like:
+/// ```text
+/// {
+///     "namespaces" | "identifiers": [$T, $T, ...],
+///     "next-page-token": "token" or null,
+/// }
+/// ```
+async fn read_all_pages<T>(request: RequestBuilder) -> PolarsResult<Vec<T>>
+where
+    T: for<'de> serde::de::Deserialize<'de>,
+{
+    let mut out = vec![];
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let request = request.try_clone().unwrap();
+
+        let request = if let Some(page_token) = page_token.take() {
+            request.query(&[("pageToken", page_token)])
+        } else {
+            request
+        };
+
+        let Page {
+            items,
+            next_page_token,
+        } = decode_json_response(&do_request(request).await?)?;
+
+        out.extend(items);
+
+        match next_page_token {
+            Some(v) if !v.is_empty() => page_token = Some(v),
+            _ => return Ok(out),
+        }
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct Page<T> {
+        #[serde(default = "Vec::new", alias = "namespaces", alias = "identifiers")]
+        items: Vec<T>,
+        #[serde(default)]
+        next_page_token: Option<String>,
+    }
+}
+
+pub struct IcebergCatalogClientBuilder {
+    uri: Option<String>,
+    warehouse: Option<String>,
+    prefix: Option<String>,
+    bearer_token: Option<String>,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for IcebergCatalogClientBuilder {
+    fn default() -> Self {
+        Self {
+            uri: None,
+            warehouse: None,
+            prefix: None,
+            bearer_token: None,
+        }
+    }
+}
+
+impl IcebergCatalogClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Base URI of the catalog, without the `/v1` suffix.
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    pub fn with_warehouse(mut self, warehouse: impl Into<String>) -> Self {
+        self.warehouse = Some(warehouse.into());
+        self
+    }
+
+    /// Path prefix for all catalog requests. Prefixes configured by the catalog take precedence.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn with_bearer_token(mut self, bearer_token: impl Into<String>) -> Self {
+        self.bearer_token = Some(bearer_token.into());
+        self
+    }
+
+    /// Build the client. This fetches the catalog configuration, which may override the URI and
+    /// prefix used for subsequent requests.
+    pub async fn build(self) -> PolarsResult<IcebergCatalogClient> {
+        let Some(uri) = self.uri else {
+            polars_bail!(ComputeError: "expected Some(_) for uri")
+        };
+
+        let http_client = {
+            let builder = reqwest::ClientBuilder::new().user_agent("polars");
+
+            let builder = if let Some(bearer_token) = self.bearer_token {
+                use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT};
+
+                let mut headers = HeaderMap::new();
+
+                let mut auth_value =
+                    HeaderValue::from_str(format!("Bearer {bearer_token}").as_str())
+                        .map_err(to_compute_err)?;
+                auth_value.set_sensitive(true);
+
+                headers.insert(AUTHORIZATION, auth_value);
+                headers.insert(USER_AGENT, "polars".try_into().unwrap());
+
+                builder.default_headers(headers)
+            } else {
+                builder
+            };
+
+            builder.build().map_err(to_compute_err)?
+        };
+
+        let uri = uri.trim_end_matches('/');
+
+        let request = http_client.get(format!("{uri}/v1/config"));
+        let request = if let Some(warehouse) = &self.warehouse {
+            request.query(&[("warehouse", warehouse)])
+        } else {
+            request
+        };
+
+        let config: CatalogConfig = decode_json_response(&do_request(request).await?)?;
+
+        let uri = config
+            .overrides
+            .get("uri")
+            .map_or(uri, |v| v.as_str())
+            .trim_end_matches('/');
+
+        let prefix = config
+            .overrides
+            .get("prefix")
+            .or(self.prefix.as_ref())
+            .or_else(|| config.defaults.get("prefix"))
+            .map(|v| v.trim_matches('/'));
+
+        let base_url = match prefix {
+            Some(prefix) if !prefix.is_empty() => format!("{uri}/v1/{prefix}"),
+            _ => format!("{uri}/v1"),
+        };
+
+        Ok(IcebergCatalogClient {
+            base_url,
+            config,
+            http_client,
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use polars_error::PolarsResult;
+
+use super::models::LoadTableResult;
+use crate::catalog::gcp_token_credential;
+use crate::cloud::CloudOptions;
+use crate::cloud::credential_provider::PlCredentialProvider;
+
+impl LoadTableResult {
+    /// Path to scan the table from, this is the current metadata file if the catalog returned
+    /// one, otherwise the table location.
+    pub fn scan_location(&self) -> &str {
+        self.metadata_location
+            .as_deref()
+            .unwrap_or(&self.metadata.location)
+    }
+
+    /// Apply the table configuration and vended credentials to `cloud_options`.
+    ///
+    /// Of the vended credentials, the one with the longest prefix matching the scan location is
+    /// used. Configuration keys that are not recognized are ignored.
+    pub fn cloud_options(&self, cloud_options: Option<CloudOptions>) -> PolarsResult<CloudOptions> {
+        let location = self.scan_location();
+        let mut cloud_options = cloud_options.unwrap_or_default();
+
+        let storage_credential = self
+            .storage_credentials
+            .iter()
+            .filter(|v| location.starts_with(&v.prefix))
+            .max_by_key(|v| v.prefix.len());
+
+        let properties = self
+            .config
+            .iter()
+            .chain(storage_credential.into_iter().flat_map(|v| v.config.iter()));
+
+        let mut untyped_config: Vec<(&str, String)> = vec![];
+        let mut gcs_token = None;
+        let mut gcs_token_expires_at_ms = None;
+
+        for (k, v) in properties {
+            let k = k.as_str();
+
+            let key = match k {
+                "s3.access-key-id" => "aws_access_key_id",
+                "s3.secret-access-key" => "aws_secret_access_key",
+                "s3.session-token" => "aws_session_token",
+                "s3.endpoint" => "aws_endpoint",
+                "s3.region" | "client.region" => "aws_region",
+                "s3.path-style-access" => {
+                    let virtual_hosted_style = !v.eq_ignore_ascii_case("true");
+                    untyped_config.push((
+                        "aws_virtual_hosted_style_request",
+                        virtual_hosted_style.to_string(),
+                    ));
+                    continue;
+                },
+                "gcs.oauth2.token" => {
+                    gcs_token = Some(v.clone());
+                    continue;
+                },
+                "gcs.oauth2.token-expires-at" => {
+                    gcs_token_expires_at_ms = v.parse::<u64>().ok();
+                    continue;
+                },
+                _ if k.starts_with("adls.sas-token.") => "azure_storage_sas_key",
+                _ => continue,
+            };
+
+            untyped_config.push((key, v.clone()));
+        }
+
+        if !untyped_config.is_empty() {
+            cloud_options = cloud_options.with_untyped_config_update(location, untyped_config)?;
+        }
+
+        if let Some(token) = gcs_token {
+            // Tokens without an expiry are treated as valid for the lifetime of the process.
+            let expiry = gcs_token_expires_at_ms.map_or(u64::MAX, |v| v / 1000);
+            cloud_options = cloud_options
+                .with_credential_provider(Some(gcs_credential_provider(token, expiry)?));
+        }
+
+        Ok(cloud_options)
+    }
+}
+
+fn gcs_credential_provider(token: String, expiry: u64) -> PolarsResult<PlCredentialProvider> {
+    // Fail when the provider is created rather than on first use.
+    gcp_token_credential(token.clone())?;
+
+    Ok(PlCredentialProvider::from_func(move || {
+        let credential = gcp_token_credential(token.clone());
+        Box::pin(async move { Ok((credential?, expiry)) })
+    }))
+}
@@ -0,0 +1,4 @@
+//! Client for catalogs implementing the Iceberg REST catalog specification.
+pub mod client;
+mod credentials;
+pub mod models;
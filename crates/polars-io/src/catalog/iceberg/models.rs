@@ -0,0 +1,71 @@
+use polars_core::prelude::PlHashMap;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct CatalogConfig {
+    /// Properties used as defaults, the client configuration takes precedence over these.
+    #[serde(default)]
+    pub defaults: PlHashMap<String, String>,
+
+    /// Properties that take precedence over the client configuration.
+    #[serde(default)]
+    pub overrides: PlHashMap<String, String>,
+
+    #[serde(default)]
+    pub endpoints: Option<Vec<String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct NamespaceInfo {
+    pub namespace: Vec<String>,
+
+    #[serde(default)]
+    pub properties: PlHashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct TableIdentifier {
+    pub namespace: Vec<String>,
+    pub name: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadTableResult {
+    /// May be `None` for tables that are not yet committed.
+    #[serde(default)]
+    pub metadata_location: Option<String>,
+
+    pub metadata: TableMetadata,
+
+    /// Table-specific configuration, e.g. credentials for accessing the table storage.
+    #[serde(default)]
+    pub config: PlHashMap<String, String>,
+
+    /// Credentials vended by the catalog, applicable to paths starting with their prefix.
+    #[serde(default)]
+    pub storage_credentials: Vec<StorageCredential>,
+}
+
+/// Subset of the table metadata that is needed to locate the table.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: i32,
+
+    #[serde(default)]
+    pub table_uuid: Option<String>,
+
+    pub location: String,
+
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+
+    #[serde(default)]
+    pub properties: PlHashMap<String, String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct StorageCredential {
+    pub prefix: String,
+    pub config: PlHashMap<String, String>,
+}
@@ -1 +1,25 @@
+pub mod iceberg;
 pub mod unity;
+
+use polars_error::PolarsResult;
+
+use crate::cloud::credential_provider::ObjectStoreCredential;
+
+/// A GCP credential for an OAuth token vended by a catalog.
+pub(crate) fn gcp_token_credential(token: String) -> PolarsResult<ObjectStoreCredential> {
+    #[cfg(feature = "gcp")]
+    {
+        use std::sync::Arc;
+
+        use crate::cloud::credential_provider::GcpCredential;
+
+        Ok(ObjectStoreCredential::Gcp(Arc::new(GcpCredential {
+            bearer: token,
+        })))
+    }
+    #[cfg(not(feature = "gcp"))]
+    {
+        let _ = token;
+        polars_error::polars_bail!(ComputeError: "'gcp' feature is not enabled");
+    }
+}
@@ -4,12 +4,13 @@ use polars_error::{PolarsResult, polars_bail, to_compute_err};
 
 use super::models::{CatalogInfo, NamespaceInfo, TableCredentials, TableInfo};
 use super::schema::schema_to_column_info_list;
-use super::utils::{PageWalker, do_request};
+use super::utils::{PageWalker, do_request, do_request_opt};
 use crate::catalog::unity::models::{ColumnInfo, DataSourceFormat, TableType};
 use crate::impl_page_walk;
 use crate::utils::decode_json_response;
 
 /// Unity catalog client.
+#[derive(Clone)]
 pub struct CatalogClient {
     workspace_url: String,
     http_client: reqwest::Client,
@@ -61,6 +62,33 @@ impl CatalogClient {
         namespace: &str,
         table_name: &str,
     ) -> PolarsResult<TableInfo> {
+        let bytes =
+            do_request(self.table_info_request(catalog_name, namespace, table_name)).await?;
+
+        let out: TableInfo = decode_json_response(&bytes)?;
+
+        Ok(out)
+    }
+
+    /// Returns `None` if the table does not exist.
+    pub async fn try_get_table_info(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> PolarsResult<Option<TableInfo>> {
+        do_request_opt(self.table_info_request(catalog_name, namespace, table_name))
+            .await?
+            .map(|bytes| decode_json_response(&bytes))
+            .transpose()
+    }
+
+    fn table_info_request(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> reqwest::RequestBuilder {
         let full_table_name = format!(
             "{}.{}.{}",
             catalog_name.replace('/', "%2F"),
@@ -68,19 +96,12 @@ impl CatalogClient {
             table_name.replace('/', "%2F")
         );
 
-        let bytes = do_request(
-            self.http_client
-                .get(format!(
-                    "{}{}{}",
-                    &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_table_name
-                ))
-                .query(&[("full_name", full_table_name)]),
-        )
-        .await?;
-
-        let out: TableInfo = decode_json_response(&bytes)?;
-
-        Ok(out)
+        self.http_client
+            .get(format!(
+                "{}{}{}",
+                &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_table_name
+            ))
+            .query(&[("full_name", full_table_name)])
     }
 
     pub async fn get_table_credentials(
@@ -106,6 +127,39 @@ impl CatalogClient {
         Ok(out)
     }
 
+    /// Temporary credentials for a storage path, e.g. the location of a table that is yet to be
+    /// created.
+    pub async fn get_path_credentials(
+        &self,
+        url: &str,
+        write: bool,
+    ) -> PolarsResult<TableCredentials> {
+        let bytes = do_request(
+            self.http_client
+                .post(format!(
+                    "{}{}",
+                    &self.workspace_url, "/api/2.1/unity-catalog/temporary-path-credentials"
+                ))
+                .json(&Body {
+                    url,
+                    operation: if write {
+                        "PATH_READ_WRITE"
+                    } else {
+                        "PATH_READ"
+                    },
+                }),
+        )
+        .await?;
+
+        return decode_json_response(&bytes);
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            url: &'a str,
+            operation: &'a str,
+        }
+    }
+
     pub async fn create_catalog(
         &self,
         catalog_name: &str,
@@ -225,8 +279,36 @@ impl CatalogClient {
         properties: &mut (dyn Iterator<Item = (&str, &str)> + Send + Sync),
     ) -> PolarsResult<TableInfo> {
         let columns = schema.map(schema_to_column_info_list).transpose()?;
-        let columns = columns.as_deref();
 
+        self.create_table_with_columns(
+            catalog_name,
+            namespace,
+            table_name,
+            columns.as_deref(),
+            table_type,
+            data_source_format,
+            comment,
+            storage_location,
+            properties,
+        )
+        .await
+    }
+
+    /// Like [`CatalogClient::create_table`], but takes the column list directly, e.g. to set the
+    /// `partition_index` of columns.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_table_with_columns(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        columns: Option<&[ColumnInfo]>,
+        table_type: &TableType,
+        data_source_format: Option<&DataSourceFormat>,
+        comment: Option<&str>,
+        storage_location: Option<&str>,
+        properties: &mut (dyn Iterator<Item = (&str, &str)> + Send + Sync),
+    ) -> PolarsResult<TableInfo> {
         let resp = do_request(
             self.http_client
                 .post(format!(
@@ -264,6 +346,47 @@ impl CatalogClient {
         }
     }
 
+    /// Replace the columns, comment and properties of an existing table.
+    pub async fn update_table(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        columns: Option<&[ColumnInfo]>,
+        comment: Option<&str>,
+        properties: &mut (dyn Iterator<Item = (&str, &str)> + Send + Sync),
+    ) -> PolarsResult<TableInfo> {
+        let full_name = format!(
+            "{}.{}.{}",
+            catalog_name.replace('/', "%2F"),
+            namespace.replace('/', "%2F"),
+            table_name.replace('/', "%2F"),
+        );
+
+        let resp = do_request(
+            self.http_client
+                .patch(format!(
+                    "{}{}{}",
+                    &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_name
+                ))
+                .json(&Body {
+                    comment,
+                    columns,
+                    properties: properties.collect(),
+                }),
+        )
+        .await?;
+
+        return decode_json_response(&resp);
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            comment: Option<&'a str>,
+            columns: Option<&'a [ColumnInfo]>,
+            properties: PlHashMap<&'a str, &'a str>,
+        }
+    }
+
     pub async fn delete_table(
         &self,
         catalog_name: &str,
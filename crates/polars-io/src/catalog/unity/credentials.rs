@@ -0,0 +1,327 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use polars_error::{PolarsResult, polars_bail, polars_err, to_compute_err};
+
+use super::client::CatalogClient;
+use super::models::{
+    TableCredentials, TableCredentialsAws, TableCredentialsAzure, TableCredentialsVariants,
+};
+use crate::catalog::gcp_token_credential;
+use crate::cloud::CloudOptions;
+use crate::cloud::credential_provider::{ObjectStoreCredential, PlCredentialProvider};
+
+/// Credentials that expire within this many seconds are requested again.
+const MIN_CREDENTIALS_VALIDITY_SECS: u64 = 60;
+
+/// What temporary credentials are vended for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CredentialsTarget {
+    /// A registered table, by its table id.
+    Table(String),
+    /// A storage path, e.g. the location of a table that is yet to be created.
+    Path(String),
+}
+
+impl CatalogClient {
+    /// Temporary credentials for reading or, with `write`, writing `target`.
+    pub async fn get_credentials(
+        &self,
+        target: &CredentialsTarget,
+        write: bool,
+    ) -> PolarsResult<TableCredentials> {
+        match target {
+            CredentialsTarget::Table(table_id) => self.get_table_credentials(table_id, write).await,
+            CredentialsTarget::Path(url) => self.get_path_credentials(url, write).await,
+        }
+    }
+
+    /// Attach the temporary credentials vended by the catalog for `target` to `cloud_options`.
+    ///
+    /// The credentials are installed as a credential provider, which requests new credentials
+    /// from the catalog once the previous ones expire. Any configured credential provider is
+    /// replaced, other configuration is kept as-is.
+    pub async fn cloud_options_with_credentials(
+        &self,
+        target: CredentialsTarget,
+        write: bool,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<CloudOptions> {
+        let mut cloud_options = cloud_options.unwrap_or_default();
+        let credentials = self.get_credentials(&target, write).await?;
+
+        if let Some(aws) = &credentials.aws_temp_credentials {
+            apply_aws_config(aws, &mut cloud_options)?;
+        }
+
+        let Some(first) = object_store_credential(credentials)? else {
+            return Ok(cloud_options);
+        };
+
+        let client = self.clone();
+        let vended = Arc::new(VendedCredentials::new(first, move || {
+            let client = client.clone();
+            let target = target.clone();
+
+            async move {
+                // The request is spawned as its future is not `Sync`.
+                let credentials = crate::pl_async::get_runtime()
+                    .spawn(async move { client.get_credentials(&target, write).await })
+                    .await
+                    .map_err(to_compute_err)??;
+
+                object_store_credential(credentials)?.ok_or_else(
+                    || polars_err!(ComputeError: "catalog returned no credentials on refresh"),
+                )
+            }
+        }));
+
+        let credential_provider = PlCredentialProvider::from_func(move || {
+            let vended = vended.clone();
+            Box::pin(async move { vended.get().await })
+        });
+
+        Ok(cloud_options.with_credential_provider(Some(credential_provider)))
+    }
+}
+
+/// Credentials vended by a catalog. The credentials that were requested when planning the query
+/// are handed out first, after which `request` is called for new ones.
+struct VendedCredentials<F> {
+    first: Mutex<Option<(ObjectStoreCredential, u64)>>,
+    request: F,
+}
+
+impl<F, Fut> VendedCredentials<F>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = PolarsResult<(ObjectStoreCredential, u64)>>,
+{
+    fn new(first: (ObjectStoreCredential, u64), request: F) -> Self {
+        Self {
+            first: Mutex::new(Some(first)),
+            request,
+        }
+    }
+
+    async fn get(&self) -> PolarsResult<(ObjectStoreCredential, u64)> {
+        let first = self.first.lock().unwrap().take();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        match first {
+            Some((credential, expiry))
+                if expiry.saturating_sub(now) >= MIN_CREDENTIALS_VALIDITY_SECS =>
+            {
+                Ok((credential, expiry))
+            },
+            _ => (self.request)().await,
+        }
+    }
+}
+
+/// The credential for `object_store` with its expiry time in seconds since the UNIX epoch, or
+/// `None` if the catalog vended no credentials.
+fn object_store_credential(
+    credentials: TableCredentials,
+) -> PolarsResult<Option<(ObjectStoreCredential, u64)>> {
+    let expiry = u64::try_from(credentials.expiration_time / 1000).unwrap_or(0);
+
+    let Some(credentials) = credentials.into_enum() else {
+        return Ok(None);
+    };
+
+    let credential = match credentials {
+        TableCredentialsVariants::Aws(v) => aws_credential(v),
+        TableCredentialsVariants::Azure(v) => azure_credential(v),
+        TableCredentialsVariants::Gcp(v) => gcp_token_credential(v.oauth_token),
+    }?;
+
+    Ok(Some((credential, expiry)))
+}
+
+/// Route the requests through the S3 access point that the credentials are scoped to, if any.
+fn apply_aws_config(
+    aws: &TableCredentialsAws,
+    cloud_options: &mut CloudOptions,
+) -> PolarsResult<()> {
+    #[cfg(feature = "aws")]
+    {
+        use crate::cloud::options::CloudConfig;
+
+        let Some(access_point) = &aws.access_point else {
+            return Ok(());
+        };
+        let access_point_configs = aws_access_point_config(access_point)?;
+
+        match &mut cloud_options.config {
+            Some(CloudConfig::Aws(configs)) => {
+                configs.retain(|(k, _)| !access_point_configs.iter().any(|(key, _)| key == k));
+                configs.extend(access_point_configs);
+            },
+            config => *config = Some(CloudConfig::Aws(access_point_configs)),
+        }
+
+        Ok(())
+    }
+    #[cfg(not(feature = "aws"))]
+    {
+        let _ = (aws, cloud_options);
+        polars_bail!(ComputeError: "'aws' feature is not enabled");
+    }
+}
+
+/// The configuration to send requests to an S3 access point, given by its ARN
+/// `arn:<partition>:s3:<region>:<account id>:accesspoint/<name>`.
+///
+/// The access point endpoint is used as the bucket endpoint, so the object keys are requested
+/// directly below it.
+#[cfg(feature = "aws")]
+fn aws_access_point_config(
+    arn: &str,
+) -> PolarsResult<Vec<(object_store::aws::AmazonS3ConfigKey, String)>> {
+    use object_store::aws::AmazonS3ConfigKey;
+
+    let parsed = match arn.splitn(6, ':').collect::<Vec<_>>()[..] {
+        ["arn", partition, "s3", region, account_id, resource] => resource
+            .strip_prefix("accesspoint/")
+            .map(|name| (partition, region, account_id, name)),
+        _ => None,
+    };
+    let Some((partition, region, account_id, name)) = parsed else {
+        polars_bail!(ComputeError: "invalid S3 access point ARN returned by catalog: '{}'", arn)
+    };
+    let domain = if partition.starts_with("aws-cn") {
+        "amazonaws.com.cn"
+    } else {
+        "amazonaws.com"
+    };
+
+    Ok(vec![
+        (
+            AmazonS3ConfigKey::Endpoint,
+            format!("https://{name}-{account_id}.s3-accesspoint.{region}.{domain}"),
+        ),
+        (
+            AmazonS3ConfigKey::VirtualHostedStyleRequest,
+            "true".to_string(),
+        ),
+        (AmazonS3ConfigKey::Region, region.to_string()),
+    ])
+}
+
+fn aws_credential(aws: TableCredentialsAws) -> PolarsResult<ObjectStoreCredential> {
+    #[cfg(feature = "aws")]
+    {
+        use crate::cloud::credential_provider::AwsCredential;
+
+        Ok(ObjectStoreCredential::Aws(Arc::new(AwsCredential {
+            key_id: aws.access_key_id,
+            secret_key: aws.secret_access_key,
+            token: aws.session_token,
+        })))
+    }
+    #[cfg(not(feature = "aws"))]
+    {
+        let _ = aws;
+        polars_bail!(ComputeError: "'aws' feature is not enabled");
+    }
+}
+
+fn azure_credential(azure: TableCredentialsAzure) -> PolarsResult<ObjectStoreCredential> {
+    #[cfg(feature = "azure")]
+    {
+        use crate::cloud::credential_provider::AzureCredential;
+
+        let pairs = split_sas_token(&azure.sas_token)?;
+        Ok(ObjectStoreCredential::Azure(Arc::new(
+            AzureCredential::SASToken(pairs),
+        )))
+    }
+    #[cfg(not(feature = "azure"))]
+    {
+        let _ = azure;
+        polars_bail!(ComputeError: "'azure' feature is not enabled");
+    }
+}
+
+/// Split a SAS token into its query pairs, matching what `object_store` does for
+/// `AzureConfigKey::SasKey`.
+#[cfg(feature = "azure")]
+fn split_sas_token(sas_token: &str) -> PolarsResult<Vec<(String, String)>> {
+    let sas_token = percent_encoding::percent_decode_str(sas_token)
+        .decode_utf8()
+        .map_err(to_compute_err)?;
+
+    sas_token
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|s| !s.chars().all(char::is_whitespace))
+        .map(|pair| {
+            let Some((k, v)) = pair.trim().split_once('=') else {
+                polars_bail!(ComputeError: "invalid SAS token returned by catalog: missing '=' in '{}'", pair)
+            };
+            Ok((k.to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_vended_credentials_are_requested_again() {
+        let requests = AtomicUsize::new(0);
+        let vended = VendedCredentials::new((ObjectStoreCredential::None, u64::MAX), || {
+            requests.fetch_add(1, Ordering::Relaxed);
+            async { Ok((ObjectStoreCredential::None, 0)) }
+        });
+
+        crate::pl_async::get_runtime().block_on(async {
+            assert_eq!(vended.get().await.unwrap().1, u64::MAX);
+            assert_eq!(requests.load(Ordering::Relaxed), 0);
+            vended.get().await.unwrap();
+            vended.get().await.unwrap();
+        });
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+
+        // Credentials that expired between planning and execution are not handed out.
+        let requests = AtomicUsize::new(0);
+        let vended = VendedCredentials::new((ObjectStoreCredential::None, 1), || {
+            requests.fetch_add(1, Ordering::Relaxed);
+            async { Ok((ObjectStoreCredential::None, 0)) }
+        });
+        crate::pl_async::get_runtime()
+            .block_on(vended.get())
+            .unwrap();
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_aws_access_point_config() {
+        use object_store::aws::AmazonS3ConfigKey;
+
+        let config =
+            aws_access_point_config("arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap").unwrap();
+        assert_eq!(
+            config,
+            [
+                (
+                    AmazonS3ConfigKey::Endpoint,
+                    "https://my-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com".into()
+                ),
+                (AmazonS3ConfigKey::VirtualHostedStyleRequest, "true".into()),
+                (AmazonS3ConfigKey::Region, "us-west-2".into()),
+            ]
+        );
+
+        assert!(aws_access_point_config("my-ap-alias-s3alias").is_err());
+        assert!(aws_access_point_config("arn:aws:s3:us-west-2:123456789012:bucket/b").is_err());
+    }
+}
@@ -1,4 +1,7 @@
 pub mod client;
+mod credentials;
 pub mod models;
 pub mod schema;
 pub(crate) mod utils;
+
+pub use credentials::CredentialsTarget;
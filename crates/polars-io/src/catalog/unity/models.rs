@@ -110,6 +110,7 @@ pub enum DataSourceFormat {
     Parquet,
     Orc,
     Text,
+    Iceberg,
 
     // Databricks-specific
     UnityCatalog,
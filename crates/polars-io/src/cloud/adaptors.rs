@@ -9,7 +9,7 @@ use polars_error::PolarsResult;
 use polars_utils::file::WriteClose;
 use tokio::io::AsyncWriteExt;
 
-use super::{CloudOptions, object_path_from_str};
+use super::{CloudOptions, CloudUploadOptions, RetryUploadStore, object_path_from_str};
 use crate::pl_async::{get_runtime, get_upload_chunk_size};
 
 fn clone_io_err(e: &std::io::Error) -> std::io::Error {
@@ -37,7 +37,23 @@ impl BlockingCloudWriter {
         object_store: Arc<dyn ObjectStore>,
         path: Path,
     ) -> PolarsResult<Self> {
-        let writer = BufWriter::with_capacity(object_store, path, get_upload_chunk_size());
+        Self::new_with_upload_options(object_store, path, &CloudUploadOptions::default())
+    }
+
+    /// Construct a new BlockingCloudWriter that uploads with the chunk size and concurrency of
+    /// `upload_options`.
+    ///
+    /// Retries are not handled here, wrap `object_store` in a [`RetryUploadStore`] for those.
+    pub fn new_with_upload_options(
+        object_store: Arc<dyn ObjectStore>,
+        path: Path,
+        upload_options: &CloudUploadOptions,
+    ) -> PolarsResult<Self> {
+        let chunk_size = upload_options
+            .chunk_size
+            .unwrap_or_else(get_upload_chunk_size);
+        let writer = BufWriter::with_capacity(object_store, path, chunk_size)
+            .with_max_concurrency(upload_options.max_concurrency);
         Ok(BlockingCloudWriter { state: Ok(writer) })
     }
 
@@ -55,9 +71,17 @@ impl BlockingCloudWriter {
 
         let (cloud_location, object_store) =
             crate::cloud::build_object_store(uri, cloud_options, false).await?;
-        Self::new_with_object_store(
+        let upload_options = cloud_options.map(|x| x.upload.clone()).unwrap_or_default();
+        let object_store = Arc::new(RetryUploadStore::new(
             object_store.to_dyn_object_store().await,
+            object_store.to_dyn_multipart_store(),
+            upload_options.clone(),
+        ));
+
+        Self::new_with_upload_options(
+            object_store,
             object_path_from_str(&cloud_location.prefix)?,
+            &upload_options,
         )
     }
 
@@ -10,56 +10,102 @@ use regex::Regex;
 use url::Url;
 
 use super::{CloudOptions, parse_url};
+use crate::path_utils::is_partition_commit_path;
 
 const DELIMITER: char = '/';
 
+/// Converts a glob character class starting at `v[0] == b'['` to regex form. `[!...]` is
+/// negated, and like other wildcards a class never matches the delimiter.
+///
+/// Returns `None` if the class is not closed, in which case the `[` is taken literally.
+fn translate_character_class(v: &[u8]) -> Option<(usize, Vec<u8>)> {
+    let mut out = b"[".to_vec();
+    let mut i = 1;
+
+    if matches!(v.get(i), Some(b'!' | b'^')) {
+        out.extend_from_slice(b"^/");
+        i += 1;
+    }
+
+    // A leading `]` is part of the class.
+    if v.get(i) == Some(&b']') {
+        out.extend_from_slice(b"\\]");
+        i += 1;
+    }
+
+    loop {
+        match *v.get(i)? {
+            b']' => break,
+            b'/' => return None,
+            c @ (b'\\' | b'[' | b'&' | b'~') => out.extend_from_slice(&[b'\\', c]),
+            c => out.push(c),
+        }
+        i += 1;
+    }
+
+    out.push(b']');
+    Some((i + 1, out))
+}
+
 /// Converts a glob to regex form.
 ///
+/// Supports `*`, `**`, `?` and character classes (`[abc]`, `[a-z]`, `[!abc]`). Other regex
+/// metacharacters are matched literally.
+///
 /// # Returns
-/// 1. the prefix part (all path components until the first one with '*')
+/// 1. the prefix part (all path components until the first one with a glob character)
 /// 2. a regular expression representation of the rest.
 pub(crate) fn extract_prefix_expansion(url: &str) -> PolarsResult<(Cow<'_, str>, Option<String>)> {
     let url = url.strip_prefix('/').unwrap_or(url);
     // (offset, len, replacement)
-    let mut replacements: Vec<(usize, usize, &[u8])> = vec![];
+    let mut replacements: Vec<(usize, usize, Cow<'static, [u8]>)> = vec![];
 
     // The position after the last slash before glob characters begin.
     // `a/b/c*/`
     //      ^
-    let mut pos: usize = if let Some(after_last_slash) = memchr::memchr2(b'*', b'[', url.as_bytes())
-        .map(|i| {
+    let mut pos: usize = if let Some(after_last_slash) =
+        crate::path_utils::get_glob_start_idx(url.as_bytes()).map(|i| {
             url.as_bytes()[..i]
                 .iter()
                 .rposition(|x| *x == b'/')
                 .map_or(0, |x| 1 + x)
         }) {
         // First value is used as the starting point later.
-        replacements.push((after_last_slash, 0, &[]));
+        replacements.push((after_last_slash, 0, Cow::Borrowed(&[])));
         after_last_slash
     } else {
         usize::MAX
     };
 
     while pos < url.len() {
-        match memchr::memchr2(b'*', b'.', &url.as_bytes()[pos..]) {
-            None => break,
-            Some(i) => pos += i,
-        }
+        let v = &url.as_bytes()[pos..];
 
-        let (len, replace): (usize, &[u8]) = match &url[pos..] {
+        let (len, replace): (usize, Cow<'static, [u8]>) = match v {
             // Accept:
             // - `**/`
             // - `**` only if it is the end of the url
-            v if v.starts_with("**") && (v.len() == 2 || v.as_bytes()[2] == b'/') => {
+            v if v.starts_with(b"**") && (v.len() == 2 || v[2] == b'/') => {
                 // Wrapping in a capture group ensures we also match non-nested paths.
-                (3, b"(.*/)?" as _)
+                (3, Cow::Borrowed(b"(.*/)?"))
             },
-            v if v.starts_with("**") => {
+            v if v.starts_with(b"**") => {
                 polars_bail!(ComputeError: "invalid ** glob pattern")
             },
-            v if v.starts_with('*') => (1, b"[^/]*" as _),
-            // Dots need to be escaped in regex.
-            v if v.starts_with('.') => (1, b"\\." as _),
+            [b'*', ..] => (1, Cow::Borrowed(b"[^/]*")),
+            [b'?', ..] => (1, Cow::Borrowed(b"[^/]")),
+            [b'[', ..] => match translate_character_class(v) {
+                Some((len, class)) => (len, Cow::Owned(class)),
+                None => (1, Cow::Borrowed(b"\\[")),
+            },
+            // Escape the remaining regex metacharacters.
+            [
+                b'.' | b'+' | b'(' | b')' | b'|' | b'^' | b'$' | b'{' | b'}' | b']' | b'\\',
+                ..,
+            ] => {
+                replacements.push((pos, 0, Cow::Borrowed(b"\\")));
+                pos += 1;
+                continue;
+            },
             _ => {
                 pos += 1;
                 continue;
@@ -82,7 +128,7 @@ pub(crate) fn extract_prefix_expansion(url: &str) -> PolarsResult<(Cow<'_, str>,
 
     for (offset, len, replace) in replacements {
         expansion.extend_from_slice(&url.as_bytes()[pos..offset]);
-        expansion.extend_from_slice(replace);
+        expansion.extend_from_slice(&replace);
         pos = offset + len;
     }
 
@@ -234,8 +280,13 @@ pub async fn glob(url: &str, cloud_options: Option<&CloudOptions>) -> PolarsResu
                 store
                     .list(path)
                     .try_filter_map(|x| async move {
-                        let out = (x.size > 0 && matcher.is_matching(x.location.as_ref()))
-                            .then_some(x.location);
+                        let location = x.location.as_ref();
+                        let out = (x.size > 0
+                            && matcher.is_matching(location)
+                            && !is_partition_commit_path(
+                                location.get(matcher.prefix.len()..).unwrap_or_default(),
+                            ))
+                        .then_some(x.location);
                         Ok(out)
                     })
                     .try_collect::<Vec<_>>()
@@ -314,6 +365,22 @@ mod test {
             extract_prefix_expansion("a/*b").unwrap(),
             ("a/".into(), Some("^[^/]*b$".into()))
         );
+        assert_eq!(
+            extract_prefix_expansion("a/b/part-?.c").unwrap(),
+            ("a/b/".into(), Some("^part-[^/]\\.c$".into()))
+        );
+        assert_eq!(
+            extract_prefix_expansion("a/202[3-4]/[!_]*").unwrap(),
+            ("a/".into(), Some("^202[3-4]/[^/_][^/]*$".into()))
+        );
+        assert_eq!(
+            extract_prefix_expansion("a/[]x]/[b/*").unwrap(),
+            ("a/".into(), Some("^[\\]x]/\\[b/[^/]*$".into()))
+        );
+        assert_eq!(
+            extract_prefix_expansion("a/*/(b)+.c").unwrap(),
+            ("a/".into(), Some("^[^/]*/\\(b\\)\\+\\.c$".into()))
+        );
     }
 
     #[test]
@@ -9,6 +9,8 @@ mod object_store_setup;
 pub mod options;
 #[cfg(feature = "cloud")]
 mod polars_object_store;
+#[cfg(feature = "cloud")]
+mod upload;
 
 #[cfg(feature = "cloud")]
 pub use adaptors::*;
@@ -19,6 +21,8 @@ pub use object_store_setup::*;
 pub use options::*;
 #[cfg(feature = "cloud")]
 pub use polars_object_store::*;
+#[cfg(feature = "cloud")]
+pub use upload::*;
 
 #[cfg(feature = "cloud")]
 pub mod credential_provider;
@@ -2,6 +2,8 @@ use std::sync::{Arc, LazyLock};
 
 use object_store::ObjectStore;
 use object_store::local::LocalFileSystem;
+use object_store::memory::InMemory;
+use object_store::multipart::MultipartStore;
 use polars_core::config::{self, verbose_print_sensitive};
 use polars_error::{PolarsError, PolarsResult, polars_bail, to_compute_err};
 use polars_utils::aliases::PlHashMap;
@@ -20,8 +22,34 @@ use crate::cloud::CloudConfig;
 static OBJECT_STORE_CACHE: LazyLock<RwLock<PlHashMap<Vec<u8>, PolarsObjectStore>>> =
     LazyLock::new(Default::default);
 
+/// Stores backing `memory://` urls, by bucket. These live until they are removed with
+/// [`remove_memory_store`] or [`clear_memory_stores`], so that data written to a bucket can be
+/// read back by later queries.
+static MEMORY_STORES: LazyLock<std::sync::Mutex<PlHashMap<PlSmallStr, Arc<InMemory>>>> =
+    LazyLock::new(Default::default);
+
+fn memory_store(bucket: &str) -> Arc<InMemory> {
+    MEMORY_STORES
+        .lock()
+        .unwrap()
+        .entry(bucket.into())
+        .or_insert_with(|| Arc::new(InMemory::new()))
+        .clone()
+}
+
+/// Remove the `memory://` bucket `bucket`, freeing its data once no running query uses it.
+/// Returns whether the bucket existed.
+pub fn remove_memory_store(bucket: &str) -> bool {
+    MEMORY_STORES.lock().unwrap().remove(bucket).is_some()
+}
+
+/// Remove all `memory://` buckets, see [`remove_memory_store`].
+pub fn clear_memory_stores() {
+    MEMORY_STORES.lock().unwrap().clear()
+}
+
 #[allow(dead_code)]
-fn err_missing_feature(feature: &str, scheme: &str) -> PolarsResult<Arc<dyn ObjectStore>> {
+fn err_missing_feature<T>(feature: &str, scheme: &str) -> PolarsResult<T> {
     polars_bail!(
         ComputeError:
         "feature '{}' must be enabled in order to use '{}' cloud urls", feature, scheme,
@@ -40,6 +68,8 @@ fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> Vec<u8> {
              config,
              #[cfg(feature = "cloud")]
              credential_provider,
+             // Only used by the writers, does not affect the object store.
+             upload: _,
          }| {
             CloudOptions2 {
                 max_retries: *max_retries,
@@ -101,7 +131,18 @@ pub(crate) struct PolarsObjectStoreBuilder {
 }
 
 impl PolarsObjectStoreBuilder {
-    pub(super) async fn build_impl(&self) -> PolarsResult<Arc<dyn ObjectStore>> {
+    /// Also returns the [`MultipartStore`] interface of the store, if it has one.
+    pub(super) async fn build_impl(
+        &self,
+    ) -> PolarsResult<(Arc<dyn ObjectStore>, Option<Arc<dyn MultipartStore>>)> {
+        #[allow(dead_code)]
+        fn with_multipart<S: ObjectStore + MultipartStore>(
+            store: S,
+        ) -> (Arc<dyn ObjectStore>, Option<Arc<dyn MultipartStore>>) {
+            let store = Arc::new(store);
+            (store.clone(), Some(store))
+        }
+
         let options = self
             .options
             .as_ref()
@@ -112,7 +153,7 @@ impl PolarsObjectStoreBuilder {
                 #[cfg(feature = "aws")]
                 {
                     let store = options.build_aws(&self.url).await?;
-                    Ok::<_, PolarsError>(Arc::new(store) as Arc<dyn ObjectStore>)
+                    Ok::<_, PolarsError>(with_multipart(store))
                 }
                 #[cfg(not(feature = "aws"))]
                 return err_missing_feature("aws", &self.scheme);
@@ -121,7 +162,7 @@ impl PolarsObjectStoreBuilder {
                 #[cfg(feature = "gcp")]
                 {
                     let store = options.build_gcp(&self.url)?;
-                    Ok::<_, PolarsError>(Arc::new(store) as Arc<dyn ObjectStore>)
+                    Ok::<_, PolarsError>(with_multipart(store))
                 }
                 #[cfg(not(feature = "gcp"))]
                 return err_missing_feature("gcp", &self.scheme);
@@ -131,7 +172,7 @@ impl PolarsObjectStoreBuilder {
                     #[cfg(feature = "azure")]
                     {
                         let store = options.build_azure(&self.url)?;
-                        Ok::<_, PolarsError>(Arc::new(store) as Arc<dyn ObjectStore>)
+                        Ok::<_, PolarsError>(with_multipart(store))
                     }
                 }
                 #[cfg(not(feature = "azure"))]
@@ -139,20 +180,28 @@ impl PolarsObjectStoreBuilder {
             },
             CloudType::File => {
                 let local = LocalFileSystem::new();
-                Ok::<_, PolarsError>(Arc::new(local) as Arc<dyn ObjectStore>)
+                Ok::<_, PolarsError>((Arc::new(local) as Arc<dyn ObjectStore>, None))
             },
             CloudType::Http => {
                 {
                     #[cfg(feature = "http")]
                     {
                         let store = options.build_http(&self.url)?;
-                        PolarsResult::Ok(Arc::new(store) as Arc<dyn ObjectStore>)
+                        PolarsResult::Ok((Arc::new(store) as Arc<dyn ObjectStore>, None))
                     }
                 }
                 #[cfg(not(feature = "http"))]
                 return err_missing_feature("http", &cloud_location.scheme);
             },
             CloudType::Hf => panic!("impl error: unresolved hf:// path"),
+            CloudType::Memory => {
+                let bucket = self.parsed_url.host_str().unwrap_or_default();
+                let store = memory_store(bucket);
+                Ok::<_, PolarsError>((
+                    store.clone() as Arc<dyn ObjectStore>,
+                    Some(store as Arc<dyn MultipartStore>),
+                ))
+            },
         }?;
 
         Ok(store)
@@ -165,7 +214,7 @@ impl PolarsObjectStoreBuilder {
                 &self.parsed_url,
                 self.options.as_ref(),
             )),
-            CloudType::File | CloudType::Http | CloudType::Hf => None,
+            CloudType::File | CloudType::Http | CloudType::Hf | CloudType::Memory => None,
         };
 
         let opt_cache_write_guard = if let Some(cache_key) = opt_cache_key.as_deref() {
@@ -188,8 +237,8 @@ impl PolarsObjectStoreBuilder {
             None
         };
 
-        let store = self.build_impl().await?;
-        let store = PolarsObjectStore::new_from_inner(store, self);
+        let (store, multipart_store) = self.build_impl().await?;
+        let store = PolarsObjectStore::new_from_inner(store, multipart_store, self);
 
         if let Some(mut cache) = opt_cache_write_guard {
             // Clear the cache if we surpass a certain amount of buckets.
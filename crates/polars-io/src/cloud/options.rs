@@ -88,6 +88,8 @@ pub struct CloudOptions {
     /// Note: In most cases you will want to access this via [`CloudOptions::initialized_credential_provider`]
     /// rather than directly.
     pub(crate) credential_provider: Option<PlCredentialProvider>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub upload: CloudUploadOptions,
 }
 
 impl Default for CloudOptions {
@@ -96,6 +98,70 @@ impl Default for CloudOptions {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+/// Options for writing files to cloud storage.
+pub struct CloudUploadOptions {
+    /// Size of the parts of a multipart upload. Files smaller than this are uploaded with a single
+    /// request. Defaults to `POLARS_UPLOAD_CHUNK_SIZE`, or 64MiB if that is not set.
+    pub chunk_size: Option<usize>,
+    /// Maximum number of parts of a file that are uploaded concurrently.
+    pub max_concurrency: usize,
+    /// Number of times a request that failed with a transient error is retried. The parts of a
+    /// multipart upload are retried individually, so the parts that were already uploaded are kept.
+    pub max_retries: usize,
+    /// Time to wait before the first retry in milliseconds. This is doubled for every next retry.
+    pub retry_backoff_ms: u64,
+}
+
+impl Default for CloudUploadOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: None,
+            max_concurrency: 8,
+            max_retries: 2,
+            retry_backoff_ms: 100,
+        }
+    }
+}
+
+impl CloudUploadOptions {
+    /// Set the option for an `upload_*` configuration key, e.g. `upload_chunk_size`. Returns
+    /// `false` if `key` is not an upload option.
+    fn set_from_untyped_config(&mut self, key: &str, value: &str) -> PolarsResult<bool> {
+        fn parse<T: FromStr>(key: &str, value: &str) -> PolarsResult<T> {
+            value.trim().parse().map_err(|_| {
+                polars_err!(ComputeError: "invalid value for cloud option '{}': '{}'", key, value)
+            })
+        }
+
+        match key.to_ascii_lowercase().as_str() {
+            "upload_chunk_size" => {
+                let chunk_size = parse::<usize>(key, value)?;
+                polars_ensure!(
+                    chunk_size > 0,
+                    ComputeError: "cloud option '{}' must be greater than 0", key
+                );
+                self.chunk_size = Some(chunk_size);
+            },
+            "upload_max_concurrency" => {
+                let max_concurrency = parse::<usize>(key, value)?;
+                polars_ensure!(
+                    max_concurrency > 0,
+                    ComputeError: "cloud option '{}' must be greater than 0", key
+                );
+                self.max_concurrency = max_concurrency;
+            },
+            "upload_max_retries" => self.max_retries = parse(key, value)?,
+            "upload_retry_backoff_ms" => self.retry_backoff_ms = parse(key, value)?,
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+}
+
 impl CloudOptions {
     pub fn default_static_ref() -> &'static Self {
         static DEFAULT: LazyLock<CloudOptions> = LazyLock::new(|| CloudOptions {
@@ -105,6 +171,7 @@ impl CloudOptions {
             config: None,
             #[cfg(feature = "cloud")]
             credential_provider: None,
+            upload: CloudUploadOptions::default(),
         });
 
         &DEFAULT
@@ -156,6 +223,7 @@ pub enum CloudType {
     Gcp,
     Http,
     Hf,
+    Memory,
 }
 
 impl CloudType {
@@ -168,6 +236,7 @@ impl CloudType {
             "file" => Self::File,
             "http" | "https" => Self::Http,
             "hf" => Self::Hf,
+            "memory" => Self::Memory,
             _ => polars_bail!(ComputeError: "unknown url scheme"),
         })
     }
@@ -293,6 +362,12 @@ impl CloudOptions {
         self
     }
 
+    /// Set the options for writing files.
+    pub fn with_upload_options(mut self, upload: CloudUploadOptions) -> Self {
+        self.upload = upload;
+        self
+    }
+
     #[cfg(feature = "cloud")]
     pub fn with_credential_provider(
         mut self,
@@ -316,7 +391,11 @@ impl CloudOptions {
 
     /// Build the [`object_store::ObjectStore`] implementation for AWS.
     #[cfg(feature = "aws")]
-    pub async fn build_aws(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub async fn build_aws(
+        &self,
+        url: &str,
+    ) -> PolarsResult<impl object_store::ObjectStore + object_store::multipart::MultipartStore>
+    {
         use super::credential_provider::IntoCredentialProvider;
 
         let opt_credential_provider = self.initialized_credential_provider()?;
@@ -476,7 +555,11 @@ impl CloudOptions {
 
     /// Build the [`object_store::ObjectStore`] implementation for Azure.
     #[cfg(feature = "azure")]
-    pub fn build_azure(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub fn build_azure(
+        &self,
+        url: &str,
+    ) -> PolarsResult<impl object_store::ObjectStore + object_store::multipart::MultipartStore>
+    {
         use super::credential_provider::IntoCredentialProvider;
 
         let verbose = polars_core::config::verbose();
@@ -530,7 +613,11 @@ impl CloudOptions {
 
     /// Build the [`object_store::ObjectStore`] implementation for GCP.
     #[cfg(feature = "gcp")]
-    pub fn build_gcp(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub fn build_gcp(
+        &self,
+        url: &str,
+    ) -> PolarsResult<impl object_store::ObjectStore + object_store::multipart::MultipartStore>
+    {
         use super::credential_provider::IntoCredentialProvider;
 
         let credential_provider = self.initialized_credential_provider()?;
@@ -585,13 +672,66 @@ impl CloudOptions {
         Ok(out)
     }
 
+    /// Update the configuration with untyped configuration keys for `url`. Keys that are already
+    /// set are overwritten, a configuration for a different cloud provider is replaced.
+    #[cfg(feature = "catalog")]
+    pub(crate) fn with_untyped_config_update<
+        I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>,
+    >(
+        mut self,
+        url: &str,
+        config: I,
+    ) -> PolarsResult<Self> {
+        #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+        fn merge<T: PartialEq>(mut configs: Configs<T>, update: Configs<T>) -> Configs<T> {
+            configs.retain(|(k, _)| !update.iter().any(|(u, _)| u == k));
+            configs.extend(update);
+            configs
+        }
+
+        let update = Self::from_untyped_config(url, config)?.config;
+
+        self.config = match (self.config.take(), update) {
+            (config, None) => config,
+            #[cfg(feature = "aws")]
+            (Some(CloudConfig::Aws(configs)), Some(CloudConfig::Aws(v))) => {
+                Some(CloudConfig::Aws(merge(configs, v)))
+            },
+            #[cfg(feature = "azure")]
+            (Some(CloudConfig::Azure(configs)), Some(CloudConfig::Azure(v))) => {
+                Some(CloudConfig::Azure(merge(configs, v)))
+            },
+            #[cfg(feature = "gcp")]
+            (Some(CloudConfig::Gcp(configs)), Some(CloudConfig::Gcp(v))) => {
+                Some(CloudConfig::Gcp(merge(configs, v)))
+            },
+            (_, config) => config,
+        };
+
+        Ok(self)
+    }
+
     /// Parse a configuration from a Hashmap. This is the interface from Python.
+    ///
+    /// The `upload_*` keys set the [`CloudUploadOptions`] for every cloud provider.
     #[allow(unused_variables)]
     pub fn from_untyped_config<I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
         url: &str,
         config: I,
     ) -> PolarsResult<Self> {
-        match CloudType::from_str(url)? {
+        let mut upload = CloudUploadOptions::default();
+        let mut provider_config = vec![];
+
+        for (key, value) in config {
+            let value = value.into();
+            if !upload.set_from_untyped_config(key.as_ref(), &value)? {
+                provider_config.push((key, value));
+            }
+        }
+
+        let config = provider_config;
+
+        let out = match CloudType::from_str(url)? {
             CloudType::Aws => {
                 #[cfg(feature = "aws")]
                 {
@@ -616,6 +756,7 @@ impl CloudOptions {
             },
             CloudType::File => Ok(Self::default()),
             CloudType::Http => Ok(Self::default()),
+            CloudType::Memory => Ok(Self::default()),
             CloudType::Gcp => {
                 #[cfg(feature = "gcp")]
                 {
@@ -639,7 +780,7 @@ impl CloudOptions {
                     let verbose = config::verbose();
 
                     for (i, (k, v)) in config.into_iter().enumerate() {
-                        let (k, v) = (k.as_ref(), v.into());
+                        let k = k.as_ref();
 
                         if i == 0 && k == "token" {
                             if verbose {
@@ -695,7 +836,9 @@ impl CloudOptions {
                     polars_bail!(ComputeError: "'http' feature is not enabled");
                 }
             },
-        }
+        };
+
+        out.map(|options| options.with_upload_options(upload))
     }
 
     /// Python passes a credential provider builder that needs to be called to get the actual credential
@@ -825,4 +968,57 @@ mod tests {
         );
         assert_eq!(aws_keys.len(), 1);
     }
+
+    #[test]
+    fn test_parse_untyped_upload_config() {
+        use super::CloudOptions;
+
+        let options = CloudOptions::from_untyped_config(
+            "memory://data.csv",
+            [
+                ("upload_chunk_size", "1024"),
+                ("UPLOAD_MAX_CONCURRENCY", "3"),
+                ("upload_max_retries", "5"),
+                ("upload_retry_backoff_ms", "20"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(options.upload.chunk_size, Some(1024));
+        assert_eq!(options.upload.max_concurrency, 3);
+        assert_eq!(options.upload.max_retries, 5);
+        assert_eq!(options.upload.retry_backoff_ms, 20);
+
+        assert!(
+            CloudOptions::from_untyped_config("memory://data.csv", [("upload_chunk_size", "0")])
+                .is_err()
+        );
+        assert!(
+            CloudOptions::from_untyped_config("memory://data.csv", [("upload_max_retries", "x")])
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_parse_untyped_upload_config_aws() {
+        use object_store::aws::AmazonS3ConfigKey;
+
+        use super::{CloudConfig, CloudOptions};
+
+        let options = CloudOptions::from_untyped_config(
+            "s3://bucket/data.csv",
+            [("aws_region", "eu-west-1"), ("upload_max_retries", "0")],
+        )
+        .unwrap();
+
+        assert_eq!(options.upload.max_retries, 0);
+        assert_eq!(
+            options.config,
+            Some(CloudConfig::Aws(vec![(
+                AmazonS3ConfigKey::Region,
+                "eu-west-1".into()
+            )]))
+        );
+    }
 }
@@ -21,17 +21,29 @@ mod inner {
     use std::sync::atomic::AtomicBool;
 
     use object_store::ObjectStore;
+    use object_store::multipart::MultipartStore;
     use polars_core::config;
     use polars_error::PolarsResult;
 
     use crate::cloud::PolarsObjectStoreBuilder;
+    use crate::metrics::IOMetrics;
 
-    #[derive(Debug)]
     struct Inner {
         store: tokio::sync::Mutex<Arc<dyn ObjectStore>>,
+        /// Multipart interface of `store`, replaced together with it on re-builds.
+        multipart_store: std::sync::Mutex<Option<Arc<dyn MultipartStore>>>,
         builder: PolarsObjectStoreBuilder,
     }
 
+    impl std::fmt::Debug for Inner {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Inner")
+                .field("store", &self.store)
+                .field("builder", &self.builder)
+                .finish_non_exhaustive()
+        }
+    }
+
     /// Polars wrapper around [`ObjectStore`] functionality. This struct is cheaply cloneable.
     #[derive(Debug)]
     pub struct PolarsObjectStore {
@@ -41,6 +53,8 @@ mod inner {
         /// Used for interior mutability. Doesn't need to be shared with other threads so it's not
         /// inside `Arc<>`.
         rebuilt: AtomicBool,
+        /// Requests made through this handle are recorded here if set.
+        io_metrics: Option<Arc<IOMetrics>>,
     }
 
     impl Clone for PolarsObjectStore {
@@ -49,6 +63,7 @@ mod inner {
                 inner: self.inner.clone(),
                 initial_store: self.initial_store.clone(),
                 rebuilt: AtomicBool::new(self.rebuilt.load(std::sync::atomic::Ordering::Relaxed)),
+                io_metrics: self.io_metrics.clone(),
             }
         }
     }
@@ -56,19 +71,32 @@ mod inner {
     impl PolarsObjectStore {
         pub(crate) fn new_from_inner(
             store: Arc<dyn ObjectStore>,
+            multipart_store: Option<Arc<dyn MultipartStore>>,
             builder: PolarsObjectStoreBuilder,
         ) -> Self {
             let initial_store = store.clone();
             Self {
                 inner: Arc::new(Inner {
                     store: tokio::sync::Mutex::new(store),
+                    multipart_store: std::sync::Mutex::new(multipart_store),
                     builder,
                 }),
                 initial_store,
                 rebuilt: AtomicBool::new(false),
+                io_metrics: None,
             }
         }
 
+        /// Returns a handle to the same store that records its requests in `io_metrics`.
+        pub fn with_io_metrics(mut self, io_metrics: Arc<IOMetrics>) -> Self {
+            self.io_metrics = Some(io_metrics);
+            self
+        }
+
+        pub fn io_metrics(&self) -> Option<&IOMetrics> {
+            self.io_metrics.as_deref()
+        }
+
         /// Gets the underlying [`ObjectStore`] implementation.
         pub async fn to_dyn_object_store(&self) -> Arc<dyn ObjectStore> {
             if !self.rebuilt.load(std::sync::atomic::Ordering::Relaxed) {
@@ -78,6 +106,11 @@ mod inner {
             }
         }
 
+        /// Gets the [`MultipartStore`] interface of the underlying store, if it has one.
+        pub fn to_dyn_multipart_store(&self) -> Option<Arc<dyn MultipartStore>> {
+            self.inner.multipart_store.lock().unwrap().clone()
+        }
+
         pub async fn rebuild_inner(
             &self,
             from_version: &Arc<dyn ObjectStore>,
@@ -89,9 +122,12 @@ mod inner {
 
             // If this does not eq, then `inner` was already re-built by another thread.
             if Arc::ptr_eq(&*current_store, from_version) {
-                *current_store = self.inner.builder.clone().build_impl().await.map_err(|e| {
-                    e.wrap_msg(|e| format!("attempt to rebuild object store failed: {e}"))
-                })?;
+                let (store, multipart_store) =
+                    self.inner.builder.clone().build_impl().await.map_err(|e| {
+                        e.wrap_msg(|e| format!("attempt to rebuild object store failed: {e}"))
+                    })?;
+                *current_store = store;
+                *self.inner.multipart_store.lock().unwrap() = multipart_store;
             }
 
             Ok((*current_store).clone())
@@ -169,46 +205,56 @@ impl PolarsObjectStore {
     }
 
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
-        self.try_exec_rebuild_on_err(move |store| {
-            let range = range.clone();
-            let st = store.clone();
+        let n_requests = split_range(range.clone()).len();
+        let range_len = range.len();
 
-            async move {
-                let store = st;
-                let parts = split_range(range.clone());
+        let out = self
+            .try_exec_rebuild_on_err(move |store| {
+                let range = range.clone();
+                let st = store.clone();
 
-                if parts.len() == 1 {
-                    let out = tune_with_concurrency_budget(1, move || async move {
-                        store
-                            .get_range(path, range.start as u64..range.end as u64)
-                            .await
-                    })
-                    .await?;
+                async move {
+                    let store = st;
+                    let parts = split_range(range.clone());
 
-                    Ok(out)
-                } else {
-                    let parts = tune_with_concurrency_budget(
-                        parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
-                        || {
-                            Self::get_buffered_ranges_stream(&store, path, parts)
-                                .try_collect::<Vec<Bytes>>()
-                        },
-                    )
-                    .await?;
+                    if parts.len() == 1 {
+                        let out = tune_with_concurrency_budget(1, move || async move {
+                            store
+                                .get_range(path, range.start as u64..range.end as u64)
+                                .await
+                        })
+                        .await?;
 
-                    let mut combined = Vec::with_capacity(range.len());
+                        Ok(out)
+                    } else {
+                        let parts = tune_with_concurrency_budget(
+                            parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
+                            || {
+                                Self::get_buffered_ranges_stream(&store, path, parts)
+                                    .try_collect::<Vec<Bytes>>()
+                            },
+                        )
+                        .await?;
 
-                    for part in parts {
-                        combined.extend_from_slice(&part)
-                    }
+                        let mut combined = Vec::with_capacity(range.len());
+
+                        for part in parts {
+                            combined.extend_from_slice(&part)
+                        }
 
-                    assert_eq!(combined.len(), range.len());
+                        assert_eq!(combined.len(), range.len());
 
-                    PolarsResult::Ok(Bytes::from(combined))
+                        PolarsResult::Ok(Bytes::from(combined))
+                    }
                 }
-            }
-        })
-        .await
+            })
+            .await?;
+
+        if let Some(io_metrics) = self.io_metrics() {
+            io_metrics.record_requests(0, n_requests, range_len, range_len);
+        }
+
+        Ok(out)
     }
 
     /// Fetch byte ranges into a HashMap keyed by the range start. This will mutably sort the
@@ -230,88 +276,104 @@ impl PolarsObjectStore {
         let ranges_len = ranges.len();
         let (merged_ranges, merged_ends): (Vec<_>, Vec<_>) = merge_ranges(ranges).unzip();
 
-        self.try_exec_rebuild_on_err(|store| {
-            let st = store.clone();
-
-            async {
-                let store = st;
-                let mut out = PlHashMap::with_capacity(ranges_len);
+        let out = self
+            .try_exec_rebuild_on_err(|store| {
+                let st = store.clone();
 
-                let mut stream =
-                    Self::get_buffered_ranges_stream(&store, path, merged_ranges.iter().cloned());
-
-                tune_with_concurrency_budget(
-                    merged_ranges.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
-                    || async {
-                        let mut len = 0;
-                        let mut current_offset = 0;
-                        let mut ends_iter = merged_ends.iter();
-
-                        let mut splitted_parts = vec![];
+                async {
+                    let store = st;
+                    let mut out = PlHashMap::with_capacity(ranges_len);
 
-                        while let Some(bytes) = stream.try_next().await? {
-                            len += bytes.len();
-                            let end = *ends_iter.next().unwrap();
+                    let mut stream = Self::get_buffered_ranges_stream(
+                        &store,
+                        path,
+                        merged_ranges.iter().cloned(),
+                    );
 
-                            if end == 0 {
-                                splitted_parts.push(bytes);
-                                continue;
-                            }
+                    tune_with_concurrency_budget(
+                        merged_ranges.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
+                        || async {
+                            let mut len = 0;
+                            let mut current_offset = 0;
+                            let mut ends_iter = merged_ends.iter();
 
-                            let full_range = ranges[current_offset..end]
-                                .iter()
-                                .cloned()
-                                .reduce(|l, r| l.start.min(r.start)..l.end.max(r.end))
-                                .unwrap();
+                            let mut splitted_parts = vec![];
 
-                            let bytes = if splitted_parts.is_empty() {
-                                bytes
-                            } else {
-                                let mut out = Vec::with_capacity(full_range.len());
+                            while let Some(bytes) = stream.try_next().await? {
+                                len += bytes.len();
+                                let end = *ends_iter.next().unwrap();
 
-                                for x in splitted_parts.drain(..) {
-                                    out.extend_from_slice(&x);
+                                if end == 0 {
+                                    splitted_parts.push(bytes);
+                                    continue;
                                 }
 
-                                out.extend_from_slice(&bytes);
-                                Bytes::from(out)
-                            };
-
-                            assert_eq!(bytes.len(), full_range.len());
-
-                            let bytes = MemSlice::from_bytes(bytes);
-
-                            for range in &ranges[current_offset..end] {
-                                let mem_slice = bytes.slice(
-                                    range.start - full_range.start..range.end - full_range.start,
-                                );
-
-                                match out.raw_entry_mut().from_key(&range.start) {
-                                    RawEntryMut::Vacant(slot) => {
-                                        slot.insert(range.start, mem_slice);
-                                    },
-                                    RawEntryMut::Occupied(mut slot) => {
-                                        if slot.get_mut().len() < mem_slice.len() {
-                                            *slot.get_mut() = mem_slice;
-                                        }
-                                    },
+                                let full_range = ranges[current_offset..end]
+                                    .iter()
+                                    .cloned()
+                                    .reduce(|l, r| l.start.min(r.start)..l.end.max(r.end))
+                                    .unwrap();
+
+                                let bytes = if splitted_parts.is_empty() {
+                                    bytes
+                                } else {
+                                    let mut out = Vec::with_capacity(full_range.len());
+
+                                    for x in splitted_parts.drain(..) {
+                                        out.extend_from_slice(&x);
+                                    }
+
+                                    out.extend_from_slice(&bytes);
+                                    Bytes::from(out)
+                                };
+
+                                assert_eq!(bytes.len(), full_range.len());
+
+                                let bytes = MemSlice::from_bytes(bytes);
+
+                                for range in &ranges[current_offset..end] {
+                                    let mem_slice = bytes.slice(
+                                        range.start - full_range.start
+                                            ..range.end - full_range.start,
+                                    );
+
+                                    match out.raw_entry_mut().from_key(&range.start) {
+                                        RawEntryMut::Vacant(slot) => {
+                                            slot.insert(range.start, mem_slice);
+                                        },
+                                        RawEntryMut::Occupied(mut slot) => {
+                                            if slot.get_mut().len() < mem_slice.len() {
+                                                *slot.get_mut() = mem_slice;
+                                            }
+                                        },
+                                    }
                                 }
+
+                                current_offset = end;
                             }
 
-                            current_offset = end;
-                        }
+                            assert!(splitted_parts.is_empty());
 
-                        assert!(splitted_parts.is_empty());
+                            PolarsResult::Ok(pl_async::Size::from(len as u64))
+                        },
+                    )
+                    .await?;
 
-                        PolarsResult::Ok(pl_async::Size::from(len as u64))
-                    },
-                )
-                .await?;
+                    Ok(out)
+                }
+            })
+            .await?;
+
+        if let Some(io_metrics) = self.io_metrics() {
+            io_metrics.record_requests(
+                0,
+                merged_ranges.len(),
+                merged_ranges.iter().map(|x| x.len()).sum(),
+                ranges.iter().map(|x| x.len()).sum(),
+            );
+        }
 
-                Ok(out)
-            }
-        })
-        .await
+        Ok(out)
     }
 
     pub async fn download(&self, path: &Path, file: &mut tokio::fs::File) -> PolarsResult<()> {
@@ -333,7 +395,9 @@ impl PolarsObjectStore {
                     .map(|x| split_range(0..x as usize))
                     .filter(|x| x.len() > 1);
 
-                if let Some(parts) = parts {
+                let n_parts = parts.as_ref().map_or(0, |x| x.len());
+
+                let len = if let Some(parts) = parts {
                     tune_with_concurrency_budget(
                         parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
                         || async {
@@ -349,12 +413,15 @@ impl PolarsObjectStore {
                             PolarsResult::Ok(pl_async::Size::from(len as u64))
                         },
                     )
-                    .await?
+                    .await?;
+
+                    opt_size.unwrap() as usize
                 } else {
+                    let mut len = 0;
+
                     tune_with_concurrency_budget(1, || async {
                         let mut stream = store.get(path).await?.into_stream();
 
-                        let mut len = 0;
                         while let Some(bytes) = stream.try_next().await? {
                             len += bytes.len();
                             file.write_all(&bytes).await?;
@@ -362,13 +429,19 @@ impl PolarsObjectStore {
 
                         PolarsResult::Ok(pl_async::Size::from(len as u64))
                     })
-                    .await?
+                    .await?;
+
+                    len
                 };
 
                 // Dropping is delayed for tokio async files so we need to explicitly
                 // flush here (https://github.com/tokio-rs/tokio/issues/2307#issuecomment-596336451).
                 file.sync_all().await.map_err(PolarsError::from)?;
 
+                if let Some(io_metrics) = self.io_metrics() {
+                    io_metrics.record_requests(usize::from(n_parts == 0), n_parts, len, len);
+                }
+
                 Ok(())
             }
         })
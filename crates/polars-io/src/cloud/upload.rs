@@ -0,0 +1,511 @@
+//! Retrying of uploads to object stores.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use object_store::multipart::{MultipartStore, PartId};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartId, MultipartUpload, ObjectMeta, ObjectStore,
+    PutMultipartOptions, PutOptions, PutPayload, PutResult, UploadPart,
+};
+use polars_core::config;
+
+use super::CloudUploadOptions;
+
+/// Whether a failed request may succeed when it is retried.
+///
+/// `Generic` errors are also returned for invalid requests and rejected credentials, so they are
+/// only considered transient if they were caused by a timeout or a broken connection.
+fn is_transient(err: &object_store::Error) -> bool {
+    use std::error::Error;
+
+    let source = match err {
+        object_store::Error::JoinError { .. } => return true,
+        object_store::Error::Generic { source, .. } => source.as_ref() as &(dyn Error + 'static),
+        _ => return false,
+    };
+
+    std::iter::successors(Some(source), |&e| e.source()).any(|e| {
+        if let Some(e) = e.downcast_ref::<reqwest::Error>() {
+            e.is_timeout() || e.is_connect()
+        } else if let Some(e) = e.downcast_ref::<std::io::Error>() {
+            use std::io::ErrorKind;
+
+            matches!(
+                e.kind(),
+                ErrorKind::TimedOut
+                    | ErrorKind::Interrupted
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::UnexpectedEof
+            )
+        } else {
+            false
+        }
+    })
+}
+
+/// Runs `func` until it succeeds, fails with a non-transient error or runs out of retries.
+async fn with_retries<F, Fut, O>(
+    options: &CloudUploadOptions,
+    mut func: F,
+) -> object_store::Result<O>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = object_store::Result<O>>,
+{
+    let mut backoff = Duration::from_millis(options.retry_backoff_ms);
+    let mut n_retries = 0;
+
+    loop {
+        match func().await {
+            Err(e) if n_retries < options.max_retries && is_transient(&e) => {
+                if config::verbose() {
+                    eprintln!(
+                        "[RetryUploadStore]: retrying upload request after error \
+                        (attempt {}/{}): {e}",
+                        n_retries + 1,
+                        options.max_retries
+                    );
+                }
+
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                n_retries += 1;
+            },
+            out => return out,
+        }
+    }
+}
+
+/// [`ObjectStore`] that retries the uploads to the inner store on transient errors.
+///
+/// If the store is also a [`MultipartStore`], every part of a multipart upload is retried on its
+/// own, so that a failing part does not require re-uploading the whole file. Requests other than
+/// uploads are passed through unchanged.
+pub struct RetryUploadStore {
+    inner: Arc<dyn ObjectStore>,
+    multipart_store: Option<Arc<dyn MultipartStore>>,
+    options: CloudUploadOptions,
+}
+
+impl RetryUploadStore {
+    /// `multipart_store` must refer to the same store as `inner`.
+    pub fn new(
+        inner: Arc<dyn ObjectStore>,
+        multipart_store: Option<Arc<dyn MultipartStore>>,
+        options: CloudUploadOptions,
+    ) -> Self {
+        Self {
+            inner,
+            multipart_store,
+            options,
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryUploadStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryUploadStore")
+            .field("inner", &self.inner)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Display for RetryUploadStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RetryUploadStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for RetryUploadStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        with_retries(&self.options, || {
+            self.inner.put_opts(location, payload.clone(), opts.clone())
+        })
+        .await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOptions,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        // The low-level multipart API does not take any attributes or tags.
+        let Some(store) = self
+            .multipart_store
+            .as_ref()
+            .filter(|_| opts == PutMultipartOptions::default())
+        else {
+            return with_retries(&self.options, || {
+                self.inner.put_multipart_opts(location, opts.clone())
+            })
+            .await;
+        };
+
+        let id = with_retries(&self.options, || store.create_multipart(location)).await?;
+
+        Ok(Box::new(RetryMultipartUpload {
+            store: store.clone(),
+            path: Arc::new(location.clone()),
+            id: Arc::new(id),
+            options: Arc::new(self.options.clone()),
+            parts: Default::default(),
+        }))
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn head(&self, location: &Path) -> object_store::Result<ObjectMeta> {
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+/// Multipart upload that retries every part individually.
+struct RetryMultipartUpload {
+    store: Arc<dyn MultipartStore>,
+    path: Arc<Path>,
+    id: Arc<MultipartId>,
+    options: Arc<CloudUploadOptions>,
+    /// Indexed by part index, `None` until the part has been uploaded.
+    parts: Arc<Mutex<Vec<Option<PartId>>>>,
+}
+
+impl std::fmt::Debug for RetryMultipartUpload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryMultipartUpload")
+            .field("path", &self.path)
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for RetryMultipartUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let part_idx = {
+            let mut parts = self.parts.lock().unwrap();
+            parts.push(None);
+            parts.len() - 1
+        };
+
+        let store = self.store.clone();
+        let path = self.path.clone();
+        let id = self.id.clone();
+        let options = self.options.clone();
+        let parts = self.parts.clone();
+
+        Box::pin(async move {
+            let part_id = with_retries(&options, || {
+                store.put_part(&path, &id, part_idx, data.clone())
+            })
+            .await?;
+
+            parts.lock().unwrap()[part_idx] = Some(part_id);
+
+            Ok(())
+        })
+    }
+
+    async fn complete(&mut self) -> object_store::Result<PutResult> {
+        let parts = self
+            .parts
+            .lock()
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| object_store::Error::Generic {
+                store: "RetryUploadStore",
+                source: "multipart upload completed before all parts finished uploading".into(),
+            })?;
+
+        with_retries(&self.options, || {
+            self.store
+                .complete_multipart(&self.path, &self.id, parts.clone())
+        })
+        .await
+    }
+
+    async fn abort(&mut self) -> object_store::Result<()> {
+        self.store.abort_multipart(&self.path, &self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use object_store::memory::InMemory;
+
+    use super::*;
+    use crate::cloud::BlockingCloudWriter;
+    use crate::pl_async::get_runtime;
+
+    /// In-memory store that fails the first `n_failures` upload requests of every part / file.
+    #[derive(Debug)]
+    struct FaultInjectingStore {
+        inner: InMemory,
+        n_failures: usize,
+        /// Whether the injected errors look like a broken connection.
+        transient: bool,
+        /// Number of failed requests per (part index + 1), index 0 is used for single puts.
+        failures: Mutex<Vec<usize>>,
+        n_put_part_calls: AtomicUsize,
+    }
+
+    impl FaultInjectingStore {
+        fn new(n_failures: usize) -> Self {
+            Self {
+                inner: InMemory::new(),
+                n_failures,
+                transient: true,
+                failures: Default::default(),
+                n_put_part_calls: AtomicUsize::new(0),
+            }
+        }
+
+        fn maybe_fail(&self, key: usize) -> object_store::Result<()> {
+            let mut failures = self.failures.lock().unwrap();
+            if failures.len() <= key {
+                failures.resize(key + 1, 0);
+            }
+
+            if failures[key] < self.n_failures {
+                failures[key] += 1;
+                let source = if self.transient {
+                    std::io::Error::new(std::io::ErrorKind::ConnectionReset, "injected fault")
+                        .into()
+                } else {
+                    "injected fault".into()
+                };
+                return Err(object_store::Error::Generic {
+                    store: "FaultInjectingStore",
+                    source,
+                });
+            }
+
+            Ok(())
+        }
+    }
+
+    impl std::fmt::Display for FaultInjectingStore {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "FaultInjectingStore")
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for FaultInjectingStore {
+        async fn put_opts(
+            &self,
+            location: &Path,
+            payload: PutPayload,
+            opts: PutOptions,
+        ) -> object_store::Result<PutResult> {
+            self.maybe_fail(0)?;
+            self.inner.put_opts(location, payload, opts).await
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            location: &Path,
+            opts: PutMultipartOptions,
+        ) -> object_store::Result<Box<dyn MultipartUpload>> {
+            self.inner.put_multipart_opts(location, opts).await
+        }
+
+        async fn get_opts(
+            &self,
+            location: &Path,
+            options: GetOptions,
+        ) -> object_store::Result<GetResult> {
+            self.inner.get_opts(location, options).await
+        }
+
+        async fn delete(&self, location: &Path) -> object_store::Result<()> {
+            self.inner.delete(location).await
+        }
+
+        fn list(
+            &self,
+            prefix: Option<&Path>,
+        ) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+            self.inner.list(prefix)
+        }
+
+        async fn list_with_delimiter(
+            &self,
+            prefix: Option<&Path>,
+        ) -> object_store::Result<ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+    }
+
+    #[async_trait]
+    impl MultipartStore for FaultInjectingStore {
+        async fn create_multipart(&self, path: &Path) -> object_store::Result<MultipartId> {
+            self.inner.create_multipart(path).await
+        }
+
+        async fn put_part(
+            &self,
+            path: &Path,
+            id: &MultipartId,
+            part_idx: usize,
+            data: PutPayload,
+        ) -> object_store::Result<PartId> {
+            self.n_put_part_calls.fetch_add(1, Ordering::Relaxed);
+            self.maybe_fail(part_idx + 1)?;
+            self.inner.put_part(path, id, part_idx, data).await
+        }
+
+        async fn complete_multipart(
+            &self,
+            path: &Path,
+            id: &MultipartId,
+            parts: Vec<PartId>,
+        ) -> object_store::Result<PutResult> {
+            self.inner.complete_multipart(path, id, parts).await
+        }
+
+        async fn abort_multipart(&self, path: &Path, id: &MultipartId) -> object_store::Result<()> {
+            self.inner.abort_multipart(path, id).await
+        }
+    }
+
+    fn write_with_faults(
+        store: &Arc<FaultInjectingStore>,
+        options: CloudUploadOptions,
+        data: &[u8],
+    ) -> std::io::Result<()> {
+        use std::io::Write;
+
+        let retry_store = Arc::new(RetryUploadStore::new(
+            store.clone(),
+            Some(store.clone()),
+            options.clone(),
+        ));
+
+        let mut writer =
+            BlockingCloudWriter::new_with_upload_options(retry_store, "data.bin".into(), &options)
+                .unwrap();
+
+        for chunk in data.chunks(3) {
+            writer.write_all(chunk)?;
+        }
+        writer.close()
+    }
+
+    fn read(store: &FaultInjectingStore) -> bytes::Bytes {
+        get_runtime()
+            .block_on(async { store.inner.get(&"data.bin".into()).await?.bytes().await })
+            .unwrap()
+    }
+
+    fn is_missing(store: &FaultInjectingStore) -> bool {
+        get_runtime()
+            .block_on(store.inner.head(&"data.bin".into()))
+            .is_err()
+    }
+
+    fn options(chunk_size: usize, max_retries: usize) -> CloudUploadOptions {
+        CloudUploadOptions {
+            chunk_size: Some(chunk_size),
+            max_concurrency: 2,
+            max_retries,
+            retry_backoff_ms: 1,
+        }
+    }
+
+    #[test]
+    fn test_multipart_upload_retries_failed_parts() {
+        let data = (0..100u8).collect::<Vec<_>>();
+        let store = Arc::new(FaultInjectingStore::new(2));
+
+        write_with_faults(&store, options(10, 2), &data).unwrap();
+
+        assert_eq!(read(&store).as_ref(), data.as_slice());
+        // Every part is attempted 3 times, parts that succeeded are not uploaded again.
+        assert_eq!(store.n_put_part_calls.load(Ordering::Relaxed), 10 * 3);
+    }
+
+    #[test]
+    fn test_multipart_upload_fails_after_max_retries() {
+        let data = (0..100u8).collect::<Vec<_>>();
+        let store = Arc::new(FaultInjectingStore::new(2));
+
+        assert!(write_with_faults(&store, options(10, 1), &data).is_err());
+        assert!(is_missing(&store));
+    }
+
+    #[test]
+    fn test_single_put_retries() {
+        let data = (0..5u8).collect::<Vec<_>>();
+        let store = Arc::new(FaultInjectingStore::new(1));
+
+        write_with_faults(&store, options(10, 1), &data).unwrap();
+
+        assert_eq!(read(&store).as_ref(), data.as_slice());
+        assert_eq!(store.n_put_part_calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_non_transient_errors_are_not_retried() {
+        let data = (0..100u8).collect::<Vec<_>>();
+        let store = Arc::new(FaultInjectingStore {
+            transient: false,
+            ..FaultInjectingStore::new(1)
+        });
+
+        assert!(write_with_faults(&store, options(10, 2), &data).is_err());
+        assert!(is_missing(&store));
+        assert!(store.n_put_part_calls.load(Ordering::Relaxed) <= 10);
+    }
+}
@@ -0,0 +1,229 @@
+//! Transcoding between UTF-8 and the other [`CsvEncoding`]s.
+//!
+//! Files in other encodings are transcoded to UTF-8 before parsing, the parser itself only
+//! handles UTF-8.
+use polars_error::{PolarsResult, polars_bail, polars_err};
+
+use super::CsvEncoding;
+
+/// Characters of the 0x80..=0x9F range of Windows-1252. The bytes that are undefined in
+/// Windows-1252 map to the C1 control characters, as in ISO-8859-1.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+const UTF16_LE_BOM: [u8; 2] = [0xFF, 0xFE];
+const UTF16_BE_BOM: [u8; 2] = [0xFE, 0xFF];
+const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+impl CsvEncoding {
+    /// Whether data in this encoding is parsed without transcoding.
+    pub fn is_utf8(&self) -> bool {
+        matches!(self, Self::Utf8 | Self::LossyUtf8)
+    }
+
+    /// The encoding of `bytes`, which differs from `self` if `bytes` starts with a UTF-16 byte
+    /// order mark. A byte order mark is not detected for the single byte encodings, where it is
+    /// valid text.
+    pub fn detect(self, bytes: &[u8]) -> Self {
+        match self {
+            Self::Latin1 | Self::Windows1252 => self,
+            Self::Utf8 | Self::LossyUtf8 | Self::Utf16Le | Self::Utf16Be => {
+                if bytes.starts_with(&UTF16_LE_BOM) {
+                    Self::Utf16Le
+                } else if bytes.starts_with(&UTF16_BE_BOM) {
+                    Self::Utf16Be
+                } else {
+                    self
+                }
+            },
+        }
+    }
+
+    /// Byte order mark that is written at the start of a file with this encoding.
+    pub fn bom(&self) -> &'static [u8] {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 => &UTF8_BOM,
+            Self::Utf16Le => &UTF16_LE_BOM,
+            Self::Utf16Be => &UTF16_BE_BOM,
+            Self::Latin1 | Self::Windows1252 => &[],
+        }
+    }
+
+    /// Encodes the UTF-8 `bytes` in this encoding and appends them to `out`.
+    pub fn encode(&self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        if self.is_utf8() {
+            out.extend_from_slice(bytes);
+            return Ok(());
+        }
+
+        let s = simdutf8::basic::from_utf8(bytes)
+            .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?;
+
+        match self {
+            Self::Utf8 | Self::LossyUtf8 => unreachable!(),
+            Self::Latin1 | Self::Windows1252 if s.is_ascii() => out.extend_from_slice(bytes),
+            Self::Latin1 => {
+                out.reserve(s.len());
+                for c in s.chars() {
+                    let Ok(b) = u8::try_from(c) else {
+                        polars_bail!(ComputeError: "character {c:?} cannot be encoded as latin-1")
+                    };
+                    out.push(b);
+                }
+            },
+            Self::Windows1252 => {
+                out.reserve(s.len());
+                for c in s.chars() {
+                    let b = match c {
+                        '\0'..='\u{7F}' | '\u{A0}'..='\u{FF}' => c as u8,
+                        c => match WINDOWS_1252_HIGH.iter().position(|&x| x == c) {
+                            Some(i) => 0x80 + i as u8,
+                            None => polars_bail!(
+                                ComputeError: "character {c:?} cannot be encoded as windows-1252"
+                            ),
+                        },
+                    };
+                    out.push(b);
+                }
+            },
+            Self::Utf16Le => {
+                out.reserve(2 * s.len());
+                out.extend(s.encode_utf16().flat_map(u16::to_le_bytes));
+            },
+            Self::Utf16Be => {
+                out.reserve(2 * s.len());
+                out.extend(s.encode_utf16().flat_map(u16::to_be_bytes));
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// Transcode `bytes` to UTF-8 if they are not UTF-8 encoded according to `encoding` and the byte
+/// order mark, otherwise simply return them. An `out` vec must be given for ownership of the
+/// transcoded data.
+pub fn maybe_decode_bytes<'a>(
+    bytes: &'a [u8],
+    encoding: CsvEncoding,
+    out: &'a mut Vec<u8>,
+) -> PolarsResult<&'a [u8]> {
+    assert!(out.is_empty());
+
+    let lossy = matches!(encoding, CsvEncoding::LossyUtf8);
+
+    match encoding.detect(bytes) {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => return Ok(bytes),
+        CsvEncoding::Latin1 | CsvEncoding::Windows1252 if bytes.is_ascii() => return Ok(bytes),
+        CsvEncoding::Latin1 => {
+            out.reserve(bytes.len() + bytes.len() / 2);
+            let mut buf = [0; 4];
+            for &b in bytes {
+                out.extend_from_slice(char::from(b).encode_utf8(&mut buf).as_bytes());
+            }
+        },
+        CsvEncoding::Windows1252 => {
+            out.reserve(bytes.len() + bytes.len() / 2);
+            let mut buf = [0; 4];
+            for &b in bytes {
+                let c = match b {
+                    0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(b - 0x80)],
+                    b => char::from(b),
+                };
+                out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+        },
+        encoding @ (CsvEncoding::Utf16Le | CsvEncoding::Utf16Be) => {
+            let bytes = bytes.strip_prefix(encoding.bom()).unwrap_or(bytes);
+
+            if bytes.len() % 2 != 0 && !lossy {
+                polars_bail!(ComputeError: "invalid utf-16 sequence: odd number of bytes")
+            }
+
+            let units = bytes.chunks_exact(2).map(|b| {
+                let b = [b[0], b[1]];
+                if encoding == CsvEncoding::Utf16Le {
+                    u16::from_le_bytes(b)
+                } else {
+                    u16::from_be_bytes(b)
+                }
+            });
+
+            out.reserve(bytes.len());
+            let mut buf = [0; 4];
+            for c in char::decode_utf16(units) {
+                let c = match c {
+                    Ok(c) => c,
+                    Err(_) if lossy => char::REPLACEMENT_CHARACTER,
+                    Err(_) => polars_bail!(ComputeError: "invalid utf-16 sequence"),
+                };
+                out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+        },
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<String> {
+        let mut out = vec![];
+        let bytes = maybe_decode_bytes(bytes, encoding, &mut out)?;
+        Ok(String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    fn encode(s: &str, encoding: CsvEncoding) -> PolarsResult<Vec<u8>> {
+        let mut out = vec![];
+        encoding.encode(s.as_bytes(), &mut out)?;
+        Ok(out)
+    }
+
+    #[test]
+    fn test_single_byte_encodings() -> PolarsResult<()> {
+        assert_eq!(decode(b"a,\xe9\x80", CsvEncoding::Latin1)?, "a,é\u{80}");
+        assert_eq!(decode(b"a,\xe9\x80", CsvEncoding::Windows1252)?, "a,é€");
+        // Not a byte order mark in single byte encodings.
+        assert_eq!(decode(b"\xff\xfea", CsvEncoding::Latin1)?, "ÿþa");
+
+        assert_eq!(encode("a,é€", CsvEncoding::Windows1252)?, b"a,\xe9\x80");
+        assert_eq!(encode("a,é", CsvEncoding::Latin1)?, b"a,\xe9");
+        assert!(encode("€", CsvEncoding::Latin1).is_err());
+        assert!(encode("✓", CsvEncoding::Windows1252).is_err());
+
+        for b in 0..=u8::MAX {
+            let s = decode(&[b], CsvEncoding::Windows1252)?;
+            assert_eq!(encode(&s, CsvEncoding::Windows1252)?, [b]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_utf16() -> PolarsResult<()> {
+        let s = "a,b\né,𝄞\n";
+
+        for encoding in [CsvEncoding::Utf16Le, CsvEncoding::Utf16Be] {
+            let mut bytes = encoding.bom().to_vec();
+            bytes.extend(encode(s, encoding)?);
+
+            assert_eq!(decode(&bytes[2..], encoding)?, s);
+            // Detected from the byte order mark.
+            assert_eq!(decode(&bytes, CsvEncoding::Utf8)?, s);
+            assert_eq!(decode(&bytes, CsvEncoding::Utf16Le)?, s);
+            assert_eq!(decode(&bytes, CsvEncoding::Utf16Be)?, s);
+        }
+
+        // Unpaired surrogate.
+        assert!(decode(&[0x00, 0xD8], CsvEncoding::Utf16Le).is_err());
+        assert!(decode(&[0x61, 0x00, 0x62], CsvEncoding::Utf16Le).is_err());
+
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@
 //! ```
 
 pub mod buffer;
+mod encoding;
 mod options;
 mod parser;
 mod read_impl;
@@ -25,7 +26,9 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
+pub use encoding::maybe_decode_bytes;
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+pub(crate) use parser::is_comment_line;
 pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
@@ -334,11 +334,19 @@ impl CsvParseOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum CsvEncoding {
-    /// Utf8 encoding.
+    /// Utf8 encoding. Files starting with a UTF-16 byte order mark are read as UTF-16.
     #[default]
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1 encoding.
+    Latin1,
+    /// Windows-1252 encoding, a superset of ISO-8859-1 that is common on Windows.
+    Windows1252,
+    /// UTF-16 little-endian encoding. A big-endian byte order mark takes precedence.
+    Utf16Le,
+    /// UTF-16 big-endian encoding. A little-endian byte order mark takes precedence.
+    Utf16Be,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
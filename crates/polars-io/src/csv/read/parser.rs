@@ -8,11 +8,12 @@ use polars_utils::plpath::PlPathRef;
 use polars_utils::select::select_unpredictable;
 use rayon::prelude::*;
 
-use super::CsvParseOptions;
 use super::buffer::Buffer;
+use super::encoding::maybe_decode_bytes;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
+use super::{CsvEncoding, CsvParseOptions};
 use crate::prelude::_csv_read_internal::find_starting_point;
 use crate::utils::compression::maybe_decompress_bytes;
 
@@ -25,6 +26,7 @@ pub fn count_rows(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    encoding: CsvEncoding,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -47,6 +49,8 @@ pub fn count_rows(
     let mmap = MMapSemaphore::new_from_file(&file).unwrap();
     let owned = &mut vec![];
     let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+    let decoded = &mut vec![];
+    let reader_bytes = maybe_decode_bytes(reader_bytes, encoding, decoded)?;
 
     count_rows_from_slice_par(
         reader_bytes,
@@ -188,7 +192,7 @@ pub(super) fn skip_bom(input: &[u8]) -> &[u8] {
 ///
 /// This function is used during CSV parsing to determine whether a line should be ignored based on its starting characters.
 #[inline]
-pub(super) fn is_comment_line(line: &[u8], comment_prefix: Option<&CommentPrefix>) -> bool {
+pub(crate) fn is_comment_line(line: &[u8], comment_prefix: Option<&CommentPrefix>) -> bool {
     match comment_prefix {
         Some(CommentPrefix::Single(c)) => line.first() == Some(c),
         Some(CommentPrefix::Multi(s)) => line.starts_with(s.as_bytes()),
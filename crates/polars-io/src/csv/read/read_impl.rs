@@ -13,6 +13,7 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::init_buffers;
+use super::encoding::maybe_decode_bytes;
 use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::parser::{
     CountLines, SplitLines, is_comment_line, parse_lines, skip_bom, skip_line_ending,
@@ -160,7 +161,6 @@ impl<'a> CoreReader<'a> {
         let separator = parse_options.separator;
 
         check_decimal_comma(parse_options.decimal_comma, separator)?;
-        #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -174,8 +174,15 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // Decompressing a prefix can split the code units of UTF-16.
+            let total_n_rows = n_rows
+                .filter(|_| {
+                    !matches!(
+                        parse_options.encoding,
+                        CsvEncoding::Utf16Le | CsvEncoding::Utf16Be
+                    )
+                })
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -187,6 +194,14 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        let mut decoded = vec![];
+        let is_decoded = maybe_decode_bytes(&reader_bytes, parse_options.encoding, &mut decoded)?
+            .as_ptr()
+            != reader_bytes.as_ptr();
+        if is_decoded {
+            reader_bytes = ReaderBytes::Owned(decoded.into());
+        }
+
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
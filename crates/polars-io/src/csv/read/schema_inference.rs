@@ -171,7 +171,12 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<'_, str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        // Other encodings are transcoded to UTF-8 before parsing.
+        CsvEncoding::Utf8
+        | CsvEncoding::Latin1
+        | CsvEncoding::Windows1252
+        | CsvEncoding::Utf16Le
+        | CsvEncoding::Utf16Be => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
         CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
@@ -3,6 +3,9 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::csv::read::CsvEncoding;
+use crate::utils::compression::ExternalCompression;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +15,8 @@ pub struct CsvWriterOptions {
     pub include_header: bool,
     pub batch_size: NonZeroUsize,
     pub serialize_options: SerializeOptions,
+    pub compression: ExternalCompression,
+    pub encoding: CsvEncoding,
 }
 
 impl Default for CsvWriterOptions {
@@ -21,6 +26,8 @@ impl Default for CsvWriterOptions {
             include_header: true,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default(),
+            compression: ExternalCompression::default(),
+            encoding: CsvEncoding::default(),
         }
     }
 }
@@ -10,6 +10,7 @@ use polars_error::polars_ensure;
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
+use crate::csv::read::CsvEncoding;
 use crate::csv::write::SerializeOptions;
 
 pub(crate) fn write<W: Write>(
@@ -17,6 +18,7 @@ pub(crate) fn write<W: Write>(
     df: &DataFrame,
     chunk_size: usize,
     options: &SerializeOptions,
+    encoding: CsvEncoding,
     n_threads: usize,
 ) -> PolarsResult<()> {
     for s in df.get_columns() {
@@ -193,7 +195,7 @@ pub(crate) fn write<W: Write>(
         }
 
         for (write_buffer, _) in &mut buffers {
-            writer.write_all(write_buffer)?;
+            write_encoded(writer, write_buffer, encoding)?;
             write_buffer.clear();
         }
 
@@ -207,6 +209,7 @@ pub(crate) fn write_header<W: Write>(
     writer: &mut W,
     names: &[&str],
     options: &SerializeOptions,
+    encoding: CsvEncoding,
 ) -> PolarsResult<()> {
     let mut header = Vec::new();
 
@@ -225,13 +228,27 @@ pub(crate) fn write_header<W: Write>(
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
-    writer.write_all(&header)?;
+    write_encoded(writer, &header, encoding)
+}
+
+/// Writes the BOM of `encoding` to `writer`.
+pub(crate) fn write_bom<W: Write>(writer: &mut W, encoding: CsvEncoding) -> PolarsResult<()> {
+    writer.write_all(encoding.bom())?;
     Ok(())
 }
 
-/// Writes a UTF-8 BOM to `writer`.
-pub(crate) fn write_bom<W: Write>(writer: &mut W) -> PolarsResult<()> {
-    const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
-    writer.write_all(&BOM)?;
+/// Writes the UTF-8 `bytes` to `writer` in `encoding`.
+fn write_encoded<W: Write>(
+    writer: &mut W,
+    bytes: &[u8],
+    encoding: CsvEncoding,
+) -> PolarsResult<()> {
+    if encoding.is_utf8() {
+        writer.write_all(bytes)?;
+    } else {
+        let mut encoded = Vec::new();
+        encoding.encode(bytes, &mut encoded)?;
+        writer.write_all(&encoded)?;
+    }
     Ok(())
 }
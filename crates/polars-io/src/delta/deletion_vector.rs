@@ -0,0 +1,394 @@
+//! Decoding of Delta deletion vectors.
+//!
+//! A deletion vector is a 64-bit roaring bitmap (in the "portable" serialization format) of the
+//! row indexes within a data file that have been deleted. It is either stored inline in the
+//! transaction log as a z85-encoded string, or in a separate file that can contain several
+//! vectors, each framed as `<u32 size (BE)><data><u32 CRC-32 (BE)>`.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-format>
+use bytes::Bytes;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::plpath::PlPathRef;
+use serde::{Deserialize, Serialize};
+
+use crate::cloud::{CloudOptions, build_object_store, object_path_from_str};
+use crate::utils::table_files::local_path;
+
+/// Magic number of the "portable" 64-bit roaring bitmap serialization used by Delta.
+const PORTABLE_ROARING_BITMAP_MAGIC: u32 = 1681511377;
+
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const SERIAL_COOKIE: u32 = 12347;
+const NO_OFFSET_THRESHOLD: usize = 4;
+/// Containers with more than this many values are stored as a bitmap.
+const MAX_ARRAY_CONTAINER_SIZE: usize = 4096;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The `deletionVector` field of an `add` / `remove` action in the Delta log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// * `u`: relative path of a file, derived from a z85-encoded UUID with an optional prefix.
+    /// * `i`: inline, z85-encoded bitmap.
+    /// * `p`: absolute path of a file.
+    pub storage_type: PlSmallStr,
+    pub path_or_inline_dv: PlSmallStr,
+    /// Byte offset of the vector within the file. Not set for inline vectors.
+    #[serde(default)]
+    pub offset: Option<i32>,
+    /// Size of the serialized bitmap in bytes.
+    pub size_in_bytes: i32,
+    /// Number of rows the vector deletes.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Uniquely identifies the vector. Together with the data file path this forms the logical
+    /// identity of a file within the log.
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    /// Returns the absolute path of the file containing the vector, or `None` if the vector is
+    /// stored inline.
+    pub fn absolute_path(&self, table_root: &str) -> PolarsResult<Option<String>> {
+        let table_root = table_root.trim_end_matches('/');
+
+        match self.storage_type.as_str() {
+            "i" => Ok(None),
+            "p" => Ok(Some(self.path_or_inline_dv.to_string())),
+            "u" => {
+                let s = self.path_or_inline_dv.as_str();
+                polars_ensure!(
+                    s.len() >= 20,
+                    ComputeError: "invalid deletion vector path: {}", s
+                );
+                let (prefix, encoded_uuid) = s.split_at(s.len() - 20);
+                let uuid = z85_decode(encoded_uuid)?;
+                let uuid = format_uuid(&uuid);
+
+                Ok(Some(if prefix.is_empty() {
+                    format!("{table_root}/deletion_vector_{uuid}.bin")
+                } else {
+                    format!("{table_root}/{prefix}/deletion_vector_{uuid}.bin")
+                }))
+            },
+            v => polars_bail!(ComputeError: "unknown deletion vector storage type: {}", v),
+        }
+    }
+}
+
+/// Decodes an inline (`storageType = "i"`) deletion vector into the sorted row indexes it deletes.
+pub fn decode_inline_deletion_vector(data: &str, size_in_bytes: usize) -> PolarsResult<Vec<u64>> {
+    let bytes = z85_decode(data)?;
+
+    polars_ensure!(
+        bytes.len() >= size_in_bytes,
+        ComputeError:
+        "inline deletion vector is shorter than its size: {} < {}",
+        bytes.len(), size_in_bytes,
+    );
+
+    decode_portable_roaring_bitmap(&bytes[..size_in_bytes])
+}
+
+/// Reads a deletion vector stored at `offset` in the file at `path` (`storageType = "u" | "p"`)
+/// into the sorted row indexes it deletes.
+pub async fn read_deletion_vector_file(
+    path: &str,
+    offset: usize,
+    size_in_bytes: usize,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<u64>> {
+    let range = offset..offset + 4 + size_in_bytes + 4;
+    let path = PlPathRef::new(path);
+
+    let framed = match local_path(path) {
+        Some(path) => {
+            use std::io::{Read, Seek, SeekFrom};
+
+            let mut file = polars_utils::open_file(path)?;
+            file.seek(SeekFrom::Start(range.start as u64))?;
+            let mut buf = vec![0; range.len()];
+            file.read_exact(&mut buf)?;
+            Bytes::from(buf)
+        },
+        None => {
+            let (cloud_location, store) =
+                build_object_store(path.to_str(), cloud_options, false).await?;
+            store
+                .get_range(&object_path_from_str(&cloud_location.prefix)?, range)
+                .await?
+        },
+    };
+
+    decode_framed_deletion_vector(&framed, size_in_bytes)
+}
+
+/// Decodes `<u32 size (BE)><data><u32 CRC-32 (BE)>`.
+fn decode_framed_deletion_vector(framed: &[u8], size_in_bytes: usize) -> PolarsResult<Vec<u64>> {
+    polars_ensure!(
+        framed.len() == 4 + size_in_bytes + 4,
+        ComputeError: "deletion vector out of bounds of its file"
+    );
+
+    let size = u32::from_be_bytes(framed[..4].try_into().unwrap()) as usize;
+    polars_ensure!(
+        size == size_in_bytes,
+        ComputeError:
+        "deletion vector size mismatch: descriptor: {}, file: {}",
+        size_in_bytes, size
+    );
+
+    let data = &framed[4..4 + size];
+    let expected_checksum = u32::from_be_bytes(framed[4 + size..].try_into().unwrap());
+    polars_ensure!(
+        crc32fast::hash(data) == expected_checksum,
+        ComputeError: "deletion vector checksum mismatch"
+    );
+
+    decode_portable_roaring_bitmap(data)
+}
+
+/// Decodes a z85-encoded string. The length of `s` must be a multiple of 5.
+pub(super) fn z85_decode(s: &str) -> PolarsResult<Vec<u8>> {
+    static DECODE: std::sync::LazyLock<[u8; 256]> = std::sync::LazyLock::new(|| {
+        let mut table = [u8::MAX; 256];
+        for (i, c) in Z85_ALPHABET.iter().enumerate() {
+            table[*c as usize] = i as u8;
+        }
+        table
+    });
+
+    let s = s.as_bytes();
+    polars_ensure!(
+        s.len() % 5 == 0,
+        ComputeError: "invalid z85 string length: {}", s.len()
+    );
+
+    let mut out = Vec::with_capacity(s.len() / 5 * 4);
+
+    for chunk in s.chunks_exact(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = DECODE[*c as usize];
+            polars_ensure!(
+                digit != u8::MAX,
+                ComputeError: "invalid z85 character: {:?}", *c as char
+            );
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid z85 string: value overflow"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    Ok(out)
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(36);
+    for (i, b) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        write!(out, "{b:02x}").unwrap();
+    }
+    out
+}
+
+/// Little-endian cursor over a byte slice that errors instead of panicking on truncated input.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            self.bytes.len() >= n,
+            ComputeError: "unexpected end of deletion vector data"
+        );
+        let (out, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Decodes the Delta "portable" 64-bit roaring bitmap format: a magic number, followed by a
+/// number of 32-bit roaring bitmaps, each prefixed by the high 32 bits of its values.
+fn decode_portable_roaring_bitmap(bytes: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut cursor = Cursor { bytes };
+
+    let magic = cursor.u32()?;
+    polars_ensure!(
+        magic == PORTABLE_ROARING_BITMAP_MAGIC,
+        ComputeError: "unsupported deletion vector format, magic number: {}", magic
+    );
+
+    let num_bitmaps = cursor.u64()?;
+    let mut out = vec![];
+
+    for _ in 0..num_bitmaps {
+        let high = (cursor.u32()? as u64) << 32;
+        decode_roaring_bitmap(&mut cursor, |low| out.push(high | low as u64))?;
+    }
+
+    Ok(out)
+}
+
+/// Decodes a 32-bit roaring bitmap in the standard portable serialization format.
+///
+/// Reference: <https://github.com/RoaringBitmap/RoaringFormatSpec>
+fn decode_roaring_bitmap(cursor: &mut Cursor, mut push: impl FnMut(u32)) -> PolarsResult<()> {
+    let cookie = cursor.u32()?;
+
+    let (num_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        let run_flags = cursor.take(num_containers.div_ceil(8))?;
+        (num_containers, Some(run_flags))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (cursor.u32()? as usize, None)
+    } else {
+        polars_bail!(ComputeError: "invalid roaring bitmap cookie: {}", cookie)
+    };
+
+    let mut headers = Vec::with_capacity(num_containers);
+    for _ in 0..num_containers {
+        let key = cursor.u16()?;
+        let cardinality = cursor.u16()? as usize + 1;
+        headers.push((key, cardinality));
+    }
+
+    // The offset header is only used for random access, we read the containers sequentially.
+    if run_flags.is_none() || num_containers >= NO_OFFSET_THRESHOLD {
+        cursor.take(4 * num_containers)?;
+    }
+
+    for (i, (key, cardinality)) in headers.into_iter().enumerate() {
+        let high = (key as u32) << 16;
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+        if is_run {
+            let num_runs = cursor.u16()?;
+            for _ in 0..num_runs {
+                let start = cursor.u16()? as u32;
+                let len = cursor.u16()? as u32;
+                for low in start..=start + len {
+                    push(high | low);
+                }
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_SIZE {
+            for _ in 0..cardinality {
+                push(high | cursor.u16()? as u32);
+            }
+        } else {
+            let words = cursor.take(8192)?;
+            for (word_idx, word) in words.chunks_exact(8).enumerate() {
+                let mut word = u64::from_le_bytes(word.try_into().unwrap());
+                while word != 0 {
+                    let bit = word.trailing_zeros();
+                    push(high | (word_idx as u32 * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        // Test vector from the z85 specification.
+        assert_eq!(
+            z85_decode("HelloWorld").unwrap(),
+            [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_deletion_vector_path() {
+        // Example from the Delta protocol specification.
+        let dv = DeletionVectorDescriptor {
+            storage_type: "u".into(),
+            path_or_inline_dv: "ab^-aqEH.-t@S}K{vb[*k^".into(),
+            offset: Some(4),
+            size_in_bytes: 40,
+            cardinality: 6,
+        };
+
+        assert_eq!(
+            dv.absolute_path("s3://mytable/").unwrap().unwrap(),
+            "s3://mytable/ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+        );
+        assert_eq!(dv.unique_id(), "uab^-aqEH.-t@S}K{vb[*k^@4");
+    }
+
+    #[test]
+    fn test_decode_roaring_bitmap() {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&PORTABLE_ROARING_BITMAP_MAGIC.to_le_bytes());
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+
+        // High bits 0: an array container (key 0) and a run container (key 1).
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&(SERIAL_COOKIE | (1 << 16)).to_le_bytes());
+        bytes.push(0b10);
+        bytes.extend_from_slice(&[0, 0, 2, 0]);
+        bytes.extend_from_slice(&[1, 0, 3, 0]);
+        bytes.extend_from_slice(&[1, 0, 5, 0, 9, 0]);
+        bytes.extend_from_slice(&[1, 0, 7, 0, 3, 0]);
+
+        // High bits 1: an array container without run containers.
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0, 0, 0]);
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&[4, 0]);
+
+        let out = decode_portable_roaring_bitmap(&bytes).unwrap();
+        assert_eq!(out, [1, 5, 9, 65543, 65544, 65545, 65546, (1 << 32) | 4]);
+
+        // Framed as stored in a deletion vector file.
+        let mut framed = vec![];
+        framed.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        framed.extend_from_slice(&bytes);
+        framed.extend_from_slice(&crc32fast::hash(&bytes).to_be_bytes());
+
+        assert_eq!(
+            decode_framed_deletion_vector(&framed, bytes.len()).unwrap(),
+            out
+        );
+
+        *framed.last_mut().unwrap() ^= 1;
+        assert!(decode_framed_deletion_vector(&framed, bytes.len()).is_err());
+    }
+}
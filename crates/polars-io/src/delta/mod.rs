@@ -0,0 +1,17 @@
+//! # Reading Delta Lake tables.
+//!
+//! The state of a Delta table is reconstructed from its transaction log (`_delta_log`): the latest
+//! Parquet checkpoint at or before the requested version is loaded, after which the JSON commits
+//! up to that version are replayed. The resulting [`DeltaSnapshot`] holds the table schema and the
+//! active data files, together with their partition values and deletion vectors.
+//!
+//! The partition values of the data files are taken from the log rather than from their paths, as
+//! the protocol does not require the paths to be Hive-partitioned.
+mod deletion_vector;
+mod models;
+mod snapshot;
+
+pub use deletion_vector::{
+    DeletionVectorDescriptor, decode_inline_deletion_vector, read_deletion_vector_file,
+};
+pub use snapshot::{DeltaDataFile, DeltaSnapshot, read_delta_snapshot};
@@ -0,0 +1,76 @@
+//! Actions of the Delta transaction log that are needed to reconstruct a snapshot for reading.
+//! Other actions (e.g. `commitInfo`, `txn`, `cdc`) are ignored.
+use polars_utils::aliases::PlHashMap;
+use serde::Deserialize;
+
+use super::deletion_vector::DeletionVectorDescriptor;
+
+/// A single line of a commit file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Action {
+    #[serde(default)]
+    pub add: Option<Add>,
+    #[serde(default)]
+    pub remove: Option<Remove>,
+    #[serde(default)]
+    pub meta_data: Option<Metadata>,
+    #[serde(default)]
+    pub protocol: Option<Protocol>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Add {
+    /// URI-encoded path, relative to the table root or absolute.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: PlHashMap<String, Option<String>>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Remove {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: PlHashMap<String, Option<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Protocol {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+impl Add {
+    /// Key identifying the logical file, an `add` is cancelled by a `remove` with the same key.
+    pub fn key(&self) -> (String, Option<String>) {
+        (
+            self.path.clone(),
+            self.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+        )
+    }
+}
+
+impl Remove {
+    pub fn key(&self) -> (String, Option<String>) {
+        (
+            self.path.clone(),
+            self.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+        )
+    }
+}
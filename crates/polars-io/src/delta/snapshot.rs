@@ -0,0 +1,563 @@
+use std::io::Cursor;
+use std::ops::RangeInclusive;
+
+use bytes::Bytes;
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::plpath::{PlPath, PlPathRef};
+
+use super::deletion_vector::DeletionVectorDescriptor;
+use super::models::{Action, Add, Metadata, Protocol};
+use crate::catalog::unity::schema::parse_type_json_str;
+use crate::cloud::CloudOptions;
+use crate::prelude::ParquetReader;
+use crate::utils::decode_json_response;
+use crate::utils::table_files::{local_path, read_files, resolve_absolute_path};
+use crate::{SerReader, expand_paths};
+
+/// Reader features that do not require any special handling when reading.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// The state of a Delta table at a specific version.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    pub table_root: PlPath,
+    pub version: i64,
+    /// Schema of the table, including the partition columns.
+    pub schema: SchemaRef,
+    pub partition_columns: Vec<PlSmallStr>,
+    /// The active data files, in the order they were added.
+    pub files: Vec<DeltaDataFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeltaDataFile {
+    /// Absolute path of the data file.
+    pub path: PlPath,
+    pub partition_values: PlHashMap<PlSmallStr, Option<PlSmallStr>>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+impl DeltaSnapshot {
+    /// Schema of the partition columns, or `None` if the table is not partitioned.
+    pub fn partition_schema(&self) -> PolarsResult<Option<SchemaRef>> {
+        if self.partition_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let schema = self
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let dtype = self.schema.try_get(name)?;
+                Ok(Field::new(name.clone(), dtype.clone()))
+            })
+            .collect::<PolarsResult<Schema>>()?;
+
+        Ok(Some(Arc::new(schema)))
+    }
+}
+
+/// Reads the snapshot of the Delta table at `table_path`, at `version` or the latest version.
+pub fn read_delta_snapshot(
+    table_path: PlPathRef<'_>,
+    version: Option<i64>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<DeltaSnapshot> {
+    let table_root = match local_path(table_path) {
+        Some(p) => PlPath::Local(p.into()),
+        None => PlPath::new(table_path.to_str().trim_end_matches('/')),
+    };
+    let log_dir = table_root.as_ref().join("_delta_log");
+
+    let listing = LogListing::try_new(log_dir.as_ref(), cloud_options)?;
+
+    let Some(latest_version) = listing.latest_version() else {
+        polars_bail!(
+            ComputeError:
+            "not a Delta table: no commits found in {}",
+            log_dir.display()
+        )
+    };
+
+    let version = match version {
+        Some(v) if v > latest_version => polars_bail!(
+            ComputeError:
+            "Delta table version {} does not exist, the latest version is {}",
+            v, latest_version
+        ),
+        Some(v) if v < 0 => polars_bail!(ComputeError: "invalid Delta table version: {}", v),
+        Some(v) => v,
+        None => latest_version,
+    };
+
+    let checkpoint = listing.checkpoint_for_version(version);
+    let commit_versions = checkpoint.map_or(0, |(v, _)| v + 1)..=version;
+
+    for v in commit_versions.clone() {
+        polars_ensure!(
+            listing.commits.contains(&v),
+            ComputeError:
+            "cannot reconstruct Delta table version {}: commit file for version {} is missing",
+            version, v
+        );
+    }
+
+    let mut replay = LogReplay::default();
+
+    if let Some((checkpoint_version, parts)) = checkpoint {
+        let paths = parts
+            .iter()
+            .map(|name| log_dir.as_ref().join(name))
+            .collect::<Vec<_>>();
+
+        for bytes in read_files(&paths, cloud_options)? {
+            replay.apply_checkpoint(bytes)?;
+        }
+
+        if polars_core::config::verbose() {
+            eprintln!(
+                "[read_delta_snapshot]: loaded checkpoint at version {checkpoint_version}, \
+                {} parts",
+                parts.len()
+            )
+        }
+    }
+
+    let paths = commit_paths(log_dir.as_ref(), commit_versions);
+
+    for bytes in read_files(&paths, cloud_options)? {
+        replay.apply_commit(&bytes)?;
+    }
+
+    replay.finish(table_root, version)
+}
+
+/// Files within `_delta_log` relevant for reading.
+struct LogListing {
+    commits: PlHashSet<i64>,
+    /// Version -> file names of the parts of a complete checkpoint.
+    checkpoints: PlHashMap<i64, Vec<String>>,
+}
+
+impl LogListing {
+    fn try_new(log_dir: PlPathRef<'_>, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let paths = expand_paths(&[log_dir.join("*")], true, cloud_options)?;
+
+        let mut commits = PlHashSet::new();
+        // Version -> (expected number of parts, file names)
+        let mut checkpoints: PlHashMap<i64, (usize, Vec<String>)> = PlHashMap::new();
+
+        for path in paths.iter() {
+            let Some(name) = path.to_str().rsplit(['/', '\\']).next() else {
+                continue;
+            };
+
+            let Some(version) = name.get(..20).and_then(|x| x.parse::<i64>().ok()) else {
+                continue;
+            };
+
+            match name[20..].split('.').collect::<Vec<_>>().as_slice() {
+                ["", "json"] => {
+                    commits.insert(version);
+                },
+                ["", "checkpoint", "parquet"] => {
+                    checkpoints.insert(version, (1, vec![name.to_string()]));
+                },
+                // Multi-part checkpoint: `<version>.checkpoint.<part>.<num_parts>.parquet`
+                ["", "checkpoint", _, num_parts, "parquet"] => {
+                    let Ok(num_parts) = num_parts.parse::<usize>() else {
+                        continue;
+                    };
+                    let entry = checkpoints.entry(version).or_insert((num_parts, vec![]));
+                    if entry.0 == num_parts {
+                        entry.1.push(name.to_string());
+                    }
+                },
+                // Note: V2 checkpoints (`<version>.checkpoint.<uuid>.{json,parquet}`) are not
+                // supported, we fall back to replaying the commits.
+                _ => {},
+            }
+        }
+
+        let checkpoints = checkpoints
+            .into_iter()
+            .filter(|(_, (num_parts, parts))| *num_parts == parts.len())
+            .map(|(version, (_, mut parts))| {
+                parts.sort_unstable();
+                (version, parts)
+            })
+            .collect();
+
+        Ok(Self {
+            commits,
+            checkpoints,
+        })
+    }
+
+    fn latest_version(&self) -> Option<i64> {
+        self.commits
+            .iter()
+            .chain(self.checkpoints.keys())
+            .copied()
+            .max()
+    }
+
+    /// The latest checkpoint at or before `version`.
+    fn checkpoint_for_version(&self, version: i64) -> Option<(i64, &[String])> {
+        self.checkpoints
+            .iter()
+            .filter(|(v, _)| **v <= version)
+            .max_by_key(|(v, _)| **v)
+            .map(|(v, parts)| (*v, parts.as_slice()))
+    }
+}
+
+fn commit_paths(log_dir: PlPathRef<'_>, versions: RangeInclusive<i64>) -> Vec<PlPath> {
+    versions
+        .map(|v| log_dir.join(format!("{v:020}.json")))
+        .collect()
+}
+
+#[derive(Default)]
+struct LogReplay {
+    /// (path, deletion vector id) -> add action.
+    files: PlIndexMap<(String, Option<String>), Add>,
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action) {
+        let Action {
+            add,
+            remove,
+            meta_data,
+            protocol,
+        } = action;
+
+        if let Some(remove) = remove {
+            self.files.shift_remove(&remove.key());
+        }
+        if let Some(add) = add {
+            self.files.insert(add.key(), add);
+        }
+        if let Some(metadata) = meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(protocol) = protocol {
+            self.protocol = Some(protocol);
+        }
+    }
+
+    fn apply_commit(&mut self, bytes: &[u8]) -> PolarsResult<()> {
+        for line in bytes.split(|b| *b == b'\n') {
+            if line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+
+            self.apply(decode_json_response::<Action>(line)?);
+        }
+
+        Ok(())
+    }
+
+    /// Checkpoints contain the reconciled state of the table, the `remove` actions in them are
+    /// tombstones that can be ignored.
+    fn apply_checkpoint(&mut self, bytes: Bytes) -> PolarsResult<()> {
+        let mut reader = ParquetReader::new(Cursor::new(bytes));
+        let schema = reader.schema()?;
+
+        let columns = ["add", "metaData", "protocol"]
+            .into_iter()
+            .filter(|name| schema.contains(name))
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        let df = reader.with_columns(Some(columns)).finish()?;
+
+        if let Ok(add) = df.column("add") {
+            let add = add.as_materialized_series().struct_()?;
+            let valid = add.is_not_null();
+
+            let path = add.field_by_name("path")?;
+            let path = path.str()?;
+            let partition_values = field_by_name_opt(add, "partitionValues")?;
+            let deletion_vector = field_by_name_opt(add, "deletionVector")?;
+            let deletion_vector = deletion_vector
+                .as_ref()
+                .map(DeletionVectorColumns::try_new)
+                .transpose()?;
+
+            for i in 0..add.len() {
+                if !valid.get(i).unwrap_or(false) {
+                    continue;
+                }
+
+                let path = path
+                    .get(i)
+                    .ok_or_else(|| polars_err!(ComputeError: "add action is missing path"))?;
+
+                let partition_values = match &partition_values {
+                    Some(s) => map_entries(s, i)?,
+                    None => PlHashMap::new(),
+                };
+
+                let deletion_vector = match &deletion_vector {
+                    Some(dv) => dv.get(i),
+                    None => None,
+                };
+
+                self.apply(Action {
+                    add: Some(Add {
+                        path: path.to_string(),
+                        partition_values,
+                        deletion_vector,
+                    }),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if let Ok(metadata) = df.column("metaData") {
+            let metadata = metadata.as_materialized_series().struct_()?;
+            let schema_string = metadata.field_by_name("schemaString")?;
+            let schema_string = schema_string.str()?;
+            let partition_columns = field_by_name_opt(metadata, "partitionColumns")?;
+            let configuration = field_by_name_opt(metadata, "configuration")?;
+
+            for i in 0..metadata.len() {
+                let Some(schema_string) = schema_string.get(i) else {
+                    continue;
+                };
+
+                let partition_columns = match &partition_columns {
+                    Some(s) => string_list(s, i)?,
+                    None => vec![],
+                };
+
+                let configuration = match &configuration {
+                    Some(s) => map_entries(s, i)?,
+                    None => PlHashMap::new(),
+                };
+
+                self.apply(Action {
+                    meta_data: Some(Metadata {
+                        schema_string: schema_string.to_string(),
+                        partition_columns,
+                        configuration,
+                    }),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if let Ok(protocol) = df.column("protocol") {
+            let protocol = protocol.as_materialized_series().struct_()?;
+            let min_reader_version = protocol
+                .field_by_name("minReaderVersion")?
+                .cast(&DataType::Int32)?;
+            let min_reader_version = min_reader_version.i32()?;
+            let reader_features = field_by_name_opt(protocol, "readerFeatures")?;
+
+            for i in 0..protocol.len() {
+                let Some(min_reader_version) = min_reader_version.get(i) else {
+                    continue;
+                };
+
+                let reader_features = match &reader_features {
+                    Some(s) if s.get(i)?.is_null() => None,
+                    Some(s) => Some(string_list(s, i)?),
+                    None => None,
+                };
+
+                self.apply(Action {
+                    protocol: Some(Protocol {
+                        min_reader_version,
+                        reader_features,
+                    }),
+                    ..Default::default()
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(self, table_root: PlPath, version: i64) -> PolarsResult<DeltaSnapshot> {
+        let (Some(metadata), Some(protocol)) = (self.metadata, self.protocol) else {
+            polars_bail!(
+                ComputeError:
+                "invalid Delta log: missing metaData or protocol action at version {}",
+                version
+            )
+        };
+
+        check_protocol(&protocol, &metadata)?;
+
+        let DataType::Struct(fields) = parse_type_json_str(&metadata.schema_string)? else {
+            polars_bail!(
+                ComputeError:
+                "invalid Delta table schema, expected a struct type: {}",
+                metadata.schema_string
+            )
+        };
+        let schema = Arc::new(Schema::from_iter(fields));
+
+        let partition_columns = metadata
+            .partition_columns
+            .into_iter()
+            .map(PlSmallStr::from)
+            .collect();
+
+        let files = self
+            .files
+            .into_values()
+            .map(|add| {
+                let path = resolve_data_file_path(table_root.as_ref(), &add.path)?;
+
+                Ok(DeltaDataFile {
+                    path,
+                    partition_values: add
+                        .partition_values
+                        .into_iter()
+                        .map(|(k, v)| (k.into(), v.map(PlSmallStr::from)))
+                        .collect(),
+                    deletion_vector: add.deletion_vector,
+                })
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok(DeltaSnapshot {
+            table_root,
+            version,
+            schema,
+            partition_columns,
+            files,
+        })
+    }
+}
+
+fn check_protocol(protocol: &Protocol, metadata: &Metadata) -> PolarsResult<()> {
+    polars_ensure!(
+        protocol.min_reader_version <= 3,
+        ComputeError:
+        "unsupported Delta reader version: {}",
+        protocol.min_reader_version
+    );
+
+    for feature in protocol.reader_features.iter().flatten() {
+        polars_ensure!(
+            SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+            ComputeError:
+            "unsupported Delta reader feature: {}",
+            feature
+        );
+    }
+
+    if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode") {
+        polars_ensure!(
+            mode == "none",
+            ComputeError:
+            "unsupported Delta column mapping mode: {}",
+            mode
+        );
+    }
+
+    Ok(())
+}
+
+/// Paths in the log are URI-encoded, and either relative to the table root or absolute.
+fn resolve_data_file_path(table_root: PlPathRef<'_>, path: &str) -> PolarsResult<PlPath> {
+    let decoded = percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .map_err(|_| polars_err!(ComputeError: "invalid data file path in Delta log: {}", path))?;
+
+    if path.contains("://") {
+        return Ok(resolve_absolute_path(path));
+    }
+
+    Ok(table_root.join(decoded.as_ref()))
+}
+
+fn field_by_name_opt(ca: &StructChunked, name: &str) -> PolarsResult<Option<Series>> {
+    if ca.struct_fields().iter().any(|f| f.name() == name) {
+        ca.field_by_name(name).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn string_list(s: &Series, i: usize) -> PolarsResult<Vec<String>> {
+    let Some(values) = s.list()?.get_as_series(i) else {
+        return Ok(vec![]);
+    };
+
+    Ok(values.str()?.iter().flatten().map(String::from).collect())
+}
+
+/// Reads a `map<string, string>` value, which is loaded as a list of key / value structs.
+fn map_entries(s: &Series, i: usize) -> PolarsResult<PlHashMap<String, Option<String>>> {
+    let Some(entries) = s.list()?.get_as_series(i) else {
+        return Ok(PlHashMap::new());
+    };
+
+    let entries = entries.struct_()?;
+    let fields = entries.fields_as_series();
+    polars_ensure!(
+        fields.len() == 2,
+        ComputeError: "invalid map column in Delta checkpoint: {}", s.name()
+    );
+    let keys = fields[0].str()?;
+    let values = fields[1].str()?;
+
+    Ok(keys
+        .iter()
+        .zip(values.iter())
+        .filter_map(|(k, v)| Some((k?.to_string(), v.map(String::from))))
+        .collect())
+}
+
+struct DeletionVectorColumns {
+    valid: BooleanChunked,
+    storage_type: StringChunked,
+    path_or_inline_dv: StringChunked,
+    offset: Int32Chunked,
+    size_in_bytes: Int32Chunked,
+    cardinality: Int64Chunked,
+}
+
+impl DeletionVectorColumns {
+    fn try_new(s: &Series) -> PolarsResult<Self> {
+        let ca = s.struct_()?;
+        let field = |name: &str, dtype: &DataType| ca.field_by_name(name)?.cast(dtype);
+
+        Ok(Self {
+            valid: ca.is_not_null(),
+            storage_type: field("storageType", &DataType::String)?.str()?.clone(),
+            path_or_inline_dv: field("pathOrInlineDv", &DataType::String)?.str()?.clone(),
+            offset: field("offset", &DataType::Int32)?.i32()?.clone(),
+            size_in_bytes: field("sizeInBytes", &DataType::Int32)?.i32()?.clone(),
+            cardinality: field("cardinality", &DataType::Int64)?.i64()?.clone(),
+        })
+    }
+
+    fn get(&self, i: usize) -> Option<DeletionVectorDescriptor> {
+        if !self.valid.get(i)? {
+            return None;
+        }
+
+        Some(DeletionVectorDescriptor {
+            storage_type: self.storage_type.get(i)?.into(),
+            path_or_inline_dv: self.path_or_inline_dv.get(i)?.into(),
+            offset: self.offset.get(i),
+            size_in_bytes: self.size_in_bytes.get(i)?,
+            cardinality: self.cardinality.get(i)?,
+        })
+    }
+}
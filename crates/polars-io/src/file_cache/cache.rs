@@ -1,65 +1,40 @@
 use std::path::Path;
 use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, LazyLock, RwLock};
+use std::time::SystemTime;
 
+use fs4::fs_std::FileExt;
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_utils::aliases::PlHashMap;
 use polars_utils::plpath::PlPathRef;
 
-use super::entry::{DATA_PREFIX, FileCacheEntry, METADATA_PREFIX};
+use super::cache_lock::GLOBAL_FILE_CACHE_LOCK;
+use super::entry::{DATA_PREFIX, FileCacheEntry, METADATA_PREFIX, get_data_file_path};
 use super::eviction::EvictionManager;
 use super::file_fetcher::FileFetcher;
+use super::file_lock::FileLock;
+use super::metadata::{EntryMetadata, FileVersion};
 use super::utils::FILE_CACHE_PREFIX;
 use crate::path_utils::ensure_directory_init;
 
 pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
     let prefix = FILE_CACHE_PREFIX.as_ref();
-    let prefix = Arc::<Path>::from(prefix);
 
     if config::verbose() {
         eprintln!("file cache prefix: {}", prefix.to_str().unwrap());
     }
 
-    let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
-    let notify_ttl_updated = Arc::new(tokio::sync::Notify::new());
-
-    let metadata_dir = prefix
-        .as_ref()
-        .join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap())
-        .into_boxed_path();
-    if let Err(err) = ensure_directory_init(&metadata_dir) {
-        panic!(
-            "failed to create file cache metadata directory: path = {}, err = {}",
-            metadata_dir.to_str().unwrap(),
-            err
-        )
-    }
-
-    let data_dir = prefix
-        .as_ref()
-        .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap())
-        .into_boxed_path();
-
-    if let Err(err) = ensure_directory_init(&data_dir) {
+    let file_cache = FileCache::new(prefix).unwrap_or_else(|err| {
         panic!(
-            "failed to create file cache data directory: path = {}, err = {}",
-            data_dir.to_str().unwrap(),
+            "failed to create file cache directories: path = {}, err = {}",
+            prefix.to_str().unwrap(),
             err
         )
-    }
+    });
+    file_cache.eviction_manager().run_in_background();
 
-    EvictionManager {
-        data_dir,
-        metadata_dir,
-        files_to_remove: None,
-        min_ttl: min_ttl.clone(),
-        notify_ttl_updated: notify_ttl_updated.clone(),
-    }
-    .run_in_background();
-
-    // Safety: We have created the data and metadata directories.
-    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated) }
+    file_cache
 });
 
 pub struct FileCache {
@@ -67,9 +42,49 @@ pub struct FileCache {
     entries: Arc<RwLock<PlHashMap<Arc<str>, Arc<FileCacheEntry>>>>,
     min_ttl: Arc<AtomicU64>,
     notify_ttl_updated: Arc<tokio::sync::Notify>,
+    max_size: Arc<AtomicU64>,
+    notify_size_check: Arc<tokio::sync::Notify>,
+}
+
+/// A file stored in the file cache directory.
+#[derive(Debug, Clone)]
+pub struct CachedFileInfo {
+    pub uri: Arc<str>,
+    /// Size of the cached file in bytes.
+    pub size: u64,
+    pub last_accessed: SystemTime,
+    /// TTL since last access, in seconds.
+    pub ttl: u64,
 }
 
 impl FileCache {
+    /// Create a file cache in the directory `prefix`. Files are only evicted from it when
+    /// [`FileCache::evict`] is called, unlike [`FILE_CACHE`] which evicts files in the
+    /// background.
+    pub fn new(prefix: &Path) -> PolarsResult<Self> {
+        let prefix = Arc::<Path>::from(prefix);
+
+        for dir in [METADATA_PREFIX, DATA_PREFIX] {
+            ensure_directory_init(&prefix.join(std::str::from_utf8(&[dir]).unwrap()))?;
+        }
+
+        let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
+        let max_size = Arc::new(AtomicU64::from(
+            get_env_file_cache_max_size().unwrap_or(u64::MAX),
+        ));
+
+        // Safety: We have created the data and metadata directories.
+        Ok(unsafe {
+            Self::new_unchecked(
+                prefix,
+                min_ttl,
+                Default::default(),
+                max_size,
+                Default::default(),
+            )
+        })
+    }
+
     /// # Safety
     /// The following directories exist:
     /// * `{prefix}/{METADATA_PREFIX}/`
@@ -78,12 +93,16 @@ impl FileCache {
         prefix: Arc<Path>,
         min_ttl: Arc<AtomicU64>,
         notify_ttl_updated: Arc<tokio::sync::Notify>,
+        max_size: Arc<AtomicU64>,
+        notify_size_check: Arc<tokio::sync::Notify>,
     ) -> Self {
         Self {
             prefix,
             entries: Default::default(),
             min_ttl,
             notify_ttl_updated,
+            max_size,
+            notify_size_check,
         }
     }
 
@@ -129,7 +148,7 @@ impl FileCache {
             }
         }
 
-        let uri_hash = blake3::hash(uri.as_bytes()).to_hex()[..32].to_string();
+        let uri_hash = uri_hash(&uri);
 
         {
             let mut entries = self.entries.write().unwrap();
@@ -160,6 +179,7 @@ impl FileCache {
                 self.prefix.clone(),
                 get_file_fetcher()?,
                 ttl,
+                self.notify_size_check.clone(),
             ));
             entries.insert(uri, entry.clone());
             Ok(entry.clone())
@@ -180,6 +200,167 @@ impl FileCache {
             PlPathRef::Cloud(p) => self.entries.read().unwrap().get(p.uri()).map(Arc::clone),
         }
     }
+
+    /// Maximum total size in bytes of the cached files, `None` if the size is unbounded.
+    pub fn max_size(&self) -> Option<u64> {
+        match self.max_size.load(std::sync::atomic::Ordering::Relaxed) {
+            u64::MAX => None,
+            v => Some(v),
+        }
+    }
+
+    /// Set the maximum total size in bytes of the cached files. When it is exceeded, the least
+    /// recently used files are evicted in the background, even if their TTL has not expired.
+    ///
+    /// This overrides `POLARS_FILE_CACHE_MAX_SIZE` for this process.
+    pub fn set_max_size(&self, max_size: Option<u64>) {
+        self.max_size.store(
+            max_size.unwrap_or(u64::MAX),
+            std::sync::atomic::Ordering::Relaxed,
+        );
+        self.notify_size_check.notify_one();
+    }
+
+    /// List the files in the cache directory. This includes files cached by other processes that
+    /// share the cache directory.
+    pub fn cached_files(&self) -> PolarsResult<Vec<CachedFileInfo>> {
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+        let mut out = vec![];
+
+        for file in std::fs::read_dir(self.metadata_dir())? {
+            let metadata_path = file?.path();
+            let Ok(mut metadata_file) = FileLock::from(&metadata_path).acquire_shared() else {
+                continue;
+            };
+            let Ok(metadata) = EntryMetadata::try_from_reader(&mut *metadata_file) else {
+                continue;
+            };
+
+            if metadata.remote_version == FileVersion::Uninitialized {
+                continue;
+            }
+
+            let data_file_path = get_data_file_path(
+                self.prefix.to_str().unwrap().as_bytes(),
+                metadata_path.file_name().unwrap().as_encoded_bytes(),
+                &metadata.remote_version,
+            );
+
+            if metadata.compare_local_state(&data_file_path).is_err() {
+                continue;
+            }
+
+            let Ok(data_file_metadata) = std::fs::metadata(&data_file_path) else {
+                continue;
+            };
+
+            out.push(CachedFileInfo {
+                uri: metadata.uri,
+                size: metadata.local_size,
+                last_accessed: data_file_metadata
+                    .accessed()
+                    .unwrap_or_else(|_| data_file_metadata.modified().unwrap()),
+                ttl: metadata.ttl,
+            });
+        }
+
+        Ok(out)
+    }
+
+    /// Remove the cached files of `addr`. Returns whether any file was removed.
+    ///
+    /// This function can accept relative local paths.
+    pub fn remove(&self, addr: PlPathRef<'_>) -> PolarsResult<bool> {
+        let uri_hash = match addr {
+            PlPathRef::Local(p) => uri_hash(std::fs::canonicalize(p)?.to_str().unwrap()),
+            PlPathRef::Cloud(p) => uri_hash(p.uri()),
+        };
+
+        Ok(self.remove_files(Some(&uri_hash))? > 0)
+    }
+
+    /// Remove all files from the cache directory. Returns the number of removed data files.
+    ///
+    /// This waits for in-progress cache operations of this and other processes to finish. Files
+    /// that are currently opened are kept.
+    pub fn clear(&self) -> PolarsResult<usize> {
+        self.remove_files(None)
+    }
+
+    /// Evict the files whose TTL has expired and, if the cached files exceed the maximum size,
+    /// the least recently used files. [`FILE_CACHE`] does this periodically in the background.
+    pub fn evict(&self) -> PolarsResult<()> {
+        let mut eviction_manager = self.eviction_manager();
+        eviction_manager.update_file_list()?;
+
+        let guard = GLOBAL_FILE_CACHE_LOCK.lock_eviction();
+        eviction_manager.evict_files(&guard);
+        Ok(())
+    }
+
+    fn eviction_manager(&self) -> EvictionManager {
+        EvictionManager {
+            data_dir: self.data_dir().into_boxed_path(),
+            metadata_dir: self.metadata_dir().into_boxed_path(),
+            files_to_remove: None,
+            min_ttl: self.min_ttl.clone(),
+            notify_ttl_updated: self.notify_ttl_updated.clone(),
+            max_size: self.max_size.clone(),
+            notify_size_check: self.notify_size_check.clone(),
+        }
+    }
+
+    fn remove_files(&self, uri_hash: Option<&str>) -> PolarsResult<usize> {
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_eviction();
+        let mut n_removed = 0;
+
+        for (dir, is_data_dir) in [(self.data_dir(), true), (self.metadata_dir(), false)] {
+            for file in std::fs::read_dir(dir)? {
+                let path = file?.path();
+
+                if uri_hash.is_some_and(|uri_hash| {
+                    !path
+                        .file_name()
+                        .unwrap()
+                        .as_encoded_bytes()
+                        .starts_with(uri_hash.as_bytes())
+                }) {
+                    continue;
+                }
+
+                // Files are locked while they are opened. Local files are cached as symlinks,
+                // these can always be removed.
+                if !path.is_symlink() {
+                    let Ok(file) = std::fs::OpenOptions::new().read(true).open(&path) else {
+                        continue;
+                    };
+                    if file.try_lock_exclusive().is_err() {
+                        continue;
+                    }
+                }
+
+                if std::fs::remove_file(&path).is_ok() && is_data_dir {
+                    n_removed += 1;
+                }
+            }
+        }
+
+        Ok(n_removed)
+    }
+
+    fn data_dir(&self) -> std::path::PathBuf {
+        self.prefix
+            .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap())
+    }
+
+    fn metadata_dir(&self) -> std::path::PathBuf {
+        self.prefix
+            .join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap())
+    }
+}
+
+fn uri_hash(uri: &str) -> String {
+    blake3::hash(uri.as_bytes()).to_hex()[..32].to_string()
 }
 
 pub fn get_env_file_cache_ttl() -> u64 {
@@ -187,3 +368,173 @@ pub fn get_env_file_cache_ttl() -> u64 {
         .map(|x| x.parse::<u64>().expect("integer"))
         .unwrap_or(60 * 60)
 }
+
+/// Maximum total size in bytes of the file cache, set by `POLARS_FILE_CACHE_MAX_SIZE`. The size is
+/// unbounded if it is not set.
+pub fn get_env_file_cache_max_size() -> Option<u64> {
+    std::env::var("POLARS_FILE_CACHE_MAX_SIZE")
+        .ok()
+        .map(|x| x.parse::<u64>().expect("integer"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::FileTimes;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    use polars_error::PolarsResult;
+    use polars_utils::plpath::PlPath;
+
+    use super::{FileCache, uri_hash};
+    use crate::file_cache::file_fetcher::{FileFetcher, RemoteMetadata};
+    use crate::file_cache::metadata::FileVersion;
+    use crate::file_cache::utils::last_modified_u64;
+
+    const TTL: u64 = 60 * 60;
+
+    /// Fetches copies of local files, so that they take up space in the cache.
+    struct CopyFileFetcher {
+        uri: Arc<str>,
+        path: PathBuf,
+    }
+
+    impl FileFetcher for CopyFileFetcher {
+        fn get_uri(&self) -> &Arc<str> {
+            &self.uri
+        }
+
+        fn fetch_metadata(&self) -> PolarsResult<RemoteMetadata> {
+            let metadata = std::fs::metadata(&self.path)?;
+
+            Ok(RemoteMetadata {
+                size: metadata.len(),
+                version: FileVersion::Timestamp(last_modified_u64(&metadata)),
+            })
+        }
+
+        fn fetch(&self, local_path: &Path) -> PolarsResult<()> {
+            std::fs::copy(&self.path, local_path)?;
+            Ok(())
+        }
+
+        fn fetches_as_symlink(&self) -> bool {
+            false
+        }
+    }
+
+    /// Cache a file of `size` bytes under `memory://bucket/{name}`, and set the last accessed time
+    /// of the cached file to `seconds_ago`.
+    fn cache_file(
+        cache: &FileCache,
+        dir: &Path,
+        name: &str,
+        size: usize,
+        seconds_ago: u64,
+    ) -> PolarsResult<Arc<str>> {
+        let uri: Arc<str> = format!("memory://bucket/{name}").into();
+        let path = dir.join(name);
+        std::fs::write(&path, vec![0u8; size])?;
+
+        let entry = cache.init_entry(
+            uri.clone(),
+            || {
+                Ok(Arc::new(CopyFileFetcher {
+                    uri: uri.clone(),
+                    path: path.clone(),
+                }))
+            },
+            TTL,
+        )?;
+        drop(entry.try_open_check_latest()?);
+        set_last_accessed(cache, &uri, seconds_ago)?;
+
+        Ok(uri)
+    }
+
+    fn set_last_accessed(cache: &FileCache, uri: &str, seconds_ago: u64) -> PolarsResult<()> {
+        let last_accessed = SystemTime::now() - Duration::from_secs(seconds_ago);
+        let uri_hash = uri_hash(uri);
+
+        for file in std::fs::read_dir(cache.data_dir())? {
+            let path = file?.path();
+            if path
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with(&uri_hash)
+            {
+                std::fs::File::options()
+                    .write(true)
+                    .open(&path)?
+                    .set_times(FileTimes::new().set_accessed(last_accessed))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn cached_uris(cache: &FileCache) -> PolarsResult<Vec<Arc<str>>> {
+        let mut uris = cache
+            .cached_files()?
+            .into_iter()
+            .map(|f| f.uri)
+            .collect::<Vec<_>>();
+        uris.sort();
+        Ok(uris)
+    }
+
+    #[test]
+    fn test_file_cache_evict() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = FileCache::new(&dir.path().join("cache"))?;
+
+        let a = cache_file(&cache, dir.path(), "a", 100, 30)?;
+        let b = cache_file(&cache, dir.path(), "b", 100, 10)?;
+        let c = cache_file(&cache, dir.path(), "c", 100, 20)?;
+        let expired = cache_file(&cache, dir.path(), "expired", 100, TTL + 60)?;
+
+        // Only the file that was not accessed within its TTL is evicted.
+        cache.evict()?;
+        assert_eq!(cached_uris(&cache)?, [a.clone(), b.clone(), c.clone()]);
+
+        // The least recently used files are evicted until the cache fits.
+        cache.set_max_size(Some(250));
+        cache.evict()?;
+        assert_eq!(cached_uris(&cache)?, [b.clone(), c]);
+
+        cache.set_max_size(Some(100));
+        cache.evict()?;
+        assert_eq!(cached_uris(&cache)?, std::slice::from_ref(&b));
+
+        // Evicted files are fetched again when they are opened.
+        cache.set_max_size(None);
+        let entry = cache.get_entry(PlPath::new(&a).as_ref()).unwrap();
+        drop(entry.try_open_check_latest()?);
+        assert_eq!(cached_uris(&cache)?, [a, b]);
+        assert!(cache.get_entry(PlPath::new(&expired).as_ref()).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_cache_remove_and_clear() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = FileCache::new(&dir.path().join("cache"))?;
+
+        let a = cache_file(&cache, dir.path(), "a", 10, 0)?;
+        let b = cache_file(&cache, dir.path(), "b", 10, 0)?;
+        assert_eq!(cached_uris(&cache)?, [a.clone(), b.clone()]);
+
+        assert!(cache.remove(PlPath::new(&a).as_ref())?);
+        assert!(!cache.remove(PlPath::new(&a).as_ref())?);
+        assert_eq!(cached_uris(&cache)?, [b]);
+
+        assert_eq!(cache.clear()?, 1);
+        assert!(cached_uris(&cache)?.is_empty());
+
+        Ok(())
+    }
+}
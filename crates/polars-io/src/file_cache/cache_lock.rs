@@ -219,4 +219,15 @@ impl GlobalLock {
         }
         None
     }
+
+    /// Acquire an exclusive lock on the cache directory, waiting until no cache operations are in
+    /// progress in this or other processes.
+    pub(super) fn lock_eviction(&self) -> GlobalFileCacheGuardExclusive<'_> {
+        loop {
+            if let Some(guard) = self.try_lock_eviction() {
+                return guard;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
 }
@@ -12,6 +12,7 @@ use super::file_fetcher::{FileFetcher, RemoteMetadata};
 use super::file_lock::{FileLock, FileLockAnyGuard};
 use super::metadata::{EntryMetadata, FileVersion};
 use super::utils::update_last_accessed;
+use crate::metrics::IOMetrics;
 
 pub(super) const DATA_PREFIX: u8 = b'd';
 pub(super) const METADATA_PREFIX: u8 = b'm';
@@ -30,6 +31,9 @@ struct Inner {
     cached_data: Option<CachedData>,
     ttl: Arc<AtomicU64>,
     file_fetcher: Arc<dyn FileFetcher>,
+    notify_size_check: Arc<tokio::sync::Notify>,
+    /// Number of times the data file was downloaded.
+    n_fetches: u64,
 }
 
 struct EntryData {
@@ -235,6 +239,9 @@ impl Inner {
             .try_write(&mut **metadata_file)
             .map_err(to_compute_err)?;
 
+        self.notify_size_check.notify_one();
+        self.n_fetches += 1;
+
         Ok(data_file)
     }
 
@@ -320,6 +327,7 @@ impl FileCacheEntry {
         path_prefix: Arc<Path>,
         file_fetcher: Arc<dyn FileFetcher>,
         file_cache_ttl: u64,
+        notify_size_check: Arc<tokio::sync::Notify>,
     ) -> Self {
         let metadata = FileLock::from(get_metadata_file_path(
             path_prefix.to_str().unwrap().as_bytes(),
@@ -343,6 +351,8 @@ impl FileCacheEntry {
                 cached_data: None,
                 ttl: ttl.clone(),
                 file_fetcher,
+                notify_size_check,
+                n_fetches: 0,
             }),
             ttl,
         })
@@ -365,6 +375,25 @@ impl FileCacheEntry {
         self.0.inner.lock().unwrap().try_open_check_latest()
     }
 
+    /// Same as [`Self::try_open_assume_latest`], but records in `io_metrics` whether the file was
+    /// already cached.
+    pub fn try_open_assume_latest_with_metrics(
+        &self,
+        io_metrics: &IOMetrics,
+    ) -> PolarsResult<std::fs::File> {
+        let mut inner = self.0.inner.lock().unwrap();
+        let n_fetches = inner.n_fetches;
+        let file = inner.try_open_assume_latest()?;
+
+        if inner.n_fetches == n_fetches {
+            IOMetrics::add(&io_metrics.file_cache_hits, 1);
+        } else {
+            IOMetrics::add(&io_metrics.file_cache_misses, 1);
+        }
+
+        Ok(file)
+    }
+
     pub fn update_ttl(&self, ttl: u64) {
         self.0.ttl.store(ttl, std::sync::atomic::Ordering::Relaxed);
     }
@@ -400,7 +429,7 @@ fn finish_open<F: FileLockAnyGuard>(data_file_path: &Path, _metadata_guard: &F)
 }
 
 /// `[prefix]/d/[uri hash][last modified]`
-fn get_data_file_path(
+pub(super) fn get_data_file_path(
     path_prefix: &[u8],
     uri_hash: &[u8],
     remote_version: &FileVersion,
@@ -10,12 +10,23 @@ use super::cache_lock::{GLOBAL_FILE_CACHE_LOCK, GlobalFileCacheGuardExclusive};
 use super::metadata::EntryMetadata;
 use crate::pl_async;
 
+/// Minimum time between two scans of the cache directory that are triggered by the size limit.
+const SIZE_CHECK_INTERVAL: Duration = if cfg!(debug_assertions) {
+    Duration::from_secs(1)
+} else {
+    Duration::from_secs(10)
+};
+
 #[derive(Debug, Clone)]
 pub(super) struct EvictionCandidate {
     path: PathBuf,
     metadata_path: PathBuf,
     metadata_last_modified: SystemTime,
     ttl: u64,
+    /// Set if the file is evicted to bring the cache below its maximum size, to the last
+    /// accessed time of the file when it was selected. The file is kept if it has been accessed
+    /// since.
+    lru_last_accessed: Option<SystemTime>,
 }
 
 pub(super) struct EvictionManager {
@@ -24,6 +35,10 @@ pub(super) struct EvictionManager {
     pub(super) files_to_remove: Option<Vec<EvictionCandidate>>,
     pub(super) min_ttl: Arc<AtomicU64>,
     pub(super) notify_ttl_updated: Arc<tokio::sync::Notify>,
+    /// Maximum total size of the data files in bytes, `u64::MAX` if unbounded.
+    pub(super) max_size: Arc<AtomicU64>,
+    /// Notified when a file is fetched or the maximum size is changed.
+    pub(super) notify_size_check: Arc<tokio::sync::Notify>,
 }
 
 impl EvictionCandidate {
@@ -91,14 +106,12 @@ impl EvictionCandidate {
         }
 
         let metadata = std::fs::metadata(path).unwrap();
+        let last_accessed = metadata
+            .accessed()
+            .unwrap_or_else(|_| metadata.modified().unwrap());
 
-        let since_last_accessed = match now.duration_since(
-            metadata
-                .accessed()
-                .unwrap_or_else(|_| metadata.modified().unwrap()),
-        ) {
-            Ok(v) => v.as_secs(),
-            Err(_) => {
+        if let Some(lru_last_accessed) = self.lru_last_accessed {
+            if last_accessed > lru_last_accessed {
                 if verbose {
                     eprintln!(
                         "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
@@ -106,16 +119,8 @@ impl EvictionCandidate {
                     );
                 }
                 return;
-            },
-        };
-
-        if since_last_accessed < self.ttl {
-            if verbose {
-                eprintln!(
-                    "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
-                    path.to_str().unwrap()
-                );
             }
+        } else if !self.ttl_expired(now, last_accessed, path, verbose) {
             return;
         }
 
@@ -148,6 +153,39 @@ impl EvictionCandidate {
             );
         }
     }
+
+    fn ttl_expired(
+        &self,
+        now: &SystemTime,
+        last_accessed: SystemTime,
+        path: &Path,
+        verbose: bool,
+    ) -> bool {
+        let since_last_accessed = match now.duration_since(last_accessed) {
+            Ok(v) => v.as_secs(),
+            Err(_) => {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
+                        path.to_str().unwrap()
+                    );
+                }
+                return false;
+            },
+        };
+
+        if since_last_accessed < self.ttl {
+            if verbose {
+                eprintln!(
+                    "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
+                    path.to_str().unwrap()
+                );
+            }
+            return false;
+        }
+
+        true
+    }
 }
 
 impl EvictionManager {
@@ -224,6 +262,19 @@ impl EvictionManager {
                         _ = self.notify_ttl_updated.notified() => {
                             continue;
                         }
+                        _ = self.notify_size_check.notified() => {
+                            if self.max_size.load(std::sync::atomic::Ordering::Relaxed) == u64::MAX {
+                                continue;
+                            }
+                            // Every fetched file notifies, so scanning many files would scan the
+                            // cache directory once per file. Notifications that arrive while
+                            // waiting are merged into one.
+                            let since_last_eviction = last_eviction_time.elapsed();
+                            if let Some(wait) = SIZE_CHECK_INTERVAL.checked_sub(since_last_eviction) {
+                                tokio::time::sleep(wait).await;
+                            }
+                            break;
+                        }
                         _ = tokio::time::sleep(sleep_interval) => {
                             break;
                         }
@@ -233,7 +284,7 @@ impl EvictionManager {
         });
     }
 
-    fn update_file_list(&mut self) -> PolarsResult<()> {
+    pub(super) fn update_file_list(&mut self) -> PolarsResult<()> {
         let data_files_iter = match std::fs::read_dir(self.data_dir.as_ref()) {
             Ok(v) => v,
             Err(e) => {
@@ -270,6 +321,9 @@ impl EvictionManager {
         );
 
         let now = SystemTime::now();
+        let max_size = self.max_size.load(std::sync::atomic::Ordering::Relaxed);
+        // Data files that are kept based on their TTL, with their size and last accessed time.
+        let mut retained = vec![];
 
         for file in data_files_iter {
             let file = file?;
@@ -289,14 +343,35 @@ impl EvictionManager {
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
             eviction_candidate.update_ttl();
 
             if eviction_candidate.should_remove(&now) {
                 files_to_remove.push(eviction_candidate);
+            } else if max_size != u64::MAX {
+                // Symlinks created for local files don't take up space in the cache.
+                let Ok(metadata) = std::fs::symlink_metadata(&eviction_candidate.path) else {
+                    continue;
+                };
+                let last_accessed = metadata
+                    .accessed()
+                    .unwrap_or_else(|_| metadata.modified().unwrap());
+                retained.push((eviction_candidate, metadata.len(), last_accessed));
             }
         }
 
+        let sizes_and_access_times = retained
+            .iter()
+            .map(|(_, size, last_accessed)| (*size, *last_accessed))
+            .collect::<Vec<_>>();
+
+        for i in select_least_recently_used(&sizes_and_access_times, max_size) {
+            let (mut eviction_candidate, _, last_accessed) = retained[i].clone();
+            eviction_candidate.lru_last_accessed = Some(last_accessed);
+            files_to_remove.push(eviction_candidate);
+        }
+
         for file in metadata_files_iter {
             let file = file?;
             let path = file.path();
@@ -307,6 +382,7 @@ impl EvictionManager {
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
 
             eviction_candidate.update_ttl();
@@ -323,7 +399,7 @@ impl EvictionManager {
 
     /// # Panics
     /// Panics if `self.files_to_remove` is `None`.
-    fn evict_files(&mut self, _guard: &GlobalFileCacheGuardExclusive) {
+    pub(super) fn evict_files(&mut self, _guard: &GlobalFileCacheGuardExclusive) {
         let verbose = false;
         let mut files_to_remove = self.files_to_remove.take().unwrap();
         let now = &SystemTime::now();
@@ -333,3 +409,45 @@ impl EvictionManager {
         }
     }
 }
+
+/// Returns the indices of the files to evict, least recently used first, such that the total size
+/// of the remaining files is at most `max_size`. Takes the size and last accessed time of each
+/// file.
+fn select_least_recently_used(files: &[(u64, SystemTime)], max_size: u64) -> Vec<usize> {
+    let mut total_size = files.iter().map(|(size, _)| *size).sum::<u64>();
+
+    if total_size <= max_size {
+        return vec![];
+    }
+
+    let mut indices = (0..files.len()).collect::<Vec<_>>();
+    indices.sort_by_key(|&i| files[i].1);
+
+    indices
+        .into_iter()
+        .take_while(|&i| {
+            let exceeded = total_size > max_size;
+            total_size -= files[i].0;
+            exceeded
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::select_least_recently_used;
+
+    #[test]
+    fn test_select_least_recently_used() {
+        let t = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+        let files = [(10, t(3)), (20, t(1)), (30, t(4)), (40, t(2))];
+
+        assert!(select_least_recently_used(&files, 100).is_empty());
+        assert_eq!(select_least_recently_used(&files, 99), [1]);
+        assert_eq!(select_least_recently_used(&files, 40), [1, 3]);
+        assert_eq!(select_least_recently_used(&files, 30), [1, 3, 0]);
+        assert_eq!(select_least_recently_used(&files, 0), [1, 3, 0, 2]);
+    }
+}
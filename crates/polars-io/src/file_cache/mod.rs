@@ -6,6 +6,8 @@ mod file_fetcher;
 mod file_lock;
 mod metadata;
 mod utils;
-pub use cache::{FILE_CACHE, get_env_file_cache_ttl};
+pub use cache::{
+    CachedFileInfo, FILE_CACHE, FileCache, get_env_file_cache_max_size, get_env_file_cache_ttl,
+};
 pub use entry::FileCacheEntry;
 pub use utils::{FILE_CACHE_PREFIX, init_entries_from_uri_list};
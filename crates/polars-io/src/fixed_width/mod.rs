@@ -0,0 +1,39 @@
+//! # Reading fixed-width text files.
+//!
+//! In a fixed-width file every field of a record lives at a fixed byte offset in the line, padded
+//! with whitespace to the width of the column. There is no separator or quoting, so the layout of
+//! the columns has to be provided up front. Fields are parsed with the same parsers as CSV fields.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::fixed_width::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthReader};
+//! use polars_io::SerReader;
+//! use std::io::Cursor;
+//!
+//! let data = "\
+//! 001 alice   12.50
+//! 002 bob    100.00
+//! ";
+//!
+//! let options = FixedWidthReadOptions::new(vec![
+//!     FixedWidthColumn::new("id", 0, 3, DataType::Int32),
+//!     FixedWidthColumn::new("name", 4, 6, DataType::String),
+//!     FixedWidthColumn::new("amount", 10, 7, DataType::Float64),
+//! ]);
+//!
+//! let df = FixedWidthReader::new(Cursor::new(data))
+//!     .with_options(options)
+//!     .finish()
+//!     .unwrap();
+//! assert_eq!(df.shape(), (2, 3));
+//! ```
+mod options;
+mod read;
+
+pub use options::{FixedWidthColumn, FixedWidthReadOptions};
+pub use read::{
+    FixedWidthChunk, FixedWidthReader, count_rows_fixed_width, read_fixed_width_chunk,
+    split_fixed_width_chunks,
+};
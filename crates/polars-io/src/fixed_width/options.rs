@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::read::CommentPrefix;
+
+/// Location and type of a single column in a fixed-width file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FixedWidthColumn {
+    pub name: PlSmallStr,
+    /// Byte offset of the first byte of the field, relative to the start of the line.
+    pub offset: usize,
+    /// Width of the field in bytes.
+    pub width: usize,
+    pub dtype: DataType,
+}
+
+impl FixedWidthColumn {
+    pub fn new(name: impl Into<PlSmallStr>, offset: usize, width: usize, dtype: DataType) -> Self {
+        Self {
+            name: name.into(),
+            offset,
+            width,
+            dtype,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FixedWidthReadOptions {
+    pub columns: Arc<[FixedWidthColumn]>,
+    /// Number of lines to skip at the start of the file, e.g. a header or banner.
+    pub skip_rows: usize,
+    pub comment_prefix: Option<CommentPrefix>,
+    pub eol_char: u8,
+    /// Parse fields that fail to parse as null instead of raising an error.
+    pub ignore_errors: bool,
+}
+
+impl Default for FixedWidthReadOptions {
+    fn default() -> Self {
+        Self {
+            columns: Arc::from([]),
+            skip_rows: 0,
+            comment_prefix: None,
+            eol_char: b'\n',
+            ignore_errors: false,
+        }
+    }
+}
+
+impl FixedWidthReadOptions {
+    pub fn new(columns: impl Into<Arc<[FixedWidthColumn]>>) -> Self {
+        Self {
+            columns: columns.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Number of lines to skip at the start of the file.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Lines starting with this prefix are skipped.
+    pub fn with_comment_prefix(mut self, comment_prefix: Option<&str>) -> Self {
+        self.comment_prefix = comment_prefix.map(CommentPrefix::new_from_str);
+        self
+    }
+
+    /// Set the end-of-line character. A `\r` preceding it is stripped as well.
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Parse fields that fail to parse as null instead of raising an error.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// The schema of the file, in the order in which the columns were specified.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        polars_ensure!(
+            !self.columns.is_empty(),
+            InvalidOperation: "fixed-width scan requires at least one column"
+        );
+
+        let mut schema = Schema::with_capacity(self.columns.len());
+
+        for col in self.columns.iter() {
+            polars_ensure!(
+                col.width > 0,
+                InvalidOperation: "fixed-width column '{}' must have a non-zero width", col.name
+            );
+
+            if schema.insert(col.name.clone(), col.dtype.clone()).is_some() {
+                polars_bail!(Duplicate: "column '{}' is specified more than once", col.name);
+            }
+        }
+
+        Ok(schema)
+    }
+}
@@ -0,0 +1,348 @@
+use std::ops::Range;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use rayon::prelude::*;
+
+use super::options::FixedWidthReadOptions;
+use crate::RowIndex;
+use crate::csv::read::buffer::{init_buffers, validate_utf8};
+use crate::csv::read::{CsvEncoding, is_comment_line};
+use crate::mmap::MmapBytesReader;
+use crate::shared::SerReader;
+use crate::utils::get_reader_bytes;
+
+/// A range of a fixed-width file that starts and ends at a line boundary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedWidthChunk {
+    pub bytes: Range<usize>,
+    /// Number of records in the chunk. Comments and empty lines are not counted.
+    pub n_rows: usize,
+}
+
+fn strip_cr(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+fn is_record(line: &[u8], options: &FixedWidthReadOptions) -> bool {
+    !line.is_empty() && !is_comment_line(line, options.comment_prefix.as_ref())
+}
+
+/// Byte offset of the first line after the `skip_rows` lines at the start of the file.
+fn data_start(bytes: &[u8], options: &FixedWidthReadOptions) -> usize {
+    let mut start = 0;
+    for _ in 0..options.skip_rows {
+        match memchr::memchr(options.eol_char, &bytes[start..]) {
+            Some(pos) => start += pos + 1,
+            None => return bytes.len(),
+        }
+    }
+    start
+}
+
+/// Split the data of a fixed-width file into chunks of roughly `chunk_size` bytes, so they can be
+/// parsed in parallel. Chunks without any records are omitted.
+pub fn split_fixed_width_chunks(
+    bytes: &[u8],
+    options: &FixedWidthReadOptions,
+    chunk_size: usize,
+) -> Vec<FixedWidthChunk> {
+    let mut out = vec![];
+    let mut chunk_start = data_start(bytes, options);
+    let mut n_rows = 0;
+    let mut pos = chunk_start;
+
+    while pos < bytes.len() {
+        let line_end =
+            memchr::memchr(options.eol_char, &bytes[pos..]).map_or(bytes.len(), |i| pos + i);
+
+        if is_record(strip_cr(&bytes[pos..line_end]), options) {
+            n_rows += 1;
+        }
+        pos = (line_end + 1).min(bytes.len());
+
+        if pos - chunk_start >= chunk_size {
+            if n_rows > 0 {
+                out.push(FixedWidthChunk {
+                    bytes: chunk_start..pos,
+                    n_rows,
+                });
+            }
+            chunk_start = pos;
+            n_rows = 0;
+        }
+    }
+
+    if n_rows > 0 {
+        out.push(FixedWidthChunk {
+            bytes: chunk_start..bytes.len(),
+            n_rows,
+        });
+    }
+
+    out
+}
+
+/// Count the records in a fixed-width file.
+pub fn count_rows_fixed_width(bytes: &[u8], options: &FixedWidthReadOptions) -> usize {
+    split_fixed_width_chunks(bytes, options, usize::MAX)
+        .iter()
+        .map(|chunk| chunk.n_rows)
+        .sum()
+}
+
+/// Parse the records in `bytes`, which should start at a line boundary.
+///
+/// Only the columns at the indices in `projection` are parsed. Fields are trimmed of surrounding
+/// whitespace, and empty fields or fields that lie past the end of a short line are null.
+pub fn read_fixed_width_chunk(
+    bytes: &[u8],
+    options: &FixedWidthReadOptions,
+    projection: &[usize],
+    capacity: usize,
+) -> PolarsResult<DataFrame> {
+    let schema = options.schema()?;
+    let mut buffers = init_buffers(
+        projection,
+        capacity,
+        &schema,
+        None,
+        CsvEncoding::Utf8,
+        false,
+    )?;
+    // The string buffers don't validate their input.
+    let validate = projection
+        .iter()
+        .map(|&i| options.columns[i].dtype.is_string())
+        .collect::<Vec<_>>();
+
+    let mut height = 0;
+
+    for line in bytes.split(|&b| b == options.eol_char) {
+        let line = strip_cr(line);
+        if !is_record(line, options) {
+            continue;
+        }
+        height += 1;
+
+        for ((&i, buf), &validate) in projection.iter().zip(&mut buffers).zip(&validate) {
+            let col = &options.columns[i];
+            let start = col.offset.min(line.len());
+            let end = col.offset.saturating_add(col.width).min(line.len());
+            let field = line[start..end].trim_ascii();
+
+            if validate && !validate_utf8(field) {
+                polars_ensure!(
+                    options.ignore_errors,
+                    ComputeError: "invalid utf-8 sequence in fixed-width column '{}'", col.name
+                );
+                buf.add_null(false);
+                continue;
+            }
+
+            buf.add(field, options.ignore_errors, false, true)
+                .map_err(|_| {
+                    polars_err!(
+                        ComputeError: "could not parse `{}` as dtype `{}` in fixed-width column '{}'",
+                        String::from_utf8_lossy(field), col.dtype, col.name,
+                    )
+                })?;
+        }
+    }
+
+    if projection.is_empty() {
+        return Ok(DataFrame::empty_with_height(height));
+    }
+
+    let columns = buffers
+        .into_iter()
+        .map(|buf| buf.into_series().map(Column::from))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(unsafe { DataFrame::new_no_checks(height, columns) })
+}
+
+/// Read a fixed-width file into a [`DataFrame`].
+#[must_use]
+pub struct FixedWidthReader<R: MmapBytesReader> {
+    reader: R,
+    options: FixedWidthReadOptions,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+    row_index: Option<RowIndex>,
+}
+
+impl<R: MmapBytesReader> FixedWidthReader<R> {
+    /// Set the layout of the file and how it should be parsed.
+    pub fn with_options(mut self, options: FixedWidthReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for FixedWidthReader<R> {
+    fn new(reader: R) -> Self {
+        FixedWidthReader {
+            reader,
+            options: Default::default(),
+            rechunk: true,
+            n_rows: None,
+            projection: None,
+            columns: None,
+            row_index: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let schema = self.options.schema()?;
+        let projection = match (&self.columns, self.projection.take()) {
+            (Some(columns), _) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..schema.len()).collect(),
+        };
+
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = (bytes.len() / (n_threads * 4)).clamp(1 << 16, 1 << 24);
+        let mut chunks = split_fixed_width_chunks(&bytes, &self.options, chunk_size);
+
+        // Only parse the chunks that are needed to reach `n_rows`.
+        if let Some(n_rows) = self.n_rows {
+            let mut rows_seen = 0;
+            let n_chunks = chunks
+                .iter()
+                .take_while(|chunk| {
+                    let needed = rows_seen < n_rows;
+                    rows_seen += chunk.n_rows;
+                    needed
+                })
+                .count();
+            chunks.truncate(n_chunks);
+        }
+
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|chunk| {
+                    read_fixed_width_chunk(
+                        &bytes[chunk.bytes],
+                        &self.options,
+                        &projection,
+                        chunk.n_rows,
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_schema(&schema.try_project_indices(&projection)?)
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+
+        if let Some(n_rows) = self.n_rows {
+            df = df.slice(0, n_rows);
+        }
+
+        if let Some(row_index) = &self.row_index {
+            unsafe { df.with_row_index_mut(row_index.name.clone(), Some(row_index.offset)) };
+        }
+
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::fixed_width::FixedWidthColumn;
+
+    fn options() -> FixedWidthReadOptions {
+        FixedWidthReadOptions::new(vec![
+            FixedWidthColumn::new("id", 0, 3, DataType::Int64),
+            FixedWidthColumn::new("name", 3, 6, DataType::String),
+            FixedWidthColumn::new("amount", 9, 7, DataType::Float64),
+        ])
+    }
+
+    #[test]
+    fn test_split_chunks() {
+        let data = b"HEADER\n  1alice   1.50\n# note\n\n  2bob    20.00\r\n  3carol\n";
+        let options = options().with_skip_rows(1).with_comment_prefix(Some("#"));
+
+        assert_eq!(count_rows_fixed_width(data, &options), 3);
+
+        let chunks = split_fixed_width_chunks(data, &options, 1);
+        assert_eq!(
+            chunks.iter().map(|c| c.n_rows).collect::<Vec<_>>(),
+            [1, 1, 1]
+        );
+        assert_eq!(&data[chunks[2].bytes.clone()], b"  3carol\n");
+    }
+
+    #[test]
+    fn test_read_chunk() -> PolarsResult<()> {
+        let data = b"  1alice   1.50\n  2bob    20.00\r\n  3carol";
+        let df = read_fixed_width_chunk(data, &options(), &[2, 1], 3)?;
+
+        let expected = df![
+            "amount" => [Some(1.5), Some(20.0), None],
+            "name" => ["alice", "bob", "carol"],
+        ]?;
+        assert!(df.equals_missing(&expected));
+
+        let err = read_fixed_width_chunk(b"  xalice   1.50", &options(), &[0], 1).unwrap_err();
+        assert!(err.to_string().contains("column 'id'"));
+
+        let df = FixedWidthReader::new(Cursor::new(data))
+            .with_options(options().with_ignore_errors(true))
+            .with_columns(Some(vec!["id".into()]))
+            .with_n_rows(Some(2))
+            .finish()?;
+        assert_eq!(df.column("id")?.i64()?.to_vec(), [Some(1), Some(2)]);
+
+        Ok(())
+    }
+}
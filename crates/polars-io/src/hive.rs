@@ -1,7 +1,56 @@
+use percent_encoding::{AsciiSet, CONTROLS};
 use polars_core::frame::DataFrame;
 use polars_core::frame::column::ScalarColumn;
-use polars_core::prelude::Column;
+use polars_core::prelude::{Column, DataType};
 use polars_core::series::Series;
+use polars_error::PolarsResult;
+
+/// Directory name value of a Hive partition key that is null.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Characters that are percent-encoded in the names and values of Hive partition keys. These are
+/// the characters escaped by Hive itself, along with the ones that are not allowed in Windows file
+/// names or that would be interpreted by glob patterns.
+pub const HIVE_ENCODE_CHAR_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Returns the `name=value` directory name of a Hive partition key, where the value is the first
+/// value of `column`.
+///
+/// Nulls and empty strings are written as [`HIVE_DEFAULT_PARTITION`]. Temporal values are formatted
+/// with all digits of their time unit, which allows the unit to be inferred again when scanning.
+pub fn hive_partition_dir_name(column: &Column) -> PolarsResult<String> {
+    let value = column.head(Some(1)).strict_cast(&DataType::String)?;
+    let value = value
+        .str()?
+        .get(0)
+        .filter(|v| !v.is_empty())
+        .unwrap_or(HIVE_DEFAULT_PARTITION);
+
+    Ok(format!(
+        "{}={}",
+        percent_encoding::utf8_percent_encode(column.name(), HIVE_ENCODE_CHAR_SET),
+        percent_encoding::utf8_percent_encode(value, HIVE_ENCODE_CHAR_SET)
+    ))
+}
 
 /// Materializes hive partitions.
 /// We have a special num_rows arg, as df can be empty when a projection contains
@@ -0,0 +1,159 @@
+//! Decoding of manifest lists and manifests, which are Avro files.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#manifests>
+use std::io::Cursor;
+
+use bytes::Bytes;
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_err};
+
+use crate::SerReader;
+use crate::avro::AvroReader;
+
+/// `content` of a manifest or data file.
+pub(super) const CONTENT_DATA: i32 = 0;
+pub(super) const CONTENT_POSITION_DELETES: i32 = 1;
+pub(super) const CONTENT_EQUALITY_DELETES: i32 = 2;
+
+/// `status` of a manifest entry.
+const STATUS_DELETED: i32 = 2;
+
+#[derive(Debug)]
+pub(super) struct ManifestFile {
+    pub path: String,
+    pub partition_spec_id: i32,
+    /// Sequence number inherited by entries that were added in this manifest.
+    pub sequence_number: i64,
+}
+
+/// A live data or delete file of a manifest.
+#[derive(Debug)]
+pub(super) struct ManifestEntry {
+    pub content: i32,
+    pub file_path: String,
+    pub file_format: String,
+    pub partition_spec_id: i32,
+    /// Partition tuple, with one value per field of the partition spec.
+    pub partition: Vec<AnyValue<'static>>,
+    pub record_count: i64,
+    pub sequence_number: i64,
+    pub equality_ids: Vec<i32>,
+    pub referenced_data_file: Option<String>,
+}
+
+pub(super) fn read_manifest_list(bytes: Bytes) -> PolarsResult<Vec<ManifestFile>> {
+    let df = AvroReader::new(Cursor::new(bytes)).finish()?;
+
+    let path = df.column("manifest_path")?.str()?;
+    let partition_spec_id = df.column("partition_spec_id")?.cast(&DataType::Int32)?;
+    let partition_spec_id = partition_spec_id.i32()?;
+    // Absent in format version 1, where the sequence number is 0.
+    let sequence_number = optional_column(&df, "sequence_number", &DataType::Int64)?;
+
+    (0..df.height())
+        .map(|i| {
+            Ok(ManifestFile {
+                path: path
+                    .get(i)
+                    .ok_or_else(|| polars_err!(ComputeError: "manifest_path is null"))?
+                    .to_string(),
+                partition_spec_id: partition_spec_id.get(i).unwrap_or(0),
+                sequence_number: sequence_number
+                    .as_ref()
+                    .and_then(|s| s.i64().unwrap().get(i))
+                    .unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+/// Reads the entries of a manifest, excluding entries of removed files.
+pub(super) fn read_manifest(
+    bytes: Bytes,
+    manifest_file: &ManifestFile,
+) -> PolarsResult<Vec<ManifestEntry>> {
+    let df = AvroReader::new(Cursor::new(bytes)).finish()?;
+
+    let status = df.column("status")?.cast(&DataType::Int32)?;
+    let status = status.i32()?;
+    let sequence_number = optional_column(&df, "sequence_number", &DataType::Int64)?;
+
+    let data_file = df.column("data_file")?.as_materialized_series().struct_()?;
+    let data_file = DataFrame::new(
+        data_file
+            .fields_as_series()
+            .into_iter()
+            .map(Column::from)
+            .collect(),
+    )?;
+
+    let content = optional_column(&data_file, "content", &DataType::Int32)?;
+    let file_path = data_file.column("file_path")?.str()?;
+    let file_format = data_file.column("file_format")?.str()?;
+    let record_count = data_file.column("record_count")?.cast(&DataType::Int64)?;
+    let record_count = record_count.i64()?;
+    let equality_ids = optional_column(
+        &data_file,
+        "equality_ids",
+        &DataType::List(Box::new(DataType::Int32)),
+    )?;
+    let referenced_data_file =
+        optional_column(&data_file, "referenced_data_file", &DataType::String)?;
+
+    let partition = match data_file.column("partition") {
+        Ok(c) => c.as_materialized_series().struct_()?.fields_as_series(),
+        Err(_) => vec![],
+    };
+
+    let mut out = Vec::with_capacity(df.height());
+
+    for i in 0..df.height() {
+        if status.get(i) == Some(STATUS_DELETED) {
+            continue;
+        }
+
+        let file_path = file_path
+            .get(i)
+            .ok_or_else(|| polars_err!(ComputeError: "file_path is null in Iceberg manifest"))?;
+
+        out.push(ManifestEntry {
+            content: content
+                .as_ref()
+                .and_then(|c| c.i32().unwrap().get(i))
+                .unwrap_or(CONTENT_DATA),
+            file_path: file_path.to_string(),
+            file_format: file_format.get(i).unwrap_or_default().to_string(),
+            partition_spec_id: manifest_file.partition_spec_id,
+            partition: partition
+                .iter()
+                .map(|s| Ok(s.get(i)?.into_static()))
+                .collect::<PolarsResult<_>>()?,
+            record_count: record_count.get(i).unwrap_or(0),
+            // Sequence numbers of added files are inherited from the manifest.
+            sequence_number: sequence_number
+                .as_ref()
+                .and_then(|s| s.i64().unwrap().get(i))
+                .unwrap_or(manifest_file.sequence_number),
+            equality_ids: match &equality_ids {
+                Some(s) => s
+                    .list()?
+                    .get_as_series(i)
+                    .map(|ids| ids.i32().unwrap().into_iter().flatten().collect())
+                    .unwrap_or_default(),
+                None => vec![],
+            },
+            referenced_data_file: referenced_data_file
+                .as_ref()
+                .and_then(|s| s.str().unwrap().get(i).map(String::from)),
+        });
+    }
+
+    Ok(out)
+}
+
+fn optional_column(df: &DataFrame, name: &str, dtype: &DataType) -> PolarsResult<Option<Series>> {
+    df.column(name)
+        .ok()
+        .map(|c| c.as_materialized_series().cast(dtype))
+        .transpose()
+}
@@ -0,0 +1,18 @@
+//! # Reading Iceberg tables.
+//!
+//! The files of a table snapshot are resolved from the table metadata (`metadata.json`) without a
+//! catalog: the manifest list of the snapshot is read, followed by its manifests, which list the
+//! data and delete files. Delete files are matched to the data files they apply to using their
+//! partition and sequence number.
+//!
+//! Columns of data and delete files are matched to the table schema by their field IDs. The
+//! identity partition values of the data files are used like Hive partition values, so that data
+//! files can be skipped based on predicates on the partition columns.
+mod manifest;
+mod models;
+mod schema;
+mod snapshot;
+
+pub use snapshot::{
+    IcebergDataFile, IcebergEqualityDeleteFile, IcebergSnapshot, read_iceberg_snapshot,
+};
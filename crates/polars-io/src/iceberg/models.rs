@@ -0,0 +1,119 @@
+//! Table metadata (`metadata.json`) of an Iceberg table. Only the fields needed for reading are
+//! declared.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#table-metadata-fields>
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TableMetadata {
+    pub format_version: i32,
+    /// Format version 1 only.
+    #[serde(default)]
+    pub schema: Option<IcebergSchema>,
+    #[serde(default)]
+    pub schemas: Vec<IcebergSchema>,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    /// Format version 1 only.
+    #[serde(default)]
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    /// Writers of format version 1 use `-1` for tables without snapshots.
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct IcebergSchema {
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+    pub fields: Vec<StructField>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct StructField {
+    pub id: i32,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: IcebergType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum IcebergType {
+    Primitive(String),
+    Struct {
+        fields: Vec<StructField>,
+    },
+    List {
+        #[serde(rename = "element-id", default)]
+        element_id: Option<i32>,
+        element: Box<IcebergType>,
+    },
+    Map {
+        #[serde(rename = "key-id", default)]
+        key_id: Option<i32>,
+        key: Box<IcebergType>,
+        #[serde(rename = "value-id", default)]
+        value_id: Option<i32>,
+        value: Box<IcebergType>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionField {
+    pub source_id: i32,
+    pub transform: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+}
+
+impl TableMetadata {
+    pub fn schema(&self, schema_id: Option<i32>) -> Option<&IcebergSchema> {
+        let schema_id = schema_id.or(self.current_schema_id);
+
+        match schema_id {
+            Some(id) => self
+                .schemas
+                .iter()
+                .find(|s| s.schema_id == Some(id))
+                .or(self.schema.as_ref().filter(|s| s.schema_id == Some(id))),
+            None => self.schema.as_ref().or(self.schemas.last()),
+        }
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&[PartitionField]> {
+        self.partition_specs
+            .iter()
+            .find(|s| s.spec_id == spec_id)
+            .map(|s| s.fields.as_slice())
+            .or(self.partition_spec.as_deref().filter(|_| spec_id == 0))
+    }
+
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        let id = self.current_snapshot_id.filter(|id| *id != -1)?;
+        self.snapshots.iter().find(|s| s.snapshot_id == id)
+    }
+}
@@ -0,0 +1,224 @@
+//! Conversion of Iceberg schemas to Polars schemas.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#schemas-and-data-types>
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+
+use super::models::{IcebergSchema, IcebergType, StructField};
+use crate::parquet::write::{ChildFieldOverwrites, ParquetFieldOverwrites};
+
+pub(super) fn iceberg_schema_to_schema(schema: &IcebergSchema) -> PolarsResult<Schema> {
+    schema
+        .fields
+        .iter()
+        .map(struct_field_to_field)
+        .collect::<PolarsResult<Schema>>()
+}
+
+fn struct_field_to_field(field: &StructField) -> PolarsResult<Field> {
+    Ok(Field::new(
+        PlSmallStr::from_str(&field.name),
+        iceberg_type_to_dtype(&field.field_type)?,
+    ))
+}
+
+fn iceberg_type_to_dtype(iceberg_type: &IcebergType) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match iceberg_type {
+        IcebergType::Primitive(name) => primitive_to_dtype(name)?,
+        IcebergType::Struct { fields } => Struct(
+            fields
+                .iter()
+                .map(struct_field_to_field)
+                .collect::<PolarsResult<_>>()?,
+        ),
+        IcebergType::List { element, .. } => List(Box::new(iceberg_type_to_dtype(element)?)),
+        // Loaded as a list of key / value structs, like Parquet maps.
+        IcebergType::Map { key, value, .. } => List(Box::new(Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), iceberg_type_to_dtype(key)?),
+            Field::new(
+                PlSmallStr::from_static("value"),
+                iceberg_type_to_dtype(value)?,
+            ),
+        ]))),
+    })
+}
+
+/// The field IDs of the columns of the schema, including those of nested fields, which the
+/// columns of the data files are matched on.
+pub(super) fn iceberg_schema_field_ids(schema: &IcebergSchema) -> Vec<ParquetFieldOverwrites> {
+    schema.fields.iter().map(struct_field_ids).collect()
+}
+
+fn struct_field_ids(field: &StructField) -> ParquetFieldOverwrites {
+    field_ids(
+        PlSmallStr::from_str(&field.name),
+        Some(field.id),
+        &field.field_type,
+    )
+}
+
+fn field_ids(
+    name: PlSmallStr,
+    field_id: Option<i32>,
+    iceberg_type: &IcebergType,
+) -> ParquetFieldOverwrites {
+    let children = match iceberg_type {
+        IcebergType::Primitive(_) => ChildFieldOverwrites::None,
+        IcebergType::Struct { fields } => {
+            ChildFieldOverwrites::Struct(fields.iter().map(struct_field_ids).collect())
+        },
+        IcebergType::List {
+            element_id,
+            element,
+        } => ChildFieldOverwrites::ListLike(Box::new(field_ids(
+            PlSmallStr::from_static("element"),
+            *element_id,
+            element,
+        ))),
+        IcebergType::Map {
+            key_id,
+            key,
+            value_id,
+            value,
+        } => ChildFieldOverwrites::ListLike(Box::new(ParquetFieldOverwrites {
+            name: Some(PlSmallStr::from_static("key_value")),
+            children: ChildFieldOverwrites::Struct(vec![
+                field_ids(PlSmallStr::from_static("key"), *key_id, key),
+                field_ids(PlSmallStr::from_static("value"), *value_id, value),
+            ]),
+            required: None,
+            field_id: None,
+            metadata: None,
+        })),
+    };
+
+    ParquetFieldOverwrites {
+        name: Some(name),
+        children,
+        required: None,
+        field_id,
+        metadata: None,
+    }
+}
+
+fn primitive_to_dtype(name: &str) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match name {
+        "boolean" => Boolean,
+        "int" => Int32,
+        "long" => Int64,
+        "float" => Float32,
+        "double" => Float64,
+        "date" => Date,
+        "time" => Time,
+        "timestamp" => Datetime(TimeUnit::Microseconds, None),
+        "timestamptz" => Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ns" => Datetime(TimeUnit::Nanoseconds, None),
+        "timestamptz_ns" => Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+        "string" => String,
+        "uuid" | "binary" => Binary,
+        _ if name.starts_with("fixed[") => Binary,
+        _ if name.starts_with("decimal(") => {
+            let err = || polars_err!(ComputeError: "invalid Iceberg decimal type: {}", name);
+
+            let (precision, scale) = name
+                .strip_prefix("decimal(")
+                .and_then(|s| s.strip_suffix(')'))
+                .and_then(|s| s.split_once(','))
+                .ok_or_else(err)?;
+
+            let precision = precision.trim().parse::<usize>().map_err(|_| err())?;
+            let scale = scale.trim().parse::<usize>().map_err(|_| err())?;
+
+            Decimal(Some(precision), Some(scale))
+        },
+        _ => polars_bail!(ComputeError: "unsupported Iceberg type: {}", name),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iceberg_schema_to_schema() {
+        let schema: IcebergSchema = serde_json::from_str(
+            r#"{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": [
+                    {"id": 1, "name": "id", "required": true, "type": "long"},
+                    {"id": 2, "name": "ts", "required": false, "type": "timestamptz"},
+                    {"id": 3, "name": "price", "required": false, "type": "decimal(10, 2)"},
+                    {"id": 4, "name": "tags", "required": false, "type": {
+                        "type": "list", "element-id": 5, "element-required": false,
+                        "element": "string"
+                    }},
+                    {"id": 6, "name": "props", "required": false, "type": {
+                        "type": "map", "key-id": 7, "key": "string",
+                        "value-id": 8, "value-required": false, "value": "int"
+                    }},
+                    {"id": 9, "name": "point", "required": false, "type": {
+                        "type": "struct", "fields": [
+                            {"id": 10, "name": "x", "required": true, "type": "double"}
+                        ]
+                    }},
+                    {"id": 11, "name": "hash", "required": false, "type": "fixed[16]"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let expected = Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new(
+                "ts".into(),
+                DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+            ),
+            Field::new("price".into(), DataType::Decimal(Some(10), Some(2))),
+            Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+            Field::new(
+                "props".into(),
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key".into(), DataType::String),
+                    Field::new("value".into(), DataType::Int32),
+                ]))),
+            ),
+            Field::new(
+                "point".into(),
+                DataType::Struct(vec![Field::new("x".into(), DataType::Float64)]),
+            ),
+            Field::new("hash".into(), DataType::Binary),
+        ]);
+
+        assert_eq!(iceberg_schema_to_schema(&schema).unwrap(), expected);
+        assert!(primitive_to_dtype("variant").is_err());
+
+        let field_ids = iceberg_schema_field_ids(&schema);
+        assert_eq!(
+            field_ids.iter().map(|f| f.field_id).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 6, 9, 11].map(Some)
+        );
+        let ChildFieldOverwrites::ListLike(element) = &field_ids[3].children else {
+            panic!()
+        };
+        assert_eq!(element.field_id, Some(5));
+        let ChildFieldOverwrites::ListLike(key_value) = &field_ids[4].children else {
+            panic!()
+        };
+        let ChildFieldOverwrites::Struct(key_value) = &key_value.children else {
+            panic!()
+        };
+        assert_eq!(
+            key_value.iter().map(|f| f.field_id).collect::<Vec<_>>(),
+            [Some(7), Some(8)]
+        );
+        let ChildFieldOverwrites::Struct(point) = &field_ids[5].children else {
+            panic!()
+        };
+        assert_eq!(point[0].field_id, Some(10));
+    }
+}
@@ -0,0 +1,386 @@
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::plpath::{PlPath, PlPathRef};
+
+use super::manifest::{
+    CONTENT_DATA, CONTENT_EQUALITY_DELETES, CONTENT_POSITION_DELETES, ManifestEntry, read_manifest,
+    read_manifest_list,
+};
+use super::models::{PartitionField, TableMetadata};
+use super::schema::{iceberg_schema_field_ids, iceberg_schema_to_schema};
+use crate::cloud::CloudOptions;
+use crate::expand_paths;
+use crate::parquet::write::ParquetFieldOverwrites;
+use crate::utils::decode_json_response;
+use crate::utils::table_files::{read_files, resolve_absolute_path};
+
+/// The files of an Iceberg table snapshot that are needed to read it.
+#[derive(Debug, Clone)]
+pub struct IcebergSnapshot {
+    /// Path of the `metadata.json` file the snapshot was resolved from.
+    pub metadata_path: PlPath,
+    /// `None` if the table does not have any snapshots.
+    pub snapshot_id: Option<i64>,
+    /// Schema of the snapshot.
+    pub schema: SchemaRef,
+    /// Field IDs of the columns of `schema`. Columns of data files are matched on these rather
+    /// than their names, as columns can be renamed.
+    pub field_ids: Vec<ParquetFieldOverwrites>,
+    pub files: Vec<IcebergDataFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IcebergDataFile {
+    pub path: PlPath,
+    /// The path as written in the manifest, which is how position delete files reference the
+    /// data file.
+    pub file_path: PlSmallStr,
+    pub record_count: i64,
+    /// Partition values of the columns that are partitioned with the identity transform.
+    pub identity_partition_values: PlIndexMap<PlSmallStr, AnyValue<'static>>,
+    pub position_deletes: Vec<PlPath>,
+    pub equality_deletes: Vec<IcebergEqualityDeleteFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IcebergEqualityDeleteFile {
+    pub path: PlPath,
+    /// The field IDs of the columns that rows are matched on.
+    pub equality_ids: Vec<i32>,
+    /// The names of the `equality_ids` columns in the schema of the snapshot.
+    pub columns: Vec<PlSmallStr>,
+}
+
+impl IcebergSnapshot {
+    /// The identity partition values of the data files, with a row per data file. Only columns that
+    /// are identity partitioned in every data file are included.
+    ///
+    /// Returns `None` if there are no such columns.
+    pub fn identity_partition_values(&self) -> PolarsResult<Option<DataFrame>> {
+        let Some(first) = self.files.first() else {
+            return Ok(None);
+        };
+
+        let columns = first
+            .identity_partition_values
+            .keys()
+            .filter(|name| {
+                self.files
+                    .iter()
+                    .all(|f| f.identity_partition_values.contains_key(*name))
+            })
+            .map(|name| {
+                let dtype = self.schema.try_get(name)?;
+                let values = self
+                    .files
+                    .iter()
+                    .map(|f| f.identity_partition_values.get(name).unwrap().clone())
+                    .collect::<Vec<_>>();
+
+                Series::from_any_values_and_dtype(name.clone(), &values, dtype, false)
+                    .map(Column::from)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+
+        DataFrame::new(columns).map(Some)
+    }
+}
+
+/// Reads the files of a snapshot of the Iceberg table at `table_path`, which is either the
+/// location of the table or the path of a `metadata.json` file. The current snapshot is read if
+/// `snapshot_id` is `None`.
+///
+/// Without a catalog, the table metadata with the highest version in the `metadata` directory of
+/// the table is used.
+pub fn read_iceberg_snapshot(
+    table_path: PlPathRef<'_>,
+    snapshot_id: Option<i64>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<IcebergSnapshot> {
+    let verbose = polars_core::config::verbose();
+
+    let metadata_path = resolve_metadata_path(table_path, cloud_options)?;
+    let metadata_bytes = read_files(std::slice::from_ref(&metadata_path), cloud_options)?;
+    let metadata: TableMetadata = decode_json_response(&metadata_bytes[0])?;
+
+    polars_ensure!(
+        metadata.format_version <= 2,
+        ComputeError:
+        "unsupported Iceberg format version: {}",
+        metadata.format_version
+    );
+
+    let snapshot = match snapshot_id {
+        Some(id) => Some(
+            metadata
+                .snapshots
+                .iter()
+                .find(|s| s.snapshot_id == id)
+                .ok_or_else(
+                    || polars_err!(ComputeError: "Iceberg snapshot {} does not exist", id),
+                )?,
+        ),
+        None => metadata.current_snapshot(),
+    };
+
+    let iceberg_schema = metadata
+        .schema(snapshot.and_then(|s| s.schema_id))
+        .ok_or_else(|| polars_err!(ComputeError: "schema not found in Iceberg table metadata"))?;
+    let schema = Arc::new(iceberg_schema_to_schema(iceberg_schema)?);
+
+    let column_names = iceberg_schema
+        .fields
+        .iter()
+        .map(|f| (f.id, PlSmallStr::from_str(&f.name)))
+        .collect::<PlHashMap<_, _>>();
+
+    let mut out = IcebergSnapshot {
+        metadata_path,
+        snapshot_id: snapshot.map(|s| s.snapshot_id),
+        schema,
+        field_ids: iceberg_schema_field_ids(iceberg_schema),
+        files: vec![],
+    };
+
+    let Some(snapshot) = snapshot else {
+        return Ok(out);
+    };
+
+    let manifest_list = snapshot.manifest_list.as_deref().ok_or_else(|| {
+        polars_err!(
+            ComputeError:
+            "unsupported Iceberg snapshot {}: snapshot does not have a manifest list",
+            snapshot.snapshot_id
+        )
+    })?;
+
+    let manifest_list = read_files(&[resolve_absolute_path(manifest_list)], cloud_options)?;
+    let manifest_files = read_manifest_list(manifest_list.into_iter().next().unwrap())?;
+
+    let paths = manifest_files
+        .iter()
+        .map(|m| resolve_absolute_path(&m.path))
+        .collect::<Vec<_>>();
+
+    let mut data_files = vec![];
+    let mut delete_files = vec![];
+
+    for (bytes, manifest_file) in read_files(&paths, cloud_options)?
+        .into_iter()
+        .zip(&manifest_files)
+    {
+        for entry in read_manifest(bytes, manifest_file)? {
+            polars_ensure!(
+                entry.file_format.eq_ignore_ascii_case("parquet"),
+                ComputeError:
+                "unsupported file format in Iceberg table: {} ({})",
+                entry.file_format, entry.file_path
+            );
+
+            match entry.content {
+                CONTENT_DATA => data_files.push(entry),
+                CONTENT_POSITION_DELETES | CONTENT_EQUALITY_DELETES => delete_files.push(entry),
+                v => polars_bail!(ComputeError: "invalid Iceberg data file content: {}", v),
+            }
+        }
+    }
+
+    if verbose {
+        eprintln!(
+            "[read_iceberg_snapshot]: snapshot_id: {}, {} manifests, {} data files, \
+            {} delete files",
+            snapshot.snapshot_id,
+            manifest_files.len(),
+            data_files.len(),
+            delete_files.len()
+        )
+    }
+
+    let deletes = DeleteFileIndex::new(&metadata, &delete_files)?;
+
+    out.files = data_files
+        .iter()
+        .map(|entry| {
+            let spec = partition_spec(&metadata, entry.partition_spec_id)?;
+
+            let identity_partition_values = spec
+                .iter()
+                .zip(&entry.partition)
+                .filter(|(field, _)| field.transform == "identity")
+                .filter_map(|(field, value)| {
+                    Some((column_names.get(&field.source_id)?.clone(), value.clone()))
+                })
+                .collect();
+
+            let mut position_deletes = vec![];
+            let mut equality_deletes = vec![];
+
+            for delete in deletes.get(entry) {
+                if delete.content == CONTENT_POSITION_DELETES {
+                    if delete.sequence_number >= entry.sequence_number
+                        && delete
+                            .referenced_data_file
+                            .as_ref()
+                            .is_none_or(|p| p == &entry.file_path)
+                    {
+                        position_deletes.push(resolve_absolute_path(&delete.file_path));
+                    }
+                } else if delete.sequence_number > entry.sequence_number {
+                    let columns = delete
+                        .equality_ids
+                        .iter()
+                        .map(|id| {
+                            column_names.get(id).cloned().ok_or_else(|| {
+                                polars_err!(
+                                    ComputeError:
+                                    "unsupported Iceberg equality delete file {}: field id {} \
+                                    is not a top-level column",
+                                    delete.file_path, id
+                                )
+                            })
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+
+                    polars_ensure!(
+                        !columns.is_empty(),
+                        ComputeError:
+                        "Iceberg equality delete file {} has no equality_ids",
+                        delete.file_path
+                    );
+
+                    equality_deletes.push(IcebergEqualityDeleteFile {
+                        path: resolve_absolute_path(&delete.file_path),
+                        equality_ids: delete.equality_ids.clone(),
+                        columns,
+                    });
+                }
+            }
+
+            Ok(IcebergDataFile {
+                path: resolve_absolute_path(&entry.file_path),
+                file_path: PlSmallStr::from_str(&entry.file_path),
+                record_count: entry.record_count,
+                identity_partition_values,
+                position_deletes,
+                equality_deletes,
+            })
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    Ok(out)
+}
+
+/// Index of delete files by partition. A delete file applies to data files in the same partition,
+/// or to all data files if it is in an unpartitioned spec.
+struct DeleteFileIndex<'a> {
+    by_partition: PlHashMap<(i32, &'a [AnyValue<'static>]), Vec<&'a ManifestEntry>>,
+    global: Vec<&'a ManifestEntry>,
+}
+
+impl<'a> DeleteFileIndex<'a> {
+    fn new(metadata: &TableMetadata, delete_files: &'a [ManifestEntry]) -> PolarsResult<Self> {
+        let mut by_partition: PlHashMap<_, Vec<_>> = PlHashMap::new();
+        let mut global = vec![];
+
+        for entry in delete_files {
+            if partition_spec(metadata, entry.partition_spec_id)?.is_empty() {
+                global.push(entry);
+            } else {
+                by_partition
+                    .entry((entry.partition_spec_id, entry.partition.as_slice()))
+                    .or_default()
+                    .push(entry);
+            }
+        }
+
+        Ok(Self {
+            by_partition,
+            global,
+        })
+    }
+
+    fn get(&self, data_file: &ManifestEntry) -> Vec<&'a ManifestEntry> {
+        self.by_partition
+            .get(&(data_file.partition_spec_id, data_file.partition.as_slice()))
+            .into_iter()
+            .flatten()
+            .chain(&self.global)
+            .copied()
+            .collect()
+    }
+}
+
+fn partition_spec(metadata: &TableMetadata, spec_id: i32) -> PolarsResult<&[PartitionField]> {
+    metadata.partition_spec(spec_id).ok_or_else(|| {
+        polars_err!(
+            ComputeError:
+            "partition spec {} not found in Iceberg table metadata",
+            spec_id
+        )
+    })
+}
+
+/// Resolves the `metadata.json` to use for the table at `table_path`.
+fn resolve_metadata_path(
+    table_path: PlPathRef<'_>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<PlPath> {
+    let table_path = table_path.to_str();
+
+    if table_path.ends_with(".metadata.json") {
+        return Ok(resolve_absolute_path(table_path));
+    }
+
+    let metadata_dir = resolve_absolute_path(table_path.trim_end_matches('/'))
+        .as_ref()
+        .join("metadata");
+    let paths = expand_paths(
+        &[metadata_dir.as_ref().join("*.metadata.json")],
+        true,
+        cloud_options,
+    )?;
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let name = path.to_str().rsplit(['/', '\\']).next()?;
+            Some((metadata_file_version(name)?, path))
+        })
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, path)| path.clone())
+        .ok_or_else(|| {
+            polars_err!(
+                ComputeError:
+                "not an Iceberg table: no metadata files found in {}",
+                metadata_dir.display()
+            )
+        })
+}
+
+/// Parses the version of a metadata file named `v<version>.metadata.json` or
+/// `<version>-<uuid>.metadata.json`.
+fn metadata_file_version(name: &str) -> Option<u64> {
+    let stem = name.strip_suffix(".metadata.json")?;
+    let stem = stem.strip_prefix('v').unwrap_or(stem);
+    stem.split('-').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_file_version() {
+        assert_eq!(metadata_file_version("v12.metadata.json"), Some(12));
+        assert_eq!(
+            metadata_file_version("00003-6fa3a5ec-2f5e-4a5f-9c1e-8bd9f2a6c1b0.metadata.json"),
+            Some(3)
+        );
+        assert_eq!(metadata_file_version("version-hint.text"), None);
+        assert_eq!(metadata_file_version("snap-1.metadata.json"), None);
+    }
+}
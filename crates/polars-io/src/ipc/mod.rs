@@ -6,6 +6,7 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+pub mod statistics;
 mod write;
 #[cfg(feature = "ipc")]
 pub use ipc_file::{IpcReader, IpcScanOptions};
@@ -13,4 +14,4 @@ pub use ipc_file::{IpcReader, IpcScanOptions};
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
-pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOptions};
+pub use write::{BatchedWriter, IpcCompression, IpcFormat, IpcWriter, IpcWriterOptions};
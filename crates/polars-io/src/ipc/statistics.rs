@@ -0,0 +1,225 @@
+//! Per-record-batch statistics for the Arrow IPC file format.
+//!
+//! The statistics are embedded as a base64 encoded IPC file under
+//! [`RECORD_BATCH_STATISTICS_KEY`] in the custom schema metadata of the file footer. The embedded
+//! file has one row per record batch: a `len` column followed by `{name}_min`, `{name}_max` and
+//! `{name}_nc` columns for every column that supports min/max statistics. This is the layout
+//! expected by [`SkipBatchPredicate`](crate::predicates::SkipBatchPredicate).
+
+use std::io::Cursor;
+
+use arrow::datatypes::Metadata;
+use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use arrow::io::ipc::write::{FileWriter, WriteOptions};
+use base64::Engine as _;
+use base64::engine::general_purpose;
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::predicates::{ColumnStatistics, use_min_max};
+
+/// Custom schema metadata key under which the record batch statistics are stored.
+pub const RECORD_BATCH_STATISTICS_KEY: &str = "polars:record_batch_statistics";
+
+/// Computes the statistics of a column of a single record batch.
+///
+/// Returns `None` if the [`DataType`] does not support min/max statistics.
+pub fn column_statistics(column: &Column) -> PolarsResult<Option<ColumnStatistics>> {
+    if !use_min_max(column.dtype()) {
+        return Ok(None);
+    }
+
+    Ok(Some(ColumnStatistics {
+        dtype: column.dtype().clone(),
+        min: column.min_reduce()?.into_value(),
+        max: column.max_reduce()?.into_value(),
+        null_count: Some(column.null_count() as IdxSize),
+    }))
+}
+
+/// Collects the statistics of the record batches written to an IPC file.
+pub struct RecordBatchStatisticsBuilder {
+    /// Columns of the written schema that support statistics.
+    fields: Vec<Option<Field>>,
+    len: Vec<AnyValue<'static>>,
+    min: Vec<Vec<AnyValue<'static>>>,
+    max: Vec<Vec<AnyValue<'static>>>,
+    null_count: Vec<Vec<AnyValue<'static>>>,
+}
+
+impl RecordBatchStatisticsBuilder {
+    pub fn new(schema: &Schema) -> Self {
+        let fields = schema
+            .iter_fields()
+            .map(|f| use_min_max(f.dtype()).then_some(f))
+            .collect::<Vec<_>>();
+        let n = fields.iter().flatten().count();
+
+        Self {
+            fields,
+            len: Vec::new(),
+            min: vec![Vec::new(); n],
+            max: vec![Vec::new(); n],
+            null_count: vec![Vec::new(); n],
+        }
+    }
+
+    /// Add the statistics of a record batch. `statistics` holds the result of
+    /// [`column_statistics`] for every column of the schema.
+    pub fn push(
+        &mut self,
+        height: usize,
+        statistics: impl IntoIterator<Item = Option<ColumnStatistics>>,
+    ) {
+        self.len.push(AnyValue::from(height as IdxSize));
+
+        let mut statistics = statistics.into_iter();
+        let mut i = 0;
+        for field in &self.fields {
+            let stats = statistics.next().flatten();
+
+            if field.is_none() {
+                continue;
+            }
+
+            let (min, max, null_count) = match stats {
+                None => (AnyValue::Null, AnyValue::Null, AnyValue::Null),
+                Some(stats) => (
+                    stats.min,
+                    stats.max,
+                    stats.null_count.map_or(AnyValue::Null, AnyValue::from),
+                ),
+            };
+            self.min[i].push(min);
+            self.max[i].push(max);
+            self.null_count[i].push(null_count);
+            i += 1;
+        }
+    }
+
+    /// Compute and add the statistics of a record batch.
+    pub fn push_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let statistics = df
+            .get_columns()
+            .iter()
+            .zip(&self.fields)
+            .map(|(c, f)| match f {
+                None => Ok(None),
+                Some(_) => column_statistics(c),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        self.push(df.height(), statistics);
+        Ok(())
+    }
+
+    /// Encode the statistics as a value for [`RECORD_BATCH_STATISTICS_KEY`].
+    pub fn finish(self) -> PolarsResult<PlSmallStr> {
+        let mut columns = Vec::with_capacity(1 + 3 * self.min.len());
+        columns.push(Column::from(Series::from_any_values_and_dtype(
+            PlSmallStr::from_static("len"),
+            &self.len,
+            &IDX_DTYPE,
+            true,
+        )?));
+
+        for (i, field) in self.fields.iter().flatten().enumerate() {
+            let name = field.name();
+            for (suffix, values, dtype) in [
+                ("min", &self.min[i], field.dtype()),
+                ("max", &self.max[i], field.dtype()),
+                ("nc", &self.null_count[i], &IDX_DTYPE),
+            ] {
+                columns.push(Column::from(Series::from_any_values_and_dtype(
+                    format_pl_smallstr!("{name}_{suffix}"),
+                    values,
+                    dtype,
+                    true,
+                )?));
+            }
+        }
+
+        let df = DataFrame::new_with_height(self.len.len(), columns)?;
+
+        let mut buf = Vec::new();
+        let schema = df.schema().to_arrow(CompatLevel::newest());
+        let mut writer = FileWriter::try_new(
+            &mut buf,
+            Arc::new(schema),
+            None,
+            WriteOptions { compression: None },
+        )?;
+        for batch in df.iter_chunks(CompatLevel::newest(), false) {
+            writer.write(&batch, None)?;
+        }
+        writer.finish()?;
+
+        Ok(general_purpose::STANDARD.encode(&buf).into())
+    }
+}
+
+/// The record batch statistics read from the custom schema metadata of an IPC file.
+pub struct RecordBatchStatistics {
+    df: DataFrame,
+}
+
+impl RecordBatchStatistics {
+    /// Returns `None` if the metadata does not contain statistics.
+    pub fn from_custom_metadata(metadata: &Metadata) -> PolarsResult<Option<Self>> {
+        let Some(encoded) = metadata.get(RECORD_BATCH_STATISTICS_KEY) else {
+            return Ok(None);
+        };
+
+        let bytes = general_purpose::STANDARD
+            .decode(encoded.as_bytes())
+            .map_err(
+                |err| polars_err!(ComputeError: "invalid ipc record batch statistics: {err}"),
+            )?;
+        let mut reader = Cursor::new(bytes);
+        let file_metadata = read_file_metadata(&mut reader)?;
+        let schema = Schema::from_arrow_schema(&file_metadata.schema);
+
+        let mut df = DataFrame::empty_with_schema(&schema);
+        for batch in FileReader::new(reader, file_metadata, None, None) {
+            df.append_record_batch(batch?)?;
+        }
+
+        polars_ensure!(
+            df.width() % 3 == 1 && df.get_column_names().first().is_some_and(|n| *n == "len"),
+            ComputeError: "invalid ipc record batch statistics"
+        );
+
+        Ok(Some(Self { df }))
+    }
+
+    pub fn num_batches(&self) -> usize {
+        self.df.height()
+    }
+
+    /// Number of rows in the record batch at `batch_idx`.
+    pub fn batch_len(&self, batch_idx: usize) -> PolarsResult<IdxSize> {
+        Ok(self.df.get_columns()[0].idx()?.get(batch_idx).unwrap_or(0))
+    }
+
+    /// Statistics of the record batch at `batch_idx`, keyed by column name.
+    pub fn batch_statistics(
+        &self,
+        batch_idx: usize,
+    ) -> PolarsResult<PlIndexMap<PlSmallStr, ColumnStatistics>> {
+        self.df.get_columns()[1..]
+            .chunks_exact(3)
+            .map(|c| {
+                let name = c[0].name().strip_suffix("_min").unwrap_or(c[0].name());
+                let null_count = c[2].get(batch_idx)?.extract::<IdxSize>();
+                Ok((
+                    name.into(),
+                    ColumnStatistics {
+                        dtype: c[0].dtype().clone(),
+                        min: c[0].get(batch_idx)?.into_static(),
+                        max: c[1].get(batch_idx)?.into_static(),
+                        null_count,
+                    },
+                ))
+            })
+            .collect()
+    }
+}
@@ -6,9 +6,23 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::{RECORD_BATCH_STATISTICS_KEY, RecordBatchStatisticsBuilder};
 use crate::prelude::*;
 use crate::shared::schema_to_arrow_checked;
 
+/// Flavor of the Arrow IPC format.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum IpcFormat {
+    /// The IPC file format (`.arrow`), which ends with a footer that allows random access.
+    #[default]
+    File,
+    /// The IPC stream format (`.arrows`), which has no footer and must be read sequentially.
+    /// It can be written to targets that don't support seeking, such as sockets or stdout.
+    Stream,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -19,6 +33,14 @@ pub struct IpcWriterOptions {
     pub compat_level: CompatLevel,
     /// Size of each written chunk.
     pub chunk_size: IdxSize,
+    /// Write the IPC file or stream format.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub format: IpcFormat,
+    /// Embed min/max/null-count statistics of every record batch in the file footer, which
+    /// allows scans to skip record batches based on predicates. Only supported by the file
+    /// format.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub record_batch_statistics: bool,
 }
 
 impl Default for IpcWriterOptions {
@@ -27,13 +49,18 @@ impl Default for IpcWriterOptions {
             compression: None,
             compat_level: CompatLevel::newest(),
             chunk_size: 1 << 18,
+            format: IpcFormat::File,
+            record_batch_statistics: false,
         }
     }
 }
 
 impl IpcWriterOptions {
     pub fn to_writer<W: Write>(&self, writer: W) -> IpcWriter<W> {
-        IpcWriter::new(writer).with_compression(self.compression)
+        IpcWriter::new(writer)
+            .with_compression(self.compression)
+            .with_format(self.format)
+            .with_record_batch_statistics(self.record_batch_statistics)
     }
 }
 
@@ -71,6 +98,8 @@ pub struct IpcWriter<W> {
     pub(super) compat_level: CompatLevel,
     pub(super) parallel: bool,
     pub(super) custom_schema_metadata: Option<Arc<Metadata>>,
+    pub(super) format: IpcFormat,
+    pub(super) record_batch_statistics: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -90,21 +119,57 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Write the IPC file or stream format. Defaults to the file format.
+    pub fn with_format(mut self, format: IpcFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Embed per-record-batch statistics in the file footer. Defaults to false.
+    pub fn with_record_batch_statistics(mut self, record_batch_statistics: bool) -> Self {
+        self.record_batch_statistics = record_batch_statistics;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        polars_ensure!(
+            !self.record_batch_statistics || self.format == IpcFormat::File,
+            InvalidOperation: "record batch statistics are only supported by the IPC file format"
+        );
+
+        let statistics = self
+            .record_batch_statistics
+            .then(|| RecordBatchStatisticsBuilder::new(schema));
         let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
-        let mut writer = write::FileWriter::new(
-            self.writer,
-            Arc::new(schema),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
+        let options = WriteOptions {
+            compression: self.compression.map(|c| c.into()),
+        };
+
+        let writer = match self.format {
+            IpcFormat::File => {
+                let mut writer =
+                    write::FileWriter::new(self.writer, Arc::new(schema), None, options);
+                if let Some(custom_metadata) = self.custom_schema_metadata.clone() {
+                    writer.set_custom_schema_metadata(custom_metadata);
+                }
+                writer.start()?;
+                IpcFormatWriter::File(writer)
             },
-        );
-        writer.start()?;
+            IpcFormat::Stream => {
+                let mut writer = write::StreamWriter::new(self.writer, options);
+                if let Some(custom_metadata) = self.custom_schema_metadata.clone() {
+                    writer.set_custom_schema_metadata(custom_metadata);
+                }
+                writer.start(&schema, None)?;
+                IpcFormatWriter::Stream(writer)
+            },
+        };
 
         Ok(BatchedWriter {
             writer,
             compat_level: self.compat_level,
+            statistics,
+            custom_schema_metadata: self.custom_schema_metadata,
         })
     }
 
@@ -125,41 +190,44 @@ where
             compat_level: CompatLevel::newest(),
             parallel: true,
             custom_schema_metadata: None,
+            format: IpcFormat::File,
+            record_batch_statistics: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), self.compat_level, "ipc")?;
-        let mut ipc_writer = write::FileWriter::try_new(
-            &mut self.writer,
-            Arc::new(schema),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        )?;
-        if let Some(custom_metadata) = &self.custom_schema_metadata {
-            ipc_writer.set_custom_schema_metadata(Arc::clone(custom_metadata));
-        }
-
         if self.parallel {
             df.align_chunks_par();
         } else {
             df.align_chunks();
         }
-        let iter = df.iter_chunks(self.compat_level, true);
 
-        for batch in iter {
-            ipc_writer.write(&batch, None)?
+        let mut writer = IpcWriter {
+            writer: &mut self.writer,
+            compression: self.compression,
+            compat_level: self.compat_level,
+            parallel: self.parallel,
+            custom_schema_metadata: self.custom_schema_metadata.clone(),
+            format: self.format,
+            record_batch_statistics: self.record_batch_statistics,
         }
-        ipc_writer.finish()?;
-        Ok(())
+        .batched(df.schema())?;
+
+        writer.write_batch(df)?;
+        writer.finish()
     }
 }
 
+enum IpcFormatWriter<W: Write> {
+    File(write::FileWriter<W>),
+    Stream(write::StreamWriter<W>),
+}
+
 pub struct BatchedWriter<W: Write> {
-    writer: write::FileWriter<W>,
+    writer: IpcFormatWriter<W>,
     compat_level: CompatLevel,
+    statistics: Option<RecordBatchStatisticsBuilder>,
+    custom_schema_metadata: Option<Arc<Metadata>>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -168,9 +236,18 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if let Some(statistics) = &mut self.statistics {
+            for batch in df.clone().split_chunks() {
+                statistics.push_batch(&batch)?;
+            }
+        }
+
         let iter = df.iter_chunks(self.compat_level, true);
         for batch in iter {
-            self.writer.write(&batch, None)?
+            match &mut self.writer {
+                IpcFormatWriter::File(writer) => writer.write(&batch, None)?,
+                IpcFormatWriter::Stream(writer) => writer.write(&batch, None)?,
+            }
         }
         Ok(())
     }
@@ -184,14 +261,40 @@ impl<W: Write> BatchedWriter<W> {
         dictionaries: &[EncodedData],
         message: &EncodedData,
     ) -> PolarsResult<()> {
-        self.writer.write_encoded(dictionaries, message)?;
-        Ok(())
+        match &mut self.writer {
+            IpcFormatWriter::File(writer) => writer.write_encoded(dictionaries, message),
+            IpcFormatWriter::Stream(writer) => writer.write_encoded(dictionaries, message),
+        }
     }
 
-    /// Writes the footer of the IPC file.
+    /// The statistics of the written record batches, if enabled.
+    ///
+    /// Callers of [`BatchedWriter::write_encoded`] have to add the statistics of every encoded
+    /// record batch themselves.
+    pub fn statistics_mut(&mut self) -> Option<&mut RecordBatchStatisticsBuilder> {
+        self.statistics.as_mut()
+    }
+
+    /// Writes the footer of the IPC file, or the end-of-stream marker of the IPC stream.
     pub fn finish(&mut self) -> PolarsResult<()> {
-        self.writer.finish()?;
-        Ok(())
+        match &mut self.writer {
+            IpcFormatWriter::File(writer) => {
+                if let Some(statistics) = self.statistics.take() {
+                    let mut metadata = self
+                        .custom_schema_metadata
+                        .as_deref()
+                        .cloned()
+                        .unwrap_or_default();
+                    metadata.insert(
+                        PlSmallStr::from_static(RECORD_BATCH_STATISTICS_KEY),
+                        statistics.finish()?,
+                    );
+                    writer.set_custom_schema_metadata(Arc::new(metadata));
+                }
+                writer.finish()
+            },
+            IpcFormatWriter::Stream(writer) => writer.finish(),
+        }
     }
 }
 
@@ -63,6 +63,7 @@
 //! ```
 //!
 pub(crate) mod infer;
+pub mod record_path;
 
 use std::io::Write;
 use std::num::NonZeroUsize;
@@ -77,13 +78,42 @@ use polars_json::json::write::FallibleStreamingIterator;
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+use self::record_path::RecordPath;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub struct JsonWriterOptions {}
+pub struct JsonWriterOptions {
+    pub compression: ExternalCompression,
+}
+
+/// Options for a lazy scan of JSON documents.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct JsonScanOptions {
+    /// Location of the records in every document. Defaults to the root, which must be an array of
+    /// objects or a single object.
+    pub record_path: RecordPath,
+    pub infer_schema_length: Option<NonZeroUsize>,
+    pub schema: Option<SchemaRef>,
+    pub schema_overwrite: Option<SchemaRef>,
+    pub ignore_errors: bool,
+}
+
+impl Default for JsonScanOptions {
+    fn default() -> Self {
+        Self {
+            record_path: RecordPath::default(),
+            infer_schema_length: NonZeroUsize::new(100),
+            schema: None,
+            schema_overwrite: None,
+            ignore_errors: false,
+        }
+    }
+}
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
 /// or `JsonLines` (each row output on a separate line).
@@ -0,0 +1,675 @@
+//! Incrementally reading the records of a JSON document.
+//!
+//! A [`RecordPath`] is a JSONPath expression. The document is parsed incrementally up to the value
+//! at the leading member accesses of the path (e.g. `$.data.items`), after which the elements of
+//! that value are read one at a time. Any remainder of the path is evaluated on every element with
+//! the JSONPath implementation of `str.json_path_match`. This way only a single element has to be
+//! held in memory at a time, regardless of the size of the document.
+use std::collections::VecDeque;
+use std::io::Read;
+use std::num::NonZeroUsize;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_ops::chunked_array::strings::{PathCompiled, compile_json_path, select_json_values};
+use polars_utils::format_pl_smallstr;
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::utils::compression::StreamingDecompressor;
+
+/// Path to the records in a JSON document, e.g. `$.data.items[*]`.
+///
+/// Every object selected by the path is a record, and if the path selects an array every element
+/// of the array is a record.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct RecordPath {
+    /// Member keys leading to the value whose elements are read one at a time.
+    keys: Vec<PlSmallStr>,
+    /// Whether the keys are followed by a wildcard.
+    wildcard: bool,
+    /// The rest of the path, relative to `$`.
+    rest: Option<PlSmallStr>,
+}
+
+impl RecordPath {
+    pub fn parse(path: &str) -> PolarsResult<Self> {
+        let path = path.trim();
+        let err = || polars_err!(ComputeError: "invalid JSON record path: '{}'", path);
+
+        let mut rest = path.strip_prefix('$').ok_or_else(err)?;
+        compile_json_path(path).map_err(|_| err())?;
+
+        let mut keys = vec![];
+        let mut wildcard = false;
+
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix("[*]").or_else(|| rest.strip_prefix(".*")) {
+                wildcard = true;
+                rest = r;
+                break;
+            }
+
+            let quoted_key = ["'", "\""].into_iter().find_map(|quote| {
+                let r = rest.strip_prefix('[')?.strip_prefix(quote)?;
+                let end = r.find(quote)?;
+                let after = r[end + 1..].strip_prefix(']')?;
+                Some((&r[..end], after))
+            });
+
+            if let Some((key, r)) = quoted_key {
+                keys.push(key.into());
+                rest = r;
+            } else if let Some(r) = rest.strip_prefix('.').filter(|r| !r.starts_with('.')) {
+                let end = r.find(['.', '[']).unwrap_or(r.len());
+                if end == 0 {
+                    return Err(err());
+                }
+                keys.push(r[..end].into());
+                rest = &r[end..];
+            } else {
+                break;
+            }
+        }
+
+        Ok(Self {
+            keys,
+            wildcard,
+            rest: (!rest.is_empty()).then(|| format_pl_smallstr!("${rest}")),
+        })
+    }
+}
+
+/// The records of a batch, stored contiguously.
+#[derive(Debug, Default)]
+pub struct JsonRecords {
+    bytes: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl JsonRecords {
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        let starts = std::iter::once(0).chain(self.ends.iter().copied());
+        starts
+            .zip(self.ends.iter().copied())
+            .map(|(start, end)| &self.bytes[start..end])
+    }
+
+    fn push(&mut self, record: &[u8]) {
+        self.bytes.extend_from_slice(record);
+        self.ends.push(self.bytes.len());
+    }
+}
+
+enum State {
+    Start,
+    /// Reading the elements of an array or the member values of an object.
+    Elements {
+        object: bool,
+        first: bool,
+    },
+    Done,
+}
+
+/// Reads the records at a [`RecordPath`] from a JSON document, without reading the whole document
+/// into memory.
+pub struct RecordReader<R> {
+    scanner: Scanner<R>,
+    record_path: RecordPath,
+    state: State,
+    /// Records selected from an element by the rest of the path that have not been returned yet.
+    pending: VecDeque<Vec<u8>>,
+    scratch: Vec<u8>,
+}
+
+impl RecordReader<Box<dyn Read + Send>> {
+    /// Reads the records of a possibly compressed document.
+    pub fn from_memslice(memslice: MemSlice, record_path: &RecordPath) -> PolarsResult<Self> {
+        let reader: Box<dyn Read + Send> = match StreamingDecompressor::try_new(memslice.clone())? {
+            Some(decompressor) => Box::new(decompressor),
+            None => Box::new(std::io::Cursor::new(memslice)),
+        };
+        Ok(Self::new(reader, record_path))
+    }
+}
+
+impl<R: Read> RecordReader<R> {
+    pub fn new(reader: R, record_path: &RecordPath) -> Self {
+        Self {
+            scanner: Scanner {
+                reader,
+                buf: vec![],
+                pos: 0,
+                mark: None,
+                consumed: 0,
+                eof: false,
+                key: String::new(),
+            },
+            record_path: record_path.clone(),
+            state: State::Start,
+            pending: VecDeque::new(),
+            scratch: vec![],
+        }
+    }
+
+    /// Appends up to `n` records to `out`. Returns the number of records appended, which is only
+    /// less than `n` at the end of the document.
+    pub fn read(&mut self, n: usize, out: &mut JsonRecords) -> PolarsResult<usize> {
+        self.advance(n, Some(out))
+    }
+
+    /// Skips up to `n` records. Returns the number of records skipped, which is only less than `n`
+    /// at the end of the document.
+    pub fn skip(&mut self, n: usize) -> PolarsResult<usize> {
+        self.advance(n, None)
+    }
+
+    fn advance(&mut self, n: usize, mut out: Option<&mut JsonRecords>) -> PolarsResult<usize> {
+        let mut n_records = 0;
+
+        // The rest of the path is compiled once, and applied to every element.
+        let rest = self.record_path.rest.clone();
+        let rest = rest.as_deref().map(compile_json_path).transpose()?;
+
+        while n_records < n {
+            if let Some(record) = self.pending.pop_front() {
+                if let Some(out) = out.as_deref_mut() {
+                    out.push(&record);
+                }
+                n_records += 1;
+                continue;
+            }
+
+            match self.state {
+                State::Start => self.start(rest.as_ref())?,
+                State::Elements { object, first } => {
+                    if !self.scanner.next_element(object, first)? {
+                        self.state = State::Done;
+                        continue;
+                    }
+                    self.state = State::Elements {
+                        object,
+                        first: false,
+                    };
+
+                    if let Some(rest) = &rest {
+                        self.select_rest(rest)?;
+                    } else if let Some(out) = out.as_deref_mut() {
+                        self.scanner.expect_record()?;
+                        self.scanner.capture_value(&mut out.bytes)?;
+                        out.ends.push(out.bytes.len());
+                        n_records += 1;
+                    } else {
+                        self.scanner.expect_record()?;
+                        self.scanner.skip_value()?;
+                        n_records += 1;
+                    }
+                },
+                State::Done => break,
+            }
+        }
+
+        Ok(n_records)
+    }
+
+    /// Parses the document up to the value at the keys of the path.
+    fn start(&mut self, rest: Option<&PathCompiled<'_>>) -> PolarsResult<()> {
+        let s = &mut self.scanner;
+        self.state = State::Done;
+
+        s.skip_bom()?;
+        for key in &self.record_path.keys {
+            if !s.find_member(key)? {
+                return Ok(());
+            }
+        }
+
+        s.skip_whitespace()?;
+        let value_type = s.peek()?;
+
+        if self.record_path.wildcard {
+            if let Some(b'[' | b'{') = value_type {
+                s.pos += 1;
+                self.state = State::Elements {
+                    object: value_type == Some(b'{'),
+                    first: true,
+                };
+            }
+        } else if let Some(rest) = rest {
+            if value_type.is_some() {
+                self.select_rest(rest)?;
+            }
+        } else {
+            match value_type {
+                Some(b'[') => {
+                    s.pos += 1;
+                    self.state = State::Elements {
+                        object: false,
+                        first: true,
+                    };
+                },
+                None => {},
+                _ => {
+                    s.expect_record()?;
+                    self.scratch.clear();
+                    s.capture_value(&mut self.scratch)?;
+                    self.pending.push_back(std::mem::take(&mut self.scratch));
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses the value at the cursor and adds the records selected from it by the compiled rest
+    /// of the path to `pending`.
+    fn select_rest(&mut self, rest: &PathCompiled<'_>) -> PolarsResult<()> {
+        self.scratch.clear();
+        self.scanner.capture_value(&mut self.scratch)?;
+
+        let value: serde_json::Value = serde_json::from_slice(&self.scratch)
+            .map_err(|e| polars_err!(ComputeError: "invalid JSON document: {}", e))?;
+
+        for selected in select_json_values(rest, &value)? {
+            let records = match selected {
+                serde_json::Value::Array(values) => values.as_slice(),
+                value => std::slice::from_ref(value),
+            };
+            for record in records {
+                polars_ensure!(
+                    record.is_object(),
+                    ComputeError: "invalid JSON document: JSON records must be objects"
+                );
+                self.pending
+                    .push_back(serde_json::to_vec(record).map_err(to_compute_err)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Infer the schema of the records of a JSON document from its first `infer_schema_length`
+/// records.
+pub fn infer_records_schema(
+    memslice: MemSlice,
+    record_path: &RecordPath,
+    infer_schema_length: Option<NonZeroUsize>,
+) -> PolarsResult<Schema> {
+    let limit = infer_schema_length.map_or(usize::MAX, NonZeroUsize::get);
+    let mut records = JsonRecords::default();
+    RecordReader::from_memslice(memslice, record_path)?.read(limit, &mut records)?;
+
+    polars_ensure!(
+        !records.is_empty(),
+        ComputeError: "cannot infer the schema of a JSON document without records"
+    );
+
+    let mut dtypes = PlIndexSet::default();
+    let mut buf = vec![];
+    for record in records.iter() {
+        buf.clear();
+        buf.extend_from_slice(record);
+        let value = simd_json::to_borrowed_value(&mut buf)
+            .map_err(|e| polars_err!(ComputeError: "error parsing JSON record: {}", e))?;
+        dtypes.insert(polars_json::json::infer(&value)?);
+    }
+
+    crate::ndjson::schema_from_dtypes(dtypes.into_iter())
+}
+
+/// Parse records into a [`DataFrame`] with the given schema.
+pub fn read_records(
+    records: &JsonRecords,
+    schema: &Schema,
+    ignore_errors: bool,
+) -> PolarsResult<DataFrame> {
+    if schema.is_empty() {
+        return Ok(DataFrame::empty_with_height(records.len()));
+    }
+
+    crate::ndjson::core::parse_json_values(records.iter(), records.len(), schema, ignore_errors)
+}
+
+/// Size of the reads from the underlying reader.
+const READ_SIZE: usize = 1 << 16;
+
+struct Scanner<R> {
+    reader: R,
+    buf: Vec<u8>,
+    pos: usize,
+    /// Start of the value that is being captured. Bytes from here on are kept when refilling.
+    mark: Option<usize>,
+    /// Number of bytes dropped from the front of `buf`.
+    consumed: usize,
+    eof: bool,
+    /// Key of the object member at the cursor.
+    key: String,
+}
+
+impl<R: Read> Scanner<R> {
+    fn error(&self, msg: &str) -> PolarsError {
+        polars_err!(
+            ComputeError: "invalid JSON document: {} at byte {}", msg, self.consumed + self.pos
+        )
+    }
+
+    /// Reads more bytes into the buffer. Returns `false` at the end of the document.
+    fn fill(&mut self) -> PolarsResult<bool> {
+        if self.eof {
+            return Ok(false);
+        }
+
+        let keep_from = self.mark.unwrap_or(self.pos);
+        self.buf.drain(..keep_from);
+        self.consumed += keep_from;
+        self.pos -= keep_from;
+        if let Some(mark) = &mut self.mark {
+            *mark = 0;
+        }
+
+        let len = self.buf.len();
+        self.buf.resize(len + READ_SIZE, 0);
+        let n_read = loop {
+            match self.reader.read(&mut self.buf[len..]) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e.into()),
+            }
+        };
+        self.buf.truncate(len + n_read);
+        self.eof = n_read == 0;
+
+        Ok(n_read > 0)
+    }
+
+    #[inline]
+    fn peek(&mut self) -> PolarsResult<Option<u8>> {
+        if self.pos == self.buf.len() && !self.fill()? {
+            return Ok(None);
+        }
+        Ok(Some(self.buf[self.pos]))
+    }
+
+    fn skip_bom(&mut self) -> PolarsResult<()> {
+        while self.buf.len() - self.pos < 3 && self.fill()? {}
+        if self.buf[self.pos..].starts_with(&[0xEF, 0xBB, 0xBF]) {
+            self.pos += 3;
+        }
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) -> PolarsResult<()> {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek()? {
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    fn expect(&mut self, b: u8) -> PolarsResult<()> {
+        self.skip_whitespace()?;
+        if self.peek()? != Some(b) {
+            return Err(self.error(&format!("expected '{}'", b as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Errors if the value at the cursor is not an object.
+    fn expect_record(&mut self) -> PolarsResult<()> {
+        self.skip_whitespace()?;
+        if self.peek()? != Some(b'{') {
+            return Err(self.error("JSON records must be objects"));
+        }
+        Ok(())
+    }
+
+    /// Skip a string, with the cursor on the opening quote.
+    fn skip_string(&mut self) -> PolarsResult<()> {
+        self.pos += 1;
+        loop {
+            match memchr::memchr2(b'"', b'\\', &self.buf[self.pos..]) {
+                Some(offset) if self.buf[self.pos + offset] == b'\\' => {
+                    self.pos += offset + 1;
+                    if self.peek()?.is_none() {
+                        return Err(self.error("unterminated string"));
+                    }
+                    self.pos += 1;
+                },
+                Some(offset) => {
+                    self.pos += offset + 1;
+                    return Ok(());
+                },
+                None => {
+                    self.pos = self.buf.len();
+                    if !self.fill()? {
+                        return Err(self.error("unterminated string"));
+                    }
+                },
+            }
+        }
+    }
+
+    fn skip_value(&mut self) -> PolarsResult<()> {
+        self.skip_whitespace()?;
+        let start = self.consumed + self.pos;
+
+        match self.peek()? {
+            Some(b'"') => self.skip_string()?,
+            Some(b'{' | b'[') => {
+                let mut depth = 0usize;
+                loop {
+                    match self.peek()? {
+                        Some(b'"') => {
+                            self.skip_string()?;
+                            continue;
+                        },
+                        Some(b'{' | b'[') => depth += 1,
+                        Some(b'}' | b']') => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                break;
+                            }
+                        },
+                        Some(_) => {},
+                        None => return Err(self.error("unexpected end of document")),
+                    }
+                    self.pos += 1;
+                }
+            },
+            Some(_) => {
+                while !matches!(
+                    self.peek()?,
+                    None | Some(b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+                if self.consumed + self.pos == start {
+                    return Err(self.error("expected a value"));
+                }
+            },
+            None => return Err(self.error("unexpected end of document")),
+        }
+
+        Ok(())
+    }
+
+    /// Skip the value at the cursor and append its bytes to `out`.
+    fn capture_value(&mut self, out: &mut Vec<u8>) -> PolarsResult<()> {
+        self.skip_whitespace()?;
+        self.mark = Some(self.pos);
+        let result = self.skip_value();
+        let mark = self.mark.take().unwrap();
+        result?;
+        out.extend_from_slice(&self.buf[mark..self.pos]);
+        Ok(())
+    }
+
+    /// Reads an object key into `key`, with the cursor on the opening quote.
+    fn read_key(&mut self) -> PolarsResult<()> {
+        self.mark = Some(self.pos);
+        let result = self.skip_string();
+        let mark = self.mark.take().unwrap();
+        result?;
+
+        let quoted = &self.buf[mark..self.pos];
+        self.key.clear();
+        if quoted.contains(&b'\\') {
+            // Let simd-json resolve the escapes of the quoted key.
+            let mut quoted = quoted.to_vec();
+            match simd_json::to_borrowed_value(&mut quoted) {
+                Ok(simd_json::BorrowedValue::String(key)) => self.key.push_str(&key),
+                _ => return Err(self.error("invalid object key")),
+            }
+        } else {
+            let key = std::str::from_utf8(&quoted[1..quoted.len() - 1])
+                .map_err(|_| self.error("invalid utf-8"))?;
+            self.key.push_str(key);
+        }
+
+        Ok(())
+    }
+
+    /// Moves the cursor to the value of the member `name` of the object at the cursor. Returns
+    /// `false` if the value at the cursor is not an object or has no such member.
+    fn find_member(&mut self, name: &str) -> PolarsResult<bool> {
+        self.skip_whitespace()?;
+        if self.peek()? != Some(b'{') {
+            return Ok(false);
+        }
+        self.pos += 1;
+
+        let mut first = true;
+        while self.next_element(true, first)? {
+            first = false;
+            if self.key == name {
+                return Ok(true);
+            }
+            self.skip_value()?;
+        }
+
+        Ok(false)
+    }
+
+    /// Moves the cursor to the next element of the array, or the value of the next member of the
+    /// object, that the cursor is in. Returns `false` at the end of the array or object.
+    fn next_element(&mut self, object: bool, first: bool) -> PolarsResult<bool> {
+        let close = if object { b'}' } else { b']' };
+
+        self.skip_whitespace()?;
+        match self.peek()? {
+            Some(b) if b == close => {
+                self.pos += 1;
+                return Ok(false);
+            },
+            Some(b',') if !first => self.pos += 1,
+            _ if first => {},
+            _ => return Err(self.error(&format!("expected ',' or '{}'", close as char))),
+        }
+
+        if object {
+            self.skip_whitespace()?;
+            if self.peek()? != Some(b'"') {
+                return Err(self.error("expected an object key"));
+            }
+            self.read_key()?;
+            self.expect(b':')?;
+        }
+        self.skip_whitespace()?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a single byte per read, so that every value spans multiple reads.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let Some((first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            buf[0] = *first;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    fn records(path: &str, doc: &str) -> PolarsResult<Vec<String>> {
+        let mut reader = RecordReader::new(ByteReader(doc.as_bytes()), &RecordPath::parse(path)?);
+        let mut records = JsonRecords::default();
+        while reader.read(2, &mut records)? > 0 {}
+
+        Ok(records
+            .iter()
+            .map(|r| String::from_utf8(r.to_vec()).unwrap())
+            .collect())
+    }
+
+    #[test]
+    fn test_record_path() -> PolarsResult<()> {
+        let doc = r#"{
+            "meta": {"items": [{"skip": "}]"}]},
+            "data": {"items": [{"a": 1}, {"a": [2, {"b": "]"}]}], "n": 2},
+            "pa\"ge": [{"items": [{"c": 3}]}, {"items": [{"c": 4}, {"c": 5}]}]
+        }"#;
+
+        assert_eq!(
+            records("$.data.items[*]", doc)?,
+            [r#"{"a": 1}"#, r#"{"a": [2, {"b": "]"}]}"#]
+        );
+        assert_eq!(records("$.data.items", doc)?.len(), 2);
+        assert_eq!(
+            records(r#"$['pa"ge'][*].items"#, doc)?,
+            [r#"{"c":3}"#, r#"{"c":4}"#, r#"{"c":5}"#]
+        );
+        assert_eq!(records("$.data.items[?(@.a == 1)]", doc)?, [r#"{"a":1}"#]);
+        assert_eq!(records("$.meta", doc)?, [r#"{"items": [{"skip": "}]"}]}"#]);
+        assert!(records("$.missing[*]", doc)?.is_empty());
+        assert!(records("$.data.n", doc).is_err());
+
+        assert_eq!(
+            records("$", "\u{feff}[{}, {\"x\": null}]")?,
+            ["{}", "{\"x\": null}"]
+        );
+        assert!(records("$", "[{}").is_err());
+        assert!(RecordPath::parse("data.items").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_reader_skip() -> PolarsResult<()> {
+        let doc = r#"{"items": [{"a": 0}, {"a": 1}, {"a": 2}, {"a": 3}]}"#;
+        let mut reader =
+            RecordReader::new(ByteReader(doc.as_bytes()), &RecordPath::parse("$.items")?);
+
+        assert_eq!(reader.skip(1)?, 1);
+        let mut records = JsonRecords::default();
+        assert_eq!(reader.read(2, &mut records)?, 2);
+        assert_eq!(
+            records.iter().collect::<Vec<_>>(),
+            [b"{\"a\": 1}", b"{\"a\": 2}"]
+        );
+        assert_eq!(reader.skip(usize::MAX)?, 1);
+        assert_eq!(reader.read(1, &mut records)?, 0);
+
+        Ok(())
+    }
+}
@@ -10,19 +10,27 @@ pub mod catalog;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "delta")]
+pub mod delta;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "csv")]
+pub mod fixed_width;
+#[cfg(feature = "iceberg")]
+pub mod iceberg;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+pub mod metrics;
 pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
-#[cfg(feature = "parquet")]
 pub mod partition;
 pub mod path_utils;
 #[cfg(feature = "async")]
@@ -30,6 +38,8 @@ pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
 mod shared;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod utils;
 
 #[cfg(feature = "cloud")]
@@ -0,0 +1,71 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// I/O counters of a single scan.
+///
+/// The counters are updated concurrently by the readers of the scan and can be read at any time,
+/// e.g. after the query has finished to report them in a query profile.
+#[derive(Debug, Default)]
+pub struct IOMetrics {
+    /// Number of files that were read. Files that are opened multiple times, e.g. to resolve a
+    /// negative slice, are counted once.
+    pub files_read: AtomicU64,
+    /// Number of files skipped based on the predicate, e.g. on hive partition values.
+    pub files_pruned: AtomicU64,
+    /// Number of row groups that were selected to be read. ORC stripes and IPC record batches are
+    /// counted as row groups.
+    pub row_groups_read: AtomicU64,
+    /// Number of row groups skipped based on the predicate and the row group statistics.
+    pub row_groups_pruned: AtomicU64,
+    /// Number of full object GET requests.
+    pub get_requests: AtomicU64,
+    /// Number of ranged GET requests.
+    pub range_requests: AtomicU64,
+    /// Number of bytes fetched from the object store. This includes the gaps between ranges
+    /// that were coalesced into a single request.
+    pub bytes_requested: AtomicU64,
+    /// Number of bytes the readers asked for.
+    pub bytes_used: AtomicU64,
+    /// Number of files served from the file cache without downloading them.
+    pub file_cache_hits: AtomicU64,
+    /// Number of files that had to be downloaded into the file cache.
+    pub file_cache_misses: AtomicU64,
+}
+
+impl IOMetrics {
+    pub fn add(counter: &AtomicU64, value: usize) {
+        counter.fetch_add(value as u64, Ordering::Relaxed);
+    }
+
+    /// Records a set of requests fetching `bytes_requested` bytes, of which `bytes_used` bytes were
+    /// asked for.
+    pub fn record_requests(
+        &self,
+        n_get: usize,
+        n_range: usize,
+        bytes_requested: usize,
+        bytes_used: usize,
+    ) {
+        Self::add(&self.get_requests, n_get);
+        Self::add(&self.range_requests, n_range);
+        Self::add(&self.bytes_requested, bytes_requested);
+        Self::add(&self.bytes_used, bytes_used);
+    }
+
+    /// Returns the current value of every counter along with its name.
+    pub fn counters(&self) -> [(&'static str, u64); 10] {
+        let get = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+
+        [
+            ("files_read", get(&self.files_read)),
+            ("files_pruned", get(&self.files_pruned)),
+            ("row_groups_read", get(&self.row_groups_read)),
+            ("row_groups_pruned", get(&self.row_groups_pruned)),
+            ("get_requests", get(&self.get_requests)),
+            ("range_requests", get(&self.range_requests)),
+            ("bytes_requested", get(&self.bytes_requested)),
+            ("bytes_used", get(&self.bytes_used)),
+            ("file_cache_hits", get(&self.file_cache_hits)),
+            ("file_cache_misses", get(&self.file_cache_misses)),
+        ]
+    }
+}
@@ -397,16 +397,6 @@ pub fn json_lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
     })
 }
 
-fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> PolarsResult<()> {
-    let mut scratch = Scratch::default();
-
-    let iter = json_lines(bytes);
-    for bytes in iter {
-        parse_impl(bytes, buffers, &mut scratch)?;
-    }
-    Ok(())
-}
-
 pub fn parse_ndjson(
     bytes: &[u8],
     n_rows_hint: Option<usize>,
@@ -414,9 +404,23 @@ pub fn parse_ndjson(
     ignore_errors: bool,
 ) -> PolarsResult<DataFrame> {
     let capacity = n_rows_hint.unwrap_or_else(|| estimate_n_lines_in_chunk(bytes));
+    parse_json_values(json_lines(bytes), capacity, schema, ignore_errors)
+}
 
+/// Parse JSON values, e.g. the lines of an NDJSON file, into a [`DataFrame`] with the given
+/// schema. Values that are not objects become rows of nulls.
+pub fn parse_json_values<'a>(
+    values: impl Iterator<Item = &'a [u8]>,
+    capacity: usize,
+    schema: &Schema,
+    ignore_errors: bool,
+) -> PolarsResult<DataFrame> {
     let mut buffers = init_buffers(schema, capacity, ignore_errors)?;
-    parse_lines(bytes, &mut buffers)?;
+    let mut scratch = Scratch::default();
+
+    for bytes in values {
+        parse_impl(bytes, &mut buffers, &mut scratch)?;
+    }
 
     DataFrame::new(
         buffers
@@ -14,6 +14,13 @@ pub fn infer_schema<R: std::io::BufRead>(
     infer_schema_len: Option<NonZeroUsize>,
 ) -> PolarsResult<Schema> {
     let dtypes = polars_json::ndjson::iter_unique_dtypes(reader, infer_schema_len)?;
+    schema_from_dtypes(dtypes)
+}
+
+/// Schema of the supertype of the inferred dtypes of JSON objects.
+pub(crate) fn schema_from_dtypes(
+    dtypes: impl Iterator<Item = ArrowDataType>,
+) -> PolarsResult<Schema> {
     let dtype =
         crate::json::infer::dtypes_to_supertype(dtypes.map(|dt| DataType::from_arrow_dtype(&dt)))?;
     let schema = StructArray::get_fields(&dtype.to_arrow(CompatLevel::newest()))
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use polars_core::schema::SchemaRef;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
@@ -24,8 +26,25 @@ pub struct HiveOptions {
     pub hive_start_idx: usize,
     pub schema: Option<SchemaRef>,
     pub try_parse_dates: bool,
+    /// Template to extract the partition columns from paths that are not laid out as
+    /// `key=value` directories, e.g. `{year}/{month}/{day}/{source}.parquet`.
+    ///
+    /// The template is matched against the trailing segments of each path, including the file
+    /// name. A `{name}` placeholder matches a non-empty part of a segment and yields a column,
+    /// `*` matches any part of a segment and all other characters must match literally.
+    /// Setting a template enables partitioning if `enabled` is `None`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub path_template: Option<PlSmallStr>,
+    /// Partition values of every path, in the order of the columns of `schema`, with `None` for
+    /// nulls. These are used instead of values extracted from the paths, e.g. for tables that
+    /// record the partition values of their data files in a transaction log.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub partition_values: Option<Arc<[PartitionValues]>>,
 }
 
+/// Partition values of a single path, see [`HiveOptions::partition_values`].
+pub type PartitionValues = Arc<[Option<PlSmallStr>]>;
+
 impl HiveOptions {
     pub fn new_enabled() -> Self {
         Self {
@@ -33,6 +52,8 @@ impl HiveOptions {
             hive_start_idx: 0,
             schema: None,
             try_parse_dates: true,
+            path_template: None,
+            partition_values: None,
         }
     }
 
@@ -42,6 +63,8 @@ impl HiveOptions {
             hive_start_idx: 0,
             schema: None,
             try_parse_dates: false,
+            path_template: None,
+            partition_values: None,
         }
     }
 }
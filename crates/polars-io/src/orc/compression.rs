@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::proto::CompressionKind;
+
+/// Default size of the uncompressed chunks that streams are split into before compression.
+pub(super) const DEFAULT_COMPRESSION_BLOCK_SIZE: usize = 256 * 1024;
+
+/// Compression codec used for the streams and the file tail of an ORC file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum OrcCompression {
+    Uncompressed,
+    /// Raw deflate with an optional compression level (0-9).
+    Zlib(Option<u32>),
+    Snappy,
+    Lz4,
+    /// ZSTD with an optional compression level (1-22).
+    Zstd(Option<i32>),
+}
+
+impl Default for OrcCompression {
+    fn default() -> Self {
+        Self::Zstd(None)
+    }
+}
+
+impl OrcCompression {
+    pub(super) fn kind(&self) -> CompressionKind {
+        match self {
+            Self::Uncompressed => CompressionKind::None,
+            Self::Zlib(_) => CompressionKind::Zlib,
+            Self::Snappy => CompressionKind::Snappy,
+            Self::Lz4 => CompressionKind::Lz4,
+            Self::Zstd(_) => CompressionKind::Zstd,
+        }
+    }
+
+    /// Compress `data` into `out` as a sequence of ORC compression chunks.
+    pub(super) fn compress(
+        &self,
+        data: &[u8],
+        block_size: usize,
+        out: &mut Vec<u8>,
+    ) -> PolarsResult<()> {
+        if matches!(self, Self::Uncompressed) {
+            out.extend_from_slice(data);
+            return Ok(());
+        }
+
+        let mut scratch = Vec::new();
+        for chunk in data.chunks(block_size) {
+            scratch.clear();
+            self.compress_chunk(chunk, &mut scratch)?;
+
+            // A chunk that does not shrink is stored as-is, flagged as "original".
+            let (body, is_original) = if scratch.len() < chunk.len() {
+                (scratch.as_slice(), false)
+            } else {
+                (chunk, true)
+            };
+
+            let header = ((body.len() as u32) << 1) | is_original as u32;
+            out.extend_from_slice(&header.to_le_bytes()[..3]);
+            out.extend_from_slice(body);
+        }
+
+        Ok(())
+    }
+
+    fn compress_chunk(&self, chunk: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        match self {
+            Self::Uncompressed => out.extend_from_slice(chunk),
+            Self::Zlib(level) => {
+                let level = flate2::Compression::new(level.unwrap_or(6));
+                let mut encoder = flate2::write::DeflateEncoder::new(out, level);
+                encoder.write_all(chunk)?;
+                encoder.finish()?;
+            },
+            Self::Snappy => {
+                let compressed = snap::raw::Encoder::new()
+                    .compress_vec(chunk)
+                    .map_err(to_compute_err)?;
+                out.extend_from_slice(&compressed);
+            },
+            Self::Lz4 => out.extend_from_slice(&lz4_flex::block::compress(chunk)),
+            Self::Zstd(level) => {
+                let compressed = zstd::bulk::compress(chunk, level.unwrap_or(3))?;
+                out.extend_from_slice(&compressed);
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Decompress a stream (or file tail section) that was written with the given codec.
+pub(super) fn decompress(
+    kind: CompressionKind,
+    data: &[u8],
+    block_size: usize,
+) -> PolarsResult<Cow<'_, [u8]>> {
+    if kind == CompressionKind::None {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut remaining = data;
+
+    while !remaining.is_empty() {
+        polars_ensure!(
+            remaining.len() >= 3,
+            ComputeError: "ORC: truncated compression chunk header"
+        );
+        let header = u32::from_le_bytes([remaining[0], remaining[1], remaining[2], 0]);
+        let is_original = header & 1 == 1;
+        let len = (header >> 1) as usize;
+        remaining = &remaining[3..];
+
+        polars_ensure!(
+            remaining.len() >= len,
+            ComputeError: "ORC: compression chunk exceeds stream length"
+        );
+        let (chunk, rest) = remaining.split_at(len);
+        remaining = rest;
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+
+        match kind {
+            CompressionKind::None => unreachable!(),
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut out)
+                    .map_err(to_compute_err)?;
+            },
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            CompressionKind::Lz4 => {
+                let decompressed =
+                    lz4_flex::block::decompress(chunk, block_size).map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            CompressionKind::Zstd => {
+                zstd::Decoder::with_buffer(chunk)?.read_to_end(&mut out)?;
+            },
+            CompressionKind::Lzo => {
+                polars_bail!(ComputeError: "ORC: LZO compression is not supported")
+            },
+        }
+    }
+
+    Ok(Cow::Owned(out))
+}
@@ -0,0 +1,299 @@
+use arrow::array::ListArray;
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field, TimeUnit};
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+use prost::Message;
+
+use super::compression::{DEFAULT_COMPRESSION_BLOCK_SIZE, decompress};
+use super::proto::{self, CompressionKind, TypeKind};
+use crate::predicates::ColumnStatistics;
+
+pub(super) const MAGIC: &[u8] = b"ORC";
+
+/// Seconds between the UNIX epoch and the ORC timestamp epoch (2015-01-01 00:00:00).
+pub(super) const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// Metadata found in the tail of an ORC file.
+#[derive(Debug)]
+pub struct OrcMetadata {
+    pub(super) compression: CompressionKind,
+    pub(super) compression_block_size: usize,
+    pub(super) footer: proto::Footer,
+    pub(super) stripe_statistics: Vec<proto::StripeStatistics>,
+    /// Column id (index into `footer.types`) of every top-level field.
+    pub(super) column_ids: Vec<usize>,
+    pub(super) schema: ArrowSchemaRef,
+}
+
+pub type OrcMetadataRef = Arc<OrcMetadata>;
+
+impl OrcMetadata {
+    /// Arrow schema of the top-level columns.
+    pub fn schema(&self) -> &ArrowSchemaRef {
+        &self.schema
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.footer
+            .number_of_rows
+            .unwrap_or_else(|| self.footer.stripes.iter().map(|s| s.number_of_rows()).sum())
+            as usize
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe_idx: usize) -> usize {
+        self.footer.stripes[stripe_idx].number_of_rows() as usize
+    }
+
+    /// Statistics of the top-level columns in the given stripe, keyed by column name.
+    ///
+    /// Only primitive columns whose statistics map onto a Polars value are included.
+    pub fn stripe_statistics(
+        &self,
+        stripe_idx: usize,
+    ) -> Option<PlIndexMap<PlSmallStr, ColumnStatistics>> {
+        let col_stats = &self.stripe_statistics.get(stripe_idx)?.col_stats;
+        let num_rows = self.stripe_num_rows(stripe_idx) as u64;
+
+        let stats = self
+            .schema
+            .iter_values()
+            .zip(&self.column_ids)
+            .filter_map(|(field, &id)| {
+                let stats = col_stats.get(id)?;
+                let kind = self.footer.types[id].kind();
+                let dtype = DataType::from_arrow_field(field);
+                let null_count = stats
+                    .number_of_values
+                    .map(|n| num_rows.saturating_sub(n) as IdxSize);
+                let (min, max) = column_min_max(kind, stats)?;
+                Some((
+                    field.name.clone(),
+                    ColumnStatistics {
+                        dtype,
+                        min,
+                        max,
+                        null_count,
+                    },
+                ))
+            })
+            .collect();
+
+        Some(stats)
+    }
+}
+
+fn column_min_max(
+    kind: TypeKind,
+    stats: &proto::ColumnStatistics,
+) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+    use TypeKind as K;
+
+    let out = match kind {
+        K::Byte | K::Short | K::Int | K::Long => {
+            let s = stats.int_statistics.as_ref()?;
+            let (min, max) = (s.minimum?, s.maximum?);
+            match kind {
+                K::Byte => (AnyValue::Int8(min as i8), AnyValue::Int8(max as i8)),
+                K::Short => (AnyValue::Int16(min as i16), AnyValue::Int16(max as i16)),
+                K::Int => (AnyValue::Int32(min as i32), AnyValue::Int32(max as i32)),
+                _ => (AnyValue::Int64(min), AnyValue::Int64(max)),
+            }
+        },
+        K::Float | K::Double => {
+            let s = stats.double_statistics.as_ref()?;
+            let (min, max) = (s.minimum?, s.maximum?);
+            // NaNs are not tracked by the statistics.
+            if min.is_nan() || max.is_nan() {
+                return None;
+            }
+            if kind == K::Float {
+                (AnyValue::Float32(min as f32), AnyValue::Float32(max as f32))
+            } else {
+                (AnyValue::Float64(min), AnyValue::Float64(max))
+            }
+        },
+        K::String | K::Varchar | K::Char => {
+            let s = stats.string_statistics.as_ref()?;
+            (
+                AnyValue::StringOwned(s.minimum.as_deref()?.into()),
+                AnyValue::StringOwned(s.maximum.as_deref()?.into()),
+            )
+        },
+        #[cfg(feature = "dtype-date")]
+        K::Date => {
+            let s = stats.date_statistics.as_ref()?;
+            (AnyValue::Date(s.minimum?), AnyValue::Date(s.maximum?))
+        },
+        _ => return None,
+    };
+
+    Some(out)
+}
+
+/// Read the file tail (postscript, footer and metadata sections) of an ORC file.
+pub fn read_orc_metadata(bytes: &[u8]) -> PolarsResult<OrcMetadata> {
+    let file_len = bytes.len();
+    polars_ensure!(
+        file_len > MAGIC.len() + 1,
+        ComputeError: "ORC: file is too small to be an ORC file"
+    );
+
+    let ps_len = bytes[file_len - 1] as usize;
+    let ps_start = (file_len - 1)
+        .checked_sub(ps_len)
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: invalid postscript length"))?;
+    let postscript = proto::PostScript::decode(&bytes[ps_start..file_len - 1])
+        .map_err(|e| polars_err!(ComputeError: "ORC: could not decode postscript: {e}"))?;
+
+    if let Some(magic) = &postscript.magic {
+        polars_ensure!(
+            magic.as_bytes() == MAGIC,
+            ComputeError: "ORC: file does not end with the ORC magic bytes"
+        );
+    }
+
+    let compression = postscript.compression();
+    let compression_block_size = postscript
+        .compression_block_size
+        .map_or(DEFAULT_COMPRESSION_BLOCK_SIZE, |v| v as usize);
+
+    let footer_len = postscript.footer_length() as usize;
+    let metadata_len = postscript.metadata_length() as usize;
+    let footer_start = ps_start
+        .checked_sub(footer_len)
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: invalid footer length"))?;
+    let metadata_start = footer_start
+        .checked_sub(metadata_len)
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: invalid metadata length"))?;
+
+    let footer_bytes = decompress(
+        compression,
+        &bytes[footer_start..ps_start],
+        compression_block_size,
+    )?;
+    let footer = proto::Footer::decode(footer_bytes.as_ref())
+        .map_err(|e| polars_err!(ComputeError: "ORC: could not decode footer: {e}"))?;
+
+    let metadata_bytes = decompress(
+        compression,
+        &bytes[metadata_start..footer_start],
+        compression_block_size,
+    )?;
+    let stripe_statistics = proto::Metadata::decode(metadata_bytes.as_ref())
+        .map_err(|e| polars_err!(ComputeError: "ORC: could not decode metadata: {e}"))?
+        .stripe_stats;
+
+    let (schema, column_ids) = orc_types_to_arrow_schema(&footer.types)?;
+
+    Ok(OrcMetadata {
+        compression,
+        compression_block_size,
+        footer,
+        stripe_statistics,
+        column_ids,
+        schema: Arc::new(schema),
+    })
+}
+
+fn orc_types_to_arrow_schema(types: &[proto::Type]) -> PolarsResult<(ArrowSchema, Vec<usize>)> {
+    let root = types
+        .first()
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: footer does not contain any types"))?;
+    polars_ensure!(
+        root.kind() == TypeKind::Struct,
+        ComputeError: "ORC: root type must be a struct, got {:?}", root.kind()
+    );
+
+    let column_ids = root
+        .subtypes
+        .iter()
+        .map(|&id| id as usize)
+        .collect::<Vec<_>>();
+    let schema = struct_fields(types, 0)?.into_iter().collect();
+    Ok((schema, column_ids))
+}
+
+/// Types are numbered in pre-order, so the subtypes of a type always have a higher id. Checking
+/// this rejects files with cyclic types, which would otherwise recurse forever.
+fn subtype_id(types: &[proto::Type], id: usize, i: usize) -> PolarsResult<usize> {
+    let ty = &types[id];
+    let subtype = *ty
+        .subtypes
+        .get(i)
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: missing subtype of {:?}", ty.kind()))?
+        as usize;
+    polars_ensure!(
+        subtype > id && subtype < types.len(),
+        ComputeError: "ORC: invalid subtype id {subtype} of type {id}"
+    );
+    Ok(subtype)
+}
+
+fn struct_fields(types: &[proto::Type], id: usize) -> PolarsResult<Vec<Field>> {
+    let ty = &types[id];
+    polars_ensure!(
+        ty.field_names.len() == ty.subtypes.len(),
+        ComputeError: "ORC: struct type has mismatching field names and subtypes"
+    );
+    ty.field_names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let dtype = orc_type_to_arrow(types, subtype_id(types, id, i)?)?;
+            Ok(Field::new(name.as_str().into(), dtype, true))
+        })
+        .collect()
+}
+
+pub(super) fn orc_type_to_arrow(types: &[proto::Type], id: usize) -> PolarsResult<ArrowDataType> {
+    use TypeKind as K;
+
+    let ty = types
+        .get(id)
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: type id {id} out of bounds"))?;
+    let child = |i: usize| orc_type_to_arrow(types, subtype_id(types, id, i)?);
+
+    let dtype = match ty.kind() {
+        K::Boolean => ArrowDataType::Boolean,
+        K::Byte => ArrowDataType::Int8,
+        K::Short => ArrowDataType::Int16,
+        K::Int => ArrowDataType::Int32,
+        K::Long => ArrowDataType::Int64,
+        K::Float => ArrowDataType::Float32,
+        K::Double => ArrowDataType::Float64,
+        K::String | K::Varchar | K::Char => ArrowDataType::Utf8View,
+        K::Binary => ArrowDataType::BinaryView,
+        K::Date => ArrowDataType::Date32,
+        K::Timestamp => ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
+        K::TimestampInstant => {
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some(PlSmallStr::from_static("UTC")))
+        },
+        K::Decimal => match ty.precision() {
+            // Hive 0.11 wrote decimals without a precision.
+            0 => ArrowDataType::Decimal(38, 18),
+            p => {
+                polars_ensure!(
+                    p <= 38 && ty.scale() <= p,
+                    ComputeError: "ORC: invalid decimal precision {p} and scale {}", ty.scale()
+                );
+                ArrowDataType::Decimal(p as usize, ty.scale() as usize)
+            },
+        },
+        K::List => ListArray::<i64>::default_datatype(child(0)?),
+        K::Map => {
+            let entries = ArrowDataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("key"), child(0)?, true),
+                Field::new(PlSmallStr::from_static("value"), child(1)?, true),
+            ]);
+            ListArray::<i64>::default_datatype(entries)
+        },
+        K::Struct => ArrowDataType::Struct(struct_fields(types, id)?),
+        K::Union => polars_bail!(ComputeError: "ORC: union types are not supported"),
+    };
+
+    Ok(dtype)
+}
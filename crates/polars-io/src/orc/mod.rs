@@ -0,0 +1,42 @@
+//! # (De)serializing Apache ORC files.
+//!
+//! ORC is a columnar format that stores data in stripes, each of which holds a set of
+//! (compressed) streams per column. The file tail contains the schema, the stripe locations and
+//! column statistics for the whole file and for every stripe.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//! let s0 = Column::new("days".into(), &[0, 1, 2, 3, 4]);
+//! let s1 = Column::new("temp".into(), &[22.1, 19.9, 7., 2., 3.]);
+//! let mut df = DataFrame::new(vec![s0, s1]).unwrap();
+//!
+//! let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+//! OrcWriter::new(&mut buf).finish(&mut df).expect("orc writer");
+//!
+//! buf.set_position(0);
+//! let df_read = OrcReader::new(buf).finish().unwrap();
+//! assert!(df.equals(&df_read));
+//! ```
+mod compression;
+mod metadata;
+mod proto;
+mod read;
+mod rle;
+mod write;
+
+pub use compression::OrcCompression;
+pub use metadata::{OrcMetadata, OrcMetadataRef, read_orc_metadata};
+pub use read::{OrcReader, read_orc_stripe};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use write::{BatchedWriter, OrcWriter, OrcWriterOptions};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcScanOptions {}
@@ -0,0 +1,662 @@
+use std::borrow::Cow;
+
+use arrow::array::{
+    Array, BooleanArray, ListArray, MutableBinaryViewArray, PrimitiveArray, StructArray,
+};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::{ArrowDataType, ArrowSchemaRef};
+use arrow::offset::Offsets;
+use arrow::types::NativeType;
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::to_compute_err;
+use prost::Message;
+use rayon::prelude::*;
+
+use super::compression::decompress;
+use super::metadata::{ORC_EPOCH_SECONDS, OrcMetadata, read_orc_metadata};
+use super::proto::{self, ColumnEncodingKind, StreamKind, TypeKind};
+use super::rle::*;
+use crate::RowIndex;
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+use crate::utils::{apply_projection, columns_to_projection, get_reader_bytes};
+
+fn out_of_bounds() -> PolarsError {
+    polars_err!(ComputeError: "ORC: stripe section is out of bounds of the file")
+}
+
+fn unexpected_end() -> PolarsError {
+    polars_err!(ComputeError: "ORC: unexpected end of stream")
+}
+
+fn dtype_mismatch(kind: TypeKind, dtype: &ArrowDataType) -> PolarsError {
+    polars_err!(ComputeError: "ORC: type {kind:?} does not match {dtype:?}")
+}
+
+/// Range of `len` bytes starting at `start`, checked for overflow.
+fn byte_range(start: usize, len: u64) -> PolarsResult<std::ops::Range<usize>> {
+    usize::try_from(len)
+        .ok()
+        .and_then(|len| start.checked_add(len))
+        .map(|end| start..end)
+        .ok_or_else(out_of_bounds)
+}
+
+/// Decodes the projected columns of a single stripe.
+struct StripeDecoder<'a> {
+    types: &'a [proto::Type],
+    encodings: Vec<proto::ColumnEncoding>,
+    streams: PlHashMap<(usize, StreamKind), Cow<'a, [u8]>>,
+}
+
+impl<'a> StripeDecoder<'a> {
+    fn try_new(
+        bytes: &'a [u8],
+        metadata: &'a OrcMetadata,
+        stripe_idx: usize,
+        column_ids: &[usize],
+    ) -> PolarsResult<Self> {
+        let types = metadata.footer.types.as_slice();
+        let stripe = &metadata.footer.stripes[stripe_idx];
+        let decompress =
+            |data| decompress(metadata.compression, data, metadata.compression_block_size);
+
+        let offset = usize::try_from(stripe.offset()).map_err(|_| out_of_bounds())?;
+        let footer_offset = stripe
+            .index_length()
+            .checked_add(stripe.data_length())
+            .ok_or_else(out_of_bounds)
+            .and_then(|len| byte_range(offset, len))?
+            .end;
+        let footer_bytes = bytes
+            .get(byte_range(footer_offset, stripe.footer_length())?)
+            .ok_or_else(out_of_bounds)?;
+        let footer = proto::StripeFooter::decode(decompress(footer_bytes)?.as_ref())
+            .map_err(|e| polars_err!(ComputeError: "ORC: could not decode stripe footer: {e}"))?;
+
+        let mut needed = PlHashSet::new();
+        for &id in column_ids {
+            collect_subtree(types, id, &mut needed)?;
+        }
+
+        // Streams are laid out back-to-back from the start of the stripe, in footer order.
+        let mut streams = PlHashMap::new();
+        let mut position = offset;
+        for stream in &footer.streams {
+            let range = byte_range(position, stream.length())?;
+            position = range.end;
+
+            let column = stream.column() as usize;
+            let kind = stream.kind();
+            if !needed.contains(&column)
+                || matches!(
+                    kind,
+                    StreamKind::RowIndex | StreamKind::BloomFilter | StreamKind::BloomFilterUtf8
+                )
+            {
+                continue;
+            }
+
+            let data = bytes.get(range).ok_or_else(out_of_bounds)?;
+            streams.insert((column, kind), decompress(data)?);
+        }
+
+        Ok(Self {
+            types,
+            encodings: footer.columns,
+            streams,
+        })
+    }
+
+    /// Stream data of a column. Writers may omit empty streams, so those decode as empty.
+    fn data(&self, id: usize, kind: StreamKind) -> &[u8] {
+        self.streams.get(&(id, kind)).map_or(&[], |v| v.as_ref())
+    }
+
+    fn encoding(&self, id: usize) -> ColumnEncodingKind {
+        self.encodings
+            .get(id)
+            .map_or(ColumnEncodingKind::Direct, |e| e.kind())
+    }
+
+    fn is_rle_v2(&self, id: usize) -> bool {
+        matches!(
+            self.encoding(id),
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2
+        )
+    }
+
+    fn ints(&self, id: usize, kind: StreamKind, n: usize, signed: bool) -> PolarsResult<Vec<i64>> {
+        decode_int_rle(self.data(id, kind), n, signed, self.is_rle_v2(id))
+    }
+
+    /// Combine the `PRESENT` stream of a column with the validity of its parent.
+    ///
+    /// A column only has entries for the rows in which its parent is valid, so the `PRESENT`
+    /// stream is scattered over the valid rows of the parent.
+    fn validity(
+        &self,
+        id: usize,
+        n_rows: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<Option<Bitmap>> {
+        let n_entries = parent.map_or(n_rows, |p| p.set_bits());
+        let present = self
+            .streams
+            .get(&(id, StreamKind::Present))
+            .map(|data| decode_bool_rle(data, n_entries))
+            .transpose()?;
+
+        let validity = match (parent, present) {
+            (None, present) => present,
+            (Some(parent), None) => Some(parent.clone()),
+            (Some(parent), Some(present)) => {
+                let mut present = present.iter();
+                Some(
+                    parent
+                        .iter()
+                        .map(|valid| valid && present.next().unwrap())
+                        .collect(),
+                )
+            },
+        };
+
+        Ok(validity.filter(|v| v.unset_bits() > 0))
+    }
+
+    /// Decode column `id` into an array of `n_rows` rows.
+    fn decode(
+        &self,
+        id: usize,
+        dtype: &ArrowDataType,
+        n_rows: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<Box<dyn Array>> {
+        use TypeKind as K;
+
+        let ty = self
+            .types
+            .get(id)
+            .ok_or_else(|| polars_err!(ComputeError: "ORC: type id {id} out of bounds"))?;
+        let validity = self.validity(id, n_rows, parent)?;
+        let n = validity.as_ref().map_or(n_rows, |v| v.set_bits());
+
+        let array = match ty.kind() {
+            K::Boolean => {
+                let values = decode_bool_rle(self.data(id, StreamKind::Data), n)?;
+                let values = match &validity {
+                    None => values,
+                    Some(validity) => {
+                        let mut values = values.iter();
+                        validity
+                            .iter()
+                            .map(|valid| valid && values.next().unwrap())
+                            .collect()
+                    },
+                };
+                BooleanArray::new(dtype.clone(), values, validity).boxed()
+            },
+            K::Byte => {
+                let values = decode_byte_rle(self.data(id, StreamKind::Data), n)?;
+                let values = values.into_iter().map(|v| v as i8).collect();
+                primitive(dtype, values, validity)
+            },
+            K::Short => {
+                let values = self.ints(id, StreamKind::Data, n, true)?;
+                primitive(
+                    dtype,
+                    values.into_iter().map(|v| v as i16).collect(),
+                    validity,
+                )
+            },
+            K::Int | K::Date => {
+                let values = self.ints(id, StreamKind::Data, n, true)?;
+                primitive(
+                    dtype,
+                    values.into_iter().map(|v| v as i32).collect(),
+                    validity,
+                )
+            },
+            K::Long => primitive(dtype, self.ints(id, StreamKind::Data, n, true)?, validity),
+            K::Float => {
+                let values = fixed_width(self.data(id, StreamKind::Data), n, 4)?
+                    .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                    .collect();
+                primitive(dtype, values, validity)
+            },
+            K::Double => {
+                let values = fixed_width(self.data(id, StreamKind::Data), n, 8)?
+                    .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+                    .collect();
+                primitive(dtype, values, validity)
+            },
+            K::String | K::Varchar | K::Char => {
+                let values = self.binary_values(id, n)?;
+                let mut array = MutableBinaryViewArray::<str>::with_capacity(n_rows);
+                let mut values = values.into_iter();
+                for i in 0..n_rows {
+                    if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                        let value = values.next().unwrap();
+                        array.push_value_ignore_validity(
+                            simdutf8::basic::from_utf8(value).map_err(to_compute_err)?,
+                        );
+                    } else {
+                        array.push_value_ignore_validity("");
+                    }
+                }
+                array.freeze().with_validity(validity).boxed()
+            },
+            K::Binary => {
+                let values = self.binary_values(id, n)?;
+                let mut array = MutableBinaryViewArray::<[u8]>::with_capacity(n_rows);
+                let mut values = values.into_iter();
+                for i in 0..n_rows {
+                    if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                        array.push_value_ignore_validity(values.next().unwrap());
+                    } else {
+                        array.push_value_ignore_validity([]);
+                    }
+                }
+                array.freeze().with_validity(validity).boxed()
+            },
+            K::Timestamp | K::TimestampInstant => {
+                let seconds = self.ints(id, StreamKind::Data, n, true)?;
+                let nanos = self.ints(id, StreamKind::Secondary, n, false)?;
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        let nanos = decode_nanos(nanos as u64);
+                        let mut seconds = seconds.checked_add(ORC_EPOCH_SECONDS)?;
+                        // Writers truncate the seconds of pre-epoch timestamps towards zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds.checked_mul(1_000_000_000)?.checked_add(nanos)
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| polars_err!(ComputeError: "ORC: timestamp out of range"))?;
+                primitive(dtype, values, validity)
+            },
+            K::Decimal => {
+                let ArrowDataType::Decimal(_, target_scale) = dtype else {
+                    return Err(dtype_mismatch(ty.kind(), dtype));
+                };
+                let values = decode_i128_varints(self.data(id, StreamKind::Data), n)?;
+                let scales = self.ints(id, StreamKind::Secondary, n, true)?;
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(v, scale)| rescale_decimal(v, scale, *target_scale as i64))
+                    .collect::<PolarsResult<_>>()?;
+                primitive(dtype, values, validity)
+            },
+            K::List | K::Map => {
+                let lengths = self.ints(id, StreamKind::Length, n, false)?;
+                let mut lengths = lengths.into_iter();
+                let lengths = (0..n_rows)
+                    .map(|i| {
+                        if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                            let length = lengths.next().ok_or_else(unexpected_end)?;
+                            usize::try_from(length).map_err(
+                                |_| polars_err!(ComputeError: "ORC: invalid length {length}"),
+                            )
+                        } else {
+                            Ok(0)
+                        }
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let offsets = Offsets::<i64>::try_from_lengths(lengths.into_iter())?;
+                let n_children = *offsets.last() as usize;
+
+                let child_dtype = match dtype.to_logical_type() {
+                    ArrowDataType::LargeList(field) => field.dtype(),
+                    _ => return Err(dtype_mismatch(ty.kind(), dtype)),
+                };
+                let values = if ty.kind() == K::List {
+                    let child = *ty.subtypes.first().ok_or_else(
+                        || polars_err!(ComputeError: "ORC: list type without a subtype"),
+                    )?;
+                    self.decode(child as usize, child_dtype, n_children, None)?
+                } else {
+                    let ArrowDataType::Struct(fields) = child_dtype else {
+                        return Err(dtype_mismatch(ty.kind(), dtype));
+                    };
+                    let entries = ty
+                        .subtypes
+                        .iter()
+                        .zip(fields)
+                        .map(|(&child, field)| {
+                            self.decode(child as usize, &field.dtype, n_children, None)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    StructArray::try_new(child_dtype.clone(), n_children, entries, None)?.boxed()
+                };
+
+                ListArray::<i64>::try_new(dtype.clone(), offsets.into(), values, validity)?.boxed()
+            },
+            K::Struct => {
+                let ArrowDataType::Struct(fields) = dtype else {
+                    return Err(dtype_mismatch(ty.kind(), dtype));
+                };
+                let values = ty
+                    .subtypes
+                    .iter()
+                    .zip(fields)
+                    .map(|(&child, field)| {
+                        self.decode(child as usize, &field.dtype, n_rows, validity.as_ref())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructArray::try_new(dtype.clone(), n_rows, values, validity)?.boxed()
+            },
+            K::Union => polars_bail!(ComputeError: "ORC: union types are not supported"),
+        };
+
+        Ok(array)
+    }
+
+    /// The `n` values of a string or binary column, which is either direct or dictionary encoded.
+    fn binary_values(&self, id: usize, n: usize) -> PolarsResult<Vec<&[u8]>> {
+        match self.encoding(id) {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::DirectV2 => {
+                let lengths = self.ints(id, StreamKind::Length, n, false)?;
+                split_by_lengths(self.data(id, StreamKind::Data), &lengths)
+            },
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2 => {
+                let dictionary_size = self.encodings[id].dictionary_size() as usize;
+                let lengths = self.ints(id, StreamKind::Length, dictionary_size, false)?;
+                let dictionary =
+                    split_by_lengths(self.data(id, StreamKind::DictionaryData), &lengths)?;
+                self.ints(id, StreamKind::Data, n, false)?
+                    .into_iter()
+                    .map(|idx| {
+                        dictionary.get(idx as usize).copied().ok_or_else(
+                            || polars_err!(ComputeError: "ORC: dictionary index out of bounds"),
+                        )
+                    })
+                    .collect()
+            },
+        }
+    }
+}
+
+fn collect_subtree(
+    types: &[proto::Type],
+    id: usize,
+    out: &mut PlHashSet<usize>,
+) -> PolarsResult<()> {
+    let ty = types
+        .get(id)
+        .ok_or_else(|| polars_err!(ComputeError: "ORC: type id {id} out of bounds"))?;
+    // Subtypes are validated to have a higher id when reading the schema, so this terminates.
+    if out.insert(id) {
+        for &child in &ty.subtypes {
+            collect_subtree(types, child as usize, out)?;
+        }
+    }
+    Ok(())
+}
+
+/// Build a primitive array of `validity.len()` rows from the values of the valid rows.
+fn primitive<T: NativeType>(
+    dtype: &ArrowDataType,
+    values: Vec<T>,
+    validity: Option<Bitmap>,
+) -> Box<dyn Array> {
+    let values = match &validity {
+        None => values,
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|valid| {
+                    if valid {
+                        values.next().unwrap()
+                    } else {
+                        T::default()
+                    }
+                })
+                .collect()
+        },
+    };
+    PrimitiveArray::new(dtype.clone(), values.into(), validity).boxed()
+}
+
+fn fixed_width(
+    data: &[u8],
+    n: usize,
+    size: usize,
+) -> PolarsResult<std::slice::ChunksExact<'_, u8>> {
+    let data = n
+        .checked_mul(size)
+        .and_then(|len| data.get(..len))
+        .ok_or_else(unexpected_end)?;
+    Ok(data.chunks_exact(size))
+}
+
+fn split_by_lengths<'a>(data: &'a [u8], lengths: &[i64]) -> PolarsResult<Vec<&'a [u8]>> {
+    let mut offset = 0usize;
+    lengths
+        .iter()
+        .map(|&len| {
+            let end = usize::try_from(len)
+                .ok()
+                .and_then(|len| offset.checked_add(len))
+                .ok_or_else(unexpected_end)?;
+            let value = data.get(offset..end).ok_or_else(unexpected_end)?;
+            offset = end;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Nanoseconds are stored with their trailing decimal zeros stripped; the low 3 bits hold the
+/// number of stripped zeros minus one.
+fn decode_nanos(encoded: u64) -> i64 {
+    let zeros = encoded & 0b111;
+    let value = (encoded >> 3) as i64;
+    if zeros == 0 {
+        value
+    } else {
+        value * 10i64.pow(zeros as u32 + 1)
+    }
+}
+
+/// Convert a decimal stored with the per-value `scale` of the file to the scale of the column.
+fn rescale_decimal(value: i128, scale: i64, target_scale: i64) -> PolarsResult<i128> {
+    let out = match scale.cmp(&target_scale) {
+        std::cmp::Ordering::Equal => Some(value),
+        std::cmp::Ordering::Less => u32::try_from(target_scale - scale)
+            .ok()
+            .and_then(|exp| 10i128.checked_pow(exp))
+            .and_then(|factor| value.checked_mul(factor)),
+        // A divisor larger than any i128 rounds every value to zero.
+        std::cmp::Ordering::Greater => Some(
+            u32::try_from(scale - target_scale)
+                .ok()
+                .and_then(|exp| 10i128.checked_pow(exp))
+                .map_or(0, |divisor| value / divisor),
+        ),
+    };
+
+    out.ok_or_else(
+        || polars_err!(ComputeError: "ORC: decimal {value} with scale {scale} does not fit the column scale {target_scale}"),
+    )
+}
+
+/// Decode the given top-level columns (indices into [`OrcMetadata::schema`]) of a stripe.
+pub fn read_orc_stripe(
+    bytes: &[u8],
+    metadata: &OrcMetadata,
+    stripe_idx: usize,
+    projection: &[usize],
+) -> PolarsResult<DataFrame> {
+    let n_rows = metadata.stripe_num_rows(stripe_idx);
+    let column_ids = projection
+        .iter()
+        .map(|&i| {
+            metadata
+                .column_ids
+                .get(i)
+                .copied()
+                .ok_or_else(|| polars_err!(oob = i, metadata.column_ids.len()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let decoder = StripeDecoder::try_new(bytes, metadata, stripe_idx, &column_ids)?;
+
+    let columns = projection
+        .iter()
+        .zip(column_ids)
+        .map(|(&i, id)| {
+            let (_, field) = metadata.schema.get_at_index(i).unwrap();
+            let array = decoder.decode(id, &field.dtype, n_rows, None)?;
+            Ok(Series::try_from((field, array))?.into_column())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new_with_height(n_rows, columns)
+}
+
+/// Read Apache ORC files into a DataFrame.
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use std::fs::File;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R: MmapBytesReader> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+    row_index: Option<RowIndex>,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Get arrow schema of the ORC file.
+    pub fn schema(&mut self) -> PolarsResult<ArrowSchemaRef> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        Ok(read_orc_metadata(&bytes)?.schema.clone())
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            projection: None,
+            columns: None,
+            row_index: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let metadata = read_orc_metadata(&bytes)?;
+
+        let projection = match (&self.columns, self.projection.take()) {
+            (Some(columns), _) => columns_to_projection(columns, &metadata.schema)?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..metadata.schema.len()).collect(),
+        };
+
+        // Only decode the stripes that are needed to reach `n_rows`.
+        let n_rows = self.n_rows.unwrap_or(usize::MAX);
+        let mut n_stripes = 0;
+        let mut rows_seen = 0;
+        while n_stripes < metadata.num_stripes() && rows_seen < n_rows {
+            rows_seen += metadata.stripe_num_rows(n_stripes);
+            n_stripes += 1;
+        }
+
+        let dfs = POOL.install(|| {
+            (0..n_stripes)
+                .into_par_iter()
+                .map(|stripe_idx| read_orc_stripe(&bytes, &metadata, stripe_idx, &projection))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_arrow_schema(&apply_projection(&metadata.schema, &projection))
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+
+        if let Some(n_rows) = self.n_rows {
+            df = df.slice(0, n_rows);
+        }
+
+        if let Some(row_index) = &self.row_index {
+            unsafe { df.with_row_index_mut(row_index.name.clone(), Some(row_index.offset)) };
+        }
+
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rescale_decimal() {
+        assert_eq!(rescale_decimal(125, 2, 2).unwrap(), 125);
+        assert_eq!(rescale_decimal(125, 2, 4).unwrap(), 12500);
+        assert_eq!(rescale_decimal(125, 2, 1).unwrap(), 12);
+        assert_eq!(rescale_decimal(125, 100, 2).unwrap(), 0);
+
+        // Scales that are read from the file must not overflow.
+        assert!(rescale_decimal(125, 0, 38).is_err());
+        assert!(rescale_decimal(125, -1_000_000, 2).is_err());
+        assert!(rescale_decimal(i128::MAX, 0, 1).is_err());
+    }
+}
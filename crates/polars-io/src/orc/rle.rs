@@ -0,0 +1,614 @@
+//! Run length encodings used by the ORC streams.
+//!
+//! * Byte RLE is used for `BYTE` data and (bit-packed) for booleans and `PRESENT` streams.
+//! * Integer RLE v1 is used by columns with `DIRECT`/`DICTIONARY` encodings.
+//! * Integer RLE v2 is used by columns with `DIRECT_V2`/`DICTIONARY_V2` encodings. We decode all
+//!   four sub-encodings, but only emit `SHORT_REPEAT`, `DIRECT` and fixed-delta `DELTA` runs.
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+
+const MAX_LITERAL_SIZE: usize = 512;
+const MIN_REPEAT_SIZE: usize = 3;
+const MAX_SHORT_REPEAT_SIZE: usize = 10;
+
+fn truncated() -> PolarsError {
+    polars_err!(ComputeError: "ORC: unexpected end of stream")
+}
+
+struct ByteCursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    #[inline]
+    fn next_byte(&mut self) -> PolarsResult<u8> {
+        let b = *self.data.get(self.pos).ok_or_else(truncated)?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn next_bytes(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        let out = self
+            .data
+            .get(self.pos..self.pos + n)
+            .ok_or_else(truncated)?;
+        self.pos += n;
+        Ok(out)
+    }
+
+    fn read_uvarint(&mut self) -> PolarsResult<u64> {
+        let mut result = 0u64;
+        let mut shift = 0;
+        loop {
+            let b = self.next_byte()?;
+            if shift < 64 {
+                result |= ((b & 0x7f) as u64) << shift;
+            }
+            if b & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_svarint(&mut self) -> PolarsResult<i64> {
+        Ok(zigzag_decode(self.read_uvarint()?))
+    }
+
+    fn read_big_endian(&mut self, n_bytes: usize) -> PolarsResult<u64> {
+        let mut out = 0u64;
+        for &b in self.next_bytes(n_bytes)? {
+            out = (out << 8) | b as u64;
+        }
+        Ok(out)
+    }
+
+    /// Read `n` values of `width` bits, packed MSB-first. The run is byte-aligned at the end.
+    fn read_bit_packed(&mut self, width: u32, n: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+        let mut current = 0u8;
+        let mut bits_left = 0u32;
+
+        for _ in 0..n {
+            let mut result = 0u64;
+            let mut needed = width;
+            while needed > 0 {
+                if bits_left == 0 {
+                    current = self.next_byte()?;
+                    bits_left = 8;
+                }
+                let take = needed.min(bits_left);
+                let shift = bits_left - take;
+                let bits = (current as u64 >> shift) & ((1u64 << take) - 1);
+                result = (result << take) | bits;
+                bits_left -= take;
+                needed -= take;
+            }
+            out.push(result);
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+pub(super) fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+#[inline]
+pub(super) fn zigzag_encode(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+pub(super) fn write_uvarint(mut v: u64, out: &mut Vec<u8>) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Decode `n` unbounded zigzag varints, as used by the `DATA` stream of decimal columns.
+pub(super) fn decode_i128_varints(data: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut cursor = ByteCursor::new(data);
+    let mut out = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut result = 0u128;
+        let mut shift = 0;
+        loop {
+            let b = cursor.next_byte()?;
+            if shift < 128 {
+                result |= ((b & 0x7f) as u128) << shift;
+            }
+            if b & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        out.push(((result >> 1) as i128) ^ -((result & 1) as i128));
+    }
+    Ok(out)
+}
+
+pub(super) fn write_i128_varint(v: i128, out: &mut Vec<u8>) {
+    let mut v = ((v << 1) ^ (v >> 127)) as u128;
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Decode `n` bytes from a byte RLE stream.
+pub(super) fn decode_byte_rle(data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut cursor = ByteCursor::new(data);
+    let mut out = Vec::with_capacity(n);
+
+    while out.len() < n {
+        let header = cursor.next_byte()? as i8;
+        if header >= 0 {
+            let run_length = header as usize + MIN_REPEAT_SIZE;
+            let value = cursor.next_byte()?;
+            out.extend(std::iter::repeat_n(value, run_length));
+        } else {
+            let literal_length = (-(header as i16)) as usize;
+            out.extend_from_slice(cursor.next_bytes(literal_length)?);
+        }
+    }
+
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decode `n` booleans from a bit-packed byte RLE stream.
+pub(super) fn decode_bool_rle(data: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let bytes = decode_byte_rle(data, n.div_ceil(8))?;
+    let mut out = MutableBitmap::with_capacity(n);
+    for i in 0..n {
+        out.push(bytes[i / 8] & (0x80 >> (i % 8)) != 0);
+    }
+    Ok(out.freeze())
+}
+
+/// Decode `n` integers from an RLE v1 (`v2 == false`) or RLE v2 stream.
+pub(super) fn decode_int_rle(
+    data: &[u8],
+    n: usize,
+    signed: bool,
+    v2: bool,
+) -> PolarsResult<Vec<i64>> {
+    let mut out = Vec::with_capacity(n);
+    let mut cursor = ByteCursor::new(data);
+
+    while out.len() < n {
+        if v2 {
+            decode_rle_v2_run(&mut cursor, signed, &mut out)?;
+        } else {
+            decode_rle_v1_run(&mut cursor, signed, &mut out)?;
+        }
+    }
+
+    polars_ensure!(
+        out.len() == n,
+        ComputeError: "ORC: integer run exceeds the expected number of values"
+    );
+    Ok(out)
+}
+
+fn read_varint(cursor: &mut ByteCursor<'_>, signed: bool) -> PolarsResult<i64> {
+    if signed {
+        cursor.read_svarint()
+    } else {
+        Ok(cursor.read_uvarint()? as i64)
+    }
+}
+
+fn decode_rle_v1_run(
+    cursor: &mut ByteCursor<'_>,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let header = cursor.next_byte()? as i8;
+    if header >= 0 {
+        let run_length = header as usize + MIN_REPEAT_SIZE;
+        let delta = cursor.next_byte()? as i8 as i64;
+        let base = read_varint(cursor, signed)?;
+        out.extend((0..run_length as i64).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+    } else {
+        for _ in 0..(-(header as i16)) {
+            out.push(read_varint(cursor, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// Map the 5-bit width code of RLE v2 headers to a bit width.
+fn decode_bit_width(code: u8) -> u32 {
+    match code {
+        0..=23 => code as u32 + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// Round a bit width up to one that can be represented in an RLE v2 header.
+fn closest_fixed_bits(width: u32) -> u32 {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn encode_bit_width(width: u32) -> u8 {
+    match width {
+        1..=24 => (width - 1) as u8,
+        26 => 24,
+        28 => 25,
+        30 => 26,
+        32 => 27,
+        40 => 28,
+        48 => 29,
+        56 => 30,
+        64 => 31,
+        _ => unreachable!("width must be normalized with closest_fixed_bits"),
+    }
+}
+
+fn decode_rle_v2_run(
+    cursor: &mut ByteCursor<'_>,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let header = cursor.next_byte()?;
+
+    match header >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let n_bytes = ((header >> 3) & 0x07) as usize + 1;
+            let run_length = (header & 0x07) as usize + MIN_REPEAT_SIZE;
+            let value = cursor.read_big_endian(n_bytes)?;
+            let value = if signed {
+                zigzag_decode(value)
+            } else {
+                value as i64
+            };
+            out.extend(std::iter::repeat_n(value, run_length));
+        },
+        // DIRECT
+        1 => {
+            let width = decode_bit_width((header >> 1) & 0x1f);
+            let length = ((((header & 1) as usize) << 8) | cursor.next_byte()? as usize) + 1;
+            let mut values = Vec::with_capacity(length);
+            cursor.read_bit_packed(width, length, &mut values)?;
+            out.extend(values.into_iter().map(
+                |v| {
+                    if signed { zigzag_decode(v) } else { v as i64 }
+                },
+            ));
+        },
+        // PATCHED_BASE
+        2 => {
+            let width = decode_bit_width((header >> 1) & 0x1f);
+            let length = ((((header & 1) as usize) << 8) | cursor.next_byte()? as usize) + 1;
+
+            let third = cursor.next_byte()?;
+            let base_width = ((third >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1f);
+
+            let fourth = cursor.next_byte()?;
+            let patch_gap_width = ((fourth >> 5) & 0x07) as u32 + 1;
+            let patch_list_length = (fourth & 0x1f) as usize;
+
+            // The base is stored in sign-magnitude form using the MSB of its bytes.
+            let base = cursor.read_big_endian(base_width)?;
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(length);
+            cursor.read_bit_packed(width, length, &mut values)?;
+
+            let mut patches = Vec::with_capacity(patch_list_length);
+            cursor.read_bit_packed(
+                closest_fixed_bits(patch_gap_width + patch_width),
+                patch_list_length,
+                &mut patches,
+            )?;
+
+            let patch_mask = if patch_width >= 64 {
+                u64::MAX
+            } else {
+                (1u64 << patch_width) - 1
+            };
+            let mut idx = 0usize;
+            for patch in patches {
+                idx += (patch >> patch_width) as usize;
+                let patch = patch & patch_mask;
+                // A gap of 255 with an empty patch only advances the position.
+                if patch == 0 {
+                    continue;
+                }
+                let v = values.get_mut(idx).ok_or_else(truncated)?;
+                *v |= patch << width;
+            }
+
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // DELTA
+        _ => {
+            let width_code = (header >> 1) & 0x1f;
+            let length = ((((header & 1) as usize) << 8) | cursor.next_byte()? as usize) + 1;
+
+            let base = read_varint(cursor, signed)?;
+            let delta_base = cursor.read_svarint()?;
+
+            out.push(base);
+            if length == 1 {
+                return Ok(());
+            }
+
+            let mut prev = base.wrapping_add(delta_base);
+            out.push(prev);
+
+            if width_code == 0 {
+                // Fixed delta.
+                for _ in 2..length {
+                    prev = prev.wrapping_add(delta_base);
+                    out.push(prev);
+                }
+            } else {
+                let width = decode_bit_width(width_code);
+                let mut deltas = Vec::with_capacity(length - 2);
+                cursor.read_bit_packed(width, length - 2, &mut deltas)?;
+                for delta in deltas {
+                    prev = if delta_base < 0 {
+                        prev.wrapping_sub(delta as i64)
+                    } else {
+                        prev.wrapping_add(delta as i64)
+                    };
+                    out.push(prev);
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Encode bytes using byte RLE.
+pub(super) fn encode_byte_rle(values: &[u8], out: &mut Vec<u8>) {
+    const MAX_RUN: usize = 127 + MIN_REPEAT_SIZE;
+    const MAX_LITERALS: usize = 128;
+
+    let mut i = 0;
+    let mut literal_start = 0;
+
+    let flush_literals = |out: &mut Vec<u8>, literals: &[u8]| {
+        for chunk in literals.chunks(MAX_LITERALS) {
+            out.push((-(chunk.len() as i16)) as u8);
+            out.extend_from_slice(chunk);
+        }
+    };
+
+    while i < values.len() {
+        let run = values[i..]
+            .iter()
+            .take(MAX_RUN)
+            .take_while(|&&v| v == values[i])
+            .count();
+
+        if run >= MIN_REPEAT_SIZE {
+            flush_literals(out, &values[literal_start..i]);
+            out.push((run - MIN_REPEAT_SIZE) as u8);
+            out.push(values[i]);
+            i += run;
+            literal_start = i;
+        } else {
+            i += 1;
+        }
+    }
+
+    flush_literals(out, &values[literal_start..]);
+}
+
+/// Encode booleans as bit-packed (MSB-first) byte RLE.
+pub(super) fn encode_bool_rle(values: impl Iterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = Vec::new();
+    for (i, v) in values.enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0u8);
+        }
+        if v {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_byte_rle(&bytes, out);
+}
+
+fn write_bit_packed(values: impl Iterator<Item = u64>, width: u32, out: &mut Vec<u8>) {
+    let mut current = 0u8;
+    let mut bits_used = 0u32;
+
+    for v in values {
+        let mut remaining = width;
+        while remaining > 0 {
+            let available = 8 - bits_used;
+            let take = remaining.min(available);
+            let bits = ((v >> (remaining - take)) & ((1u64 << take) - 1)) as u8;
+            current |= bits << (available - take);
+            bits_used += take;
+            remaining -= take;
+            if bits_used == 8 {
+                out.push(current);
+                current = 0;
+                bits_used = 0;
+            }
+        }
+    }
+
+    if bits_used > 0 {
+        out.push(current);
+    }
+}
+
+/// Encode integers using RLE v2.
+pub(super) fn encode_int_rle_v2(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    let to_unsigned = |v: i64| if signed { zigzag_encode(v) } else { v as u64 };
+
+    let repeat_length = |start: usize| {
+        values[start..]
+            .iter()
+            .take(MAX_LITERAL_SIZE)
+            .take_while(|&&v| v == values[start])
+            .count()
+    };
+
+    let mut i = 0;
+    while i < values.len() {
+        let run = repeat_length(i);
+
+        if run >= MIN_REPEAT_SIZE {
+            let value = values[i];
+            if run <= MAX_SHORT_REPEAT_SIZE {
+                // SHORT_REPEAT
+                let v = to_unsigned(value);
+                let n_bytes = ((64 - v.leading_zeros()).max(1)).div_ceil(8) as usize;
+                out.push((((n_bytes - 1) as u8) << 3) | (run - MIN_REPEAT_SIZE) as u8);
+                out.extend_from_slice(&v.to_be_bytes()[8 - n_bytes..]);
+            } else {
+                // DELTA with a fixed delta of zero.
+                let length = run - 1;
+                out.push((3 << 6) | ((length >> 8) as u8 & 1));
+                out.push(length as u8);
+                if signed {
+                    write_uvarint(zigzag_encode(value), out);
+                } else {
+                    write_uvarint(value as u64, out);
+                }
+                write_uvarint(zigzag_encode(0), out);
+            }
+            i += run;
+            continue;
+        }
+
+        // DIRECT: gather literals until the next repeating run.
+        let mut end = i + 1;
+        while end < values.len()
+            && end - i < MAX_LITERAL_SIZE
+            && !(end + MIN_REPEAT_SIZE <= values.len()
+                && values[end..end + MIN_REPEAT_SIZE]
+                    .iter()
+                    .all(|&v| v == values[end]))
+        {
+            end += 1;
+        }
+
+        let literals = &values[i..end];
+        let max = literals.iter().map(|&v| to_unsigned(v)).max().unwrap();
+        let width = closest_fixed_bits(64 - max.leading_zeros());
+        let length = literals.len() - 1;
+
+        out.push((1 << 6) | (encode_bit_width(width) << 1) | ((length >> 8) as u8 & 1));
+        out.push(length as u8);
+        write_bit_packed(literals.iter().map(|&v| to_unsigned(v)), width, out);
+
+        i = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(values: &[i64], signed: bool) {
+        let mut buf = Vec::new();
+        encode_int_rle_v2(values, signed, &mut buf);
+        let decoded = decode_int_rle(&buf, values.len(), signed, true).unwrap();
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn test_int_rle_v2_roundtrip() {
+        roundtrip(&[1, 1, 1, 1, 1], true);
+        roundtrip(&[7; 100], true);
+        roundtrip(&[1, 2, 3, 4, 5, 6, -7, 8, i64::MIN, i64::MAX], true);
+        roundtrip(&[0, 0, 5, 5, 5, 5, 1, 2, 9, 9, 9], false);
+        roundtrip(
+            &(0..2000).map(|i| (i * 31) % 17 - 8).collect::<Vec<_>>(),
+            true,
+        );
+    }
+
+    #[test]
+    fn test_int_rle_v2_spec_examples() {
+        // Examples from the ORC specification.
+        let short_repeat = [0x0a, 0x27, 0x10];
+        assert_eq!(
+            decode_int_rle(&short_repeat, 5, false, true).unwrap(),
+            [10000; 5]
+        );
+
+        let direct = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(
+            decode_int_rle(&direct, 4, false, true).unwrap(),
+            [23713, 43806, 57005, 48879]
+        );
+
+        let patched_base = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        assert_eq!(
+            decode_int_rle(&patched_base, 20, false, true).unwrap(),
+            [
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+
+        let delta = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        assert_eq!(
+            decode_int_rle(&delta, 10, false, true).unwrap(),
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    #[test]
+    fn test_byte_and_bool_rle_roundtrip() {
+        let values: Vec<u8> = (0..1000u32)
+            .map(|i| if i % 100 < 50 { 7 } else { i as u8 })
+            .collect();
+        let mut buf = Vec::new();
+        encode_byte_rle(&values, &mut buf);
+        assert_eq!(decode_byte_rle(&buf, values.len()).unwrap(), values);
+
+        let bools: Vec<bool> = (0..77).map(|i| i % 3 == 0).collect();
+        let mut buf = Vec::new();
+        encode_bool_rle(bools.iter().copied(), &mut buf);
+        let decoded = decode_bool_rle(&buf, bools.len()).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), bools);
+    }
+}
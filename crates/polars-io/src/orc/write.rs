@@ -0,0 +1,825 @@
+use std::io::Write;
+
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, PrimitiveArray, StructArray, Utf8ViewArray,
+};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use prost::Message;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::compression::{DEFAULT_COMPRESSION_BLOCK_SIZE, OrcCompression};
+use super::metadata::{MAGIC, ORC_EPOCH_SECONDS};
+use super::proto::{self, ColumnEncodingKind, StreamKind, TypeKind};
+use super::rle::*;
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcWriterOptions {
+    /// Compression of the column streams and the file tail.
+    pub compression: OrcCompression,
+    /// Maximum number of rows in a stripe.
+    pub stripe_size: IdxSize,
+    /// Size of the uncompressed blocks that streams are compressed in.
+    pub compression_block_size: usize,
+}
+
+impl Default for OrcWriterOptions {
+    fn default() -> Self {
+        Self {
+            compression: OrcCompression::default(),
+            stripe_size: 1 << 20,
+            compression_block_size: DEFAULT_COMPRESSION_BLOCK_SIZE,
+        }
+    }
+}
+
+impl OrcWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> OrcWriter<W> {
+        OrcWriter::new(writer)
+            .with_compression(self.compression)
+            .with_stripe_size(Some(self.stripe_size as usize))
+            .with_compression_block_size(self.compression_block_size)
+    }
+}
+
+/// Write a DataFrame to Apache ORC.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("file.orc").expect("could not create file");
+///
+///     OrcWriter::new(&mut file).finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct OrcWriter<W> {
+    writer: W,
+    compression: OrcCompression,
+    stripe_size: usize,
+    compression_block_size: usize,
+}
+
+impl<W: Write> OrcWriter<W> {
+    /// Set the compression used. Defaults to `Zstd`.
+    pub fn with_compression(mut self, compression: OrcCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the maximum number of rows in a stripe.
+    pub fn with_stripe_size(mut self, stripe_size: Option<usize>) -> Self {
+        if let Some(stripe_size) = stripe_size {
+            self.stripe_size = stripe_size.max(1);
+        }
+        self
+    }
+
+    /// Set the size of the uncompressed blocks that streams are compressed in.
+    pub fn with_compression_block_size(mut self, block_size: usize) -> Self {
+        // The chunk header stores the length in 23 bits.
+        self.compression_block_size = block_size.clamp(1, (1 << 23) - 1);
+        self
+    }
+
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        BatchedWriter::try_new(
+            self.writer,
+            schema,
+            self.compression,
+            self.stripe_size,
+            self.compression_block_size,
+        )
+    }
+}
+
+impl<W> SerWriter<W> for OrcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        OrcWriter {
+            writer,
+            compression: OrcCompression::default(),
+            stripe_size: OrcWriterOptions::default().stripe_size as usize,
+            compression_block_size: DEFAULT_COMPRESSION_BLOCK_SIZE,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut writer = BatchedWriter::try_new(
+            &mut self.writer,
+            df.schema(),
+            self.compression,
+            self.stripe_size,
+            self.compression_block_size,
+        )?;
+        writer.write_batch(df)?;
+        writer.finish()
+    }
+}
+
+/// Writes [`DataFrame`]s into stripes of at most `stripe_size` rows.
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    compression: OrcCompression,
+    stripe_size: usize,
+    compression_block_size: usize,
+    /// Schema with every dtype cast to one that can be represented in ORC.
+    schema: Schema,
+    types: Vec<proto::Type>,
+    encodings: Vec<proto::ColumnEncoding>,
+    buffered: Vec<DataFrame>,
+    buffered_rows: usize,
+    /// Number of bytes written so far.
+    position: u64,
+    num_rows: u64,
+    stripes: Vec<proto::StripeInformation>,
+    stripe_statistics: Vec<proto::StripeStatistics>,
+    file_statistics: Vec<StatisticsBuilder>,
+    scratch: Vec<u8>,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    fn try_new(
+        mut writer: W,
+        schema: &Schema,
+        compression: OrcCompression,
+        stripe_size: usize,
+        compression_block_size: usize,
+    ) -> PolarsResult<Self> {
+        let schema = schema
+            .iter()
+            .map(|(name, dtype)| Ok((name.clone(), orc_compatible_dtype(dtype)?)))
+            .collect::<PolarsResult<Schema>>()?;
+
+        let mut types = vec![proto::Type {
+            kind: Some(TypeKind::Struct as i32),
+            ..Default::default()
+        }];
+        for (name, dtype) in schema.iter() {
+            let id = types.len() as u32;
+            let arrow_dtype = dtype.to_arrow(CompatLevel::newest());
+            push_types(&arrow_dtype, &mut types)?;
+            types[0].subtypes.push(id);
+            types[0].field_names.push(name.to_string());
+        }
+
+        let encodings = types
+            .iter()
+            .map(|ty| proto::ColumnEncoding {
+                kind: Some(column_encoding(ty.kind()) as i32),
+                dictionary_size: None,
+            })
+            .collect();
+        let file_statistics = types
+            .iter()
+            .map(|ty| StatisticsBuilder::new(ty.kind()))
+            .collect();
+
+        writer.write_all(MAGIC)?;
+
+        Ok(Self {
+            writer,
+            compression,
+            stripe_size,
+            compression_block_size,
+            schema,
+            types,
+            encodings,
+            buffered: vec![],
+            buffered_rows: 0,
+            position: MAGIC.len() as u64,
+            num_rows: 0,
+            stripes: vec![],
+            stripe_statistics: vec![],
+            file_statistics,
+            scratch: vec![],
+        })
+    }
+
+    /// Buffer a batch, writing out a stripe every time `stripe_size` rows are buffered.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            df.width() == self.schema.len(),
+            ShapeMismatch: "ORC: expected {} columns, got {}", self.schema.len(), df.width()
+        );
+        let columns = df
+            .get_columns()
+            .iter()
+            .zip(self.schema.iter_values())
+            .map(|(c, dtype)| {
+                if c.dtype() == dtype {
+                    Ok(c.clone())
+                } else {
+                    c.strict_cast(dtype)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        self.buffered_rows += df.height();
+        self.buffered
+            .push(unsafe { DataFrame::new_no_checks(df.height(), columns) });
+
+        while self.buffered_rows >= self.stripe_size {
+            self.flush_stripe(self.stripe_size)?;
+        }
+        Ok(())
+    }
+
+    /// Write the remaining buffered rows and the file tail.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        if self.buffered_rows > 0 {
+            self.flush_stripe(self.buffered_rows)?;
+        }
+        self.write_tail()?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn flush_stripe(&mut self, n_rows: usize) -> PolarsResult<()> {
+        let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.buffered));
+        let remainder = df.slice(n_rows as i64, df.height() - n_rows);
+        self.buffered_rows = remainder.height();
+        if !remainder.is_empty() {
+            self.buffered.push(remainder);
+        }
+
+        let mut stripe = df.slice(0, n_rows);
+        stripe.as_single_chunk_par();
+        self.write_stripe(&stripe)
+    }
+
+    fn write_stripe(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let n_rows = df.height();
+        let mut encoder = ColumnEncoder {
+            types: &self.types,
+            streams: vec![],
+            statistics: self
+                .types
+                .iter()
+                .map(|ty| StatisticsBuilder::new(ty.kind()))
+                .collect(),
+        };
+        encoder.statistics[0].number_of_values = n_rows as u64;
+
+        for (column, &id) in df.get_columns().iter().zip(&self.types[0].subtypes) {
+            let array = column
+                .as_materialized_series()
+                .to_arrow(0, CompatLevel::newest());
+            encoder.encode(array.as_ref(), id as usize, None)?;
+        }
+        let ColumnEncoder {
+            streams,
+            statistics,
+            ..
+        } = encoder;
+
+        let mut footer = proto::StripeFooter {
+            streams: Vec::with_capacity(streams.len()),
+            columns: self.encodings.clone(),
+            writer_timezone: Some("UTC".to_string()),
+        };
+
+        let mut data_length = 0;
+        for (column, kind, data) in streams {
+            let length = self.write_section(&data)?;
+            data_length += length;
+            footer.streams.push(proto::Stream {
+                kind: Some(kind as i32),
+                column: Some(column as u32),
+                length: Some(length),
+            });
+        }
+        let footer_length = self.write_section(&footer.encode_to_vec())?;
+
+        self.stripes.push(proto::StripeInformation {
+            offset: Some(self.position),
+            index_length: Some(0),
+            data_length: Some(data_length),
+            footer_length: Some(footer_length),
+            number_of_rows: Some(n_rows as u64),
+        });
+        self.position += data_length + footer_length;
+        self.num_rows += n_rows as u64;
+
+        for (file, stripe) in self.file_statistics.iter_mut().zip(&statistics) {
+            file.merge(stripe);
+        }
+        self.stripe_statistics.push(proto::StripeStatistics {
+            col_stats: statistics.iter().map(|s| s.finish()).collect(),
+        });
+
+        Ok(())
+    }
+
+    fn write_tail(&mut self) -> PolarsResult<()> {
+        let metadata = proto::Metadata {
+            stripe_stats: std::mem::take(&mut self.stripe_statistics),
+        };
+        let metadata_length = self.write_section(&metadata.encode_to_vec())?;
+
+        self.file_statistics[0].number_of_values = self.num_rows;
+        let footer = proto::Footer {
+            header_length: Some(MAGIC.len() as u64),
+            content_length: Some(self.position),
+            stripes: std::mem::take(&mut self.stripes),
+            types: self.types.clone(),
+            metadata: vec![],
+            number_of_rows: Some(self.num_rows),
+            statistics: self.file_statistics.iter().map(|s| s.finish()).collect(),
+            row_index_stride: Some(0),
+            writer: None,
+        };
+        let footer_length = self.write_section(&footer.encode_to_vec())?;
+
+        let postscript = proto::PostScript {
+            footer_length: Some(footer_length),
+            compression: Some(self.compression.kind() as i32),
+            compression_block_size: Some(self.compression_block_size as u64),
+            version: vec![0, 12],
+            metadata_length: Some(metadata_length),
+            writer_version: None,
+            magic: Some(String::from_utf8(MAGIC.to_vec()).unwrap()),
+        }
+        .encode_to_vec();
+        // The postscript length is stored in a single trailing byte.
+        debug_assert!(postscript.len() < 256);
+        self.writer.write_all(&postscript)?;
+        self.writer.write_all(&[postscript.len() as u8])?;
+        Ok(())
+    }
+
+    /// Compress and write a stream or tail section, returning the number of bytes written.
+    fn write_section(&mut self, data: &[u8]) -> PolarsResult<u64> {
+        self.scratch.clear();
+        self.compression
+            .compress(data, self.compression_block_size, &mut self.scratch)?;
+        self.writer.write_all(&self.scratch)?;
+        Ok(self.scratch.len() as u64)
+    }
+}
+
+fn orc_compatible_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    use DataType as D;
+
+    let out = match dtype {
+        D::Boolean
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::Float32
+        | D::Float64
+        | D::String
+        | D::Binary
+        | D::Date => dtype.clone(),
+        #[cfg(feature = "dtype-decimal")]
+        D::Decimal(_, _) => dtype.clone(),
+        // ORC only has signed integers.
+        D::UInt8 => D::Int16,
+        D::UInt16 => D::Int32,
+        D::UInt32 | D::UInt64 => D::Int64,
+        D::Datetime(_, tz) => D::Datetime(TimeUnit::Nanoseconds, tz.clone()),
+        #[cfg(feature = "dtype-categorical")]
+        D::Categorical(_, _) | D::Enum(_, _) => D::String,
+        D::List(inner) => D::List(Box::new(orc_compatible_dtype(inner)?)),
+        dt if dt.is_array() => D::List(Box::new(orc_compatible_dtype(dt.inner_dtype().unwrap())?)),
+        #[cfg(feature = "dtype-struct")]
+        D::Struct(fields) => D::Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name.clone(), orc_compatible_dtype(&f.dtype)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        dt => polars_bail!(InvalidOperation: "cannot write column of type {dt} to ORC"),
+    };
+
+    Ok(out)
+}
+
+/// Append the ORC types of `dtype` in pre-order; the id of a type is its position in `types`.
+fn push_types(dtype: &ArrowDataType, types: &mut Vec<proto::Type>) -> PolarsResult<()> {
+    use TypeKind as K;
+
+    let id = types.len();
+    types.push(proto::Type::default());
+
+    let mut ty = proto::Type::default();
+    let kind = match dtype {
+        ArrowDataType::Boolean => K::Boolean,
+        ArrowDataType::Int8 => K::Byte,
+        ArrowDataType::Int16 => K::Short,
+        ArrowDataType::Int32 => K::Int,
+        ArrowDataType::Int64 => K::Long,
+        ArrowDataType::Float32 => K::Float,
+        ArrowDataType::Float64 => K::Double,
+        ArrowDataType::Utf8View => K::String,
+        ArrowDataType::BinaryView => K::Binary,
+        ArrowDataType::Date32 => K::Date,
+        ArrowDataType::Timestamp(_, None) => K::Timestamp,
+        ArrowDataType::Timestamp(_, Some(_)) => K::TimestampInstant,
+        ArrowDataType::Decimal(precision, scale) => {
+            ty.precision = Some(*precision as u32);
+            ty.scale = Some(*scale as u32);
+            K::Decimal
+        },
+        ArrowDataType::LargeList(field) => {
+            ty.subtypes.push(types.len() as u32);
+            push_types(&field.dtype, types)?;
+            K::List
+        },
+        ArrowDataType::Struct(fields) => {
+            for field in fields {
+                ty.subtypes.push(types.len() as u32);
+                ty.field_names.push(field.name.to_string());
+                push_types(&field.dtype, types)?;
+            }
+            K::Struct
+        },
+        dt => polars_bail!(InvalidOperation: "cannot write arrow type {dt:?} to ORC"),
+    };
+    ty.kind = Some(kind as i32);
+    types[id] = ty;
+
+    Ok(())
+}
+
+fn column_encoding(kind: TypeKind) -> ColumnEncodingKind {
+    use TypeKind as K;
+
+    // Only columns with integer RLE streams distinguish between the v1 and v2 encodings.
+    match kind {
+        K::Boolean | K::Byte | K::Float | K::Double | K::Struct => ColumnEncodingKind::Direct,
+        _ => ColumnEncodingKind::DirectV2,
+    }
+}
+
+/// Encodes the columns of a stripe into their (uncompressed) streams.
+struct ColumnEncoder<'a> {
+    types: &'a [proto::Type],
+    streams: Vec<(usize, StreamKind, Vec<u8>)>,
+    statistics: Vec<StatisticsBuilder>,
+}
+
+impl ColumnEncoder<'_> {
+    /// Encode the rows of `array` selected by `mask` as the entries of column `id`.
+    ///
+    /// Nested columns only store entries for the rows in which their parent is valid.
+    fn encode(&mut self, array: &dyn Array, id: usize, mask: Option<&Bitmap>) -> PolarsResult<()> {
+        let valid = match (mask, array.validity()) {
+            (None, None) => None,
+            (Some(m), None) | (None, Some(m)) => Some(m.clone()),
+            (Some(mask), Some(validity)) => Some(mask & validity),
+        };
+        let rows = match &valid {
+            None => (0..array.len()).collect::<Vec<_>>(),
+            Some(valid) => valid.true_idx_iter().collect(),
+        };
+
+        let n_entries = mask.map_or(array.len(), |m| m.set_bits());
+        let statistics = &mut self.statistics[id];
+        statistics.number_of_values += rows.len() as u64;
+        if rows.len() < n_entries {
+            statistics.has_null = true;
+            let mut present = vec![];
+            let validity = array.validity().unwrap();
+            let entries = (0..array.len()).filter(|&i| mask.is_none_or(|m| m.get_bit(i)));
+            encode_bool_rle(entries.map(|i| validity.get_bit(i)), &mut present);
+            self.streams.push((id, StreamKind::Present, present));
+        }
+
+        let mut data = vec![];
+        match array.dtype() {
+            ArrowDataType::Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                let values = array.values();
+                let true_count = rows.iter().filter(|&&i| values.get_bit(i)).count();
+                self.statistics[id].true_count += true_count as u64;
+                encode_bool_rle(rows.iter().map(|&i| values.get_bit(i)), &mut data);
+            },
+            ArrowDataType::Int8 => {
+                let values = gather::<i8>(array, &rows);
+                self.statistics[id].update_int(values.iter().map(|&v| v as i64));
+                encode_byte_rle(
+                    &values.iter().map(|&v| v as u8).collect::<Vec<_>>(),
+                    &mut data,
+                );
+            },
+            ArrowDataType::Int16 => self.encode_ints(id, &gather::<i16>(array, &rows), &mut data),
+            ArrowDataType::Int32 => self.encode_ints(id, &gather::<i32>(array, &rows), &mut data),
+            ArrowDataType::Int64 => self.encode_ints(id, &gather::<i64>(array, &rows), &mut data),
+            ArrowDataType::Date32 => {
+                let values = gather::<i32>(array, &rows);
+                self.statistics[id].update_date(values.iter().copied());
+                let values = values.into_iter().map(|v| v as i64).collect::<Vec<_>>();
+                encode_int_rle_v2(&values, true, &mut data);
+            },
+            ArrowDataType::Float32 => {
+                let values = gather::<f32>(array, &rows);
+                self.statistics[id].update_double(values.iter().map(|&v| v as f64));
+                values.iter().for_each(|v| data.extend(v.to_le_bytes()));
+            },
+            ArrowDataType::Float64 => {
+                let values = gather::<f64>(array, &rows);
+                self.statistics[id].update_double(values.iter().copied());
+                values.iter().for_each(|v| data.extend(v.to_le_bytes()));
+            },
+            ArrowDataType::Utf8View => {
+                let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+                let values = rows
+                    .iter()
+                    .map(|&i| unsafe { array.value_unchecked(i) })
+                    .collect::<Vec<_>>();
+                self.statistics[id].update_string(&values);
+                self.encode_binary(id, values.iter().map(|v| v.as_bytes()), &mut data);
+            },
+            ArrowDataType::BinaryView => {
+                let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+                let values = rows.iter().map(|&i| unsafe { array.value_unchecked(i) });
+                self.encode_binary(id, values, &mut data);
+            },
+            ArrowDataType::Timestamp(_, _) => {
+                let values = gather::<i64>(array, &rows);
+                let mut seconds = Vec::with_capacity(values.len());
+                let mut nanos = Vec::with_capacity(values.len());
+                for v in values {
+                    let mut secs = v.div_euclid(1_000_000_000);
+                    let ns = v.rem_euclid(1_000_000_000);
+                    // Mirror the truncation towards zero that readers undo for pre-epoch values.
+                    // Like other ORC writers, this can not represent values in (-1s, -1ms].
+                    if secs < 0 && ns > 999_999 {
+                        secs += 1;
+                    }
+                    seconds.push(secs - ORC_EPOCH_SECONDS);
+                    nanos.push(encode_nanos(ns) as i64);
+                }
+                encode_int_rle_v2(&seconds, true, &mut data);
+                let mut secondary = vec![];
+                encode_int_rle_v2(&nanos, false, &mut secondary);
+                self.streams
+                    .push((id, StreamKind::Data, std::mem::take(&mut data)));
+                self.streams.push((id, StreamKind::Secondary, secondary));
+                return Ok(());
+            },
+            ArrowDataType::Decimal(_, scale) => {
+                let values = gather::<i128>(array, &rows);
+                values.iter().for_each(|&v| write_i128_varint(v, &mut data));
+                let mut secondary = vec![];
+                encode_int_rle_v2(&vec![*scale as i64; values.len()], true, &mut secondary);
+                self.streams
+                    .push((id, StreamKind::Data, std::mem::take(&mut data)));
+                self.streams.push((id, StreamKind::Secondary, secondary));
+                return Ok(());
+            },
+            ArrowDataType::LargeList(_) => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let offsets = array.offsets();
+                let lengths = rows
+                    .iter()
+                    .map(|&i| offsets.length_at(i) as i64)
+                    .collect::<Vec<_>>();
+                encode_int_rle_v2(&lengths, false, &mut data);
+                self.streams
+                    .push((id, StreamKind::Length, std::mem::take(&mut data)));
+
+                // Only the elements of valid, selected rows are entries of the child column.
+                let values = array.values();
+                let child_mask = if rows.len() == array.len()
+                    && *offsets.first() == 0
+                    && *offsets.last() as usize == values.len()
+                {
+                    None
+                } else {
+                    let mut child_mask = MutableBitmap::from_len_zeroed(values.len());
+                    for &i in &rows {
+                        let (start, end) = offsets.start_end(i);
+                        (start..end).for_each(|j| child_mask.set(j, true));
+                    }
+                    Some(child_mask.freeze())
+                };
+
+                let child = self.types[id].subtypes[0] as usize;
+                return self.encode(values.as_ref(), child, child_mask.as_ref());
+            },
+            ArrowDataType::Struct(_) => {
+                let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+                for (values, &child) in array.values().iter().zip(&self.types[id].subtypes) {
+                    self.encode(values.as_ref(), child as usize, valid.as_ref())?;
+                }
+                return Ok(());
+            },
+            dt => polars_bail!(InvalidOperation: "cannot write arrow type {dt:?} to ORC"),
+        }
+
+        self.streams.push((id, StreamKind::Data, data));
+        Ok(())
+    }
+
+    fn encode_ints<T: NativeType + Into<i64>>(
+        &mut self,
+        id: usize,
+        values: &[T],
+        out: &mut Vec<u8>,
+    ) {
+        let values = values.iter().map(|&v| v.into()).collect::<Vec<i64>>();
+        self.statistics[id].update_int(values.iter().copied());
+        encode_int_rle_v2(&values, true, out);
+    }
+
+    fn encode_binary<'b>(
+        &mut self,
+        id: usize,
+        values: impl Iterator<Item = &'b [u8]>,
+        out: &mut Vec<u8>,
+    ) {
+        let mut lengths = vec![];
+        for value in values {
+            lengths.push(value.len() as i64);
+            out.extend_from_slice(value);
+        }
+        let mut length_stream = vec![];
+        encode_int_rle_v2(&lengths, false, &mut length_stream);
+        self.streams.push((id, StreamKind::Length, length_stream));
+    }
+}
+
+fn gather<T: NativeType>(array: &dyn Array, rows: &[usize]) -> Vec<T> {
+    let values = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    rows.iter().map(|&i| values[i]).collect()
+}
+
+/// Strip the trailing decimal zeros of the nanoseconds, storing their count in the low 3 bits.
+fn encode_nanos(nanos: i64) -> u64 {
+    let nanos = nanos as u64;
+    if nanos == 0 {
+        return 0;
+    }
+    let mut value = nanos;
+    let mut zeros = 0;
+    while value % 10 == 0 && zeros < 8 {
+        value /= 10;
+        zeros += 1;
+    }
+    if zeros >= 2 {
+        (value << 3) | (zeros - 1)
+    } else {
+        nanos << 3
+    }
+}
+
+/// Column statistics of a stripe or of the whole file.
+#[derive(Clone)]
+struct StatisticsBuilder {
+    kind: TypeKind,
+    number_of_values: u64,
+    has_null: bool,
+    int: Option<(i64, i64)>,
+    double: Option<(f64, f64)>,
+    /// Min/max of floats are not written if the column contains NaNs.
+    has_nan: bool,
+    string: Option<(String, String)>,
+    date: Option<(i32, i32)>,
+    true_count: u64,
+}
+
+fn merge_min_max<T: PartialOrd>(acc: &mut Option<(T, T)>, other: Option<(T, T)>) {
+    let Some((min, max)) = other else {
+        return;
+    };
+    *acc = match acc.take() {
+        None => Some((min, max)),
+        Some((acc_min, acc_max)) => Some((
+            if min < acc_min { min } else { acc_min },
+            if max > acc_max { max } else { acc_max },
+        )),
+    };
+}
+
+fn min_max<T: PartialOrd + Copy>(mut values: impl Iterator<Item = T>) -> Option<(T, T)> {
+    let first = values.next()?;
+    Some(values.fold((first, first), |(min, max), v| {
+        (if v < min { v } else { min }, if v > max { v } else { max })
+    }))
+}
+
+impl StatisticsBuilder {
+    fn new(kind: TypeKind) -> Self {
+        Self {
+            kind,
+            number_of_values: 0,
+            has_null: false,
+            int: None,
+            double: None,
+            has_nan: false,
+            string: None,
+            date: None,
+            true_count: 0,
+        }
+    }
+
+    fn update_int(&mut self, values: impl Iterator<Item = i64>) {
+        merge_min_max(&mut self.int, min_max(values));
+    }
+
+    fn update_double(&mut self, values: impl Iterator<Item = f64>) {
+        let mut has_nan = false;
+        merge_min_max(
+            &mut self.double,
+            min_max(values.filter(|v| {
+                has_nan |= v.is_nan();
+                !v.is_nan()
+            })),
+        );
+        self.has_nan |= has_nan;
+    }
+
+    fn update_date(&mut self, values: impl Iterator<Item = i32>) {
+        merge_min_max(&mut self.date, min_max(values));
+    }
+
+    fn update_string(&mut self, values: &[&str]) {
+        let min_max = min_max(values.iter().copied());
+        merge_min_max(
+            &mut self.string,
+            min_max.map(|(min, max)| (min.to_string(), max.to_string())),
+        );
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.number_of_values += other.number_of_values;
+        self.has_null |= other.has_null;
+        self.has_nan |= other.has_nan;
+        self.true_count += other.true_count;
+        merge_min_max(&mut self.int, other.int);
+        merge_min_max(&mut self.double, other.double);
+        merge_min_max(&mut self.date, other.date);
+        merge_min_max(&mut self.string, other.string.clone());
+    }
+
+    fn finish(&self) -> proto::ColumnStatistics {
+        use TypeKind as K;
+
+        let mut out = proto::ColumnStatistics {
+            number_of_values: Some(self.number_of_values),
+            has_null: Some(self.has_null),
+            ..Default::default()
+        };
+        match self.kind {
+            K::Byte | K::Short | K::Int | K::Long => {
+                out.int_statistics = self.int.map(|(min, max)| proto::IntegerStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                    sum: None,
+                })
+            },
+            K::Float | K::Double if !self.has_nan => {
+                out.double_statistics = self.double.map(|(min, max)| proto::DoubleStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                    sum: None,
+                })
+            },
+            K::String => {
+                out.string_statistics =
+                    self.string
+                        .as_ref()
+                        .map(|(min, max)| proto::StringStatistics {
+                            minimum: Some(min.clone()),
+                            maximum: Some(max.clone()),
+                            sum: None,
+                        })
+            },
+            K::Date => {
+                out.date_statistics = self.date.map(|(min, max)| proto::DateStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                })
+            },
+            K::Boolean => {
+                out.bucket_statistics = Some(proto::BucketStatistics {
+                    count: vec![self.true_count],
+                })
+            },
+            _ => {},
+        }
+        out
+    }
+}
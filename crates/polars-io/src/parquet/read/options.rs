@@ -1,7 +1,12 @@
-use polars_core::schema::SchemaRef;
+use polars_core::prelude::CompatLevel;
+use polars_core::schema::{SchemaExt, SchemaRef};
+use polars_error::PolarsResult;
+use polars_parquet::write::{SchemaDescriptor, to_parquet_schema};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::parquet::write::{ParquetFieldOverwrites, get_column_write_options};
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -10,6 +15,16 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Match the columns of every file to those of `schema` by their Parquet field IDs, so that
+    /// columns and struct fields that were renamed are read under their name in `schema`.
+    ///
+    /// The field IDs of `schema` are given by `field_overwrites`. Without them, the field IDs of
+    /// the first file are used, which is only correct if `schema` is that of the first file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub use_field_ids: bool,
+    /// Field IDs of the columns (and nested fields) of `schema`, see `use_field_ids`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub field_overwrites: Vec<ParquetFieldOverwrites>,
 }
 
 impl Default for ParquetOptions {
@@ -19,10 +34,31 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            use_field_ids: false,
+            field_overwrites: Vec::new(),
         }
     }
 }
 
+impl ParquetOptions {
+    /// The Parquet schema that the fields of the files are matched to by their field ID, if
+    /// `use_field_ids` is set and `schema` has field IDs.
+    pub fn field_id_reference(&self) -> PolarsResult<Option<SchemaDescriptor>> {
+        let Some(schema) = self
+            .schema
+            .as_ref()
+            .filter(|_| self.use_field_ids && !self.field_overwrites.is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let schema = schema.to_arrow(CompatLevel::newest());
+        let column_options = get_column_write_options(&schema, &self.field_overwrites);
+
+        to_parquet_schema(&schema, &column_options).map(Some)
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -107,6 +107,12 @@ where
         self
     }
 
+    /// Set per-field overwrites of the written schema, e.g. field IDs.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set custom file-level key value metadata for the Parquet file
     pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
         self.key_value_metadata = key_value_metadata;
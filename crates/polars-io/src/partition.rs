@@ -1,19 +1,35 @@
 //! Functionality for writing a DataFrame partitioned into multiple files.
 
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "parquet")]
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "parquet")]
 use polars_core::POOL;
 use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
 use polars_core::series::IsSorted;
-use polars_utils::plpath::PlPathRef;
+use polars_utils::plpath::{PlPath, PlPathRef};
+#[cfg(feature = "parquet")]
 use rayon::prelude::*;
 
 use crate::cloud::CloudOptions;
+#[cfg(feature = "parquet")]
+use crate::hive::hive_partition_dir_name;
+#[cfg(feature = "parquet")]
 use crate::parquet::write::ParquetWriteOptions;
-#[cfg(feature = "ipc")]
+use crate::path_utils::is_partition_commit_path;
+#[cfg(all(feature = "parquet", feature = "ipc"))]
 use crate::prelude::IpcWriterOptions;
-use crate::prelude::URL_ENCODE_CHAR_SET;
+#[cfg(feature = "parquet")]
 use crate::utils::file::try_get_writeable;
+#[cfg(feature = "parquet")]
 use crate::{SerWriter, WriteDataFrameToFile};
 
+#[cfg(feature = "parquet")]
 impl WriteDataFrameToFile for ParquetWriteOptions {
     fn write_df_to_file(
         &self,
@@ -27,7 +43,7 @@ impl WriteDataFrameToFile for ParquetWriteOptions {
     }
 }
 
-#[cfg(feature = "ipc")]
+#[cfg(all(feature = "parquet", feature = "ipc"))]
 impl WriteDataFrameToFile for IpcWriterOptions {
     fn write_df_to_file(
         &self,
@@ -42,6 +58,7 @@ impl WriteDataFrameToFile for IpcWriterOptions {
 }
 
 /// Write a partitioned parquet dataset. This functionality is unstable.
+#[cfg(feature = "parquet")]
 pub fn write_partitioned_dataset(
     df: &mut DataFrame,
     addr: PlPathRef<'_>,
@@ -50,6 +67,53 @@ pub fn write_partitioned_dataset(
     cloud_options: Option<&CloudOptions>,
     chunk_size: usize,
 ) -> PolarsResult<()> {
+    let options = PartitionedWriteOptions {
+        chunk_size,
+        write_mode: None,
+    };
+    write_partitioned_dataset_with_options(
+        df,
+        addr,
+        partition_by,
+        file_write_options,
+        cloud_options,
+        &options,
+    )
+}
+
+/// Options for [`write_partitioned_dataset_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartitionedWriteOptions {
+    /// The estimated size in bytes at which a partition is split over multiple files.
+    pub chunk_size: usize,
+    /// Without a write mode the files are written straight into the target directory. With a
+    /// write mode they are staged and committed through a [`PartitionCommit`].
+    pub write_mode: Option<PartitionWriteMode>,
+}
+
+/// Write a partitioned parquet dataset with the given [`PartitionedWriteOptions`]. This
+/// functionality is unstable.
+#[cfg(feature = "parquet")]
+pub fn write_partitioned_dataset_with_options(
+    df: &mut DataFrame,
+    addr: PlPathRef<'_>,
+    partition_by: Vec<PlSmallStr>,
+    file_write_options: &(dyn WriteDataFrameToFile + Send + Sync),
+    cloud_options: Option<&CloudOptions>,
+    options: &PartitionedWriteOptions,
+) -> PolarsResult<()> {
+    let chunk_size = options.chunk_size;
+    let commit = options
+        .write_mode
+        .map(|mode| PartitionCommit::try_new(addr, mode, cloud_options))
+        .transpose()?;
+    if let Some(commit) = &commit {
+        commit.begin()?;
+    }
+    let staging_path = commit.as_ref().map(PartitionCommit::staging_path);
+    let addr = staging_path.as_ref().map_or(addr, |p| p.as_ref());
+    let written = Mutex::new(Vec::new());
+
     // Ensure we have a single chunk as the gather will otherwise rechunk per group.
     df.as_single_chunk_par();
 
@@ -72,26 +136,13 @@ pub fn write_partitioned_dataset(
         move |df: &DataFrame| {
             let cols = df.get_columns();
 
-            partition_by_col_idx
-                .iter()
-                .map(|&i| {
-                    let s = &cols[i].slice(0, 1).cast(&DataType::String).unwrap();
-
-                    format!(
-                        "{}={}",
-                        s.name(),
-                        percent_encoding::percent_encode(
-                            s.str()
-                                .unwrap()
-                                .get(0)
-                                .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-                                .as_bytes(),
-                            URL_ENCODE_CHAR_SET
-                        )
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("/")
+            PolarsResult::Ok(
+                partition_by_col_idx
+                    .iter()
+                    .map(|&i| hive_partition_dir_name(&cols[i]))
+                    .collect::<PolarsResult<Vec<_>>>()?
+                    .join("/"),
+            )
         }
     };
 
@@ -99,7 +150,7 @@ pub fn write_partitioned_dataset(
     let groups = df.group_by(partition_by)?.take_groups();
 
     let init_part_base_dir = |part_df: &DataFrame| {
-        let path_part = get_hive_path_part(part_df);
+        let path_part = get_hive_path_part(part_df)?;
         let dir = base_path.join(path_part);
 
         if let Some(dir) = dir.as_ref().as_local_path() {
@@ -122,6 +173,10 @@ pub fn write_partitioned_dataset(
 
     let write_part = |mut df: DataFrame, addr: PlPathRef| {
         file_write_options.write_df_to_file(&mut df, addr, cloud_options)?;
+        if commit.is_some() {
+            let mut written = written.lock().unwrap();
+            written.push((addr.display().to_string(), df.height() as u64));
+        }
         PolarsResult::Ok(())
     };
 
@@ -200,5 +255,710 @@ pub fn write_partitioned_dataset(
             .collect::<PolarsResult<Vec<()>>>(),
     })?;
 
+    if let Some(commit) = commit {
+        let (paths, num_rows): (Vec<_>, Vec<_>) = written.into_inner().unwrap().into_iter().unzip();
+        let metrics = DataFrame::new(vec![
+            Column::new(PlSmallStr::from_static("path"), paths),
+            Column::new(PlSmallStr::from_static("num_rows"), num_rows),
+        ])?;
+        commit.commit(Some(metrics))?;
+    }
+
     Ok(())
 }
+
+/// What to do with the data that already exists in the target directory of a partitioned write.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum PartitionWriteMode {
+    /// Raise an error if the target directory contains any data.
+    #[default]
+    Error,
+    /// Keep the existing files. A written file whose name is already taken is given a unique
+    /// name instead.
+    Append,
+    /// Remove all existing data from the target directory.
+    OverwriteAll,
+    /// Remove the existing files from the partition directories that files are written to, and
+    /// keep all other partitions.
+    OverwriteTouched,
+}
+
+impl PartitionWriteMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Append => "append",
+            Self::OverwriteAll => "overwrite_all",
+            Self::OverwriteTouched => "overwrite_touched",
+        }
+    }
+}
+
+/// Directory within the target directory below which the files of a write are staged.
+pub const STAGING_DIR_NAME: &str = "_temporary";
+/// Manifest file written to the target directory when a partitioned write is committed.
+pub const SUCCESS_FILE_NAME: &str = "_SUCCESS";
+
+/// Commit protocol for a partitioned write to a local directory or a cloud storage prefix.
+///
+/// The files are first written to a staging directory, `<target>/_temporary/<job id>`. Once all
+/// of them are written, [`PartitionCommit::commit`] applies the [`PartitionWriteMode`], renames
+/// the files into the target directory and writes a `_SUCCESS` manifest that lists them. Readers
+/// skip both the staging directory and the manifest when expanding the target directory.
+///
+/// Files that are replaced by the write are first moved to `<target>/_temporary/<job id>-old`,
+/// and only removed once the new files are in place. If the commit fails they are moved back.
+/// Object stores have no atomic rename, so on cloud storage every rename is a copy followed by a
+/// delete, and readers can observe the target while the files are being moved.
+///
+/// The staged files are removed if the commit is dropped before being committed, so a failed
+/// write doesn't leave partial files behind.
+pub struct PartitionCommit {
+    storage: CommitStorage,
+    mode: PartitionWriteMode,
+    job_id: String,
+    started: AtomicBool,
+    committed: AtomicBool,
+}
+
+impl PartitionCommit {
+    /// Create the commit protocol for a write to `base_path`. This doesn't touch the storage,
+    /// the target directory is only checked by [`PartitionCommit::begin`].
+    pub fn try_new(
+        base_path: PlPathRef<'_>,
+        mode: PartitionWriteMode,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let storage = CommitStorage::new(base_path, cloud_options)?;
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        let job_id = format!("{nanos:x}-{:x}", std::process::id());
+
+        Ok(Self {
+            storage,
+            mode,
+            job_id,
+            started: AtomicBool::new(false),
+            committed: AtomicBool::new(false),
+        })
+    }
+
+    fn staging_dir(&self) -> String {
+        format!("{STAGING_DIR_NAME}/{}", self.job_id)
+    }
+
+    fn replaced_dir(&self) -> String {
+        format!("{STAGING_DIR_NAME}/{}-old", self.job_id)
+    }
+
+    /// Check the target directory against the write mode and create the staging directory.
+    /// Must be called before any file is written, calling it again does nothing.
+    pub fn begin(&self) -> PolarsResult<()> {
+        if self.started.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        if self.mode == PartitionWriteMode::Error && self.storage.contains_data()? {
+            polars_bail!(
+                ComputeError: "partitioned write target '{}' already contains data",
+                self.storage.path("").display()
+            )
+        }
+        self.storage.create_dir(&self.staging_dir())?;
+        self.started.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// The directory that the files of the write have to be written to.
+    pub fn staging_path(&self) -> PlPath {
+        self.storage.path(&self.staging_dir())
+    }
+
+    /// Move the staged files into the target directory and write the `_SUCCESS` manifest.
+    ///
+    /// `metrics` has a `path` column with the staged paths of the written files and optionally a
+    /// `num_rows` column, as the output of the partitioned sinks. It is returned with the paths
+    /// replaced by the committed paths.
+    pub fn commit(&self, metrics: Option<DataFrame>) -> PolarsResult<Option<DataFrame>> {
+        polars_ensure!(
+            !self.committed.load(Ordering::Acquire),
+            InvalidOperation: "partitioned write was already committed"
+        );
+        self.begin()?;
+
+        let mut staged = self.storage.list_files(&self.staging_dir())?;
+        staged.sort_unstable();
+
+        let mut replaced = Vec::new();
+        let mut committed = PlHashMap::with_capacity(staged.len());
+        let result = self
+            .replace_files(&staged, &mut replaced, &mut committed)
+            .and_then(|()| {
+                let metrics = metrics
+                    .map(|df| self.rewrite_metrics_paths(df, &committed))
+                    .transpose()?;
+                self.write_manifest(&staged, &committed, metrics.as_ref())?;
+                Ok(metrics)
+            });
+
+        let metrics = match result {
+            Ok(metrics) => metrics,
+            Err(err) => {
+                self.rollback(&replaced, &committed);
+                return Err(err);
+            },
+        };
+
+        self.committed.store(true, Ordering::Release);
+        let _ = self.storage.remove_all(&self.replaced_dir());
+        self.remove_staging_dir();
+        Ok(metrics)
+    }
+
+    /// Move the files replaced by the write mode out of the way, and the staged files into the
+    /// target directory. `replaced` and `committed` record the renames so far, so that they can
+    /// be undone on failure. All paths are relative, `committed` maps the staged paths to the
+    /// committed paths.
+    fn replace_files(
+        &self,
+        staged: &[String],
+        replaced: &mut Vec<String>,
+        committed: &mut PlHashMap<String, String>,
+    ) -> PolarsResult<()> {
+        let replaced_dir = self.replaced_dir();
+        let mut replace = |rel_path: String| -> PolarsResult<()> {
+            self.storage
+                .rename(&rel_path, &format!("{replaced_dir}/{rel_path}"))?;
+            replaced.push(rel_path);
+            Ok(())
+        };
+
+        match self.mode {
+            PartitionWriteMode::Error | PartitionWriteMode::Append => {},
+            PartitionWriteMode::OverwriteAll => {
+                for rel_path in self.storage.list_entries()? {
+                    if rel_path.split('/').next() != Some(STAGING_DIR_NAME) {
+                        replace(rel_path)?;
+                    }
+                }
+            },
+            PartitionWriteMode::OverwriteTouched => {
+                let touched = staged
+                    .iter()
+                    .map(|p| p.rsplit_once('/').map_or("", |(dir, _)| dir))
+                    .collect::<BTreeSet<_>>();
+                for dir in touched {
+                    for rel_path in self.storage.dir_files(dir)? {
+                        replace(rel_path)?;
+                    }
+                }
+            },
+        }
+
+        let staging_dir = self.staging_dir();
+        for rel_path in staged {
+            let mut to = rel_path.clone();
+            if self.storage.exists(&to)? {
+                polars_ensure!(
+                    self.mode == PartitionWriteMode::Append,
+                    ComputeError: "partitioned write target file '{}' already exists",
+                    self.storage.path(&to).display()
+                );
+                to = self.unique_path(&to);
+            }
+            self.storage
+                .rename(&format!("{staging_dir}/{rel_path}"), &to)?;
+            committed.insert(rel_path.clone(), to);
+        }
+        Ok(())
+    }
+
+    /// Undo the renames of a failed commit, so that the target directory is left as it was.
+    fn rollback(&self, replaced: &[String], committed: &PlHashMap<String, String>) {
+        let staging_dir = self.staging_dir();
+        for (from, to) in committed {
+            let _ = self.storage.rename(to, &format!("{staging_dir}/{from}"));
+        }
+        let replaced_dir = self.replaced_dir();
+        for rel_path in replaced.iter().rev() {
+            let _ = self
+                .storage
+                .rename(&format!("{replaced_dir}/{rel_path}"), rel_path);
+        }
+        let _ = self.storage.remove_all(&replaced_dir);
+    }
+
+    /// Insert the job id before the extension of the file name of `rel_path`.
+    fn unique_path(&self, rel_path: &str) -> String {
+        let (dir, name) = match rel_path.rsplit_once('/') {
+            Some((dir, name)) => (format!("{dir}/"), name),
+            None => (String::new(), rel_path),
+        };
+        match name.split_once('.') {
+            Some((stem, ext)) => format!("{dir}{stem}-{}.{ext}", self.job_id),
+            None => format!("{dir}{name}-{}", self.job_id),
+        }
+    }
+
+    fn rewrite_metrics_paths(
+        &self,
+        mut df: DataFrame,
+        committed: &PlHashMap<String, String>,
+    ) -> PolarsResult<DataFrame> {
+        let staging_dir = self.staging_dir();
+        let paths = df.column("path")?.str()?.apply_values(|p| {
+            match self
+                .storage
+                .relative_path(p, &staging_dir)
+                .and_then(|rel_path| committed.get(&rel_path))
+            {
+                Some(to) => self.storage.path(to).display().to_string().into(),
+                None => p.into(),
+            }
+        });
+        df.with_column(paths.into_column())?;
+        Ok(df)
+    }
+
+    /// Write the manifest that lists the committed files, relative to the target directory.
+    fn write_manifest(
+        &self,
+        staged: &[String],
+        committed: &PlHashMap<String, String>,
+        metrics: Option<&DataFrame>,
+    ) -> PolarsResult<()> {
+        let mut num_rows = PlHashMap::new();
+        if let Some(df) = metrics {
+            if let Ok(rows) = df
+                .column("num_rows")
+                .and_then(|c| c.cast(&DataType::UInt64))
+            {
+                let paths = df.column("path")?.str()?;
+                for (path, rows) in paths.into_iter().zip(rows.u64()?) {
+                    if let (Some(path), Some(rows)) = (path, rows) {
+                        if let Some(rel_path) = self.storage.relative_path(path, "") {
+                            num_rows.insert(rel_path, rows);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut files = Vec::with_capacity(staged.len());
+        for rel_path in staged {
+            let rel_path = &committed[rel_path];
+            let file_size = self.storage.file_size(rel_path)?;
+            let num_rows = num_rows
+                .get(rel_path)
+                .map_or_else(|| "null".to_string(), |n| n.to_string());
+            files.push(format!(
+                "    {{\"path\": {}, \"num_rows\": {num_rows}, \"file_size\": {file_size}}}",
+                json_string(rel_path)
+            ));
+        }
+
+        let manifest = format!(
+            "{{\n  \"write_mode\": \"{}\",\n  \"files\": [\n{}\n  ]\n}}\n",
+            self.mode.as_str(),
+            files.join(",\n")
+        );
+        self.storage.write(SUCCESS_FILE_NAME, manifest.into_bytes())
+    }
+
+    fn remove_staging_dir(&self) {
+        let _ = self.storage.remove_all(&self.staging_dir());
+        // Fails if other writes are staging files in the same target directory.
+        self.storage.remove_empty_dir(STAGING_DIR_NAME);
+    }
+}
+
+impl Drop for PartitionCommit {
+    fn drop(&mut self) {
+        if *self.started.get_mut() && !*self.committed.get_mut() {
+            self.remove_staging_dir();
+        }
+    }
+}
+
+/// The target directory of a [`PartitionCommit`]. Paths passed to it are relative to the target
+/// directory and separated by `/`.
+enum CommitStorage {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud(cloud_commit::CloudCommitStorage),
+}
+
+impl CommitStorage {
+    fn new(base_path: PlPathRef<'_>, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        match base_path {
+            PlPathRef::Local(path) => {
+                let _ = cloud_options;
+                Ok(Self::Local(path.to_path_buf()))
+            },
+            #[cfg(feature = "cloud")]
+            PlPathRef::Cloud(_) => Ok(Self::Cloud(cloud_commit::CloudCommitStorage::new(
+                base_path.to_str(),
+                cloud_options,
+            ))),
+            #[cfg(not(feature = "cloud"))]
+            PlPathRef::Cloud(_) => polars_bail!(
+                ComputeError: "'cloud' feature is not enabled, cannot write to '{}'",
+                base_path.display()
+            ),
+        }
+    }
+
+    fn path(&self, rel_path: &str) -> PlPath {
+        match self {
+            Self::Local(base) => PlPath::Local(base.join(rel_path).into()),
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => PlPathRef::new(cloud.url()).join(rel_path),
+        }
+    }
+
+    /// The path of `path` relative to the directory `rel_dir`, if it is below it.
+    fn relative_path(&self, path: &str, rel_dir: &str) -> Option<String> {
+        match self {
+            Self::Local(base) => {
+                let rel_path = Path::new(path).strip_prefix(base.join(rel_dir)).ok()?;
+                let rel_path = rel_path
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                Some(rel_path)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => {
+                let rel_path = path.strip_prefix(cloud.url())?.strip_prefix('/')?;
+                if rel_dir.is_empty() {
+                    Some(rel_path.to_string())
+                } else {
+                    Some(
+                        rel_path
+                            .strip_prefix(rel_dir)?
+                            .strip_prefix('/')?
+                            .to_string(),
+                    )
+                }
+            },
+        }
+    }
+
+    /// Whether the target directory contains anything besides the files of the commit protocol.
+    fn contains_data(&self) -> PolarsResult<bool> {
+        match self {
+            Self::Local(base) => {
+                if !base.exists() {
+                    return Ok(false);
+                }
+                for entry in std::fs::read_dir(base)? {
+                    let name = entry?.file_name();
+                    if !name.to_str().is_some_and(is_partition_commit_path) {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => Ok(cloud
+                .list("", true)?
+                .iter()
+                .any(|rel_path| !is_partition_commit_path(rel_path))),
+        }
+    }
+
+    fn create_dir(&self, rel_dir: &str) -> PolarsResult<()> {
+        match self {
+            Self::Local(base) => Ok(std::fs::create_dir_all(base.join(rel_dir))?),
+            // Object stores have no directories.
+            #[cfg(feature = "cloud")]
+            Self::Cloud(_) => Ok(()),
+        }
+    }
+
+    /// The paths of all files below `rel_dir`, relative to `rel_dir`.
+    fn list_files(&self, rel_dir: &str) -> PolarsResult<Vec<String>> {
+        match self {
+            Self::Local(base) => {
+                let mut out = Vec::new();
+                list_local_files(&base.join(rel_dir), "", &mut out)?;
+                Ok(out)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => Ok(cloud
+                .list(rel_dir, true)?
+                .into_iter()
+                .map(|rel_path| rel_path[rel_dir.len() + 1..].to_string())
+                .collect()),
+        }
+    }
+
+    /// The entries that hold all data of the target directory: its direct children for a local
+    /// directory, and every object below it on cloud storage.
+    fn list_entries(&self) -> PolarsResult<Vec<String>> {
+        match self {
+            Self::Local(base) => std::fs::read_dir(base)?
+                .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+                .collect(),
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.list("", true),
+        }
+    }
+
+    /// The paths of the files directly in `rel_dir`, or nothing if it doesn't exist.
+    fn dir_files(&self, rel_dir: &str) -> PolarsResult<Vec<String>> {
+        match self {
+            Self::Local(base) => {
+                let dir = base.join(rel_dir);
+                if !dir.is_dir() {
+                    return Ok(vec![]);
+                }
+                let mut out = Vec::new();
+                for entry in std::fs::read_dir(dir)? {
+                    let entry = entry?;
+                    if entry.file_type()?.is_file() {
+                        let name = entry.file_name();
+                        let name = name.to_string_lossy();
+                        out.push(if rel_dir.is_empty() {
+                            name.into_owned()
+                        } else {
+                            format!("{rel_dir}/{name}")
+                        });
+                    }
+                }
+                Ok(out)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.list(rel_dir, false),
+        }
+    }
+
+    fn exists(&self, rel_path: &str) -> PolarsResult<bool> {
+        match self {
+            Self::Local(base) => Ok(base.join(rel_path).exists()),
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.exists(rel_path),
+        }
+    }
+
+    fn file_size(&self, rel_path: &str) -> PolarsResult<u64> {
+        match self {
+            Self::Local(base) => Ok(std::fs::metadata(base.join(rel_path))?.len()),
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.file_size(rel_path),
+        }
+    }
+
+    fn rename(&self, from: &str, to: &str) -> PolarsResult<()> {
+        match self {
+            Self::Local(base) => {
+                let to = base.join(to);
+                if let Some(parent) = to.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                Ok(std::fs::rename(base.join(from), to)?)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.rename(from, to),
+        }
+    }
+
+    fn write(&self, rel_path: &str, bytes: Vec<u8>) -> PolarsResult<()> {
+        match self {
+            Self::Local(base) => Ok(std::fs::write(base.join(rel_path), bytes)?),
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.write(rel_path, bytes),
+        }
+    }
+
+    /// Remove `rel_dir` and everything below it.
+    fn remove_all(&self, rel_dir: &str) -> PolarsResult<()> {
+        match self {
+            Self::Local(base) => Ok(std::fs::remove_dir_all(base.join(rel_dir))?),
+            #[cfg(feature = "cloud")]
+            Self::Cloud(cloud) => cloud.remove_all(rel_dir),
+        }
+    }
+
+    fn remove_empty_dir(&self, rel_dir: &str) {
+        match self {
+            Self::Local(base) => {
+                let _ = std::fs::remove_dir(base.join(rel_dir));
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud(_) => {},
+        }
+    }
+}
+
+/// Collect the paths of all files below `dir`, relative to the directory that is listed.
+fn list_local_files(dir: &Path, rel_dir: &str, out: &mut Vec<String>) -> PolarsResult<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let rel_path = if rel_dir.is_empty() {
+            name.to_string_lossy().into_owned()
+        } else {
+            format!("{rel_dir}/{}", name.to_string_lossy())
+        };
+        if entry.file_type()?.is_dir() {
+            list_local_files(&entry.path(), &rel_path, out)?;
+        } else {
+            out.push(rel_path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "cloud")]
+mod cloud_commit {
+    use std::sync::{Arc, OnceLock};
+
+    use futures::TryStreamExt;
+    use object_store::ObjectStore;
+    use object_store::path::Path as ObjectPath;
+    use polars_error::PolarsResult;
+
+    use crate::cloud::{CloudOptions, build_object_store, object_path_from_str};
+    use crate::pl_async::get_runtime;
+
+    /// A [`super::CommitStorage`] on an object store. The object store is only built once it is
+    /// first accessed.
+    pub(super) struct CloudCommitStorage {
+        url: String,
+        cloud_options: Option<CloudOptions>,
+        store: OnceLock<(Arc<dyn ObjectStore>, ObjectPath)>,
+    }
+
+    impl CloudCommitStorage {
+        pub(super) fn new(url: &str, cloud_options: Option<&CloudOptions>) -> Self {
+            Self {
+                url: url.trim_end_matches('/').to_string(),
+                cloud_options: cloud_options.cloned(),
+                store: OnceLock::new(),
+            }
+        }
+
+        pub(super) fn url(&self) -> &str {
+            &self.url
+        }
+
+        /// The object store and the key prefix of the target directory.
+        fn store(&self) -> PolarsResult<(Arc<dyn ObjectStore>, ObjectPath)> {
+            if let Some(store) = self.store.get() {
+                return Ok(store.clone());
+            }
+
+            let store = get_runtime().block_in_place_on(async {
+                let (location, store) =
+                    build_object_store(&self.url, self.cloud_options.as_ref(), false).await?;
+                let prefix = object_path_from_str(&location.prefix)?;
+                PolarsResult::Ok((store.to_dyn_object_store().await, prefix))
+            })?;
+            Ok(self.store.get_or_init(|| store).clone())
+        }
+
+        fn object_path(prefix: &ObjectPath, rel_path: &str) -> PolarsResult<ObjectPath> {
+            if prefix.as_ref().is_empty() {
+                object_path_from_str(rel_path)
+            } else {
+                object_path_from_str(&format!("{prefix}/{rel_path}"))
+            }
+        }
+
+        /// The paths of the objects below `rel_dir`, relative to the target directory. Without
+        /// `recursive` only the objects directly in `rel_dir` are listed.
+        pub(super) fn list(&self, rel_dir: &str, recursive: bool) -> PolarsResult<Vec<String>> {
+            let (store, prefix) = self.store()?;
+            let dir = Self::object_path(&prefix, rel_dir)?;
+
+            let locations = get_runtime().block_in_place_on(async {
+                if recursive {
+                    store
+                        .list(Some(&dir))
+                        .map_ok(|meta| meta.location)
+                        .try_collect::<Vec<_>>()
+                        .await
+                } else {
+                    Ok(store
+                        .list_with_delimiter(Some(&dir))
+                        .await?
+                        .objects
+                        .into_iter()
+                        .map(|meta| meta.location)
+                        .collect())
+                }
+            })?;
+
+            Ok(locations
+                .iter()
+                .filter_map(|location| {
+                    let rel_path = location.as_ref().strip_prefix(prefix.as_ref())?;
+                    Some(rel_path.trim_start_matches('/').to_string())
+                })
+                .collect())
+        }
+
+        pub(super) fn exists(&self, rel_path: &str) -> PolarsResult<bool> {
+            let (store, prefix) = self.store()?;
+            let path = Self::object_path(&prefix, rel_path)?;
+
+            match get_runtime().block_in_place_on(store.head(&path)) {
+                Ok(_) => Ok(true),
+                Err(object_store::Error::NotFound { .. }) => Ok(false),
+                Err(err) => Err(err.into()),
+            }
+        }
+
+        pub(super) fn file_size(&self, rel_path: &str) -> PolarsResult<u64> {
+            let (store, prefix) = self.store()?;
+            let path = Self::object_path(&prefix, rel_path)?;
+            Ok(get_runtime().block_in_place_on(store.head(&path))?.size)
+        }
+
+        pub(super) fn rename(&self, from: &str, to: &str) -> PolarsResult<()> {
+            let (store, prefix) = self.store()?;
+            let from = Self::object_path(&prefix, from)?;
+            let to = Self::object_path(&prefix, to)?;
+            Ok(get_runtime().block_in_place_on(store.rename(&from, &to))?)
+        }
+
+        pub(super) fn write(&self, rel_path: &str, bytes: Vec<u8>) -> PolarsResult<()> {
+            let (store, prefix) = self.store()?;
+            let path = Self::object_path(&prefix, rel_path)?;
+            get_runtime().block_in_place_on(store.put(&path, bytes.into()))?;
+            Ok(())
+        }
+
+        pub(super) fn remove_all(&self, rel_dir: &str) -> PolarsResult<()> {
+            let (store, prefix) = self.store()?;
+            for rel_path in self.list(rel_dir, true)? {
+                let path = Self::object_path(&prefix, &rel_path)?;
+                get_runtime().block_in_place_on(store.delete(&path))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
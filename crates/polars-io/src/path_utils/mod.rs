@@ -3,7 +3,8 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock};
 
 use polars_core::config;
-use polars_core::error::{PolarsError, PolarsResult, polars_bail, to_compute_err};
+use polars_core::error::{PolarsError, PolarsResult, polars_bail, polars_ensure, to_compute_err};
+use polars_utils::aliases::PlHashSet;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::{CloudScheme, PlPath, PlPathRef};
 
@@ -11,6 +12,7 @@ use polars_utils::plpath::{CloudScheme, PlPath, PlPathRef};
 mod hugging_face;
 
 use crate::cloud::CloudOptions;
+use crate::partition::{STAGING_DIR_NAME, SUCCESS_FILE_NAME};
 
 pub static POLARS_TEMP_DIR_BASE_PATH: LazyLock<Box<Path>> = LazyLock::new(|| {
     (|| {
@@ -140,6 +142,134 @@ pub fn get_glob_start_idx(path: &[u8]) -> Option<usize> {
     memchr::memchr3(b'*', b'?', b'[', path)
 }
 
+/// Expands `{a,b}` alternatives in a glob pattern, e.g. `data/{2023,2024}/*.parquet` expands to
+/// `data/2023/*.parquet` and `data/2024/*.parquet`. Groups can be nested, and braces that don't
+/// contain a top-level `,` are kept as-is.
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+    let bytes = pattern.as_bytes();
+
+    for start in memchr::memchr_iter(b'{', bytes) {
+        let mut depth = 0usize;
+        let mut commas = vec![];
+        let mut end = None;
+
+        for (i, b) in bytes.iter().enumerate().skip(start + 1) {
+            match b {
+                b'{' => depth += 1,
+                b'}' if depth == 0 => {
+                    end = Some(i);
+                    break;
+                },
+                b'}' => depth -= 1,
+                b',' if depth == 0 => commas.push(i),
+                _ => {},
+            }
+        }
+
+        let Some(end) = end else {
+            break;
+        };
+
+        if commas.is_empty() {
+            continue;
+        }
+
+        let prefix = &pattern[..start];
+        let suffix = &pattern[end + 1..];
+
+        return std::iter::once(start)
+            .chain(commas.iter().copied())
+            .zip(commas.iter().copied().chain(std::iter::once(end)))
+            .flat_map(|(l, r)| expand_braces(&format!("{prefix}{}{suffix}", &pattern[l + 1..r])))
+            .collect();
+    }
+
+    vec![pattern.to_string()]
+}
+
+/// Glob patterns that exclude paths from an expanded scan. A path is excluded if a pattern
+/// matches the full path or any of its parent directories, so both `!**/_tmp` and `!**/_tmp/**`
+/// exclude everything below a `_tmp` directory.
+struct ExclusionPatterns(Vec<glob::Pattern>);
+
+impl ExclusionPatterns {
+    const MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+
+    fn is_excluded(&self, path: &str) -> bool {
+        self.0.iter().any(|pattern| {
+            pattern.matches_with(path, Self::MATCH_OPTIONS)
+                || memchr::memchr_iter(b'/', path.as_bytes())
+                    .filter(|&i| i > 0)
+                    .any(|i| pattern.matches_with(&path[..i], Self::MATCH_OPTIONS))
+        })
+    }
+}
+
+/// Splits the input paths into the patterns to expand and the exclusion patterns (those starting
+/// with `!`), and expands brace alternatives. Every alternative becomes its own pattern, so that
+/// cloud listings start from the longest literal prefix of each of them.
+///
+/// The returned flag is set if a brace pattern expanded into multiple patterns. The alternatives
+/// can overlap, e.g. `{2023,*}`, so the expanded paths then have to be de-duplicated.
+fn split_glob_patterns(paths: &[PlPath]) -> PolarsResult<(Vec<PlPath>, ExclusionPatterns, bool)> {
+    let mut out = vec![];
+    let mut exclusions = vec![];
+    let mut has_alternatives = false;
+
+    for path in paths {
+        if matches!(
+            path.cloud_scheme(),
+            Some(CloudScheme::Http | CloudScheme::Https)
+        ) {
+            out.push(path.clone());
+            continue;
+        }
+
+        let s = path.to_str();
+
+        if let Some(pattern) = s.strip_prefix('!') {
+            for pattern in expand_braces(pattern) {
+                let Ok(pattern) = glob::Pattern::new(&pattern) else {
+                    polars_bail!(ComputeError: "invalid exclusion glob pattern given: {}", s)
+                };
+                exclusions.push(pattern);
+            }
+        } else if memchr::memchr(b'{', s.as_bytes()).is_some() {
+            let expanded = expand_braces(s);
+            has_alternatives |= expanded.len() > 1;
+            for expanded in expanded {
+                let expanded = PlPath::from_string(expanded);
+                if !out.contains(&expanded) {
+                    out.push(expanded);
+                }
+            }
+        } else {
+            out.push(path.clone());
+        }
+    }
+
+    polars_ensure!(
+        !out.is_empty() || exclusions.is_empty(),
+        InvalidOperation: "only exclusion patterns (starting with '!') were given, at least one \
+        path to scan is required"
+    );
+
+    Ok((out, ExclusionPatterns(exclusions), has_alternatives))
+}
+
+/// Returns `true` if `rel_path` is the `_SUCCESS` manifest of a partitioned write or lies in its
+/// `_temporary` staging directory. `rel_path` is relative to the expanded directory or to the
+/// non-glob prefix of the pattern, so that these names are only matched below it.
+pub fn is_partition_commit_path(rel_path: &str) -> bool {
+    rel_path
+        .split(['/', '\\'])
+        .any(|name| name == STAGING_DIR_NAME || name == SUCCESS_FILE_NAME)
+}
+
 /// Returns `true` if `expanded_paths` were expanded from a single directory
 pub fn expanded_from_single_directory(addrs: &[PlPath], expanded_addrs: &[PlPath]) -> bool {
     // Single input that isn't a glob
@@ -200,12 +330,27 @@ impl HiveIdxTracker<'_> {
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 /// Returns the expanded paths and the index at which to start parsing hive
 /// partitions from the path.
+///
+/// When `glob` is `true`, `{a,b}` alternatives are expanded and paths starting with `!` are
+/// treated as patterns that exclude matching paths from the output, e.g. `!**/_tmp/**`.
+/// Exclusion patterns are matched against the full expanded path. A relative path whose name
+/// starts with `!` has to be given as `./!name` to be scanned instead. A path that is matched by
+/// multiple brace alternatives is only returned once.
 pub fn expand_paths_hive(
     paths: &[PlPath],
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
     check_directory_level: bool,
 ) -> PolarsResult<(Arc<[PlPath]>, usize)> {
+    let (expanded_patterns, exclusions, has_alternatives);
+    let paths = if glob {
+        (expanded_patterns, exclusions, has_alternatives) = split_glob_patterns(paths)?;
+        expanded_patterns.as_slice()
+    } else {
+        (exclusions, has_alternatives) = (ExclusionPatterns(vec![]), false);
+        paths
+    };
+
     let Some(first_path) = paths.first() else {
         return Ok((vec![].into(), 0));
     };
@@ -214,13 +359,16 @@ pub fn expand_paths_hive(
 
     /// Wrapper around `Vec<PathBuf>` that also tracks file extensions, so that
     /// we don't have to traverse the entire list again to validate extensions.
-    struct OutPaths {
+    struct OutPaths<'a> {
         paths: Vec<PlPath>,
         exts: [Option<(PlSmallStr, usize)>; 2],
         current_idx: usize,
+        exclusions: &'a ExclusionPatterns,
+        /// The paths returned so far, if paths can be matched by multiple patterns.
+        seen: Option<PlHashSet<PlPath>>,
     }
 
-    impl OutPaths {
+    impl OutPaths<'_> {
         fn update_ext_status(
             current_idx: &mut usize,
             exts: &mut [Option<(PlSmallStr, usize)>; 2],
@@ -240,7 +388,22 @@ pub fn expand_paths_hive(
             *current_idx += 1;
         }
 
+        fn is_skipped(
+            exclusions: &ExclusionPatterns,
+            seen: &mut Option<PlHashSet<PlPath>>,
+            value: &PlPath,
+        ) -> bool {
+            exclusions.is_excluded(value.to_str())
+                || seen
+                    .as_mut()
+                    .is_some_and(|seen| !seen.insert(value.clone()))
+        }
+
         fn push(&mut self, value: PlPath) {
+            if Self::is_skipped(self.exclusions, &mut self.seen, &value) {
+                return;
+            }
+
             {
                 let current_idx = &mut self.current_idx;
                 let exts = &mut self.exts;
@@ -252,10 +415,17 @@ pub fn expand_paths_hive(
         fn extend(&mut self, values: impl IntoIterator<Item = PlPath>) {
             let current_idx = &mut self.current_idx;
             let exts = &mut self.exts;
-
-            self.paths.extend(values.into_iter().inspect(|x| {
-                Self::update_ext_status(current_idx, exts, x.as_ref());
-            }))
+            let exclusions = self.exclusions;
+            let seen = &mut self.seen;
+
+            self.paths.extend(
+                values
+                    .into_iter()
+                    .filter(|x| !Self::is_skipped(exclusions, seen, x))
+                    .inspect(|x| {
+                        Self::update_ext_status(current_idx, exts, x.as_ref());
+                    }),
+            )
         }
 
         fn extend_from_slice(&mut self, values: &[PlPath]) {
@@ -267,6 +437,8 @@ pub fn expand_paths_hive(
         paths: vec![],
         exts: [None, None],
         current_idx: 0,
+        exclusions: &exclusions,
+        seen: has_alternatives.then(PlHashSet::default),
     };
 
     let mut hive_idx_tracker = HiveIdxTracker {
@@ -344,6 +516,7 @@ pub fn expand_paths_hive(
                         }
 
                         let cloud_location = &cloud_location;
+                        let prefix = &prefix;
 
                         let mut paths = store
                             .try_exec_rebuild_on_err(|store| {
@@ -352,9 +525,16 @@ pub fn expand_paths_hive(
                                 async {
                                     let store = st;
                                     let out = store
-                                        .list(Some(&prefix))
+                                        .list(Some(prefix))
                                         .try_filter_map(|x| async move {
-                                            let out = (x.size > 0).then(|| {
+                                            let rel_path = x
+                                                .location
+                                                .as_ref()
+                                                .get(prefix.as_ref().len()..)
+                                                .unwrap_or_default();
+                                            let out = (x.size > 0
+                                                && !is_partition_commit_path(rel_path))
+                                            .then(|| {
                                                 PlPath::from_string({
                                                     format_path(
                                                         &cloud_location.scheme,
@@ -457,6 +637,14 @@ pub fn expand_paths_hive(
                     paths.sort_unstable();
 
                     for path in paths {
+                        if path
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .is_some_and(is_partition_commit_path)
+                        {
+                            continue;
+                        }
+
                         if path.is_dir() {
                             stack.push_back(path);
                         } else if path.metadata()?.len() > 0 {
@@ -470,15 +658,24 @@ pub fn expand_paths_hive(
 
             let i = get_glob_start_idx(path.to_str().unwrap().as_bytes());
 
-            if glob && i.is_some() {
+            if let Some(i) = i.filter(|_| glob) {
                 hive_idx_tracker.update(0, path_idx)?;
 
-                let Ok(paths) = glob::glob(path.to_str().unwrap()) else {
+                let path = path.to_str().unwrap();
+                let Ok(paths) = glob::glob(path) else {
                     polars_bail!(ComputeError: "invalid glob pattern given")
                 };
+                let prefix_len = path[..i].rfind(['/', '\\']).map_or(0, |i| i + 1);
 
                 for path in paths {
                     let path = path.map_err(to_compute_err)?;
+                    if path
+                        .to_str()
+                        .and_then(|p| p.get(prefix_len..))
+                        .is_some_and(is_partition_commit_path)
+                    {
+                        continue;
+                    }
                     if !path.is_dir() && path.metadata()?.len() > 0 {
                         out_paths.push(PlPath::Local(path.into()));
                     }
@@ -560,6 +757,73 @@ mod tests {
         assert!(resolved[2].is_absolute());
     }
 
+    #[test]
+    fn test_expand_braces() {
+        use super::expand_braces;
+
+        assert_eq!(expand_braces("a/*.c"), ["a/*.c"]);
+        assert_eq!(
+            expand_braces("a/{2023,2024}/*.{c,d}"),
+            ["a/2023/*.c", "a/2023/*.d", "a/2024/*.c", "a/2024/*.d"]
+        );
+        assert_eq!(expand_braces("a/{b,c{d,e}}"), ["a/b", "a/cd", "a/ce"]);
+        assert_eq!(expand_braces("a/{b}/{c,}"), ["a/{b}/c", "a/{b}/"]);
+        assert_eq!(expand_braces("a/{b,c"), ["a/{b,c"]);
+    }
+
+    #[test]
+    fn test_exclusion_patterns() {
+        use super::ExclusionPatterns;
+
+        let exclusions = ExclusionPatterns(vec![
+            glob::Pattern::new("**/_tmp/**").unwrap(),
+            glob::Pattern::new("s3://bucket/a/*.csv").unwrap(),
+        ]);
+
+        assert!(exclusions.is_excluded("/data/_tmp/1.parquet"));
+        assert!(exclusions.is_excluded("s3://bucket/data/_tmp/x/1.parquet"));
+        assert!(exclusions.is_excluded("s3://bucket/a/1.csv"));
+        assert!(!exclusions.is_excluded("s3://bucket/a/b/1.csv"));
+        assert!(!exclusions.is_excluded("/data/tmp/1.parquet"));
+
+        // Matching a parent directory excludes everything below it.
+        let exclusions = ExclusionPatterns(vec![glob::Pattern::new("data/2023").unwrap()]);
+        assert!(exclusions.is_excluded("data/2023/1.parquet"));
+        assert!(!exclusions.is_excluded("data/2024/1.parquet"));
+    }
+
+    #[test]
+    fn test_split_glob_patterns() {
+        use super::split_glob_patterns;
+
+        let (patterns, exclusions, has_alternatives) =
+            split_glob_patterns(&[PlPath::new("a/{b,c}/*.csv"), PlPath::new("!**/_tmp")]).unwrap();
+        assert_eq!(
+            patterns,
+            [PlPath::new("a/b/*.csv"), PlPath::new("a/c/*.csv")]
+        );
+        assert_eq!(exclusions.0.len(), 1);
+        assert!(has_alternatives);
+
+        let (_, _, has_alternatives) =
+            split_glob_patterns(&[PlPath::new("a/{b}/*.csv"), PlPath::new("./!b.csv")]).unwrap();
+        assert!(!has_alternatives);
+
+        assert!(split_glob_patterns(&[PlPath::new("!**/_tmp")]).is_err());
+    }
+
+    #[test]
+    fn test_is_partition_commit_path() {
+        use super::is_partition_commit_path;
+
+        assert!(is_partition_commit_path("_SUCCESS"));
+        assert!(is_partition_commit_path("_temporary/1f-2a/a=1/0.parquet"));
+        assert!(is_partition_commit_path("a=1/_temporary"));
+        assert!(!is_partition_commit_path("a=1/_part-0.parquet"));
+        assert!(!is_partition_commit_path(".hidden/0.parquet"));
+        assert!(!is_partition_commit_path("_temporary_data/0.parquet"));
+    }
+
     #[test]
     fn test_http_path_with_query_parameters_is_not_expanded_as_glob() {
         // Don't confuse HTTP URL's with query parameters for globs.
@@ -1,16 +1,24 @@
 pub use crate::cloud;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
+#[cfg(feature = "csv")]
+pub use crate::fixed_width::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthReader};
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
 pub use crate::json::*;
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
+#[cfg(feature = "orc")]
+pub use crate::orc::{OrcCompression, OrcReader, OrcWriter, OrcWriterOptions};
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
+pub use crate::partition::PartitionWriteMode;
 #[cfg(feature = "parquet")]
 pub use crate::partition::write_partitioned_dataset;
 pub use crate::path_utils::*;
 pub use crate::shared::{SerReader, SerWriter};
+#[cfg(feature = "sqlite")]
+pub use crate::sqlite::{SqliteIfExists, SqliteReader, SqliteSource, SqliteWriter};
+pub use crate::utils::compression::ExternalCompression;
 pub use crate::utils::*;
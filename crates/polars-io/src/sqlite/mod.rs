@@ -0,0 +1,16 @@
+//! Reading and writing tables of [SQLite](https://sqlite.org) databases.
+mod read;
+mod write;
+
+pub use read::*;
+pub use write::*;
+
+/// Quote an identifier so that it can be used as a table or column name in a SQL statement.
+pub fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Quote a string so that it can be used as a literal in a SQL statement.
+pub fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
@@ -0,0 +1,687 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use rusqlite::types::{Type, ValueRef};
+use rusqlite::{Connection, OpenFlags};
+
+use super::quote_identifier;
+
+/// The table or query that is read from a SQLite database.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SqliteSource {
+    Table(PlSmallStr),
+    Query(PlSmallStr),
+}
+
+impl SqliteSource {
+    /// Interpret `table_or_query` as a query if it starts with `SELECT`, `WITH` or `VALUES`, and as
+    /// the name of a table otherwise.
+    pub fn new(table_or_query: &str) -> Self {
+        let trimmed = table_or_query.trim();
+        let is_query = ["select", "with", "values"].iter().any(|keyword| {
+            trimmed
+                .get(..keyword.len())
+                .is_some_and(|s| s.eq_ignore_ascii_case(keyword))
+                && trimmed[keyword.len()..].starts_with(|c: char| c.is_whitespace() || c == '(')
+        });
+
+        if is_query {
+            Self::Query(
+                trimmed
+                    .trim_end_matches(|c: char| c == ';' || c.is_whitespace())
+                    .into(),
+            )
+        } else {
+            Self::Table(trimmed.into())
+        }
+    }
+
+    /// The source as it appears in the `FROM` clause of a `SELECT` statement.
+    fn table_expr(&self) -> String {
+        match self {
+            Self::Table(name) => quote_identifier(name),
+            Self::Query(query) => format!("({query})"),
+        }
+    }
+}
+
+/// Map the declared type of a column to a [`DataType`], following the type affinity rules of
+/// SQLite. Returns `None` for columns without an affinity that determines the type of the values.
+///
+/// SQLite has no temporal storage class, but the declared types `DATE`, `DATETIME`, `TIMESTAMP`
+/// and `TIME` (as written by [`SqliteWriter`](super::SqliteWriter)) are read as temporal columns.
+fn dtype_from_declared_type(declared_type: &str) -> Option<DataType> {
+    let declared_type = declared_type.to_ascii_uppercase();
+    let contains = |s: &str| declared_type.contains(s);
+
+    #[cfg(feature = "dtype-datetime")]
+    if contains("DATETIME") || contains("TIMESTAMP") {
+        return Some(DataType::Datetime(TimeUnit::Microseconds, None));
+    }
+    #[cfg(feature = "dtype-date")]
+    if contains("DATE") {
+        return Some(DataType::Date);
+    }
+    #[cfg(feature = "dtype-time")]
+    if contains("TIME") {
+        return Some(DataType::Time);
+    }
+
+    if contains("INT") {
+        Some(DataType::Int64)
+    } else if contains("CHAR") || contains("CLOB") || contains("TEXT") {
+        Some(DataType::String)
+    } else if contains("BLOB") {
+        Some(DataType::Binary)
+    } else if contains("REAL") || contains("FLOA") || contains("DOUB") {
+        Some(DataType::Float64)
+    } else if contains("BOOL") {
+        Some(DataType::Boolean)
+    } else {
+        None
+    }
+}
+
+/// The storage classes of the values seen in a column during schema inference.
+#[derive(Clone, Copy, Default)]
+struct SeenTypes {
+    integer: bool,
+    real: bool,
+    text: bool,
+    blob: bool,
+}
+
+impl SeenTypes {
+    fn update(&mut self, tp: Type) {
+        match tp {
+            Type::Null => {},
+            Type::Integer => self.integer = true,
+            Type::Real => self.real = true,
+            Type::Text => self.text = true,
+            Type::Blob => self.blob = true,
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        if self.blob {
+            DataType::Binary
+        } else if self.text {
+            DataType::String
+        } else if self.real {
+            DataType::Float64
+        } else if self.integer {
+            DataType::Int64
+        } else {
+            // Every value can be read as a string.
+            DataType::String
+        }
+    }
+}
+
+fn open_read_only(path: &Path) -> PolarsResult<Connection> {
+    Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI,
+    )
+    .map_err(|err| {
+        polars_err!(ComputeError: "could not open sqlite database '{}': {err}", path.display())
+    })
+}
+
+/// Infer the schema of `source` from the declared types of its columns. The types of columns
+/// without a declared type are inferred from the values in the first `infer_schema_length` rows.
+fn infer_schema(
+    conn: &Connection,
+    source: &SqliteSource,
+    infer_schema_length: Option<usize>,
+) -> PolarsResult<Schema> {
+    let sql = format!("SELECT * FROM {}", source.table_expr());
+    let mut stmt = conn.prepare(&sql).map_err(to_compute_err)?;
+
+    let mut columns = stmt
+        .columns()
+        .into_iter()
+        .map(|c| {
+            (
+                PlSmallStr::from_str(c.name()),
+                c.decl_type().and_then(dtype_from_declared_type),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if columns.iter().any(|(_, dtype)| dtype.is_none()) {
+        let mut seen = vec![SeenTypes::default(); columns.len()];
+        let mut rows = stmt.query([]).map_err(to_compute_err)?;
+        let mut n_rows = 0;
+
+        while infer_schema_length.is_none_or(|max| n_rows < max) {
+            let Some(row) = rows.next().map_err(to_compute_err)? else {
+                break;
+            };
+            for (i, seen) in seen.iter_mut().enumerate() {
+                seen.update(row.get_ref(i).map_err(to_compute_err)?.data_type());
+            }
+            n_rows += 1;
+        }
+
+        for ((_, dtype), seen) in columns.iter_mut().zip(seen) {
+            dtype.get_or_insert_with(|| seen.dtype());
+        }
+    }
+
+    let n_columns = columns.len();
+    let schema = Schema::from_iter(
+        columns
+            .into_iter()
+            .map(|(name, dtype)| Field::new(name, dtype.unwrap())),
+    );
+    polars_ensure!(
+        schema.len() == n_columns,
+        Duplicate: "sqlite source contains duplicate column names"
+    );
+
+    Ok(schema)
+}
+
+/// Parse a date stored as text, e.g. `2024-01-31`.
+#[cfg(feature = "dtype-date")]
+fn parse_date(value: &str) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+/// Parse a timestamp stored as text, e.g. `2024-01-31 12:30:00.5`. Timestamps with a UTC offset
+/// are converted to UTC, and dates without a time are read as midnight.
+#[cfg(feature = "dtype-datetime")]
+fn parse_datetime(value: &str) -> Option<chrono::NaiveDateTime> {
+    let value = value.trim();
+
+    for fmt in [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(value, fmt) {
+            return Some(dt);
+        }
+        if let Ok(dt) = chrono::DateTime::parse_from_str(value, &format!("{fmt}%#z")) {
+            return Some(dt.naive_utc());
+        }
+    }
+
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .map(|date| date.and_time(chrono::NaiveTime::MIN))
+}
+
+/// The time since the UNIX epoch in the given unit, `None` if it overflows.
+#[cfg(feature = "dtype-datetime")]
+fn datetime_to_timestamp(dt: chrono::NaiveDateTime, tu: TimeUnit) -> Option<i64> {
+    let dt = dt.and_utc();
+    match tu {
+        TimeUnit::Nanoseconds => dt.timestamp_nanos_opt(),
+        TimeUnit::Microseconds => Some(dt.timestamp_micros()),
+        TimeUnit::Milliseconds => Some(dt.timestamp_millis()),
+    }
+}
+
+/// Parse a time of day stored as text, e.g. `12:30:00.5`.
+#[cfg(feature = "dtype-time")]
+fn parse_time(value: &str) -> Option<chrono::NaiveTime> {
+    let value = value.trim();
+    chrono::NaiveTime::parse_from_str(value, "%H:%M:%S%.f")
+        .or_else(|_| chrono::NaiveTime::parse_from_str(value, "%H:%M"))
+        .ok()
+}
+
+/// Appends the values of a result column to a typed builder.
+enum ColumnBuilder {
+    Boolean(BooleanChunkedBuilder),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    String(StringChunkedBuilder),
+    Binary(BinaryChunkedBuilder),
+    /// Days since the UNIX epoch.
+    #[cfg(feature = "dtype-date")]
+    Date(PrimitiveChunkedBuilder<Int32Type>),
+    /// Time since the UNIX epoch in the given unit.
+    #[cfg(feature = "dtype-datetime")]
+    Datetime(PrimitiveChunkedBuilder<Int64Type>, TimeUnit),
+    /// Nanoseconds since midnight.
+    #[cfg(feature = "dtype-time")]
+    Time(PrimitiveChunkedBuilder<Int64Type>),
+}
+
+impl ColumnBuilder {
+    /// Values are read as the closest type that SQLite can store, and cast to `dtype` afterwards.
+    fn new(name: PlSmallStr, dtype: &DataType) -> Self {
+        match dtype {
+            DataType::Boolean => Self::Boolean(BooleanChunkedBuilder::new(name, 0)),
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => Self::Date(PrimitiveChunkedBuilder::new(name, 0)),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, _) => Self::Datetime(PrimitiveChunkedBuilder::new(name, 0), *tu),
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => Self::Time(PrimitiveChunkedBuilder::new(name, 0)),
+            dt if dt.is_integer() => Self::Int64(PrimitiveChunkedBuilder::new(name, 0)),
+            dt if dt.is_float() => Self::Float64(PrimitiveChunkedBuilder::new(name, 0)),
+            DataType::Binary => Self::Binary(BinaryChunkedBuilder::new(name, 0)),
+            _ => Self::String(StringChunkedBuilder::new(name, 0)),
+        }
+    }
+
+    /// Returns `false` if the value cannot be represented by the builder.
+    fn push(&mut self, value: ValueRef<'_>) -> bool {
+        if let ValueRef::Null = value {
+            match self {
+                Self::Boolean(b) => b.append_null(),
+                Self::Int64(b) => b.append_null(),
+                Self::Float64(b) => b.append_null(),
+                Self::String(b) => b.append_null(),
+                Self::Binary(b) => b.append_null(),
+                #[cfg(feature = "dtype-date")]
+                Self::Date(b) => b.append_null(),
+                #[cfg(feature = "dtype-datetime")]
+                Self::Datetime(b, _) => b.append_null(),
+                #[cfg(feature = "dtype-time")]
+                Self::Time(b) => b.append_null(),
+            }
+            return true;
+        }
+
+        match (self, value) {
+            (Self::Boolean(b), ValueRef::Integer(v)) => b.append_value(v != 0),
+            (Self::Boolean(b), ValueRef::Text(v)) => match v {
+                b"1" | b"true" | b"TRUE" => b.append_value(true),
+                b"0" | b"false" | b"FALSE" => b.append_value(false),
+                _ => return false,
+            },
+            (Self::Int64(b), ValueRef::Integer(v)) => b.append_value(v),
+            (Self::Int64(b), ValueRef::Real(v)) => {
+                if v.fract() != 0.0 || !(i64::MIN as f64..=i64::MAX as f64).contains(&v) {
+                    return false;
+                }
+                b.append_value(v as i64)
+            },
+            (Self::Int64(b), ValueRef::Text(v)) => {
+                match std::str::from_utf8(v)
+                    .ok()
+                    .and_then(|v| v.trim().parse().ok())
+                {
+                    Some(v) => b.append_value(v),
+                    None => return false,
+                }
+            },
+            (Self::Float64(b), ValueRef::Integer(v)) => b.append_value(v as f64),
+            (Self::Float64(b), ValueRef::Real(v)) => b.append_value(v),
+            (Self::Float64(b), ValueRef::Text(v)) => {
+                match std::str::from_utf8(v)
+                    .ok()
+                    .and_then(|v| v.trim().parse().ok())
+                {
+                    Some(v) => b.append_value(v),
+                    None => return false,
+                }
+            },
+            (Self::String(b), ValueRef::Integer(v)) => b.append_value(v.to_string()),
+            (Self::String(b), ValueRef::Real(v)) => b.append_value(v.to_string()),
+            (Self::String(b), ValueRef::Text(v) | ValueRef::Blob(v)) => {
+                match std::str::from_utf8(v) {
+                    Ok(v) => b.append_value(v),
+                    Err(_) => return false,
+                }
+            },
+            (Self::Binary(b), ValueRef::Text(v) | ValueRef::Blob(v)) => b.append_value(v),
+            (Self::Binary(b), ValueRef::Integer(v)) => b.append_value(v.to_string()),
+            (Self::Binary(b), ValueRef::Real(v)) => b.append_value(v.to_string()),
+            // Integers are interpreted as seconds since the UNIX epoch, like SQLite's `unixepoch`.
+            #[cfg(feature = "dtype-date")]
+            (Self::Date(b), ValueRef::Integer(v)) => match i32::try_from(v.div_euclid(86_400)) {
+                Ok(v) => b.append_value(v),
+                Err(_) => return false,
+            },
+            #[cfg(feature = "dtype-date")]
+            (Self::Date(b), ValueRef::Text(v)) => {
+                use chrono::Datelike;
+
+                match std::str::from_utf8(v).ok().and_then(parse_date) {
+                    Some(date) => b.append_value(
+                        date.num_days_from_ce() - arrow::temporal_conversions::EPOCH_DAYS_FROM_CE,
+                    ),
+                    None => return false,
+                }
+            },
+            #[cfg(feature = "dtype-datetime")]
+            (Self::Datetime(b, tu), ValueRef::Integer(v)) => {
+                match chrono::DateTime::from_timestamp(v, 0)
+                    .and_then(|dt| datetime_to_timestamp(dt.naive_utc(), *tu))
+                {
+                    Some(v) => b.append_value(v),
+                    None => return false,
+                }
+            },
+            #[cfg(feature = "dtype-datetime")]
+            (Self::Datetime(b, tu), ValueRef::Text(v)) => {
+                match std::str::from_utf8(v)
+                    .ok()
+                    .and_then(parse_datetime)
+                    .and_then(|dt| datetime_to_timestamp(dt, *tu))
+                {
+                    Some(v) => b.append_value(v),
+                    None => return false,
+                }
+            },
+            #[cfg(feature = "dtype-time")]
+            (Self::Time(b), ValueRef::Text(v)) => {
+                use chrono::Timelike;
+
+                match std::str::from_utf8(v).ok().and_then(parse_time) {
+                    Some(time) => b.append_value(
+                        time.num_seconds_from_midnight() as i64 * 1_000_000_000
+                            + time.nanosecond() as i64,
+                    ),
+                    None => return false,
+                }
+            },
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn finish(self, dtype: &DataType) -> PolarsResult<Column> {
+        let series = match self {
+            Self::Boolean(b) => b.finish().into_series(),
+            Self::Int64(b) => b.finish().into_series(),
+            Self::Float64(b) => b.finish().into_series(),
+            Self::String(b) => b.finish().into_series(),
+            Self::Binary(b) => b.finish().into_series(),
+            #[cfg(feature = "dtype-date")]
+            Self::Date(b) => b.finish().into_date().into_series(),
+            #[cfg(feature = "dtype-datetime")]
+            Self::Datetime(b, tu) => b.finish().into_datetime(tu, None).into_series(),
+            #[cfg(feature = "dtype-time")]
+            Self::Time(b) => b.finish().into_time().into_series(),
+        };
+
+        if series.dtype() == dtype {
+            Ok(series.into_column())
+        } else {
+            series.strict_cast(dtype).map(Column::from)
+        }
+    }
+}
+
+/// Read a table or the result of a query from a SQLite database into a [`DataFrame`].
+///
+/// The projection, filter and row limit are part of the generated `SELECT` statement, so that
+/// SQLite only returns the requested data. Rows are decoded directly into typed columns, in
+/// batches that can be processed as they are read with [`SqliteReader::read_batches`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::sqlite::{SqliteReader, SqliteSource};
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     SqliteReader::new("example.db", SqliteSource::new("species"))
+///         .with_columns(Some(["name".into(), "genus".into()].into()))
+///         .with_filter(Some("\"genus\" = 'Quercus'".into()))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct SqliteReader {
+    path: PathBuf,
+    source: SqliteSource,
+    schema: Option<SchemaRef>,
+    columns: Option<Arc<[PlSmallStr]>>,
+    filter: Option<String>,
+    n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+}
+
+impl SqliteReader {
+    pub fn new(path: impl Into<PathBuf>, source: SqliteSource) -> Self {
+        Self {
+            path: path.into(),
+            source,
+            schema: None,
+            columns: None,
+            filter: None,
+            n_rows: None,
+            infer_schema_length: Some(100),
+        }
+    }
+
+    /// Set the schema of the source. The schema is inferred if it is not set.
+    pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Only read the given columns.
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only read the rows for which this SQL expression is true.
+    pub fn with_filter(mut self, filter: Option<String>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Stop reading after `n_rows` rows of the source. The limit is applied before the filter.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Number of rows used to infer the type of columns without a declared type. `None` uses
+    /// all rows. Defaults to 100.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// The schema of the source, this does not take the projection into account.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        if let Some(schema) = &self.schema {
+            return Ok(schema.clone());
+        }
+
+        let conn = open_read_only(&self.path)?;
+        let schema = Arc::new(infer_schema(&conn, &self.source, self.infer_schema_length)?);
+        self.schema = Some(schema.clone());
+        Ok(schema)
+    }
+
+    /// The schema of the columns that are read.
+    fn projected_schema(&mut self) -> PolarsResult<Schema> {
+        let schema = self.schema()?;
+        match &self.columns {
+            None => Ok(schema.as_ref().clone()),
+            Some(columns) => schema.try_project(columns.iter()),
+        }
+    }
+
+    /// The `SELECT` statement that is sent to SQLite.
+    pub fn sql(&mut self) -> PolarsResult<String> {
+        let schema = self.projected_schema()?;
+        Ok(self.sql_for(&schema))
+    }
+
+    fn sql_for(&self, schema: &Schema) -> String {
+        let columns = if schema.is_empty() {
+            // Only the number of rows is needed.
+            "NULL".to_string()
+        } else {
+            schema
+                .iter_names()
+                .map(|name| quote_identifier(name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let from = self.source.table_expr();
+
+        match (self.n_rows, &self.filter) {
+            (None, None) => format!("SELECT {columns} FROM {from}"),
+            (None, Some(filter)) => format!("SELECT {columns} FROM {from} WHERE {filter}"),
+            (Some(n), None) => format!("SELECT {columns} FROM {from} LIMIT {n}"),
+            (Some(n), Some(filter)) => {
+                format!("SELECT {columns} FROM (SELECT * FROM {from} LIMIT {n}) WHERE {filter}")
+            },
+        }
+    }
+
+    /// The columns of the source that can be compared in SQL with the same result as in Polars.
+    ///
+    /// These are the columns with a declared type whose affinity matches their type in the
+    /// schema: `TEXT` affinity for string columns, and `INTEGER`, `REAL` or `NUMERIC` affinity for
+    /// numeric columns. Other columns, such as the expressions of a query, can hold values that
+    /// SQLite converts or orders differently than Polars. Comparisons of string columns must use
+    /// `COLLATE BINARY`, as the declared collation of the column can differ.
+    pub fn comparable_columns(&mut self) -> PolarsResult<PlHashSet<PlSmallStr>> {
+        let schema = self.schema()?;
+        let conn = open_read_only(&self.path)?;
+        let stmt = conn
+            .prepare(&format!("SELECT * FROM {}", self.source.table_expr()))
+            .map_err(to_compute_err)?;
+
+        Ok(stmt
+            .columns()
+            .into_iter()
+            .filter(|c| {
+                let (Some(dtype), Some(declared_type)) = (schema.get(c.name()), c.decl_type())
+                else {
+                    return false;
+                };
+                match Affinity::of(declared_type) {
+                    Affinity::Text => dtype.is_string(),
+                    Affinity::Integer | Affinity::Real | Affinity::Numeric => {
+                        dtype.is_primitive_numeric()
+                    },
+                    Affinity::Blob => false,
+                }
+            })
+            .map(|c| PlSmallStr::from_str(c.name()))
+            .collect())
+    }
+
+    /// Read the rows in batches of at most `batch_size` rows, which are passed to `f` as they are
+    /// read.
+    pub fn read_batches(
+        mut self,
+        batch_size: usize,
+        mut f: impl FnMut(DataFrame) -> PolarsResult<()>,
+    ) -> PolarsResult<()> {
+        polars_ensure!(batch_size > 0, InvalidOperation: "batch size must be positive");
+
+        let schema = self.projected_schema()?;
+        let sql = self.sql_for(&schema);
+
+        if polars_core::config::verbose() {
+            eprintln!("[SqliteReader]: {sql}");
+        }
+
+        let conn = open_read_only(&self.path)?;
+        let mut stmt = conn.prepare(&sql).map_err(to_compute_err)?;
+        let mut rows = stmt.query([]).map_err(to_compute_err)?;
+
+        let new_builders = || {
+            schema
+                .iter()
+                .map(|(name, dtype)| ColumnBuilder::new(name.clone(), dtype))
+                .collect::<Vec<_>>()
+        };
+        let finish_batch = |builders: Vec<ColumnBuilder>, height| {
+            let columns = builders
+                .into_iter()
+                .zip(schema.iter_values())
+                .map(|(builder, dtype)| builder.finish(dtype))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            DataFrame::new_with_height(height, columns)
+        };
+
+        let mut builders = new_builders();
+        let mut height = 0;
+        let mut row_idx = 0;
+
+        while let Some(row) = rows.next().map_err(to_compute_err)? {
+            for (i, builder) in builders.iter_mut().enumerate() {
+                let value = row.get_ref(i).map_err(to_compute_err)?;
+                if !builder.push(value) {
+                    let (name, dtype) = schema.get_at_index(i).unwrap();
+                    polars_bail!(
+                        SchemaMismatch: "could not read sqlite value {:?} in row {} of column '{}' as {}",
+                        value, row_idx, name, dtype
+                    );
+                }
+            }
+            height += 1;
+            row_idx += 1;
+
+            if height == batch_size {
+                f(finish_batch(
+                    std::mem::replace(&mut builders, new_builders()),
+                    height,
+                )?)?;
+                height = 0;
+            }
+        }
+
+        // The last batch is also passed if it is empty, so that there is at least one batch.
+        if height > 0 || row_idx == 0 {
+            f(finish_batch(builders, height)?)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        let mut batches = vec![];
+        self.read_batches(DEFAULT_BATCH_SIZE, |df| {
+            batches.push(df);
+            Ok(())
+        })?;
+        Ok(accumulate_dataframes_vertical_unchecked(batches))
+    }
+}
+
+/// Number of rows that are decoded into a chunk at a time.
+pub const DEFAULT_BATCH_SIZE: usize = 100_000;
+
+/// The type affinity of a column, which determines how SQLite converts the values stored in it and
+/// the values it is compared with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Affinity {
+    Text,
+    Numeric,
+    Integer,
+    Real,
+    Blob,
+}
+
+impl Affinity {
+    /// The affinity of a declared type, see <https://sqlite.org/datatype3.html#determination_of_column_affinity>.
+    fn of(declared_type: &str) -> Self {
+        let declared_type = declared_type.to_ascii_uppercase();
+        let contains = |s: &str| declared_type.contains(s);
+
+        if contains("INT") {
+            Self::Integer
+        } else if contains("CHAR") || contains("CLOB") || contains("TEXT") {
+            Self::Text
+        } else if contains("BLOB") || declared_type.trim().is_empty() {
+            Self::Blob
+        } else if contains("REAL") || contains("FLOA") || contains("DOUB") {
+            Self::Real
+        } else {
+            Self::Numeric
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::path::PathBuf;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use rusqlite::Connection;
+use rusqlite::types::{ToSqlOutput, ValueRef};
+
+use super::quote_identifier;
+
+/// What to do when the table that is written to already exists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SqliteIfExists {
+    /// Raise an error.
+    #[default]
+    Fail,
+    /// Insert the rows into the existing table.
+    Append,
+    /// Drop the existing table and create a new one.
+    Replace,
+}
+
+/// The declared type of a column created for `dtype`.
+fn declared_type(dtype: &DataType) -> PolarsResult<&'static str> {
+    Ok(match dtype {
+        DataType::Boolean => "BOOLEAN",
+        dt if dt.is_integer() => "INTEGER",
+        dt if dt.is_float() => "REAL",
+        DataType::Binary => "BLOB",
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => "DATE",
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => "TIMESTAMP",
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => "TIME",
+        DataType::String | DataType::Null => "TEXT",
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => "TEXT",
+        dt => polars_bail!(InvalidOperation: "cannot write column of type {} to sqlite", dt),
+    })
+}
+
+/// Cast a column to the type that is bound to the insert statement.
+fn to_storage_column(column: &Column) -> PolarsResult<Series> {
+    // The default string representation of times drops the fractional seconds.
+    #[cfg(feature = "dtype-time")]
+    if let DataType::Time = column.dtype() {
+        let s = column.as_materialized_series();
+        return Ok(s.time()?.to_string("%H:%M:%S%.f").into_series().rechunk());
+    }
+
+    let dtype = match column.dtype() {
+        DataType::Boolean | DataType::Binary | DataType::Null => column.dtype().clone(),
+        dt if dt.is_integer() => DataType::Int64,
+        dt if dt.is_float() => DataType::Float64,
+        _ => DataType::String,
+    };
+    Ok(column
+        .as_materialized_series()
+        .strict_cast(&dtype)?
+        .rechunk())
+}
+
+/// Write a [`DataFrame`] to a table of a SQLite database.
+///
+/// The table is created from the schema of the [`DataFrame`] if it does not exist. All rows are
+/// inserted in a single transaction. Temporal and categorical columns are stored as text, temporal
+/// columns are declared as `DATE`, `TIMESTAMP` or `TIME` so that they are read back with their type.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::sqlite::{SqliteIfExists, SqliteWriter};
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     SqliteWriter::new("example.db", "species")
+///         .with_if_exists(SqliteIfExists::Append)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct SqliteWriter {
+    path: PathBuf,
+    table: PlSmallStr,
+    if_exists: SqliteIfExists,
+}
+
+impl SqliteWriter {
+    pub fn new(path: impl Into<PathBuf>, table: impl Into<PlSmallStr>) -> Self {
+        Self {
+            path: path.into(),
+            table: table.into(),
+            if_exists: SqliteIfExists::default(),
+        }
+    }
+
+    /// Set what to do if the table already exists. Defaults to [`SqliteIfExists::Fail`].
+    pub fn with_if_exists(mut self, if_exists: SqliteIfExists) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    pub fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        self.finish_batches(df.schema(), std::iter::once(Ok(df.clone())))
+    }
+
+    /// Write the batches with the given `schema`, which are inserted as they are produced by the
+    /// iterator. All batches are inserted in a single transaction, which is rolled back if the
+    /// iterator returns an error.
+    pub fn finish_batches(
+        &mut self,
+        schema: &Schema,
+        batches: impl IntoIterator<Item = PolarsResult<DataFrame>>,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            !schema.is_empty(),
+            InvalidOperation: "cannot write a DataFrame without columns to sqlite"
+        );
+
+        let mut conn = Connection::open(&self.path).map_err(|err| {
+            polars_err!(ComputeError: "could not open sqlite database '{}': {err}", self.path.display())
+        })?;
+        let tx = conn.transaction().map_err(to_compute_err)?;
+        let table = quote_identifier(&self.table);
+
+        let exists = tx
+            .prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1")
+            .and_then(|mut stmt| stmt.exists([self.table.as_str()]))
+            .map_err(to_compute_err)?;
+
+        match (exists, self.if_exists) {
+            (true, SqliteIfExists::Fail) => {
+                polars_bail!(ComputeError: "sqlite table '{}' already exists", self.table)
+            },
+            (true, SqliteIfExists::Replace) => {
+                tx.execute(&format!("DROP TABLE {table}"), [])
+                    .map_err(to_compute_err)?;
+            },
+            _ => {},
+        }
+
+        if !exists || self.if_exists == SqliteIfExists::Replace {
+            let columns = schema
+                .iter()
+                .map(|(name, dtype)| {
+                    Ok(format!(
+                        "{} {}",
+                        quote_identifier(name),
+                        declared_type(dtype)?
+                    ))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            tx.execute(
+                &format!("CREATE TABLE {table} ({})", columns.join(", ")),
+                [],
+            )
+            .map_err(to_compute_err)?;
+        }
+
+        for df in batches {
+            let columns = df?
+                .get_columns()
+                .iter()
+                .map(to_storage_column)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            insert_rows(&tx, &table, &columns)?;
+        }
+
+        tx.commit().map_err(to_compute_err)
+    }
+}
+
+fn insert_rows(conn: &Connection, table: &str, columns: &[Series]) -> PolarsResult<()> {
+    let names = columns
+        .iter()
+        .map(|s| quote_identifier(s.name()))
+        .collect::<Vec<_>>();
+    let params = (1..=columns.len())
+        .map(|i| format!("?{i}"))
+        .collect::<Vec<_>>();
+    let sql = format!(
+        "INSERT INTO {table} ({}) VALUES ({})",
+        names.join(", "),
+        params.join(", ")
+    );
+    let mut stmt = conn.prepare(&sql).map_err(to_compute_err)?;
+
+    let mut values = columns
+        .iter()
+        .map(|s| -> Box<dyn Iterator<Item = ValueRef<'_>> + '_> {
+            match s.dtype() {
+                DataType::Boolean => Box::new(
+                    s.bool()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map_or(ValueRef::Null, |v| ValueRef::Integer(v as i64))),
+                ),
+                DataType::Int64 => Box::new(
+                    s.i64()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map_or(ValueRef::Null, ValueRef::Integer)),
+                ),
+                DataType::Float64 => Box::new(
+                    s.f64()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map_or(ValueRef::Null, ValueRef::Real)),
+                ),
+                DataType::String => Box::new(
+                    s.str()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map_or(ValueRef::Null, |v| ValueRef::Text(v.as_bytes()))),
+                ),
+                DataType::Binary => Box::new(
+                    s.binary()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map_or(ValueRef::Null, ValueRef::Blob)),
+                ),
+                _ => Box::new(std::iter::repeat_n(ValueRef::Null, s.len())),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..columns[0].len() {
+        for (i, values) in values.iter_mut().enumerate() {
+            stmt.raw_bind_parameter(i + 1, ToSqlOutput::Borrowed(values.next().unwrap()))
+                .map_err(to_compute_err)?;
+        }
+        stmt.raw_execute().map_err(to_compute_err)?;
+    }
+
+    Ok(())
+}
@@ -10,6 +10,7 @@ use crate::cloud::{
     CloudLocation, CloudOptions, ObjectStorePath, PolarsObjectStore, build_object_store,
     object_path_from_str,
 };
+use crate::metrics::IOMetrics;
 
 #[allow(async_fn_in_trait)]
 pub trait ByteSource: Send + Sync {
@@ -116,6 +117,20 @@ impl DynByteSource {
             Self::Cloud(_) => "Cloud",
         }
     }
+
+    /// Records the requests made to the object store in `io_metrics`. This has no effect on
+    /// in-memory sources, as they don't make any requests.
+    pub fn with_io_metrics(self, io_metrics: Arc<IOMetrics>) -> Self {
+        match self {
+            Self::MemSlice(_) => self,
+            Self::Cloud(ObjectStoreByteSource { store, path }) => {
+                Self::Cloud(ObjectStoreByteSource {
+                    store: store.with_io_metrics(io_metrics),
+                    path,
+                })
+            },
+        }
+    }
 }
 
 impl Default for DynByteSource {
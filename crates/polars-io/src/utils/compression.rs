@@ -1,7 +1,10 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use polars_core::prelude::*;
-use polars_error::{feature_gated, to_compute_err};
+use polars_error::{feature_gated, polars_ensure, to_compute_err};
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
@@ -59,3 +62,292 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Incrementally decompresses a compressed buffer, so that the output can be processed in batches
+/// without holding all of the decompressed data in memory.
+pub struct StreamingDecompressor {
+    reader: Box<dyn Read + Send>,
+}
+
+impl StreamingDecompressor {
+    /// Returns `None` if no supported compression is detected in `bytes`.
+    pub fn try_new(bytes: MemSlice) -> PolarsResult<Option<Self>> {
+        let Some(algo) = SupportedCompression::check(&bytes) else {
+            return Ok(None);
+        };
+
+        feature_gated!("decompress", {
+            let bytes = std::io::Cursor::new(bytes);
+            let reader: Box<dyn Read + Send> = match algo {
+                SupportedCompression::GZIP => Box::new(flate2::read::MultiGzDecoder::new(bytes)),
+                SupportedCompression::ZLIB => Box::new(flate2::read::ZlibDecoder::new(bytes)),
+                SupportedCompression::ZSTD => Box::new(zstd::Decoder::new(bytes)?),
+            };
+
+            Ok(Some(Self { reader }))
+        })
+    }
+
+    /// Decompresses into `out` until it has a length of at least `len`. Returns `false` if the end
+    /// of the data was reached.
+    pub fn fill(&mut self, out: &mut Vec<u8>, len: usize) -> PolarsResult<bool> {
+        let n_wanted = len.saturating_sub(out.len());
+        let n_read = (&mut self.reader)
+            .take(n_wanted as u64)
+            .read_to_end(out)
+            .map_err(to_compute_err)?;
+
+        Ok(n_read == n_wanted)
+    }
+}
+
+impl Read for StreamingDecompressor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// Compression of a whole file, as opposed to the compression of pages or blocks within a file
+/// format. This is used by the text formats, e.g. to write `.csv.gz` files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    /// Level between 0 and 9, defaults to 6.
+    Gzip { level: Option<u32> },
+    /// Level between 1 and 22, defaults to 3.
+    Zstd { level: Option<i32> },
+}
+
+impl ExternalCompression {
+    pub fn gzip(level: Option<u32>) -> PolarsResult<Self> {
+        let out = Self::Gzip { level };
+        out.check_level()?;
+        Ok(out)
+    }
+
+    pub fn zstd(level: Option<i32>) -> PolarsResult<Self> {
+        let out = Self::Zstd { level };
+        out.check_level()?;
+        Ok(out)
+    }
+
+    pub fn is_uncompressed(&self) -> bool {
+        matches!(self, Self::Uncompressed)
+    }
+
+    /// Extension that is appended to the extension of the file format, e.g. `gz` for `.csv.gz`.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            Self::Uncompressed => None,
+            Self::Gzip { .. } => Some("gz"),
+            Self::Zstd { .. } => Some("zst"),
+        }
+    }
+
+    fn check_level(&self) -> PolarsResult<()> {
+        match *self {
+            Self::Uncompressed => {},
+            Self::Gzip { level } => polars_ensure!(
+                level.is_none_or(|l| l <= 9),
+                InvalidOperation: "gzip compression level must be between 0 and 9, got {}",
+                level.unwrap()
+            ),
+            Self::Zstd { level } => polars_ensure!(
+                level.is_none_or(|l| (1..=22).contains(&l)),
+                InvalidOperation: "zstd compression level must be between 1 and 22, got {}",
+                level.unwrap()
+            ),
+        }
+
+        Ok(())
+    }
+
+    /// Compresses `bytes` and appends the result to `out`.
+    ///
+    /// The output is a complete gzip member or zstd frame, so the outputs of consecutive calls
+    /// can be concatenated into a single valid file. This allows chunks of a file to be compressed
+    /// in parallel.
+    pub fn compress(&self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        self.check_level()?;
+
+        match *self {
+            Self::Uncompressed => {
+                out.extend_from_slice(bytes);
+                Ok(())
+            },
+            Self::Gzip { level } => feature_gated!("compress", {
+                let level = flate2::Compression::new(level.unwrap_or(6));
+                let mut encoder = flate2::write::GzEncoder::new(out, level);
+                encoder.write_all(bytes)?;
+                encoder.finish()?;
+                Ok(())
+            }),
+            Self::Zstd { level } => feature_gated!("compress", {
+                zstd::stream::copy_encode(bytes, out, level.unwrap_or(3))?;
+                Ok(())
+            }),
+        }
+    }
+
+    /// Compress `bytes` if compression is enabled, otherwise simply return it.
+    pub fn maybe_compress(&self, bytes: Vec<u8>) -> PolarsResult<Vec<u8>> {
+        if self.is_uncompressed() {
+            return Ok(bytes);
+        }
+
+        let mut out = Vec::with_capacity(bytes.len() / 4);
+        self.compress(&bytes, &mut out)?;
+        Ok(out)
+    }
+}
+
+/// Writer that compresses the bytes written to it with an [`ExternalCompression`].
+///
+/// [`CompressedWriter::finish`] must be called to write the end of the compressed stream.
+pub struct CompressedWriter<W: Write> {
+    inner: CompressedWriterInner<W>,
+}
+
+enum CompressedWriterInner<W: Write> {
+    Uncompressed(W),
+    #[cfg(feature = "compress")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "compress")]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(writer: W, compression: ExternalCompression) -> PolarsResult<Self> {
+        compression.check_level()?;
+
+        let inner = match compression {
+            ExternalCompression::Uncompressed => CompressedWriterInner::Uncompressed(writer),
+            ExternalCompression::Gzip { level } => feature_gated!("compress", {
+                CompressedWriterInner::Gzip(flate2::write::GzEncoder::new(
+                    writer,
+                    flate2::Compression::new(level.unwrap_or(6)),
+                ))
+            }),
+            ExternalCompression::Zstd { level } => feature_gated!("compress", {
+                CompressedWriterInner::Zstd(zstd::Encoder::new(writer, level.unwrap_or(3))?)
+            }),
+        };
+
+        Ok(Self { inner })
+    }
+
+    /// Finishes the compressed stream and returns the inner writer.
+    pub fn finish(self) -> PolarsResult<W> {
+        let mut writer = match self.inner {
+            CompressedWriterInner::Uncompressed(writer) => writer,
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Gzip(encoder) => encoder.finish()?,
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Zstd(encoder) => encoder.finish()?,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl CompressedWriter<Vec<u8>> {
+    /// Writes `bytes` to the compressed stream and returns the compressed output that is ready.
+    ///
+    /// The encoder keeps its state between calls, so the output of all calls followed by the
+    /// output of [`CompressedWriter::finish`] form a single gzip member or zstd frame. Without
+    /// compression, `bytes` is returned as is.
+    pub fn compress_chunk(&mut self, bytes: Vec<u8>) -> PolarsResult<Vec<u8>> {
+        match &mut self.inner {
+            CompressedWriterInner::Uncompressed(_) => Ok(bytes),
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Gzip(encoder) => {
+                encoder.write_all(&bytes)?;
+                Ok(std::mem::take(encoder.get_mut()))
+            },
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Zstd(encoder) => {
+                encoder.write_all(&bytes)?;
+                Ok(std::mem::take(encoder.get_mut()))
+            },
+        }
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match &mut self.inner {
+            CompressedWriterInner::Uncompressed(writer) => writer.write(buf),
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.inner {
+            CompressedWriterInner::Uncompressed(writer) => writer.flush(),
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "compress")]
+            CompressedWriterInner::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "compress", feature = "decompress"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress_concatenated_chunks() {
+        for compression in [
+            ExternalCompression::gzip(None).unwrap(),
+            ExternalCompression::gzip(Some(9)).unwrap(),
+            ExternalCompression::zstd(Some(19)).unwrap(),
+        ] {
+            let mut compressed = vec![];
+            compression.compress(b"a,b\n", &mut compressed).unwrap();
+            compression.compress(b"1,2\n", &mut compressed).unwrap();
+
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&compressed, &mut out).unwrap(),
+                b"a,b\n1,2\n"
+            );
+        }
+
+        let mut writer =
+            CompressedWriter::new(vec![], ExternalCompression::zstd(None).unwrap()).unwrap();
+        writer.write_all(b"a,b\n").unwrap();
+        let compressed = writer.finish().unwrap();
+
+        let mut out = vec![];
+        assert_eq!(
+            maybe_decompress_bytes(&compressed, &mut out).unwrap(),
+            b"a,b\n"
+        );
+
+        for compression in [
+            ExternalCompression::gzip(None).unwrap(),
+            ExternalCompression::zstd(None).unwrap(),
+        ] {
+            let mut writer = CompressedWriter::new(vec![], compression).unwrap();
+            let mut compressed = writer.compress_chunk(b"a,b\n".to_vec()).unwrap();
+            compressed.extend(writer.compress_chunk(b"1,2\n".to_vec()).unwrap());
+            compressed.extend(writer.finish().unwrap());
+
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&compressed, &mut out).unwrap(),
+                b"a,b\n1,2\n"
+            );
+        }
+
+        assert!(ExternalCompression::gzip(Some(10)).is_err());
+        assert!(ExternalCompression::zstd(Some(0)).is_err());
+    }
+}
@@ -8,6 +8,8 @@ pub mod file;
 pub mod mkdir;
 pub mod slice;
 pub mod sync_on_close;
+#[cfg(any(feature = "delta", feature = "iceberg"))]
+pub(crate) mod table_files;
 
 pub const URL_ENCODE_CHAR_SET: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
     .add(b'/')
@@ -48,7 +48,8 @@ pub fn get_reader_bytes<R: Read + MmapBytesReader + ?Sized>(
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "parquet",
-    feature = "avro"
+    feature = "avro",
+    feature = "orc"
 ))]
 pub fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> ArrowSchema {
     projection
@@ -62,6 +63,7 @@ pub fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> ArrowSche
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "avro",
+    feature = "orc",
     feature = "parquet"
 ))]
 pub fn columns_to_projection<T: AsRef<str>>(
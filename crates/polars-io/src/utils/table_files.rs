@@ -0,0 +1,110 @@
+//! Reading of the metadata files of table formats (Delta Lake, Iceberg).
+use std::path::Path;
+
+use bytes::Bytes;
+use polars_error::PolarsResult;
+use polars_utils::plpath::{CloudScheme, PlPath, PlPathRef};
+
+use crate::cloud::CloudOptions;
+
+/// Returns the local path for local paths and `file:` URIs.
+pub(crate) fn local_path(path: PlPathRef<'_>) -> Option<&Path> {
+    match path {
+        PlPathRef::Local(p) => {
+            let s = p.to_str()?;
+            Some(s.strip_prefix("file:").map_or(p, Path::new))
+        },
+        PlPathRef::Cloud(p) if p.scheme() == CloudScheme::File => {
+            Some(Path::new(&path.to_str()["file://".len()..]))
+        },
+        PlPathRef::Cloud(_) => None,
+    }
+}
+
+/// Resolves an absolute path or URI found in table metadata, normalizing `file:` URIs to local
+/// paths.
+pub(crate) fn resolve_absolute_path(path: &str) -> PlPath {
+    let path = PlPathRef::new(path);
+
+    match local_path(path) {
+        Some(p) => PlPath::Local(p.into()),
+        None => path.into_owned(),
+    }
+}
+
+fn read_local_file(path: &Path) -> PolarsResult<Bytes> {
+    std::fs::read(path)
+        .map(Bytes::from)
+        .map_err(|e| polars_utils::_limit_path_len_io_err(path, e))
+}
+
+/// Reads the full contents of `paths`, concurrently for cloud paths. A list can contain both
+/// local and cloud paths, e.g. a manifest that refers to files on different storage.
+pub(crate) fn read_files(
+    paths: &[PlPath],
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<Bytes>> {
+    if paths.iter().all(|p| local_path(p.as_ref()).is_some()) {
+        return paths
+            .iter()
+            .map(|p| read_local_file(local_path(p.as_ref()).unwrap()))
+            .collect();
+    }
+
+    use futures::{StreamExt, TryStreamExt};
+
+    use crate::cloud::{build_object_store, object_path_from_str};
+    use crate::pl_async::{get_concurrency_limit, get_runtime};
+
+    get_runtime().block_in_place_on(async {
+        futures::stream::iter(paths)
+            .map(|p| async move {
+                if let Some(path) = local_path(p.as_ref()) {
+                    return read_local_file(path);
+                }
+
+                let (cloud_location, store) =
+                    build_object_store(p.to_str(), cloud_options, false).await?;
+                let path = object_path_from_str(&cloud_location.prefix)?;
+                let size = store.head(&path).await?.size;
+                store.get_range(&path, 0..size as usize).await
+            })
+            .buffered(get_concurrency_limit() as usize)
+            .try_collect()
+            .await
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_files_mixed_local_and_cloud() -> PolarsResult<()> {
+        use crate::cloud::{build_object_store, object_path_from_str};
+        use crate::pl_async::get_runtime;
+
+        let local = std::env::temp_dir().join("polars_test_read_files_mixed_local_and_cloud");
+        std::fs::write(&local, b"local")?;
+        let local = PlPath::Local(local.into());
+
+        let uri = "memory://test_read_files_mixed_local_and_cloud/file";
+        get_runtime().block_in_place_on(async {
+            let (cloud_location, store) = build_object_store(uri, None, false).await?;
+            store
+                .to_dyn_object_store()
+                .await
+                .put(
+                    &object_path_from_str(&cloud_location.prefix)?,
+                    Bytes::from_static(b"cloud").into(),
+                )
+                .await?;
+            PolarsResult::Ok(())
+        })?;
+
+        let out = read_files(&[local.clone(), PlPath::new(uri), local], None)?;
+        assert_eq!(out, [&b"local"[..], b"cloud", b"local"]);
+
+        Ok(())
+    }
+}
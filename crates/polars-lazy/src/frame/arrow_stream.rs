@@ -0,0 +1,111 @@
+use std::thread::JoinHandle;
+
+use arrow::array::{Array, StructArray};
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use arrow::ffi::{ArrowArrayStream, export_iterator};
+use polars_stream::BatchReceiver;
+
+use super::*;
+
+/// Number of batches that the streaming engine may compute ahead of the consumer.
+const BATCH_BUFFER_SIZE: usize = 2;
+
+impl LazyFrame {
+    /// Execute the query with the streaming engine and export the result as an
+    /// [Arrow C stream](https://arrow.apache.org/docs/format/CStreamInterface.html).
+    ///
+    /// Every batch of the stream is a struct array with a field per column. The batches are
+    /// computed while the stream is consumed: the query is paused when the consumer falls
+    /// behind, so only a bounded part of the result is held in memory at a time. Releasing the
+    /// stream before the end stops the query. Errors raised while executing the query are
+    /// reported by the stream.
+    pub fn into_arrow_c_stream(self) -> PolarsResult<ArrowArrayStream> {
+        let (schema, batches) = self.spawn_batched_query()?;
+        polars_ensure!(
+            !schema.is_empty(),
+            InvalidOperation: "cannot export the result of a query without columns to an Arrow C stream"
+        );
+        let dtype = ArrowDataType::Struct(
+            schema
+                .to_arrow(CompatLevel::newest())
+                .into_iter_values()
+                .collect(),
+        );
+
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype.clone(), false);
+        let batches = StreamBatchIter { batches, dtype };
+        Ok(export_iterator(Box::new(batches), field))
+    }
+
+    /// Start the query on the streaming engine in a background thread, returning the schema of
+    /// the result and the batches in which it is computed.
+    pub(super) fn spawn_batched_query(mut self) -> PolarsResult<(SchemaRef, BatchedQuery)> {
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let lf = self.with_new_streaming(true);
+        let node = lf.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![])?;
+        let schema = lp_arena.get(node).schema(&lp_arena).into_owned();
+
+        let (mut executor, receiver) = build_batched_streaming_query_executor(
+            node,
+            &mut lp_arena,
+            &mut expr_arena,
+            BATCH_BUFFER_SIZE,
+        )?;
+        // The streaming engine must not be driven from a thread of the rayon pool.
+        let query = std::thread::spawn(move || executor.execute(&mut ExecutionState::new()));
+
+        Ok((
+            schema,
+            BatchedQuery {
+                receiver,
+                query: Some(query),
+            },
+        ))
+    }
+}
+
+/// The batches of a query running in a background thread. Dropping this stops the query.
+pub(super) struct BatchedQuery {
+    receiver: BatchReceiver,
+    query: Option<JoinHandle<PolarsResult<DataFrame>>>,
+}
+
+impl Iterator for BatchedQuery {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(df) = self.receiver.recv() {
+            return Some(Ok(df));
+        }
+
+        // The query has finished, report its error if it failed.
+        match self.query.take()?.join() {
+            Ok(result) => result.err().map(Err),
+            Err(_) => Some(Err(
+                polars_err!(ComputeError: "the streaming query panicked"),
+            )),
+        }
+    }
+}
+
+struct StreamBatchIter {
+    batches: BatchedQuery,
+    dtype: ArrowDataType,
+}
+
+impl Iterator for StreamBatchIter {
+    type Item = PolarsResult<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let df = match self.batches.next()? {
+            Ok(df) => df,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let height = df.height();
+        let arrays = df
+            .rechunk_to_record_batch(CompatLevel::newest())
+            .into_arrays();
+        Some(StructArray::try_new(self.dtype.clone(), height, arrays, None).map(|a| a.boxed()))
+    }
+}
@@ -0,0 +1,36 @@
+use super::arrow_stream::BatchedQuery;
+use super::*;
+
+impl LazyFrame {
+    /// Execute the query with the streaming engine and return an iterator over the result in
+    /// batches.
+    ///
+    /// The batches are computed while the iterator is consumed, in the same way as for
+    /// [`LazyFrame::into_arrow_c_stream`]. Dropping the iterator before the end stops the query.
+    /// Errors raised while executing the query are returned by the iterator.
+    pub fn collect_batches(self) -> PolarsResult<CollectBatches> {
+        let (schema, batches) = self.spawn_batched_query()?;
+        Ok(CollectBatches { schema, batches })
+    }
+}
+
+/// Iterator over the result of a query in batches, created by [`LazyFrame::collect_batches`].
+pub struct CollectBatches {
+    schema: SchemaRef,
+    batches: BatchedQuery,
+}
+
+impl CollectBatches {
+    /// The schema of the batches.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
+
+impl Iterator for CollectBatches {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches.next()
+    }
+}
@@ -2,6 +2,10 @@
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "new_streaming")]
+mod arrow_stream;
+#[cfg(feature = "new_streaming")]
+mod batches;
 mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
@@ -12,15 +16,29 @@ pub mod pivot;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "new_streaming")]
+pub use batches::*;
+#[cfg(feature = "catalog")]
+pub use catalog::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "csv")]
+pub use fixed_width::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
@@ -38,6 +56,8 @@ use polars_plan::global::FETCH_ROWS;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "sqlite")]
+pub use sqlite::*;
 
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
@@ -889,6 +909,25 @@ impl LazyFrame {
         self._profile_post_opt(|_, _, _, _| Ok(()))
     }
 
+    /// Profile a LazyFrame, including the I/O of its scans.
+    ///
+    /// Same as [`LazyFrame::profile`], but additionally returns a DataFrame with a row per scan
+    /// containing its I/O counters: the number of files and row groups read and pruned by
+    /// predicates, the number of GET and ranged GET requests with the bytes they fetched versus
+    /// the bytes the readers used, and the number of file cache hits and misses.
+    ///
+    /// Requests and bytes are counted for reads through the object store, i.e. cloud paths or
+    /// local paths with `POLARS_FORCE_ASYNC`.
+    pub fn profile_with_io_metrics(self) -> PolarsResult<(DataFrame, DataFrame, DataFrame)> {
+        let query_start = std::time::Instant::now();
+        let (mut state, mut physical_plan, _) =
+            self.prepare_collect_post_opt(false, Some(query_start), |_, _, _, _| Ok(()))?;
+        state.time_nodes(query_start);
+        let out = physical_plan.execute(&mut state)?;
+        let (timer_df, io_metrics_df) = state.finish_timer_with_io_metrics()?;
+        Ok((out, timer_df, io_metrics_df))
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -965,9 +1004,52 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an ORC file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "orc")]
+    pub fn sink_orc(
+        self,
+        target: SinkTarget,
+        options: OrcWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Orc(options),
+            cloud_options,
+        }))
+    }
+
+    /// Stream the result of the query into a table of a SQLite database. The table is created
+    /// from the schema of the result if it does not exist yet.
+    ///
+    /// The query is executed with the streaming engine, and its batches are inserted as they are
+    /// computed, see [`LazyFrame::collect_batches`]. All rows are inserted in a single transaction,
+    /// so the table is left unchanged if the query fails.
+    #[cfg(all(feature = "sqlite", feature = "new_streaming"))]
+    pub fn sink_sqlite(
+        self,
+        path: impl Into<std::path::PathBuf>,
+        table: impl Into<PlSmallStr>,
+        if_exists: polars_io::sqlite::SqliteIfExists,
+    ) -> PolarsResult<()> {
+        let batches = self.collect_batches()?;
+        let schema = batches.schema().clone();
+        polars_io::sqlite::SqliteWriter::new(path, table)
+            .with_if_exists(if_exists)
+            .finish_batches(&schema, batches)
+    }
+
     /// Stream a query result into a parquet file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
+    ///
+    /// With [`SinkOptions::write_mode`] the files are staged and only moved into `base_path` once
+    /// the query has succeeded, see [`PartitionWriteMode`]. This is the same for all partitioned
+    /// sinks.
     #[cfg(feature = "parquet")]
     #[allow(clippy::too_many_arguments)]
     pub fn sink_parquet_partitioned(
@@ -1077,6 +1159,34 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an ORC file in a partitioned manner. This is useful if the final
+    /// result doesn't fit into memory. This methods will return an error if the query cannot be
+    /// completely done in a streaming fashion.
+    #[cfg(feature = "orc")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_orc_partitioned(
+        self,
+        base_path: Arc<PlPath>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: OrcWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+        per_partition_sort_by: Option<Vec<SortColumn>>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Orc(options),
+            cloud_options,
+            per_partition_sort_by,
+            finish_callback,
+        }))
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -2459,19 +2569,22 @@ pub const BUILD_STREAMING_EXECUTOR: Option<polars_mem_engine::StreamingExecutorB
     }
 };
 #[cfg(feature = "new_streaming")]
-pub use streaming_dispatch::build_streaming_query_executor;
+pub use streaming_dispatch::{
+    build_batched_streaming_query_executor, build_streaming_query_executor,
+};
 
 #[cfg(feature = "new_streaming")]
 mod streaming_dispatch {
     use std::sync::{Arc, Mutex};
 
     use polars_core::POOL;
-    use polars_core::error::PolarsResult;
+    use polars_core::error::{PolarsResult, polars_ensure};
     use polars_core::frame::DataFrame;
     use polars_expr::state::ExecutionState;
     use polars_mem_engine::Executor;
     use polars_plan::dsl::SinkTypeIR;
     use polars_plan::plans::{AExpr, IR};
+    use polars_stream::BatchReceiver;
     use polars_utils::arena::{Arena, Node};
 
     pub fn build_streaming_query_executor(
@@ -2486,14 +2599,7 @@ mod streaming_dispatch {
             _ => false,
         };
 
-        let node = match ir_arena.get(node) {
-            IR::SinkMultiple { .. } => panic!("SinkMultiple not supported"),
-            IR::Sink { .. } => node,
-            _ => ir_arena.add(IR::Sink {
-                input: node,
-                payload: SinkTypeIR::Memory,
-            }),
-        };
+        let node = to_sink_node(node, ir_arena);
 
         polars_stream::StreamingQuery::build(node, ir_arena, expr_arena)
             .map(Some)
@@ -2506,6 +2612,47 @@ mod streaming_dispatch {
             .map(|x| Box::new(x) as Box<dyn Executor>)
     }
 
+    /// Like [`build_streaming_query_executor`], but the result is not returned by the executor.
+    /// Instead it is sent in batches to the returned receiver while the executor runs, with at
+    /// most `capacity` batches buffered.
+    pub fn build_batched_streaming_query_executor(
+        node: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        capacity: usize,
+    ) -> PolarsResult<(Box<dyn Executor>, BatchReceiver)> {
+        let node = to_sink_node(node, ir_arena);
+        polars_ensure!(
+            matches!(
+                ir_arena.get(node),
+                IR::Sink {
+                    payload: SinkTypeIR::Memory,
+                    ..
+                }
+            ),
+            InvalidOperation: "cannot receive the result of a query with a file sink in batches"
+        );
+
+        let (query, receiver) =
+            polars_stream::StreamingQuery::build_batched(node, ir_arena, expr_arena, capacity)?;
+        let executor = StreamingQueryExecutor {
+            executor: Arc::new(Mutex::new(Some(query))),
+            rechunk: false,
+        };
+        Ok((Box::new(executor), receiver))
+    }
+
+    fn to_sink_node(node: Node, ir_arena: &mut Arena<IR>) -> Node {
+        match ir_arena.get(node) {
+            IR::SinkMultiple { .. } => panic!("SinkMultiple not supported"),
+            IR::Sink { .. } => node,
+            _ => ir_arena.add(IR::Sink {
+                input: node,
+                payload: SinkTypeIR::Memory,
+            }),
+        }
+    }
+
     // Note: Arc/Mutex is because Executor requires Sync, but SlotMap is not Sync.
     struct StreamingQueryExecutor {
         executor: Arc<Mutex<Option<polars_stream::StreamingQuery>>>,
@@ -2513,14 +2660,22 @@ mod streaming_dispatch {
     }
 
     impl Executor for StreamingQueryExecutor {
-        fn execute(&mut self, _cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
             // Must not block rayon thread on pending new-streaming future.
             assert!(POOL.current_thread_index().is_none());
 
-            let mut df = { self.executor.try_lock().unwrap().take() }
-                .expect("unhandled: execute() more than once")
-                .execute()
-                .map(|x| x.unwrap_single())?;
+            let query = { self.executor.try_lock().unwrap().take() }
+                .expect("unhandled: execute() more than once");
+
+            let mut df = if state.has_node_timer() {
+                for (node, sources, io_metrics) in query.io_metrics() {
+                    state.record_io_metrics(node, sources, io_metrics);
+                }
+                state.record(|| query.execute(), "new-streaming".into())
+            } else {
+                query.execute()
+            }
+            .map(|x| x.unwrap_single())?;
 
             if self.rechunk {
                 df.as_single_chunk_par();
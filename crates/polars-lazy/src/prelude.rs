@@ -1,12 +1,17 @@
 pub(crate) use polars_expr::prelude::*;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "csv")]
+pub use polars_io::fixed_width::FixedWidthColumn;
 #[cfg(feature = "ipc")]
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "orc")]
+pub use polars_io::orc::{OrcCompression, OrcWriterOptions};
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
+pub use polars_io::partition::PartitionWriteMode;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
@@ -1,12 +1,71 @@
+#[cfg(feature = "parquet")]
+use std::sync::Arc;
+
 use polars_core::error::{PolarsResult, feature_gated, polars_bail};
+#[cfg(feature = "parquet")]
+use polars_core::prelude::*;
+#[cfg(feature = "iceberg")]
+use polars_io::catalog::iceberg::client::IcebergCatalogClient;
+use polars_io::catalog::unity::CredentialsTarget;
+use polars_io::catalog::unity::client::CatalogClient;
 use polars_io::catalog::unity::models::{DataSourceFormat, TableInfo};
 use polars_io::catalog::unity::schema::table_info_to_schemas;
 use polars_io::cloud::CloudOptions;
+use polars_io::pl_async;
 use polars_utils::plpath::PlPath;
 
 use crate::frame::LazyFrame;
+#[cfg(feature = "parquet")]
+use crate::prelude::*;
+
+#[cfg(feature = "iceberg")]
+const ICEBERG_METADATA_LOCATION_KEYS: &[&str] = &["metadata_location", "metadata-location"];
 
 impl LazyFrame {
+    /// Scan a table registered in a Unity catalog, reading with the temporary credentials vended
+    /// by the catalog for that table.
+    pub fn scan_catalog_table_from_client(
+        client: &CatalogClient,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let (table_info, cloud_options) = pl_async::get_runtime().block_in_place_on(async {
+            let table_info = client
+                .get_table_info(catalog_name, namespace, table_name)
+                .await?;
+            let cloud_options = client
+                .cloud_options_with_credentials(
+                    CredentialsTarget::Table(table_info.table_id.clone()),
+                    false,
+                    cloud_options,
+                )
+                .await?;
+
+            PolarsResult::Ok((table_info, cloud_options))
+        })?;
+
+        Self::scan_catalog_table(&table_info, Some(cloud_options))
+    }
+
+    /// Scan a table of an Iceberg REST catalog. Credentials vended by the catalog are applied on
+    /// top of the cloud options in `args`.
+    #[cfg(feature = "iceberg")]
+    pub fn scan_iceberg_catalog_table(
+        client: &IcebergCatalogClient,
+        namespace: &[&str],
+        table_name: &str,
+        mut args: crate::frame::ScanArgsIceberg,
+    ) -> PolarsResult<Self> {
+        let table =
+            pl_async::get_runtime().block_in_place_on(client.load_table(namespace, table_name))?;
+
+        args.cloud_options = Some(table.cloud_options(args.cloud_options.take())?);
+
+        Self::scan_iceberg(PlPath::new(table.scan_location()), args)
+    }
+
     pub fn scan_catalog_table(
         table_info: &TableInfo,
         cloud_options: Option<CloudOptions>,
@@ -47,6 +106,33 @@ impl LazyFrame {
                     .with_schema(schema)
                     .finish()
             }),
+            DataSourceFormat::Delta => feature_gated!("delta", {
+                use crate::frame::ScanArgsDelta;
+
+                let args = ScanArgsDelta {
+                    cloud_options,
+                    ..Default::default()
+                };
+
+                Self::scan_delta(storage_location, args)
+            }),
+            DataSourceFormat::Iceberg => feature_gated!("iceberg", {
+                use crate::frame::ScanArgsIceberg;
+
+                // Managed Iceberg tables point at their current metadata file through the table
+                // properties, otherwise the latest metadata under the table root is used.
+                let path = ICEBERG_METADATA_LOCATION_KEYS
+                    .iter()
+                    .find_map(|k| table_info.properties.get(*k))
+                    .map_or(storage_location, |v| PlPath::new(v));
+
+                let args = ScanArgsIceberg {
+                    cloud_options,
+                    ..Default::default()
+                };
+
+                Self::scan_iceberg(path, args)
+            }),
             v => polars_bail!(
                 ComputeError:
                 "not yet supported data_source_format: {:?}",
@@ -55,3 +141,290 @@ impl LazyFrame {
         }
     }
 }
+
+/// Arguments for [`LazyFrame::sink_catalog_table`].
+#[cfg(feature = "parquet")]
+#[derive(Clone, Debug, Default)]
+pub struct SinkArgsCatalog {
+    /// Storage location of the table, required if the table does not exist yet.
+    pub storage_location: Option<String>,
+    /// Columns to partition the table by. Existing tables keep their partitioning if this is
+    /// `None`.
+    pub partition_by: Option<Vec<PlSmallStr>>,
+    /// `None` adds the written files to the table like [`PartitionWriteMode::Append`].
+    pub write_mode: Option<PartitionWriteMode>,
+    /// Comment of a newly registered table.
+    pub comment: Option<String>,
+    /// Properties of a newly registered table.
+    pub properties: Vec<(String, String)>,
+    pub options: ParquetWriteOptions,
+    pub cloud_options: Option<CloudOptions>,
+}
+
+#[cfg(feature = "parquet")]
+impl LazyFrame {
+    /// Write the query result to a PARQUET table of a Unity catalog, using temporary write
+    /// credentials vended by the catalog.
+    ///
+    /// Tables that do not exist yet are registered as EXTERNAL tables at
+    /// [`SinkArgsCatalog::storage_location`]. Writing into an existing table requires the schema
+    /// and partitioning to match the table, unless the table is EXTERNAL and overwritten with
+    /// [`PartitionWriteMode::OverwriteAll`], in which case its definition is updated with the
+    /// resulting schema. The data files are written to a staging directory and only moved into
+    /// the storage location once the table is registered or updated.
+    ///
+    /// Returns the table info of the written table.
+    pub fn sink_catalog_table(
+        self,
+        client: &CatalogClient,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        args: SinkArgsCatalog,
+    ) -> PolarsResult<TableInfo> {
+        use polars_io::catalog::unity::models::TableType;
+        use polars_io::catalog::unity::schema::schema_to_column_info_list;
+        use polars_io::partition::PartitionCommit;
+
+        let mut lf = self;
+        let schema = lf.collect_schema()?;
+        let runtime = pl_async::get_runtime();
+
+        let existing = runtime.block_in_place_on(client.try_get_table_info(
+            catalog_name,
+            namespace,
+            table_name,
+        ))?;
+
+        let (storage_location, partition_by, register) = match &existing {
+            Some(table_info) => {
+                let Some(DataSourceFormat::Parquet) = &table_info.data_source_format else {
+                    polars_bail!(
+                        ComputeError:
+                        "sink_catalog_table only supports PARQUET tables, got data_source_format: {:?}",
+                        table_info.data_source_format
+                    )
+                };
+
+                let Some(storage_location) = table_info.storage_location.clone() else {
+                    polars_bail!(ComputeError: "cannot sink to catalog table: no storage_location found")
+                };
+
+                let (table_schema, hive_schema) = table_info_to_schemas(table_info)?;
+                let table_partition_by: Vec<PlSmallStr> = hive_schema
+                    .as_deref()
+                    .map_or(vec![], |s| s.iter_names_cloned().collect());
+
+                let mut table_schema = table_schema.as_deref().cloned().unwrap_or_default();
+                if let Some(hive_schema) = hive_schema.as_deref() {
+                    table_schema.merge_from_ref(hive_schema);
+                }
+
+                let partition_by = args
+                    .partition_by
+                    .clone()
+                    .unwrap_or(table_partition_by.clone());
+
+                let schema_matches = table_schema.len() == schema.len()
+                    && table_schema
+                        .iter()
+                        .all(|(name, dtype)| schema.get(name) == Some(dtype));
+
+                if schema_matches && partition_by == table_partition_by {
+                    lf = lf.select(
+                        table_schema
+                            .iter_names_cloned()
+                            .map(col)
+                            .collect::<Vec<_>>(),
+                    );
+                    (storage_location, partition_by, false)
+                } else if args.write_mode == Some(PartitionWriteMode::OverwriteAll)
+                    && matches!(table_info.table_type, TableType::External)
+                {
+                    (storage_location, partition_by, true)
+                } else {
+                    polars_bail!(
+                        SchemaMismatch:
+                        "schema or partitioning of the data does not match catalog table '{}', \
+                        only EXTERNAL tables can be replaced (with write_mode OverwriteAll)",
+                        table_name
+                    )
+                }
+            },
+            None => {
+                let Some(storage_location) = args.storage_location.clone() else {
+                    polars_bail!(
+                        ComputeError:
+                        "storage_location is required to create catalog table '{}'",
+                        table_name
+                    )
+                };
+
+                (
+                    storage_location,
+                    args.partition_by.clone().unwrap_or_default(),
+                    true,
+                )
+            },
+        };
+
+        for name in &partition_by {
+            polars_ensure!(
+                schema.contains(name),
+                ColumnNotFound: "partition column '{}' not found in the data", name
+            );
+        }
+
+        let credentials_target = match &existing {
+            Some(table_info) => CredentialsTarget::Table(table_info.table_id.clone()),
+            None => CredentialsTarget::Path(storage_location.clone()),
+        };
+        let cloud_options = runtime.block_in_place_on(client.cloud_options_with_credentials(
+            credentials_target,
+            true,
+            args.cloud_options,
+        ))?;
+
+        let variant = if partition_by.is_empty() {
+            PartitionVariant::MaxSize(IdxSize::MAX)
+        } else {
+            PartitionVariant::ByKey {
+                key_exprs: partition_by.iter().cloned().map(col).collect(),
+                include_key: false,
+            }
+        };
+
+        // Prefix the file names with an id of this write, so that files added to the table by
+        // different writes do not replace each other.
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        let job_id = format!("{nanos:x}-{:x}", std::process::id());
+
+        // Partition columns come last, this is the order in which they are scanned.
+        let mut table_schema: Schema = schema
+            .iter()
+            .filter(|(name, _)| !partition_by.contains(name))
+            .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+            .collect();
+        for name in &partition_by {
+            table_schema.with_column(name.clone(), schema.get(name).unwrap().clone());
+        }
+
+        let mut columns = schema_to_column_info_list(&table_schema)?;
+        for (i, name) in partition_by.iter().enumerate() {
+            let column = columns.iter_mut().find(|c| c.name == *name).unwrap();
+            column.partition_index = Some(i.try_into().unwrap());
+        }
+
+        let comment = args
+            .comment
+            .or_else(|| existing.as_ref().and_then(|t| t.comment.clone()));
+        let mut properties: PlHashMap<String, String> = existing
+            .iter()
+            .flat_map(|t| t.properties.iter().map(|(k, v)| (k.to_string(), v.clone())))
+            .collect();
+        properties.extend(args.properties);
+
+        // The data files are staged first and only moved into the table location once the table
+        // definition is up to date, so that a definition that is rejected by the catalog leaves
+        // the table as it was.
+        let commit = PartitionCommit::try_new(
+            PlPath::new(&storage_location).as_ref(),
+            args.write_mode.unwrap_or(PartitionWriteMode::Append),
+            Some(&cloud_options),
+        )?;
+        commit.begin()?;
+
+        let file_path_cb = PartitionTargetCallback::Rust(SpecialEq::new(Arc::new(
+            move |ctx: PartitionTargetContext| {
+                let (dir, file_name) = match ctx.file_path.rsplit_once('/') {
+                    Some((dir, file_name)) => (format!("{dir}/"), file_name),
+                    None => (String::new(), ctx.file_path.as_str()),
+                };
+                Ok(PartitionTargetCallbackResult::Str(format!(
+                    "{dir}part-{job_id}-{file_name}"
+                )))
+            },
+        )));
+
+        lf.sink_parquet_partitioned(
+            Arc::new(commit.staging_path()),
+            Some(file_path_cb),
+            variant,
+            args.options,
+            Some(cloud_options),
+            SinkOptions {
+                mkdir: true,
+                ..Default::default()
+            },
+            None,
+            None,
+        )?
+        .collect()?;
+
+        let properties = &mut properties.iter().map(|(k, v)| (k.as_str(), v.as_str()));
+        let registered = match &existing {
+            _ if !register => None,
+            Some(_) => Some(runtime.block_in_place_on(client.update_table(
+                catalog_name,
+                namespace,
+                table_name,
+                Some(&columns),
+                comment.as_deref(),
+                properties,
+            ))?),
+            None => Some(runtime.block_in_place_on(client.create_table_with_columns(
+                catalog_name,
+                namespace,
+                table_name,
+                Some(&columns),
+                &TableType::External,
+                Some(&DataSourceFormat::Parquet),
+                comment.as_deref(),
+                Some(&storage_location),
+                properties,
+            ))?),
+        };
+
+        let Err(err) = commit.commit(None) else {
+            return Ok(registered.or(existing).unwrap());
+        };
+        if !register {
+            return Err(err);
+        }
+
+        // The files were not moved, restore the previous table definition.
+        let restored = runtime.block_in_place_on(async {
+            match &existing {
+                Some(table_info) => client
+                    .update_table(
+                        catalog_name,
+                        namespace,
+                        table_name,
+                        table_info.columns.as_deref(),
+                        table_info.comment.as_deref(),
+                        &mut table_info
+                            .properties
+                            .iter()
+                            .map(|(k, v)| (k.as_str(), v.as_str())),
+                    )
+                    .await
+                    .map(|_| ()),
+                None => {
+                    client
+                        .delete_table(catalog_name, namespace, table_name)
+                        .await
+                },
+            }
+        });
+        match restored {
+            Ok(()) => Err(err),
+            Err(restore_err) => Err(polars_err!(
+                ComputeError:
+                "{err}; restoring the definition of catalog table '{}' also failed: {restore_err}",
+                table_name
+            )),
+        }
+    }
+}
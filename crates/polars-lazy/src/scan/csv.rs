@@ -2,6 +2,7 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
+    maybe_decode_bytes,
 };
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::compression::maybe_decompress_bytes;
@@ -253,6 +254,8 @@ impl LazyCsvReader {
 
             let mut owned = vec![];
             let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let mut decoded = vec![];
+            let bytes = maybe_decode_bytes(bytes, parse_options.encoding, &mut decoded)?;
 
             PolarsResult::Ok(
                 infer_file_schema(
@@ -0,0 +1,126 @@
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::read_delta_snapshot;
+use polars_io::{HiveOptions, RowIndex};
+use polars_plan::dsl::deletion::{DeletionFilesList, DeltaDeletionVector};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use super::scan_table_data_files;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// Version of the table to read. Reads the latest version if `None`.
+    pub version: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: None,
+            n_rows: None,
+            row_index: None,
+            cloud_options: None,
+            rechunk: false,
+            cache: true,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a Delta Lake table.
+    ///
+    /// The transaction log is read when this is called, to resolve the data files that make up
+    /// the requested version of the table. The partition values of the data files are taken from
+    /// the log, and deletion vectors are applied while scanning.
+    pub fn scan_delta(path: PlPath, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let snapshot =
+            read_delta_snapshot(path.as_ref(), args.version, args.cloud_options.as_ref())?;
+
+        let hive_schema = snapshot.partition_schema()?;
+        let partition_values = hive_schema.as_ref().map(|_| {
+            snapshot
+                .files
+                .iter()
+                .map(|file| {
+                    snapshot
+                        .partition_columns
+                        .iter()
+                        // An empty string is the serialized form of a null partition value.
+                        .map(|name| file.partition_values.get(name).cloned().flatten())
+                        .map(|value| value.filter(|v| !v.is_empty()))
+                        .collect::<Arc<[_]>>()
+                })
+                .collect::<Arc<[_]>>()
+        });
+        let table_root = snapshot.table_root.to_str();
+
+        let mut deletion_vectors = PlIndexMap::new();
+
+        for (i, file) in snapshot.files.iter().enumerate() {
+            let Some(dv) = &file.deletion_vector else {
+                continue;
+            };
+
+            let size_in_bytes = usize::try_from(dv.size_in_bytes).map_err(
+                |_| polars_err!(ComputeError: "invalid deletion vector size: {}", dv.size_in_bytes),
+            )?;
+
+            let deletion_vector = match dv.absolute_path(table_root)? {
+                None => DeltaDeletionVector::Inline {
+                    data: dv.path_or_inline_dv.clone(),
+                    size_in_bytes,
+                },
+                Some(path) => DeltaDeletionVector::File {
+                    path: path.into(),
+                    offset: dv.offset.and_then(|x| usize::try_from(x).ok()).ok_or_else(
+                        || polars_err!(ComputeError: "invalid deletion vector offset: {:?}", dv.offset),
+                    )?,
+                    size_in_bytes,
+                },
+            };
+
+            deletion_vectors.insert(i, deletion_vector);
+        }
+
+        let paths = snapshot
+            .files
+            .into_iter()
+            .map(|file| file.path)
+            .collect::<Arc<[_]>>();
+
+        let unified_scan_args = UnifiedScanArgs {
+            cloud_options: args.cloud_options,
+            hive_options: HiveOptions {
+                enabled: Some(hive_schema.is_some()),
+                schema: hive_schema,
+                partition_values,
+                ..HiveOptions::new_enabled()
+            },
+            rechunk: args.rechunk,
+            cache: args.cache,
+            pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
+            include_file_paths: args.include_file_paths,
+            deletion_files: DeletionFilesList::filter_empty(Some(
+                DeletionFilesList::DeltaDeletionVector(Arc::new(deletion_vectors)),
+            )),
+            ..Default::default()
+        };
+
+        let mut lf = scan_table_data_files(paths, snapshot.schema, vec![], unified_scan_args)?;
+
+        if let Some(row_index) = args.row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset))
+        }
+
+        Ok(lf)
+    }
+}
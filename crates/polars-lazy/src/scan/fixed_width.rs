@@ -0,0 +1,174 @@
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::csv::read::CommentPrefix;
+use polars_io::fixed_width::{FixedWidthColumn, FixedWidthReadOptions};
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsFixedWidth {
+    /// Layout of the columns in every line of the file.
+    pub columns: Arc<[FixedWidthColumn]>,
+    /// Number of lines to skip at the start of every file.
+    pub skip_rows: usize,
+    pub comment_prefix: Option<CommentPrefix>,
+    pub eol_char: u8,
+    pub ignore_errors: bool,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsFixedWidth {
+    fn default() -> Self {
+        Self {
+            columns: Arc::from([]),
+            skip_rows: 0,
+            comment_prefix: None,
+            eol_char: b'\n',
+            ignore_errors: false,
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: Default::default(),
+            include_file_paths: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyFixedWidthReader {
+    args: ScanArgsFixedWidth,
+    sources: ScanSources,
+}
+
+impl LazyFixedWidthReader {
+    fn new(args: ScanArgsFixedWidth) -> Self {
+        Self {
+            args,
+            sources: ScanSources::default(),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyFixedWidthReader {
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let args = self.args;
+
+        let options = FixedWidthReadOptions {
+            columns: args.columns,
+            skip_rows: args.skip_rows,
+            comment_prefix: args.comment_prefix,
+            eol_char: args.eol_char,
+            ignore_errors: args.ignore_errors,
+        };
+        // Validate the column specification before building the plan.
+        options.schema()?;
+
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_fixed_width(
+            self.sources,
+            options,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: HiveOptions::new_disabled(),
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: true,
+                projection: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                missing_columns_policy: MissingColumnsPolicy::Raise,
+                extra_columns_policy: ExtraColumnsPolicy::Raise,
+                include_file_paths: args.include_file_paths,
+                deletion_files: Default::default(),
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a fixed-width text file.
+    ///
+    /// Every line holds one record, with the fields at the byte offsets given by
+    /// [`ScanArgsFixedWidth::columns`]. Fields are trimmed of surrounding whitespace before they
+    /// are parsed, and empty fields are null.
+    pub fn scan_fixed_width(path: PlPath, args: ScanArgsFixedWidth) -> PolarsResult<Self> {
+        Self::scan_fixed_width_sources(ScanSources::Paths([path].into()), args)
+    }
+
+    pub fn scan_fixed_width_files(
+        paths: Arc<[PlPath]>,
+        args: ScanArgsFixedWidth,
+    ) -> PolarsResult<Self> {
+        Self::scan_fixed_width_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_fixed_width_sources(
+        sources: ScanSources,
+        args: ScanArgsFixedWidth,
+    ) -> PolarsResult<Self> {
+        LazyFixedWidthReader::new(args)
+            .with_sources(sources)
+            .finish()
+    }
+}
@@ -0,0 +1,165 @@
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::iceberg::read_iceberg_snapshot;
+use polars_io::{HiveOptions, PartitionValues, RowIndex};
+use polars_plan::dsl::deletion::{DeletionFilesList, IcebergDeleteFiles, IcebergEqualityDelete};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use super::scan_table_data_files;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIceberg {
+    /// Snapshot of the table to read. Reads the current snapshot if `None`.
+    pub snapshot_id: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsIceberg {
+    fn default() -> Self {
+        Self {
+            snapshot_id: None,
+            n_rows: None,
+            row_index: None,
+            cloud_options: None,
+            rechunk: false,
+            cache: true,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an Iceberg table.
+    ///
+    /// `path` is either the location of the table, in which case the `metadata.json` with the
+    /// highest version is used, or the path of a `metadata.json` file. The manifests of the
+    /// snapshot are read when this is called, to resolve the data and delete files. Position and
+    /// equality deletes are applied while scanning. The identity partition values of the data
+    /// files are used as Hive partition values, so that predicates on the partition columns skip
+    /// data files.
+    pub fn scan_iceberg(path: PlPath, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let snapshot =
+            read_iceberg_snapshot(path.as_ref(), args.snapshot_id, args.cloud_options.as_ref())?;
+
+        if snapshot.files.is_empty() {
+            let mut lf = DataFrame::empty_with_schema(&snapshot.schema).lazy();
+
+            if let Some(row_index) = args.row_index {
+                lf = lf.with_row_index(row_index.name, Some(row_index.offset))
+            }
+
+            return Ok(lf);
+        }
+
+        let (hive_schema, partition_values) = match snapshot.identity_partition_values()? {
+            Some(df) => {
+                let (schema, values) = partition_values_from_df(&df)?;
+                (Some(schema), Some(values))
+            },
+            None => (None, None),
+        };
+
+        let mut deletes = PlIndexMap::new();
+
+        for (i, file) in snapshot.files.iter().enumerate() {
+            if file.position_deletes.is_empty() && file.equality_deletes.is_empty() {
+                continue;
+            }
+
+            let equality_deletes = file
+                .equality_deletes
+                .iter()
+                .map(|delete| {
+                    let schema = delete
+                        .columns
+                        .iter()
+                        .map(|name| Ok((name.clone(), snapshot.schema.try_get(name)?.clone())))
+                        .collect::<PolarsResult<Schema>>()?;
+
+                    Ok(IcebergEqualityDelete {
+                        path: delete.path.to_str().into(),
+                        schema: Arc::new(schema),
+                        equality_ids: delete.equality_ids.as_slice().into(),
+                    })
+                })
+                .collect::<PolarsResult<Arc<[_]>>>()?;
+
+            deletes.insert(
+                i,
+                IcebergDeleteFiles {
+                    data_file_path: file.file_path.clone(),
+                    position_deletes: file
+                        .position_deletes
+                        .iter()
+                        .map(|path| path.to_str().into())
+                        .collect(),
+                    equality_deletes,
+                },
+            );
+        }
+
+        let paths = snapshot
+            .files
+            .into_iter()
+            .map(|file| file.path)
+            .collect::<Arc<[_]>>();
+
+        let unified_scan_args = UnifiedScanArgs {
+            cloud_options: args.cloud_options,
+            hive_options: HiveOptions {
+                enabled: Some(hive_schema.is_some()),
+                schema: hive_schema,
+                partition_values,
+                ..HiveOptions::new_enabled()
+            },
+            rechunk: args.rechunk,
+            cache: args.cache,
+            pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
+            row_index: args.row_index,
+            include_file_paths: args.include_file_paths,
+            deletion_files: DeletionFilesList::filter_empty(Some(
+                DeletionFilesList::IcebergDeletes(Arc::new(deletes)),
+            )),
+            ..Default::default()
+        };
+
+        scan_table_data_files(
+            paths,
+            snapshot.schema,
+            snapshot.field_ids,
+            unified_scan_args,
+        )
+    }
+}
+
+/// Converts the identity partition values of the data files, with a row per data file, to Hive
+/// partition values.
+fn partition_values_from_df(df: &DataFrame) -> PolarsResult<(SchemaRef, Arc<[PartitionValues]>)> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|c| c.cast(&DataType::String))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let columns = columns
+        .iter()
+        .map(|c| c.str())
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let values = (0..df.height())
+        .map(|i| {
+            columns
+                .iter()
+                .map(|ca| ca.get(i).map(PlSmallStr::from_str))
+                .collect::<PartitionValues>()
+        })
+        .collect();
+
+    Ok((df.schema().clone(), values))
+}
@@ -36,6 +36,8 @@ impl Default for ScanArgsIpc {
 struct LazyIpcReader {
     args: ScanArgsIpc,
     sources: ScanSources,
+    /// Read the streaming format instead of the file format.
+    stream: bool,
 }
 
 impl LazyIpcReader {
@@ -43,6 +45,7 @@ impl LazyIpcReader {
         Self {
             args,
             sources: ScanSources::default(),
+            stream: false,
         }
     }
 }
@@ -61,7 +64,13 @@ impl LazyFileListReader for LazyIpcReader {
         let row_index = args.row_index;
         let include_file_paths = args.include_file_paths;
 
-        let lf: LazyFrame = DslBuilder::scan_ipc(
+        let scan = if self.stream {
+            DslBuilder::scan_ipc_stream
+        } else {
+            DslBuilder::scan_ipc
+        };
+
+        let lf: LazyFrame = scan(
             self.sources,
             options,
             UnifiedScanArgs {
@@ -146,4 +155,21 @@ impl LazyFrame {
     pub fn scan_ipc_sources(sources: ScanSources, args: ScanArgsIpc) -> PolarsResult<Self> {
         LazyIpcReader::new(args).with_sources(sources).finish()
     }
+
+    /// Create a LazyFrame directly from a scan of data in the Arrow IPC streaming format.
+    ///
+    /// Streams carry no footer, so the data is decoded sequentially.
+    pub fn scan_ipc_stream(path: PlPath, args: ScanArgsIpc) -> PolarsResult<Self> {
+        Self::scan_ipc_stream_sources(ScanSources::Paths([path].into()), args)
+    }
+
+    pub fn scan_ipc_stream_files(paths: Arc<[PlPath]>, args: ScanArgsIpc) -> PolarsResult<Self> {
+        Self::scan_ipc_stream_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_ipc_stream_sources(sources: ScanSources, args: ScanArgsIpc) -> PolarsResult<Self> {
+        let mut reader = LazyIpcReader::new(args).with_sources(sources);
+        reader.stream = true;
+        reader.finish()
+    }
 }
@@ -1,13 +1,99 @@
 pub(super) mod anonymous_scan;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
 pub(super) mod file_list_reader;
+#[cfg(feature = "csv")]
+pub(super) mod fixed_width;
+#[cfg(feature = "iceberg")]
+pub(super) mod iceberg;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
 #[cfg(feature = "json")]
+pub(super) mod json;
+#[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "sqlite")]
+pub(super) mod sqlite;
 
 #[cfg(feature = "catalog")]
-mod catalog;
+pub(super) mod catalog;
+
+/// Splits a predicate into the expressions that are combined with `AND`.
+#[cfg(feature = "sqlite")]
+fn split_conjuncts<'a>(
+    expr: &'a polars_plan::dsl::Expr,
+    out: &mut Vec<&'a polars_plan::dsl::Expr>,
+) {
+    use polars_plan::dsl::{Expr, Operator};
+
+    match expr {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjuncts(left, out);
+            split_conjuncts(right, out);
+        },
+        e => out.push(e),
+    }
+}
+
+/// Scans the Parquet data files of a table format with the table `schema`.
+///
+/// Data files written before a schema change of the table can have missing or narrower columns,
+/// which are inserted and upcast to the table schema respectively. If `field_ids` are given, the
+/// columns of the data files are matched to those of `schema` by their field ID.
+#[cfg(any(feature = "delta", feature = "iceberg"))]
+fn scan_table_data_files(
+    paths: std::sync::Arc<[polars_utils::plpath::PlPath]>,
+    schema: polars_core::schema::SchemaRef,
+    field_ids: Vec<polars_io::prelude::ParquetFieldOverwrites>,
+    unified_scan_args: polars_plan::dsl::UnifiedScanArgs,
+) -> polars_core::error::PolarsResult<crate::frame::LazyFrame> {
+    use polars_io::parquet::read::ParallelStrategy;
+    use polars_io::prelude::ParquetOptions;
+    use polars_plan::dsl::{
+        CastColumnsPolicy, DslBuilder, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSources,
+        UnifiedScanArgs,
+    };
+
+    let options = ParquetOptions {
+        schema: Some(schema),
+        parallel: ParallelStrategy::Auto,
+        low_memory: false,
+        use_statistics: true,
+        use_field_ids: !field_ids.is_empty(),
+        field_overwrites: field_ids,
+    };
+
+    let unified_scan_args = UnifiedScanArgs {
+        schema: None,
+        glob: false,
+        cast_columns_policy: CastColumnsPolicy {
+            integer_upcast: true,
+            float_upcast: true,
+            datetime_nanoseconds_downcast: true,
+            datetime_convert_timezone: true,
+            decimal_upcast: true,
+            missing_struct_fields: MissingColumnsPolicy::Insert,
+            extra_struct_fields: ExtraColumnsPolicy::Ignore,
+            ..CastColumnsPolicy::ERROR_ON_MISMATCH
+        },
+        missing_columns_policy: MissingColumnsPolicy::Insert,
+        extra_columns_policy: ExtraColumnsPolicy::Ignore,
+        ..unified_scan_args
+    };
+
+    Ok(
+        DslBuilder::scan_parquet(ScanSources::Paths(paths), options, unified_scan_args)?
+            .build()
+            .into(),
+    )
+}
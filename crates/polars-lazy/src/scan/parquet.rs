@@ -1,7 +1,7 @@
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::prelude::ParquetOptions;
+use polars_io::prelude::{ParquetFieldOverwrites, ParquetOptions};
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
@@ -24,6 +24,13 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Casts that are allowed when the dtypes of a file differ from the schema.
+    pub cast_columns_policy: CastColumnsPolicy,
+    pub extra_columns_policy: ExtraColumnsPolicy,
+    /// See [`ParquetOptions::use_field_ids`].
+    pub use_field_ids: bool,
+    /// See [`ParquetOptions::field_overwrites`].
+    pub field_overwrites: Vec<ParquetFieldOverwrites>,
 }
 
 impl Default for ScanArgsParquet {
@@ -42,6 +49,10 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            extra_columns_policy: ExtraColumnsPolicy::Raise,
+            use_field_ids: false,
+            field_overwrites: Vec::new(),
         }
     }
 }
@@ -69,6 +80,8 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            use_field_ids: self.args.use_field_ids,
+            field_overwrites: self.args.field_overwrites,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -85,13 +98,13 @@ impl LazyFileListReader for LazyParquetReader {
                 .args
                 .n_rows
                 .map(|len| Slice::Positive { offset: 0, len }),
-            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            cast_columns_policy: self.args.cast_columns_policy,
             missing_columns_policy: if self.args.allow_missing_columns {
                 MissingColumnsPolicy::Insert
             } else {
                 MissingColumnsPolicy::Raise
             },
-            extra_columns_policy: ExtraColumnsPolicy::Raise,
+            extra_columns_policy: self.args.extra_columns_policy,
             include_file_paths: self.args.include_file_paths,
             deletion_files: Default::default(),
         };
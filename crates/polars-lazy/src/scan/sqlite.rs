@@ -0,0 +1,362 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::RowIndex;
+use polars_io::sqlite::{
+    DEFAULT_BATCH_SIZE, SqliteReader, SqliteSource, quote_identifier, quote_string,
+};
+use polars_plan::utils::expr_to_leaf_column_names;
+
+use super::split_conjuncts;
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsSqlite {
+    /// Schema of the table or query. Inferred from the database if `None`.
+    pub schema: Option<SchemaRef>,
+    /// Number of rows used to infer the type of columns without a declared type. `None` uses all
+    /// rows.
+    pub infer_schema_length: Option<usize>,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+}
+
+impl Default for ScanArgsSqlite {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            infer_schema_length: Some(100),
+            n_rows: None,
+            row_index: None,
+        }
+    }
+}
+
+struct LazySqliteScan {
+    path: PathBuf,
+    source: SqliteSource,
+}
+
+impl AnonymousScan for LazySqliteScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let AnonymousScanArgs {
+            n_rows,
+            with_columns,
+            schema,
+            output_schema: _,
+            predicate,
+        } = scan_opts;
+
+        let mut reader =
+            SqliteReader::new(&self.path, self.source.clone()).with_schema(Some(schema.clone()));
+
+        let (filter, residual) = match &predicate {
+            None => (None, None),
+            Some(predicate) => {
+                let columns = reader
+                    .comparable_columns()?
+                    .into_iter()
+                    .filter_map(|name| {
+                        let kind = match schema.get(&name)? {
+                            dt if dt.is_string() => Kind::String,
+                            _ => Kind::Numeric,
+                        };
+                        Some((name, kind))
+                    })
+                    .collect();
+                split_predicate(predicate, &schema, &columns)
+            },
+        };
+
+        // The residual predicate is applied after reading, so its columns have to be read as well.
+        let columns = match (&with_columns, &residual) {
+            (Some(columns), Some(residual)) => {
+                let mut columns = columns.to_vec();
+                for name in expr_to_leaf_column_names(residual) {
+                    if !columns.contains(&name) {
+                        columns.push(name);
+                    }
+                }
+                Some(columns.into())
+            },
+            _ => with_columns.clone(),
+        };
+
+        let reader = reader
+            .with_columns(columns)
+            .with_filter(filter)
+            .with_n_rows(n_rows);
+
+        // The residual predicate is applied to every batch, so that the rows it removes are not
+        // all held in memory.
+        let mut batches = vec![];
+        reader.read_batches(DEFAULT_BATCH_SIZE, |df| {
+            let Some(residual) = &residual else {
+                batches.push(df);
+                return Ok(());
+            };
+
+            let mut lf = df.lazy().filter(residual.clone());
+            if let Some(columns) = &with_columns {
+                lf = lf.select(
+                    columns
+                        .iter()
+                        .map(|name| col(name.clone()))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            batches.push(lf.collect()?);
+            Ok(())
+        })?;
+
+        Ok(accumulate_dataframes_vertical_unchecked(batches))
+    }
+
+    fn schema(&self, infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        SqliteReader::new(&self.path, self.source.clone())
+            .with_infer_schema_length(infer_schema_length)
+            .schema()
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// The kind of values of an operand of a comparison in SQL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Numeric,
+    String,
+}
+
+/// The columns that can be compared in SQL, see [`SqliteReader::comparable_columns`].
+type SqlColumns = PlHashMap<PlSmallStr, Kind>;
+
+/// Translates the conjuncts of `predicate` to a SQL expression. The conjuncts that cannot be
+/// translated are returned as the residual predicate.
+fn split_predicate(
+    predicate: &Expr,
+    schema: &Schema,
+    columns: &SqlColumns,
+) -> (Option<String>, Option<Expr>) {
+    let mut conjuncts = vec![];
+    split_conjuncts(predicate, &mut conjuncts);
+
+    let mut sql = vec![];
+    let mut residual = vec![];
+    for e in conjuncts {
+        match predicate_to_sql(e, schema, columns) {
+            Some(s) => sql.push(format!("({s})")),
+            None => residual.push(e.clone()),
+        }
+    }
+
+    (
+        (!sql.is_empty()).then(|| sql.join(" AND ")),
+        residual.into_iter().reduce(|acc, e| acc.and(e)),
+    )
+}
+
+/// Translates a predicate to SQL. Comparisons are only translated if both operands are of the
+/// same kind, and strings are compared with `COLLATE BINARY`, so that SQLite neither converts the
+/// values nor uses the collation of the column.
+fn predicate_to_sql(expr: &Expr, schema: &Schema, columns: &SqlColumns) -> Option<String> {
+    match expr {
+        Expr::BinaryExpr { left, op, right } => {
+            let op = match op {
+                Operator::And | Operator::LogicalAnd => {
+                    return Some(format!(
+                        "({}) AND ({})",
+                        predicate_to_sql(left, schema, columns)?,
+                        predicate_to_sql(right, schema, columns)?
+                    ));
+                },
+                Operator::Or | Operator::LogicalOr => {
+                    return Some(format!(
+                        "({}) OR ({})",
+                        predicate_to_sql(left, schema, columns)?,
+                        predicate_to_sql(right, schema, columns)?
+                    ));
+                },
+                Operator::Eq => "=",
+                Operator::EqValidity => "IS",
+                Operator::NotEq => "<>",
+                Operator::NotEqValidity => "IS NOT",
+                Operator::Lt => "<",
+                Operator::LtEq => "<=",
+                Operator::Gt => ">",
+                Operator::GtEq => ">=",
+                _ => return None,
+            };
+            let (left, left_kind) = operand_to_sql(left, columns)?;
+            let (right, right_kind) = operand_to_sql(right, columns)?;
+            if let (Some(l), Some(r)) = (left_kind, right_kind) {
+                if l != r {
+                    return None;
+                }
+            }
+            Some(format!("{left} {op} {right}"))
+        },
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+        } => match (function, input.as_slice()) {
+            (BooleanFunction::IsNull, [Expr::Column(name)]) if schema.contains(name) => {
+                Some(format!("{} IS NULL", quote_identifier(name)))
+            },
+            (BooleanFunction::IsNotNull, [Expr::Column(name)]) if schema.contains(name) => {
+                Some(format!("{} IS NOT NULL", quote_identifier(name)))
+            },
+            (BooleanFunction::Not, [e]) => {
+                Some(format!("NOT ({})", predicate_to_sql(e, schema, columns)?))
+            },
+            #[cfg(feature = "is_in")]
+            (BooleanFunction::IsIn { nulls_equal }, [e, other]) => {
+                is_in_to_sql(e, other, *nulls_equal, columns)
+            },
+            _ => None,
+        },
+        Expr::Literal(lv) => match lv.to_any_value()? {
+            AnyValue::Boolean(v) => Some(if v { "TRUE" } else { "FALSE" }.to_string()),
+            AnyValue::Null => Some("NULL".to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(feature = "is_in")]
+fn is_in_to_sql(
+    expr: &Expr,
+    other: &Expr,
+    nulls_equal: bool,
+    columns: &SqlColumns,
+) -> Option<String> {
+    let Expr::Column(_) = expr else {
+        return None;
+    };
+    let (column, kind) = operand_to_sql(expr, columns)?;
+    let other = match other {
+        Expr::Alias(e, _) => e.as_ref(),
+        e => e,
+    };
+    let Expr::Literal(other) = other else {
+        return None;
+    };
+    let values = match other {
+        LiteralValue::Series(s) => s.rechunk(),
+        LiteralValue::Scalar(sc) => match sc.value() {
+            AnyValue::List(s) => s.rechunk(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let mut has_null = false;
+    let mut items = vec![];
+    for av in values.iter() {
+        if av.is_null() {
+            has_null = true;
+        } else {
+            let (item, item_kind) = any_value_to_sql(&av)?;
+            if Some(item_kind) != kind {
+                return None;
+            }
+            items.push(item);
+        }
+    }
+
+    let sql = format!("{column} IN ({})", items.join(", "));
+    // With `nulls_equal` a missing value results in `true` or `false` instead of null.
+    Some(match (nulls_equal, has_null) {
+        (false, _) => sql,
+        (true, true) => format!("{sql} OR {column} IS NULL"),
+        (true, false) => format!("{sql} AND {column} IS NOT NULL"),
+    })
+}
+
+/// Translates a column or a scalar literal, together with the kind of its values. Null literals
+/// have no kind.
+fn operand_to_sql(expr: &Expr, columns: &SqlColumns) -> Option<(String, Option<Kind>)> {
+    match expr {
+        Expr::Column(name) => {
+            let kind = *columns.get(name)?;
+            let sql = match kind {
+                Kind::Numeric => quote_identifier(name),
+                Kind::String => format!("{} COLLATE BINARY", quote_identifier(name)),
+            };
+            Some((sql, Some(kind)))
+        },
+        Expr::Literal(lv) if lv.is_scalar() => match lv.to_any_value()? {
+            AnyValue::Null => Some(("NULL".to_string(), None)),
+            av => any_value_to_sql(&av).map(|(sql, kind)| (sql, Some(kind))),
+        },
+        // Typed nulls, e.g. `lit(NULL).cast(DataType::String)`.
+        Expr::Cast { expr, .. } => match expr.as_ref() {
+            Expr::Literal(lv) if lv.to_any_value()?.is_null() => Some(("NULL".to_string(), None)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Translates a non-null value.
+fn any_value_to_sql(av: &AnyValue) -> Option<(String, Kind)> {
+    match av {
+        AnyValue::String(s) => Some((quote_string(s), Kind::String)),
+        AnyValue::StringOwned(s) => Some((quote_string(s), Kind::String)),
+        AnyValue::Float32(_) | AnyValue::Float64(_) => {
+            let v = av.extract::<f64>()?;
+            v.is_finite().then(|| (format!("{v:?}"), Kind::Numeric))
+        },
+        av if av.is_integer() => av.extract::<i64>().map(|v| (v.to_string(), Kind::Numeric)),
+        _ => None,
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a table or query of a SQLite database.
+    ///
+    /// `table_or_query` is treated as a query if it starts with `SELECT`, `WITH` or `VALUES`, and
+    /// as the name of a table otherwise. Projections, row limits and comparisons of columns with
+    /// literals are pushed into the generated `SELECT` statement; the rest of a filter is applied
+    /// to the rows as they are read in batches. Comparisons are only pushed down for columns whose
+    /// declared type matches their type in the schema, see [`SqliteReader::comparable_columns`].
+    pub fn scan_sqlite(
+        path: impl AsRef<Path>,
+        table_or_query: &str,
+        args: ScanArgsSqlite,
+    ) -> PolarsResult<Self> {
+        let function = Arc::new(LazySqliteScan {
+            path: path.as_ref().to_path_buf(),
+            source: SqliteSource::new(table_or_query),
+        });
+
+        Self::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                infer_schema_length: args.infer_schema_length,
+                schema: args.schema,
+                skip_rows: None,
+                n_rows: args.n_rows,
+                row_index: args.row_index,
+                name: "SQLITE SCAN",
+            },
+        )
+    }
+}
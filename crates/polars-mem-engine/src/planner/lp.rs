@@ -297,14 +297,25 @@ fn create_physical_plan_impl(
                                     IpcWriter::new(BufWriter::new(writer))
                                         .with_compression(options.compression)
                                         .with_compat_level(options.compat_level)
+                                        .with_format(options.format)
+                                        .with_record_batch_statistics(
+                                            options.record_batch_statistics,
+                                        )
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "csv")]
                                 FileType::Csv(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::csv::write::CsvWriter;
-                                    CsvWriter::new(BufWriter::new(writer))
+                                    use polars_io::utils::compression::CompressedWriter;
+
+                                    let mut writer = CompressedWriter::new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    CsvWriter::new(&mut writer)
                                         .include_bom(options.include_bom)
+                                        .with_encoding(options.encoding)
                                         .include_header(options.include_header)
                                         .with_separator(options.serialize_options.separator)
                                         .with_line_terminator(
@@ -331,15 +342,27 @@ fn create_physical_plan_impl(
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
+                                    use polars_io::utils::compression::CompressedWriter;
 
-                                    JsonWriter::new(BufWriter::new(writer))
+                                    let mut writer = CompressedWriter::new(
+                                        BufWriter::new(writer),
+                                        options.compression,
+                                    )?;
+                                    JsonWriter::new(&mut writer)
                                         .with_json_format(JsonFormat::JsonLines)
                                         .finish(&mut df)?;
+                                    writer.finish()?;
+                                },
+                                #[cfg(feature = "orc")]
+                                FileType::Orc(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
                                 },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
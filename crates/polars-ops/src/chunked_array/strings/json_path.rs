@@ -1,12 +1,27 @@
 use std::borrow::Cow;
 
 use arrow::array::ValueSize;
-use jsonpath_lib::PathCompiled;
+pub use jsonpath_lib::PathCompiled;
 use polars_core::prelude::arity::{broadcast_try_binary_elementwise, unary_elementwise};
 use serde_json::Value;
 
 use super::*;
 
+/// Compiles a JSON path expression, see <https://goessner.net/articles/JsonPath/>.
+pub fn compile_json_path(json_path: &str) -> PolarsResult<PathCompiled<'_>> {
+    PathCompiled::compile(json_path)
+        .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
+}
+
+/// Returns all values in `value` that match the compiled JSON path expression.
+pub fn select_json_values<'a>(
+    expr: &PathCompiled<'a>,
+    value: &'a Value,
+) -> PolarsResult<Vec<&'a Value>> {
+    expr.select(value)
+        .map_err(|e| polars_err!(ComputeError: "error evaluating JSON path expression: {}", e))
+}
+
 pub fn extract_json(expr: &PathCompiled, json_str: &str) -> Option<String> {
     serde_json::from_str(json_str).ok().and_then(|value| {
         // TODO: a lot of heap allocations here. Improve json path by adding a take?
@@ -49,9 +64,7 @@ pub trait Utf8JsonPathImpl: AsString {
                 // SAFETY: `json_path` was verified to have exactly 1 element.
                 let opt_path = unsafe { json_path.get_unchecked(0) };
                 let out = if let Some(path) = opt_path {
-                    let pat = PathCompiled::compile(path).map_err(
-                        |e| polars_err!(ComputeError: "error compiling JSON path expression {}", e),
-                    )?;
+                    let pat = compile_json_path(path)?;
                     unary_elementwise(ca, |opt_s| opt_s.and_then(|s| extract_json(&pat, s)))
                 } else {
                     StringChunked::full_null(ca.name().clone(), ca.len())
@@ -61,13 +74,11 @@ pub trait Utf8JsonPathImpl: AsString {
             (len_ca, len_path) if len_ca == 1 || len_ca == len_path => {
                 broadcast_try_binary_elementwise(ca, json_path, |opt_str, opt_path| {
                     match (opt_str, opt_path) {
-                    (Some(str_val), Some(path)) => {
-                        PathCompiled::compile(path)
-                            .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
-                            .map(|path| extract_json(&path, str_val))
-                    },
-                    _ => Ok(None),
-                }
+                        (Some(str_val), Some(path)) => {
+                            compile_json_path(path).map(|path| extract_json(&path, str_val))
+                        },
+                        _ => Ok(None),
+                    }
                 })
             },
             (len_ca, len_path) => {
@@ -1,5 +1,5 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use arrow::datatypes::{ArrowSchema, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
 
 mod convert;
 mod metadata;
@@ -57,3 +57,71 @@ pub fn infer_schema_with_options(
         parquet_to_arrow_schema_with_options(file_metadata.schema().fields(), options)
     }))
 }
+
+/// Renames the fields of `schema` (including nested struct fields) to the names of the
+/// corresponding Parquet `fields`.
+///
+/// This is used after the Parquet schema was renamed with
+/// [`FileMetadata::rename_fields_by_id`], as a schema read from the `"ARROW:schema"` key still
+/// contains the names the file was written with. Fields are matched by position; the schema is
+/// left unchanged where the structures do not line up.
+pub fn apply_parquet_field_names(schema: &mut ArrowSchema, fields: &[ParquetType]) {
+    if schema.len() != fields.len() {
+        return;
+    }
+
+    *schema = std::mem::take(schema)
+        .into_iter()
+        .zip(fields)
+        .map(|((_, mut field), parquet_field)| {
+            field.name = parquet_field.name().into();
+            field.dtype = apply_parquet_field_names_dtype(field.dtype, parquet_field);
+            (field.name.clone(), field)
+        })
+        .collect();
+}
+
+fn apply_parquet_field_names_dtype(
+    dtype: ArrowDataType,
+    parquet_field: &ParquetType,
+) -> ArrowDataType {
+    let ParquetType::GroupType { fields, .. } = parquet_field else {
+        return dtype;
+    };
+
+    // The item of a list is the `element` inside the repeated group of the 3-level encoding, or
+    // the repeated field itself in the legacy 2-level encoding.
+    let list_element = || match fields.as_slice() {
+        [ParquetType::GroupType { fields, .. }] if fields.len() == 1 => Some(&fields[0]),
+        [field] => Some(field),
+        _ => None,
+    };
+    let apply_to_item = |mut item: Box<Field>| {
+        if let Some(element) = list_element() {
+            item.dtype = apply_parquet_field_names_dtype(item.dtype, element);
+        }
+        item
+    };
+
+    match dtype {
+        ArrowDataType::Struct(struct_fields) if struct_fields.len() == fields.len() => {
+            ArrowDataType::Struct(
+                struct_fields
+                    .into_iter()
+                    .zip(fields)
+                    .map(|(mut field, parquet_field)| {
+                        field.name = parquet_field.name().into();
+                        field.dtype = apply_parquet_field_names_dtype(field.dtype, parquet_field);
+                        field
+                    })
+                    .collect(),
+            )
+        },
+        ArrowDataType::List(item) => ArrowDataType::List(apply_to_item(item)),
+        ArrowDataType::LargeList(item) => ArrowDataType::LargeList(apply_to_item(item)),
+        ArrowDataType::FixedSizeList(item, width) => {
+            ArrowDataType::FixedSizeList(apply_to_item(item), width)
+        },
+        dtype => dtype,
+    }
+}
@@ -63,6 +63,23 @@ impl FileMetadata {
             .unwrap_or(ColumnOrder::Undefined)
     }
 
+    /// Renames the fields of this file to the names of the fields with the same field ID in
+    /// `reference`, e.g. to read a file written before a column was renamed under its current
+    /// name. Nested fields are renamed as well.
+    ///
+    /// Returns whether any field was renamed.
+    pub fn rename_fields_by_id(&mut self, reference: &SchemaDescriptor) -> bool {
+        if !self.schema_descr.rename_fields_by_id(reference.fields()) {
+            return false;
+        }
+
+        for row_group in self.row_groups.iter_mut() {
+            row_group.set_column_descriptors(self.schema_descr.columns());
+        }
+
+        true
+    }
+
     /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] into this struct
     pub fn try_from_thrift(
         metadata: polars_parquet_format::FileMetaData,
@@ -8,6 +8,7 @@ use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unitvec;
 
 use super::column_chunk_metadata::{ColumnChunkMetadata, column_metadata_byte_range};
+use super::column_descriptor::ColumnDescriptor;
 use super::schema_descriptor::SchemaDescriptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
@@ -102,6 +103,29 @@ impl RowGroupMetadata {
         self.sorting_columns.as_deref()
     }
 
+    /// Replaces the descriptors of the columns, e.g. after fields of the schema were renamed.
+    pub(crate) fn set_column_descriptors(&mut self, descriptors: &[ColumnDescriptor]) {
+        assert_eq!(descriptors.len(), self.columns.len());
+
+        let mut column_lookup = ColumnLookup::with_capacity(self.columns.len());
+
+        let columns = self
+            .columns
+            .iter()
+            .zip(descriptors)
+            .enumerate()
+            .map(|(i, (column, descriptor))| {
+                let column =
+                    ColumnChunkMetadata::new(column.column_chunk().clone(), descriptor.clone());
+                column_lookup.add_column(i, &column);
+                column
+            })
+            .collect();
+
+        self.columns = Arc::new(columns);
+        self.column_lookup = column_lookup;
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
@@ -1,4 +1,5 @@
 use polars_parquet_format::SchemaElement;
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -62,6 +63,18 @@ impl SchemaDescriptor {
         &self.leaves
     }
 
+    /// See [`FileMetadata::rename_fields_by_id`](super::FileMetadata::rename_fields_by_id).
+    pub(crate) fn rename_fields_by_id(&mut self, reference: &[ParquetType]) -> bool {
+        let mut fields = self.fields.clone();
+
+        if !rename_fields_by_id(&mut fields, reference) {
+            return false;
+        }
+
+        *self = Self::new(self.name.clone(), fields);
+        true
+    }
+
     pub(crate) fn into_thrift(self) -> Vec<SchemaElement> {
         ParquetType::GroupType {
             field_info: FieldInfo {
@@ -97,6 +110,67 @@ impl SchemaDescriptor {
     }
 }
 
+/// Fields are matched by their ID, or by name if either of them does not have one (e.g. the
+/// intermediate groups of a list). Renames that would lead to duplicate names are skipped.
+fn rename_fields_by_id(fields: &mut [ParquetType], reference: &[ParquetType]) -> bool {
+    let matches = fields
+        .iter()
+        .map(|field| {
+            let field_info = field.get_field_info();
+
+            reference.iter().position(|ref_field| {
+                match (field_info.id, ref_field.get_field_info().id) {
+                    (Some(id), Some(ref_id)) => id == ref_id,
+                    _ => field_info.name == ref_field.name(),
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let new_names = {
+        let new_names = fields
+            .iter()
+            .zip(&matches)
+            .map(|(field, m)| m.map_or(field.name(), |i| reference[i].name()))
+            .collect::<Vec<_>>();
+
+        let mut name_counts = PlHashMap::<&str, usize>::with_capacity(new_names.len());
+        for name in new_names.iter().copied() {
+            *name_counts.entry(name).or_default() += 1;
+        }
+
+        new_names
+            .iter()
+            .map(|name| (name_counts[name] == 1).then(|| PlSmallStr::from_str(name)))
+            .collect::<Vec<_>>()
+    };
+
+    let mut renamed = false;
+
+    for ((field, m), new_name) in fields.iter_mut().zip(matches).zip(new_names) {
+        let Some(i) = m else {
+            continue;
+        };
+
+        if let Some(new_name) = new_name.filter(|x| x.as_str() != field.name()) {
+            field.get_field_info_mut().name = new_name;
+            renamed = true;
+        }
+
+        if let (
+            ParquetType::GroupType { fields, .. },
+            ParquetType::GroupType {
+                fields: ref_fields, ..
+            },
+        ) = (field, &reference[i])
+        {
+            renamed |= rename_fields_by_id(fields, ref_fields);
+        }
+    }
+
+    renamed
+}
+
 fn build_tree<'a>(
     tp: &'a ParquetType,
     base_tp: BaseType,
@@ -146,3 +220,83 @@ fn build_tree<'a>(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_names(fields: &[ParquetType]) -> Vec<&str> {
+        fields.iter().map(|x| x.name()).collect()
+    }
+
+    #[test]
+    fn test_rename_fields_by_id() -> ParquetResult<()> {
+        let reference = SchemaDescriptor::try_from_message(
+            "message schema {
+                required int64 id = 1;
+                optional group point = 2 {
+                    optional double x = 3;
+                    optional double y = 4;
+                }
+                optional binary name (UTF8) = 5;
+            }",
+        )?;
+
+        // Written before `id` and `point.x` were renamed, `b` was dropped and the columns were
+        // reordered.
+        let mut schema = SchemaDescriptor::try_from_message(
+            "message schema {
+                optional binary name (UTF8) = 5;
+                optional group p = 2 {
+                    optional double y = 4;
+                    optional double x_old = 3;
+                }
+                optional int32 b = 6;
+                required int64 key = 1;
+            }",
+        )?;
+
+        assert!(schema.rename_fields_by_id(reference.fields()));
+        assert_eq!(field_names(schema.fields()), ["name", "point", "b", "id"]);
+
+        let ParquetType::GroupType { fields, .. } = &schema.fields()[1] else {
+            unreachable!()
+        };
+        assert_eq!(field_names(fields), ["y", "x"]);
+
+        assert_eq!(
+            schema
+                .columns()
+                .iter()
+                .map(|x| x.path_in_schema.join("."))
+                .collect::<Vec<_>>(),
+            ["name", "point.y", "point.x", "b", "id"]
+        );
+
+        assert!(!schema.rename_fields_by_id(reference.fields()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_fields_by_id_skips_duplicates() -> ParquetResult<()> {
+        let reference = SchemaDescriptor::try_from_message(
+            "message schema {
+                optional int64 a = 1;
+            }",
+        )?;
+
+        // The field with ID 1 cannot take the name `a` as that is used by a different field.
+        let mut schema = SchemaDescriptor::try_from_message(
+            "message schema {
+                optional int64 a = 2;
+                optional int64 c = 1;
+            }",
+        )?;
+
+        assert!(!schema.rename_fields_by_id(reference.fields()));
+        assert_eq!(field_names(schema.fields()), ["a", "c"]);
+
+        Ok(())
+    }
+}
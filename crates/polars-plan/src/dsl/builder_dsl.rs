@@ -3,8 +3,12 @@ use std::sync::Arc;
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "csv")]
+use polars_io::fixed_width::FixedWidthReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 
@@ -82,6 +86,51 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "ipc")]
+    pub fn scan_ipc_stream(
+        sources: ScanSources,
+        options: IpcScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::IpcStream { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "orc")]
+    pub fn scan_orc(
+        sources: ScanSources,
+        options: OrcScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Orc { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "csv")]
+    pub fn scan_fixed_width(
+        sources: ScanSources,
+        options: FixedWidthReadOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::FixedWidth { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
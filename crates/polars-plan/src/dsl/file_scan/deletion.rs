@@ -2,9 +2,8 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use polars_core::prelude::PlIndexMap;
-
-// Note, there are a lot of single variant enums here, but the intention is that we'll support
-// Delta deletion vectors as well at some point in the future.
+use polars_core::schema::SchemaRef;
+use polars_utils::pl_str::PlSmallStr;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -19,6 +18,52 @@ pub enum DeletionFilesList {
     // * ListArray(inner: Utf8Array)
     /// Iceberg positional deletes
     IcebergPositionDelete(Arc<PlIndexMap<usize, Arc<[String]>>>),
+    /// Delta deletion vectors. A data file has at most one deletion vector.
+    DeltaDeletionVector(Arc<PlIndexMap<usize, DeltaDeletionVector>>),
+    /// Iceberg positional and equality deletes.
+    IcebergDeletes(Arc<PlIndexMap<usize, IcebergDeleteFiles>>),
+}
+
+/// The delete files that apply to an Iceberg data file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergDeleteFiles {
+    /// Path of the data file as it is referenced by positional delete files.
+    pub data_file_path: PlSmallStr,
+    pub position_deletes: Arc<[PlSmallStr]>,
+    pub equality_deletes: Arc<[IcebergEqualityDelete]>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergEqualityDelete {
+    pub path: PlSmallStr,
+    /// The columns that rows are matched on, with their types in the table schema. Rows of the
+    /// data file that are equal to a row of the delete file in all of these columns are deleted.
+    pub schema: SchemaRef,
+    /// The field IDs of the columns of `schema`, which the columns of the delete file and the data
+    /// file are resolved with.
+    pub equality_ids: Arc<[i32]>,
+}
+
+/// Location of a Delta deletion vector, resolved from the descriptor in the transaction log.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeltaDeletionVector {
+    /// z85-encoded bitmap stored in the log.
+    Inline {
+        data: PlSmallStr,
+        size_in_bytes: usize,
+    },
+    /// Bitmap stored at `offset` within a deletion vector file.
+    File {
+        path: PlSmallStr,
+        offset: usize,
+        size_in_bytes: usize,
+    },
 }
 
 impl DeletionFilesList {
@@ -30,6 +75,12 @@ impl DeletionFilesList {
             Some(IcebergPositionDelete(paths)) => {
                 (!paths.is_empty()).then_some(IcebergPositionDelete(paths))
             },
+            Some(DeltaDeletionVector(vectors)) => {
+                (!vectors.is_empty()).then_some(DeltaDeletionVector(vectors))
+            },
+            Some(IcebergDeletes(deletes)) => {
+                (!deletes.is_empty()).then_some(IcebergDeletes(deletes))
+            },
             None => None,
         }
     }
@@ -45,6 +96,18 @@ impl DeletionFilesList {
                     .map(|(k, v)| (*k, v.clone()))
                     .collect(),
             )),
+            DeltaDeletionVector(vectors) => DeltaDeletionVector(Arc::new(
+                vectors.as_slice()[range]
+                    .iter()
+                    .map(|(k, v)| (*k, v.clone()))
+                    .collect(),
+            )),
+            IcebergDeletes(deletes) => IcebergDeletes(Arc::new(
+                deletes.as_slice()[range]
+                    .iter()
+                    .map(|(k, v)| (*k, v.clone()))
+                    .collect(),
+            )),
         }
     }
 
@@ -53,6 +116,8 @@ impl DeletionFilesList {
 
         match self {
             IcebergPositionDelete(paths) => paths.len(),
+            DeltaDeletionVector(vectors) => vectors.len(),
+            IcebergDeletes(deletes) => deletes.len(),
         }
     }
 }
@@ -71,6 +136,17 @@ impl std::hash::Hash for DeletionFilesList {
 
                 addr.hash(state)
             },
+            DeltaDeletionVector(vectors) => {
+                vectors.len().hash(state);
+                vectors.first().hash(state)
+            },
+            IcebergDeletes(deletes) => {
+                deletes.len().hash(state);
+                deletes
+                    .first()
+                    .map(|(k, v)| (k, &v.data_file_path))
+                    .hash(state)
+            },
         }
     }
 }
@@ -84,6 +160,14 @@ impl std::fmt::Display for DeletionFilesList {
                 let s = if paths.len() == 1 { "" } else { "s" };
                 write!(f, "iceberg-position-delete: {} source{s}", paths.len())?;
             },
+            DeltaDeletionVector(vectors) => {
+                let s = if vectors.len() == 1 { "" } else { "s" };
+                write!(f, "delta-deletion-vector: {} source{s}", vectors.len())?;
+            },
+            IcebergDeletes(deletes) => {
+                let s = if deletes.len() == 1 { "" } else { "s" };
+                write!(f, "iceberg-deletes: {} source{s}", deletes.len())?;
+            },
         }
 
         Ok(())
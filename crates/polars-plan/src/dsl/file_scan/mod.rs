@@ -6,8 +6,14 @@ use polars_core::utils::get_numeric_upcast_supertype_lossless;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "csv")]
+use polars_io::fixed_width::FixedWidthReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "json")]
+use polars_io::json::JsonScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::{OrcMetadataRef, OrcScanOptions};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
@@ -41,15 +47,27 @@ pub enum FileScanDsl {
     #[cfg(feature = "csv")]
     Csv { options: CsvReadOptions },
 
+    #[cfg(feature = "csv")]
+    FixedWidth { options: FixedWidthReadOptions },
+
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    #[cfg(feature = "json")]
+    Json { options: JsonScanOptions },
+
     #[cfg(feature = "parquet")]
     Parquet { options: ParquetOptions },
 
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "ipc")]
+    IpcStream { options: IpcScanOptions },
+
+    #[cfg(feature = "orc")]
+    Orc { options: OrcScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -71,9 +89,15 @@ pub enum FileScanIR {
     #[cfg(feature = "csv")]
     Csv { options: CsvReadOptions },
 
+    #[cfg(feature = "csv")]
+    FixedWidth { options: FixedWidthReadOptions },
+
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    #[cfg(feature = "json")]
+    Json { options: JsonScanOptions },
+
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
@@ -88,6 +112,16 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "ipc")]
+    IpcStream { options: IpcScanOptions },
+
+    #[cfg(feature = "orc")]
+    Orc {
+        options: OrcScanOptions,
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+        metadata: Option<OrcMetadataRef>,
+    },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -109,6 +143,8 @@ impl FileScanIR {
             Self::Csv { .. } => ScanFlags::empty(),
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => ScanFlags::empty(),
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => ScanFlags::empty(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
@@ -122,8 +158,16 @@ impl FileScanIR {
         match self {
             #[cfg(feature = "csv")]
             Self::Csv { .. } => true,
+            #[cfg(feature = "csv")]
+            Self::FixedWidth { .. } => true,
+            #[cfg(feature = "json")]
+            Self::Json { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => _has_row_index,
+            #[cfg(feature = "ipc")]
+            Self::IpcStream { .. } => false,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => false,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => false,
             #[allow(unreachable_patterns)]
@@ -135,12 +179,20 @@ impl FileScanIR {
         match self {
             #[cfg(feature = "csv")]
             Self::Csv { .. } => true,
+            #[cfg(feature = "csv")]
+            Self::FixedWidth { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
+            #[cfg(feature = "ipc")]
+            Self::IpcStream { .. } => true,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => true,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "json")]
+            Self::Json { .. } => true,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -179,6 +231,11 @@ pub struct CastColumnsPolicy {
     /// Allow casting to change time units.
     pub datetime_convert_timezone: bool,
 
+    /// Allow casting to a decimal type that can represent all incoming values, i.e. one with at
+    /// least the same number of integer and fractional digits.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decimal_upcast: bool,
+
     pub missing_struct_fields: MissingColumnsPolicy,
     pub extra_struct_fields: ExtraColumnsPolicy,
 }
@@ -192,6 +249,7 @@ impl CastColumnsPolicy {
         datetime_nanoseconds_downcast: false,
         datetime_microseconds_downcast: false,
         datetime_convert_timezone: false,
+        decimal_upcast: false,
         missing_struct_fields: MissingColumnsPolicy::Raise,
         extra_struct_fields: ExtraColumnsPolicy::Raise,
     };
@@ -294,11 +352,21 @@ mod _file_scan_eq_hash {
             options: &'a polars_io::csv::read::CsvReadOptions,
         },
 
+        #[cfg(feature = "csv")]
+        FixedWidth {
+            options: &'a polars_io::fixed_width::FixedWidthReadOptions,
+        },
+
         #[cfg(feature = "json")]
         NDJson {
             options: &'a crate::prelude::NDJsonReadOptions,
         },
 
+        #[cfg(feature = "json")]
+        Json {
+            options: &'a polars_io::json::JsonScanOptions,
+        },
+
         #[cfg(feature = "parquet")]
         Parquet {
             options: &'a polars_io::prelude::ParquetOptions,
@@ -311,6 +379,17 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "ipc")]
+        IpcStream {
+            options: &'a polars_io::prelude::IpcScanOptions,
+        },
+
+        #[cfg(feature = "orc")]
+        Orc {
+            options: &'a polars_io::orc::OrcScanOptions,
+            metadata: Option<usize>,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -333,9 +412,15 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { options } => FileScanEqHashWrap::Csv { options },
 
+                #[cfg(feature = "csv")]
+                FileScanIR::FixedWidth { options } => FileScanEqHashWrap::FixedWidth { options },
+
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { options } => FileScanEqHashWrap::NDJson { options },
 
+                #[cfg(feature = "json")]
+                FileScanIR::Json { options } => FileScanEqHashWrap::Json { options },
+
                 #[cfg(feature = "parquet")]
                 FileScanIR::Parquet { options, metadata } => FileScanEqHashWrap::Parquet {
                     options,
@@ -348,6 +433,15 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "ipc")]
+                FileScanIR::IpcStream { options } => FileScanEqHashWrap::IpcStream { options },
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { options, metadata } => FileScanEqHashWrap::Orc {
+                    options,
+                    metadata: metadata.as_ref().map(arc_as_ptr),
+                },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
@@ -615,6 +709,33 @@ impl CastColumnsPolicy {
             return Ok(true);
         }
 
+        #[cfg(feature = "dtype-decimal")]
+        if let (
+            DataType::Decimal(target_precision, target_scale),
+            DataType::Decimal(incoming_precision, incoming_scale),
+        ) = (target_dtype, incoming_dtype)
+        {
+            if !self.decimal_upcast {
+                return mismatch_err(
+                    "hint: pass cast_options=pl.ScanCastOptions(decimal_cast='upcast')",
+                );
+            }
+
+            return match (
+                target_precision,
+                target_scale,
+                incoming_precision,
+                incoming_scale,
+            ) {
+                (Some(tp), Some(ts), Some(ip), Some(is))
+                    if ts >= is && tp.saturating_sub(*ts) >= ip.saturating_sub(*is) =>
+                {
+                    Ok(true)
+                },
+                _ => mismatch_err("incoming dtype cannot safely cast to target dtype"),
+            };
+        }
+
         mismatch_err("")
     }
 }
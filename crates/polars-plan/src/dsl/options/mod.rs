@@ -14,6 +14,8 @@ use polars_io::csv::write::CsvWriterOptions;
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "iejoin")]
@@ -24,8 +26,8 @@ use polars_ops::prelude::{JoinArgs, JoinType};
 use polars_time::DynamicGroupOptions;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
-use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::{IdxSize, format_pl_smallstr};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use sink::*;
@@ -361,22 +363,33 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "orc")]
+    Orc(OrcWriterOptions),
 }
 
 impl FileType {
-    pub fn extension(&self) -> &'static str {
-        match self {
+    /// Extension of the files that are written, including the extension of the compression
+    /// of text formats, e.g. `csv.gz`.
+    pub fn extension(&self) -> PlSmallStr {
+        let (base, compression): (&str, Option<&str>) = match self {
             #[cfg(feature = "parquet")]
-            Self::Parquet(_) => "parquet",
+            Self::Parquet(_) => ("parquet", None),
             #[cfg(feature = "ipc")]
-            Self::Ipc(_) => "ipc",
+            Self::Ipc(_) => ("ipc", None),
             #[cfg(feature = "csv")]
-            Self::Csv(_) => "csv",
+            Self::Csv(options) => ("csv", options.compression.extension()),
             #[cfg(feature = "json")]
-            Self::Json(_) => "jsonl",
+            Self::Json(options) => ("jsonl", options.compression.extension()),
+            #[cfg(feature = "orc")]
+            Self::Orc(_) => ("orc", None),
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
+        };
+
+        match compression {
+            None => PlSmallStr::from_static(base),
+            Some(compression) => format_pl_smallstr!("{base}.{compression}"),
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
@@ -7,7 +8,8 @@ use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::DataType;
 use polars_core::scalar::Scalar;
-use polars_io::cloud::CloudOptions;
+use polars_io::cloud::{CloudOptions, CloudUploadOptions};
+use polars_io::partition::PartitionWriteMode;
 use polars_io::utils::file::{DynWriteable, Writeable};
 use polars_io::utils::sync_on_close::SyncOnCloseType;
 use polars_utils::IdxSize;
@@ -31,6 +33,15 @@ pub struct SinkOptions {
 
     /// Recursively create all the directories in the path.
     pub mkdir: bool,
+
+    /// Stage the files of a partitioned sink and commit them with this mode. `None` writes
+    /// straight into the base path. Only partitioned sinks support this.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_mode: Option<PartitionWriteMode>,
+
+    /// Overrides the upload options of the cloud options when writing to cloud storage.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub upload: Option<CloudUploadOptions>,
 }
 
 impl Default for SinkOptions {
@@ -39,6 +50,26 @@ impl Default for SinkOptions {
             sync_on_close: Default::default(),
             maintain_order: true,
             mkdir: false,
+            write_mode: None,
+            upload: None,
+        }
+    }
+}
+
+impl SinkOptions {
+    /// The cloud options to open a file with, including the upload options of the sink.
+    fn resolve_cloud_options<'a>(
+        &self,
+        cloud_options: Option<&'a CloudOptions>,
+    ) -> Option<Cow<'a, CloudOptions>> {
+        match &self.upload {
+            None => cloud_options.map(Cow::Borrowed),
+            Some(upload) => Some(Cow::Owned(
+                cloud_options
+                    .cloned()
+                    .unwrap_or_default()
+                    .with_upload_options(upload.clone()),
+            )),
         }
     }
 }
@@ -63,7 +94,8 @@ impl SinkTarget {
                     polars_io::utils::mkdir::mkdir_recursive(addr.as_ref())?;
                 }
 
-                polars_io::utils::file::Writeable::try_new(addr.as_ref(), cloud_options)
+                let cloud_options = sink_options.resolve_cloud_options(cloud_options);
+                polars_io::utils::file::Writeable::try_new(addr.as_ref(), cloud_options.as_deref())
             },
             SinkTarget::Dyn(memory_writer) => Ok(Writeable::Dyn(
                 memory_writer.lock().unwrap().take().unwrap(),
@@ -92,7 +124,8 @@ impl SinkTarget {
                     polars_io::utils::mkdir::tokio_mkdir_recursive(addr.as_ref()).await?;
                 }
 
-                polars_io::utils::file::Writeable::try_new(addr.as_ref(), cloud_options)
+                let cloud_options = sink_options.resolve_cloud_options(cloud_options);
+                polars_io::utils::file::Writeable::try_new(addr.as_ref(), cloud_options.as_deref())
             },
             SinkTarget::Dyn(memory_writer) => Ok(Writeable::Dyn(
                 memory_writer.lock().unwrap().take().unwrap(),
@@ -6,6 +6,7 @@ use polars_core::error::{PolarsResult, feature_gated};
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "cloud")]
 use polars_io::file_cache::FileCacheEntry;
+use polars_io::metrics::IOMetrics;
 #[cfg(feature = "cloud")]
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder};
 use polars_io::{expand_paths, expand_paths_hive, expanded_from_single_directory};
@@ -175,7 +176,7 @@ impl ScanSources {
 
     /// This will update `scan_args.hive_options.enabled` to `true` if the existing value is `None`
     /// and the paths are expanded from a single directory. Otherwise the existing value is maintained.
-    #[cfg(any(feature = "ipc", feature = "parquet"))]
+    #[cfg(any(feature = "ipc", feature = "parquet", feature = "orc"))]
     pub fn expand_paths_with_hive_update(
         &self,
         scan_args: &mut UnifiedScanArgs,
@@ -364,6 +365,20 @@ impl ScanSourceRef<'_> {
         self.to_memslice_async(|entry| entry.try_open_check_latest(), run_async)
     }
 
+    /// Same as [`Self::to_memslice_async_assume_latest`], but records file cache hits and misses
+    /// in `io_metrics`.
+    #[cfg(feature = "cloud")]
+    pub fn to_memslice_async_assume_latest_with_metrics(
+        &self,
+        run_async: bool,
+        io_metrics: &IOMetrics,
+    ) -> PolarsResult<MemSlice> {
+        self.to_memslice_async(
+            |entry| entry.try_open_assume_latest_with_metrics(io_metrics),
+            run_async,
+        )
+    }
+
     #[cfg(not(feature = "cloud"))]
     fn to_memslice_async(&self, run_async: bool) -> PolarsResult<MemSlice> {
         match self {
@@ -386,6 +401,15 @@ impl ScanSourceRef<'_> {
         self.to_memslice_async(run_async)
     }
 
+    #[cfg(not(feature = "cloud"))]
+    pub fn to_memslice_async_assume_latest_with_metrics(
+        &self,
+        run_async: bool,
+        _io_metrics: &IOMetrics,
+    ) -> PolarsResult<MemSlice> {
+        self.to_memslice_async(run_async)
+    }
+
     pub fn to_memslice_possibly_async(
         &self,
         run_async: bool,
@@ -537,6 +537,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             datetime_nanoseconds_downcast: false,
                             datetime_microseconds_downcast: false,
                             datetime_convert_timezone: false,
+                            decimal_upcast: false,
                             missing_struct_fields: per_column.missing_struct_fields,
                             extra_struct_fields: per_column.extra_struct_fields,
                         };
@@ -873,7 +874,13 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
             let payload = match payload {
                 SinkType::Memory => SinkTypeIR::Memory,
-                SinkType::File(f) => SinkTypeIR::File(f),
+                SinkType::File(f) => {
+                    polars_ensure!(
+                        f.sink_options.write_mode.is_none(),
+                        InvalidOperation: "write modes are only supported by partitioned sinks"
+                    );
+                    SinkTypeIR::File(f)
+                },
                 SinkType::Partition(f) => SinkTypeIR::Partition(PartitionSinkTypeIR {
                     base_path: f.base_path,
                     file_path_cb: f.file_path_cb,
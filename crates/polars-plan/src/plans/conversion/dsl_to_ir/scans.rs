@@ -40,6 +40,17 @@ pub(super) fn dsl_to_ir(
             }
         }
 
+        if unified_scan_args.hive_options.path_template.is_some() {
+            match unified_scan_args.hive_options.enabled {
+                None => unified_scan_args.hive_options.enabled = Some(true),
+                Some(false) => polars_bail!(
+                    ComputeError:
+                    "a path template was given but hive_partitioning was disabled"
+                ),
+                Some(true) => {},
+            }
+        }
+
         let sources = match &*scan_type {
             #[cfg(feature = "parquet")]
             FileScanDsl::Parquet { .. } => {
@@ -49,10 +60,24 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Ipc { .. } => {
                 sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
             },
+            #[cfg(feature = "ipc")]
+            FileScanDsl::IpcStream { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
+            #[cfg(feature = "csv")]
+            FileScanDsl::FixedWidth { .. } => {
+                sources.expand_paths(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
+            #[cfg(feature = "json")]
+            FileScanDsl::Json { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { .. } => {
                 // There are a lot of places that short-circuit if the paths is empty,
@@ -100,6 +125,8 @@ pub(super) fn dsl_to_ir(
                     Either::Right(v) => v.as_ref(),
                 },
                 unified_scan_args.hive_options.try_parse_dates,
+                unified_scan_args.hive_options.path_template.as_deref(),
+                unified_scan_args.hive_options.partition_values.as_deref(),
             )?
         } else {
             None
@@ -189,7 +216,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "orc"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -315,6 +342,91 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+/// An IPC stream has no footer, so only the schema is read from the start of the first source.
+#[cfg(feature = "ipc")]
+pub(super) fn ipc_stream_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.first_path().unwrap().to_str())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata =
+        arrow::io::ipc::read::read_stream_metadata(&mut std::io::Cursor::new(memslice.as_ref()))?;
+    let schema = Arc::new(metadata.schema);
+
+    Ok(FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(schema.as_ref()), row_index)?,
+        Some(Either::Left(schema)),
+        (None, usize::MAX),
+    ))
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn orc_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<(FileInfo, polars_io::orc::OrcMetadataRef)> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.first_path().unwrap().to_str())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = polars_io::orc::read_orc_metadata(&memslice)?;
+    let num_rows = metadata.num_rows();
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(
+            Schema::from_arrow_schema(metadata.schema().as_ref()),
+            row_index,
+        )?,
+        Some(Either::Left(Arc::clone(metadata.schema()))),
+        (Some(num_rows), num_rows),
+    );
+
+    Ok((file_info, Arc::new(metadata)))
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -326,6 +438,7 @@ pub fn csv_file_info(
 
     use polars_core::error::feature_gated;
     use polars_core::{POOL, config};
+    use polars_io::csv::read::maybe_decode_bytes;
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -362,7 +475,12 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let decoded = &mut vec![];
+        let mut reader = std::io::Cursor::new(maybe_decode_bytes(
+            maybe_decompress_bytes(&memslice, owned)?,
+            csv_options.parse_options.encoding,
+            decoded,
+        )?);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -439,6 +557,22 @@ pub fn csv_file_info(
     ))
 }
 
+/// The schema of a fixed-width scan is fully determined by its column specification, so the
+/// files don't have to be read.
+#[cfg(feature = "csv")]
+pub(super) fn fixed_width_file_info(
+    row_index: Option<&RowIndex>,
+    options: &polars_io::fixed_width::FixedWidthReadOptions,
+) -> PolarsResult<FileInfo> {
+    let (reader_schema, schema) = prepare_schemas(options.schema()?, row_index)?;
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
 #[cfg(feature = "json")]
 pub fn ndjson_file_info(
     sources: &ScanSources,
@@ -505,6 +639,72 @@ pub fn ndjson_file_info(
     ))
 }
 
+/// The schema of a JSON document scan is inferred from the first records of the first source.
+#[cfg(feature = "json")]
+pub fn json_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    options: &polars_io::json::JsonScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::json::record_path::infer_records_schema;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let mut schema = if let Some(schema) = options.schema.clone() {
+        schema
+    } else {
+        let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+
+        Arc::new(infer_records_schema(
+            memslice,
+            &options.record_path,
+            options.infer_schema_length,
+        )?)
+    };
+
+    if let Some(overwriting_schema) = &options.schema_overwrite {
+        overwrite_schema(Arc::make_mut(&mut schema), overwriting_schema)?;
+    }
+
+    let mut reader_schema = schema.clone();
+
+    if row_index.is_some() {
+        (schema, reader_schema) = prepare_schemas(Arc::unwrap_or_clone(schema), row_index)?
+    }
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
 // Add flags that influence metadata/schema here
 #[derive(Eq, Hash, PartialEq)]
 enum CachedSourceKey {
@@ -538,6 +738,20 @@ impl SourcesToFileInfo {
                 if let Some(schema) = &options.schema {
                     // We were passed a schema, we don't have to call `parquet_file_info`,
                     // but this does mean we don't have `row_estimation` and `first_metadata`.
+                    // The latter is still needed as the reference for matching field IDs if
+                    // those of the schema are not given.
+                    let metadata = if options.use_field_ids && options.field_overwrites.is_empty() {
+                        scans::parquet_file_info(
+                            sources,
+                            unified_scan_args.row_index.as_ref(),
+                            cloud_options,
+                        )
+                        .map_err(|e| e.context(failed_here!(parquet scan)))?
+                        .1
+                    } else {
+                        None
+                    };
+
                     (
                         FileInfo {
                             schema: schema.clone(),
@@ -546,10 +760,7 @@ impl SourcesToFileInfo {
                             ))),
                             row_estimation: (None, 0),
                         },
-                        FileScanIR::Parquet {
-                            options,
-                            metadata: None,
-                        },
+                        FileScanIR::Parquet { options, metadata },
                     )
                 } else {
                     let (file_info, metadata) = scans::parquet_file_info(
@@ -578,6 +789,32 @@ impl SourcesToFileInfo {
                     },
                 )
             },
+            #[cfg(feature = "ipc")]
+            FileScanDsl::IpcStream { options } => (
+                scans::ipc_stream_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(ipc stream scan)))?,
+                FileScanIR::IpcStream { options },
+            ),
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options } => {
+                let (file_info, metadata) = scans::orc_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(orc scan)))?;
+                (
+                    file_info,
+                    FileScanIR::Orc {
+                        options,
+                        metadata: Some(metadata),
+                    },
+                )
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 // TODO: This is a hack. We conditionally set `allow_missing_columns` to
@@ -598,6 +835,12 @@ impl SourcesToFileInfo {
                     FileScanIR::Csv { options },
                 )
             },
+            #[cfg(feature = "csv")]
+            FileScanDsl::FixedWidth { options } => (
+                scans::fixed_width_file_info(unified_scan_args.row_index.as_ref(), &options)
+                    .map_err(|e| e.context(failed_here!(fixed width scan)))?,
+                FileScanIR::FixedWidth { options },
+            ),
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { options } => (
                 scans::ndjson_file_info(
@@ -609,6 +852,17 @@ impl SourcesToFileInfo {
                 .map_err(|e| e.context(failed_here!(ndjson scan)))?,
                 FileScanIR::NDJson { options },
             ),
+            #[cfg(feature = "json")]
+            FileScanDsl::Json { options } => (
+                scans::json_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    &options,
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(json scan)))?,
+                FileScanIR::Json { options },
+            ),
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { dataset_object } => {
                 if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -689,6 +943,16 @@ impl SourcesToFileInfo {
                 let v = self.inner.get(&key);
                 (key, v)
             },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options: _ } => {
+                let key = CachedSourceKey::ParquetIpc {
+                    first_path: paths[0].clone(),
+                    schema_overwrite: None,
+                };
+
+                let v = self.inner.get(&key);
+                (key, v)
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { options } => {
                 let key = CachedSourceKey::CsvJson {
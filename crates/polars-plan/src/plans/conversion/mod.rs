@@ -61,10 +61,18 @@ impl IR {
                     FileScanIR::Csv { options } => FileScanDsl::Csv {
                         options: options.clone(),
                     },
+                    #[cfg(feature = "csv")]
+                    FileScanIR::FixedWidth { options } => FileScanDsl::FixedWidth {
+                        options: options.clone(),
+                    },
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { options } => FileScanDsl::NDJson {
                         options: options.clone(),
                     },
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { options } => FileScanDsl::Json {
+                        options: options.clone(),
+                    },
                     #[cfg(feature = "parquet")]
                     FileScanIR::Parquet {
                         options,
@@ -79,6 +87,17 @@ impl IR {
                     } => FileScanDsl::Ipc {
                         options: options.clone(),
                     },
+                    #[cfg(feature = "ipc")]
+                    FileScanIR::IpcStream { options } => FileScanDsl::IpcStream {
+                        options: options.clone(),
+                    },
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc {
+                        options,
+                        metadata: _,
+                    } => FileScanDsl::Orc {
+                        options: options.clone(),
+                    },
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object,
@@ -115,6 +115,7 @@ impl OptimizationRule for TypeCoercionRule {
                             datetime_nanoseconds_downcast: true,
                             datetime_microseconds_downcast: true,
                             datetime_convert_timezone: true,
+                            decimal_upcast: true,
                             missing_struct_fields: MissingColumnsPolicy::Insert,
                             extra_struct_fields: ExtraColumnsPolicy::Ignore,
                         }
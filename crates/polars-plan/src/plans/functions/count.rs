@@ -30,7 +30,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "orc"
     )))]
     {
         unreachable!()
@@ -40,12 +41,15 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "orc"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
             #[cfg(feature = "csv")]
             FileScanIR::Csv { options } => count_all_rows_csv(sources, options),
+            #[cfg(feature = "csv")]
+            FileScanIR::FixedWidth { options } => count_rows_fixed_width(sources, options),
             #[cfg(feature = "parquet")]
             FileScanIR::Parquet { .. } => count_rows_parquet(sources, cloud_options),
             #[cfg(feature = "ipc")]
@@ -55,8 +59,14 @@ pub fn count_rows(
                 cloud_options,
                 metadata.as_deref(),
             ),
+            #[cfg(feature = "ipc")]
+            FileScanIR::IpcStream { .. } => count_rows_ipc_stream(sources, cloud_options),
+            #[cfg(feature = "orc")]
+            FileScanIR::Orc { metadata, .. } => count_rows_orc(sources, metadata.as_deref()),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "json")]
+            FileScanIR::Json { options } => count_rows_json(sources, options, cloud_options),
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -88,6 +98,7 @@ fn count_all_rows_csv(
                 parse_options.quote_char,
                 parse_options.comment_prefix.as_ref(),
                 parse_options.eol_char,
+                parse_options.encoding,
                 options.has_header,
                 options.skip_lines,
                 options.skip_rows,
@@ -95,9 +106,14 @@ fn count_all_rows_csv(
             ),
             _ => {
                 let memslice = source.to_memslice()?;
+                let decoded = &mut vec![];
 
                 polars_io::csv::read::count_rows_from_slice_par(
-                    &memslice[..],
+                    polars_io::csv::read::maybe_decode_bytes(
+                        &memslice[..],
+                        parse_options.encoding,
+                        decoded,
+                    )?,
                     parse_options.separator,
                     parse_options.quote_char,
                     parse_options.comment_prefix.as_ref(),
@@ -112,6 +128,38 @@ fn count_all_rows_csv(
         .sum()
 }
 
+#[cfg(feature = "csv")]
+fn count_rows_fixed_width(
+    sources: &ScanSources,
+    options: &polars_io::fixed_width::FixedWidthReadOptions,
+) -> PolarsResult<usize> {
+    sources
+        .iter()
+        .map(|source| {
+            let memslice = source.to_memslice()?;
+            Ok(polars_io::fixed_width::count_rows_fixed_width(
+                &memslice, options,
+            ))
+        })
+        .sum()
+}
+
+#[cfg(feature = "orc")]
+fn count_rows_orc(
+    sources: &ScanSources,
+    metadata: Option<&polars_io::orc::OrcMetadata>,
+) -> PolarsResult<usize> {
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| match metadata {
+            // The metadata of the first source is already resolved during conversion.
+            Some(metadata) if i == 0 => Ok(metadata.num_rows()),
+            _ => Ok(polars_io::orc::read_orc_metadata(&source.to_memslice()?)?.num_rows()),
+        })
+        .sum()
+}
+
 #[cfg(feature = "parquet")]
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
@@ -206,6 +254,46 @@ async fn count_rows_cloud_ipc(
         .map(|rows| rows.iter().map(|v| *v as usize).sum())
 }
 
+#[cfg(feature = "ipc")]
+fn count_rows_ipc_stream(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            arrow::io::ipc::read::get_stream_row_count(&mut std::io::Cursor::new(memslice))
+                .map(|v| v as usize)
+        })
+        .sum()
+}
+
 #[cfg(feature = "json")]
 pub(super) fn count_rows_ndjson(
     sources: &ScanSources,
@@ -254,3 +342,45 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "json")]
+fn count_rows_json(
+    sources: &ScanSources,
+    options: &polars_io::json::JsonScanOptions,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::json::record_path::RecordReader;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+
+            RecordReader::from_memslice(memslice, &options.record_path)?.skip(usize::MAX)
+        })
+        .sum()
+}
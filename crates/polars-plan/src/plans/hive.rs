@@ -1,4 +1,8 @@
+use std::borrow::Cow;
+
 use polars_core::prelude::*;
+use polars_io::PartitionValues;
+use polars_io::hive::HIVE_DEFAULT_PARTITION;
 use polars_io::prelude::schema_inference::{finish_infer_field_schema, infer_field_schema};
 use polars_utils::plpath::PlPath;
 #[cfg(feature = "serde")]
@@ -64,6 +68,10 @@ impl From<DataFrame> for HivePartitionsDf {
 
 /// Note: Returned hive partitions are ordered by their position in the `reader_schema`
 ///
+/// With a `path_template` the partitions are extracted using the template instead of from
+/// `key=value` segments. If `partition_values` are given they are used as they are, without
+/// looking at the paths.
+///
 /// # Safety
 /// `hive_start_idx <= [min path length]`
 pub fn hive_partitions_from_paths(
@@ -72,18 +80,32 @@ pub fn hive_partitions_from_paths(
     schema: Option<SchemaRef>,
     reader_schema: &Schema,
     try_parse_dates: bool,
+    path_template: Option<&str>,
+    partition_values: Option<&[PartitionValues]>,
 ) -> PolarsResult<Option<HivePartitionsDf>> {
     let Some(path) = paths.first() else {
         return Ok(None);
     };
 
-    fn parse_hive_string_and_decode(part: &'_ str) -> Option<(&'_ str, std::borrow::Cow<'_, str>)> {
-        let (k, v) = parse_hive_string(part)?;
-        let v = percent_encoding::percent_decode(v.as_bytes())
-            .decode_utf8()
-            .ok()?;
+    if let Some(partition_values) = partition_values {
+        return partitions_from_values(
+            paths.len(),
+            partition_values,
+            schema,
+            reader_schema,
+            try_parse_dates,
+        );
+    }
 
-        Some((k, v))
+    if let Some(path_template) = path_template {
+        return partitions_from_path_template(
+            paths,
+            hive_start_idx,
+            schema,
+            reader_schema,
+            try_parse_dates,
+            &PathTemplate::parse(path_template)?,
+        );
     }
 
     // generate (k,v) tuples from 'k=v' partition strings
@@ -106,7 +128,7 @@ pub fn hive_partitions_from_paths(
         let path = path.as_ref();
         let path = path.offset_bytes(hive_start_idx);
         Arc::new(get_hive_parts_iter!(path).map(|(name, _)| {
-                let Some(dtype) = schema.get(name) else {
+                let Some(dtype) = schema.get(&name) else {
                     polars_bail!(
                         SchemaFieldNotFound:
                         "path contains column not present in the given Hive schema: {:?}, path = {:?}",
@@ -121,30 +143,30 @@ pub fn hive_partitions_from_paths(
                     dtype.clone()
                 };
 
-                Ok(Field::new(PlSmallStr::from_str(name), dtype))
+                Ok(Field::new(name, dtype))
             }).collect::<PolarsResult<Schema>>()?)
     } else {
         let path = path.as_ref();
         let path = path.offset_bytes(hive_start_idx);
 
         let mut hive_schema = Schema::with_capacity(16);
-        let mut schema_inference_map: PlHashMap<&str, PlHashSet<DataType>> =
+        let mut schema_inference_map: PlHashMap<PlSmallStr, PlHashSet<DataType>> =
             PlHashMap::with_capacity(16);
 
         for (name, _) in get_hive_parts_iter!(path) {
             // If the column is also in the file we can use the dtype stored there.
-            if let Some(dtype) = reader_schema.get(name) {
+            if let Some(dtype) = reader_schema.get(&name) {
                 let dtype = if !try_parse_dates && dtype.is_temporal() {
                     DataType::String
                 } else {
                     dtype.clone()
                 };
 
-                hive_schema.insert_at_index(hive_schema.len(), name.into(), dtype.clone())?;
+                hive_schema.insert_at_index(hive_schema.len(), name, dtype.clone())?;
                 continue;
             }
 
-            hive_schema.insert_at_index(hive_schema.len(), name.into(), DataType::String)?;
+            hive_schema.insert_at_index(hive_schema.len(), name.clone(), DataType::String)?;
             schema_inference_map.insert(name, PlHashSet::with_capacity(4));
         }
 
@@ -157,21 +179,21 @@ pub fn hive_partitions_from_paths(
                 let path = path.as_ref();
                 let path = path.offset_bytes(hive_start_idx);
                 for (name, value) in get_hive_parts_iter!(path) {
-                    let Some(entry) = schema_inference_map.get_mut(name) else {
+                    let Some(entry) = schema_inference_map.get_mut(&name) else {
                         continue;
                     };
 
-                    if value.is_empty() || value == "__HIVE_DEFAULT_PARTITION__" {
+                    if value.is_empty() || value == HIVE_DEFAULT_PARTITION {
                         continue;
                     }
 
-                    entry.insert(infer_field_schema(value.as_ref(), try_parse_dates, false));
+                    entry.insert(infer_partition_value_dtype(&value, try_parse_dates));
                 }
             }
 
             for (name, ref possibilities) in schema_inference_map.drain() {
                 let dtype = finish_infer_field_schema(possibilities);
-                *hive_schema.try_get_mut(name).unwrap() = dtype;
+                *hive_schema.try_get_mut(&name).unwrap() = dtype;
             }
         }
         Arc::new(hive_schema)
@@ -190,7 +212,7 @@ pub fn hive_partitions_from_paths(
         let path = path.as_ref();
         let path = path.offset_bytes(hive_start_idx);
         for (name, value) in get_hive_parts_iter!(path) {
-            let Some(index) = hive_schema.index_of(name) else {
+            let Some(index) = hive_schema.index_of(&name) else {
                 polars_bail!(
                     SchemaFieldNotFound:
                     "path contains column not present in the given Hive schema: {:?}, path = {:?}",
@@ -201,7 +223,7 @@ pub fn hive_partitions_from_paths(
 
             let buf = buffers.get_mut(index).unwrap();
 
-            if !value.is_empty() && value != "__HIVE_DEFAULT_PARTITION__" {
+            if !value.is_empty() && value != HIVE_DEFAULT_PARTITION {
                 buf.add(value.as_bytes(), false, false, false)?;
             } else {
                 buf.add_null(false);
@@ -209,6 +231,168 @@ pub fn hive_partitions_from_paths(
         }
     }
 
+    finish_partitions_df(buffers, paths.len(), reader_schema)
+}
+
+/// Extracts the partitions from the paths using a [`PathTemplate`]. The columns are typed in the
+/// same way as Hive partition columns.
+fn partitions_from_path_template(
+    paths: &[PlPath],
+    hive_start_idx: usize,
+    schema: Option<SchemaRef>,
+    reader_schema: &Schema,
+    try_parse_dates: bool,
+    path_template: &PathTemplate,
+) -> PolarsResult<Option<HivePartitionsDf>> {
+    // Calls `f` with the values of the placeholders for every path.
+    let for_each_match = |f: &mut dyn FnMut(&[&str]) -> PolarsResult<()>| {
+        for path in paths {
+            let path = path.as_ref();
+            let path = path.offset_bytes(hive_start_idx);
+            let components = path.get_normal_components().collect::<Vec<_>>();
+
+            let Some(values) = path_template.match_components(&components) else {
+                polars_bail!(
+                    ComputeError:
+                    "path does not match the path template {:?}: {}",
+                    path_template.template,
+                    path.display()
+                )
+            };
+
+            f(&values)?;
+        }
+        Ok(())
+    };
+
+    let mut hive_schema = Schema::with_capacity(path_template.names.len());
+    let mut schema_inference = vec![];
+
+    for (i, name) in path_template.names.iter().enumerate() {
+        let dtype = if let Some(ref schema) = schema {
+            let Some(dtype) = schema.get(name) else {
+                polars_bail!(
+                    SchemaFieldNotFound:
+                    "path template contains column not present in the given Hive schema: {:?}",
+                    name
+                )
+            };
+            dtype.clone()
+        } else if let Some(dtype) = reader_schema.get(name) {
+            dtype.clone()
+        } else {
+            schema_inference.push((i, PlHashSet::with_capacity(4)));
+            DataType::String
+        };
+
+        let dtype = if !try_parse_dates && dtype.is_temporal() {
+            DataType::String
+        } else {
+            dtype
+        };
+
+        hive_schema.insert_at_index(hive_schema.len(), name.clone(), dtype)?;
+    }
+
+    if !schema_inference.is_empty() {
+        for_each_match(&mut |values| {
+            for (i, possibilities) in schema_inference.iter_mut() {
+                possibilities.insert(infer_partition_value_dtype(values[*i], try_parse_dates));
+            }
+            Ok(())
+        })?;
+
+        for (i, possibilities) in &schema_inference {
+            *hive_schema.get_at_index_mut(*i).unwrap().1 = finish_infer_field_schema(possibilities);
+        }
+    }
+
+    let mut buffers = polars_io::csv::read::buffer::init_buffers(
+        &(0..hive_schema.len()).collect::<Vec<_>>(),
+        paths.len(),
+        &hive_schema,
+        None,
+        polars_io::prelude::CsvEncoding::Utf8,
+        false,
+    )?;
+
+    for_each_match(&mut |values| {
+        for (buf, value) in buffers.iter_mut().zip(values) {
+            buf.add(value.as_bytes(), false, false, false)?;
+        }
+        Ok(())
+    })?;
+
+    finish_partitions_df(buffers, paths.len(), reader_schema)
+}
+
+/// Builds the partitions from values given for every path, which are typed with the Hive `schema`.
+fn partitions_from_values(
+    n_paths: usize,
+    partition_values: &[PartitionValues],
+    schema: Option<SchemaRef>,
+    reader_schema: &Schema,
+    try_parse_dates: bool,
+) -> PolarsResult<Option<HivePartitionsDf>> {
+    let Some(schema) = schema else {
+        polars_bail!(ComputeError: "partition values were given without a Hive schema")
+    };
+    polars_ensure!(
+        partition_values.len() == n_paths,
+        ComputeError:
+        "expected partition values for {} paths, got {}",
+        n_paths, partition_values.len()
+    );
+
+    if schema.is_empty() {
+        return Ok(None);
+    }
+
+    let hive_schema = schema
+        .iter()
+        .map(|(name, dtype)| {
+            let dtype = if !try_parse_dates && dtype.is_temporal() {
+                DataType::String
+            } else {
+                dtype.clone()
+            };
+            Field::new(name.clone(), dtype)
+        })
+        .collect::<Schema>();
+
+    let mut buffers = polars_io::csv::read::buffer::init_buffers(
+        &(0..hive_schema.len()).collect::<Vec<_>>(),
+        n_paths,
+        &hive_schema,
+        None,
+        polars_io::prelude::CsvEncoding::Utf8,
+        false,
+    )?;
+
+    for values in partition_values {
+        polars_ensure!(
+            values.len() == hive_schema.len(),
+            ComputeError:
+            "expected {} partition values, got {}",
+            hive_schema.len(), values.len()
+        );
+
+        for (buf, value) in buffers.iter_mut().zip(values.iter()) {
+            match value {
+                Some(value) => buf.add(value.as_bytes(), false, false, false)?,
+                None => buf.add_null(false),
+            }
+        }
+    }
+
+    finish_partitions_df(buffers, n_paths, reader_schema)
+}
+
+fn finish_partitions_df(
+    buffers: Vec<polars_io::csv::read::buffer::Buffer>,
+    height: usize,
+    reader_schema: &Schema,
+) -> PolarsResult<Option<HivePartitionsDf>> {
     let mut buffers = buffers
         .into_iter()
         .map(|x| Ok(x.into_series()?.into_column()))
@@ -216,11 +400,193 @@ pub fn hive_partitions_from_paths(
     buffers.sort_by_key(|s| reader_schema.index_of(s.name()).unwrap_or(usize::MAX));
 
     Ok(Some(HivePartitionsDf(DataFrame::new_with_height(
-        paths.len(),
-        buffers,
+        height, buffers,
     )?)))
 }
 
+enum TemplateToken {
+    Literal(String),
+    Placeholder,
+    Wildcard,
+}
+
+/// A template that extracts values from the trailing segments of a path, e.g.
+/// `{year}/{month}/{source}.parquet`.
+struct PathTemplate {
+    template: PlSmallStr,
+    /// Tokens of each segment.
+    segments: Vec<Vec<TemplateToken>>,
+    /// Names of the placeholders, in the order in which they appear.
+    names: Vec<PlSmallStr>,
+}
+
+impl PathTemplate {
+    fn parse(template: &str) -> PolarsResult<Self> {
+        let mut segments = vec![];
+        let mut names: Vec<PlSmallStr> = vec![];
+
+        for segment in template.split('/').filter(|s| !s.is_empty()) {
+            let mut tokens = vec![];
+            let mut chars = segment.chars();
+
+            while let Some(c) = chars.next() {
+                let token = match c {
+                    '{' => {
+                        let mut name = String::new();
+                        let mut closed = false;
+
+                        for c in chars.by_ref() {
+                            if c == '}' {
+                                closed = true;
+                                break;
+                            }
+                            name.push(c);
+                        }
+
+                        if !closed || name.is_empty() || name.contains('{') {
+                            polars_bail!(
+                                InvalidOperation:
+                                "invalid placeholder in path template {:?}", template
+                            )
+                        }
+                        if names.iter().any(|x| x == name.as_str()) {
+                            polars_bail!(
+                                InvalidOperation:
+                                "duplicate placeholder {:?} in path template {:?}", name, template
+                            )
+                        }
+                        names.push(name.into());
+                        TemplateToken::Placeholder
+                    },
+                    '}' => polars_bail!(
+                        InvalidOperation:
+                        "unmatched '}}' in path template {:?}", template
+                    ),
+                    '*' => TemplateToken::Wildcard,
+                    c => {
+                        if let Some(TemplateToken::Literal(s)) = tokens.last_mut() {
+                            s.push(c);
+                            continue;
+                        }
+                        TemplateToken::Literal(c.into())
+                    },
+                };
+
+                // A placeholder directly next to another placeholder or a wildcard has no
+                // unambiguous end.
+                if !matches!(token, TemplateToken::Literal(_))
+                    && matches!(
+                        tokens.last(),
+                        Some(TemplateToken::Placeholder | TemplateToken::Wildcard)
+                    )
+                {
+                    polars_bail!(
+                        InvalidOperation:
+                        "path template {:?} contains adjacent placeholders", template
+                    )
+                }
+
+                tokens.push(token);
+            }
+
+            segments.push(tokens);
+        }
+
+        if names.is_empty() {
+            polars_bail!(
+                InvalidOperation:
+                "path template {:?} does not contain any placeholders", template
+            )
+        }
+
+        Ok(Self {
+            template: template.into(),
+            segments,
+            names,
+        })
+    }
+
+    /// Returns the values of the placeholders if the trailing `components` of a path match the
+    /// template.
+    fn match_components<'a>(&self, components: &[&'a str]) -> Option<Vec<&'a str>> {
+        let offset = components.len().checked_sub(self.segments.len())?;
+        let mut values = Vec::with_capacity(self.names.len());
+
+        for (tokens, component) in self.segments.iter().zip(&components[offset..]) {
+            if !match_tokens(tokens, component, &mut values) {
+                return None;
+            }
+        }
+
+        Some(values)
+    }
+}
+
+/// Matches `s` against `tokens`, pushing the values of the placeholders to `values`.
+/// Placeholders and wildcards match as few characters as possible.
+fn match_tokens<'a>(tokens: &[TemplateToken], s: &'a str, values: &mut Vec<&'a str>) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return s.is_empty();
+    };
+
+    if let TemplateToken::Literal(literal) = token {
+        return s
+            .strip_prefix(literal.as_str())
+            .is_some_and(|s| match_tokens(rest, s, values));
+    }
+
+    let is_placeholder = matches!(token, TemplateToken::Placeholder);
+    let n_values = values.len();
+
+    for end in usize::from(is_placeholder)..=s.len() {
+        if !s.is_char_boundary(end) {
+            continue;
+        }
+
+        if is_placeholder {
+            values.push(&s[..end]);
+        }
+
+        if match_tokens(rest, &s[end..], values) {
+            return true;
+        }
+
+        values.truncate(n_values);
+    }
+
+    false
+}
+
+/// Infers the dtype of a partition value. Partition values are written with all fractional digits
+/// of their time unit, so the time unit of a datetime is inferred from the number of digits.
+fn infer_partition_value_dtype(value: &str, try_parse_dates: bool) -> DataType {
+    let dtype = infer_field_schema(value, try_parse_dates, false);
+
+    #[cfg(feature = "dtype-datetime")]
+    if let DataType::Datetime(_, tz) = dtype {
+        let n_fractional_digits = value.split_once('.').map_or(0, |(_, fraction)| {
+            fraction.bytes().take_while(u8::is_ascii_digit).count()
+        });
+        let time_unit = match n_fractional_digits {
+            3 => TimeUnit::Milliseconds,
+            9 => TimeUnit::Nanoseconds,
+            _ => TimeUnit::Microseconds,
+        };
+
+        return DataType::Datetime(time_unit, tz);
+    }
+
+    dtype
+}
+
+/// Parse a Hive partition string (e.g. "column=1.5") into a percent-decoded name and value.
+fn parse_hive_string_and_decode(part: &str) -> Option<(PlSmallStr, Cow<'_, str>)> {
+    let (k, v) = parse_hive_string(part)?;
+    let decode = |s| percent_encoding::percent_decode_str(s).decode_utf8().ok();
+
+    Some((PlSmallStr::from_str(&decode(k)?), decode(v)?))
+}
+
 /// Parse a Hive partition string (e.g. "column=1.5") into a name and value part.
 ///
 /// Returns `None` if the string is not a Hive partition string.
@@ -241,3 +607,42 @@ fn parse_hive_string(part: &'_ str) -> Option<(&'_ str, &'_ str)> {
 
     Some((name, value))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PathTemplate;
+
+    #[test]
+    fn test_path_template() {
+        let template = PathTemplate::parse("/{year}/{month}/{source}_*.parquet").unwrap();
+        assert_eq!(template.names, ["year", "month", "source"]);
+
+        assert_eq!(
+            template.match_components(&["data", "2024", "01", "web_part_0.parquet"]),
+            Some(vec!["2024", "01", "web"])
+        );
+        assert_eq!(
+            template.match_components(&["2024", "01", "web_.parquet"]),
+            Some(vec!["2024", "01", "web"])
+        );
+        assert_eq!(
+            template.match_components(&["01", "web_part_0.parquet"]),
+            None
+        );
+        assert_eq!(
+            template.match_components(&["2024", "01", "web.parquet"]),
+            None
+        );
+        assert_eq!(template.match_components(&["2024", "01", "web.csv"]), None);
+
+        let template = PathTemplate::parse("{a}.{b}.parquet").unwrap();
+        assert_eq!(
+            template.match_components(&["x.y.z.parquet"]),
+            Some(vec!["x", "y.z"])
+        );
+
+        for template in ["{a}{b}", "{a}*", "{a", "a}", "{}", "{a}/{a}", "a/b"] {
+            assert!(PathTemplate::parse(template).is_err(), "{template}");
+        }
+    }
+}
@@ -152,12 +152,28 @@ impl OptimizationRule for ExpandDatasets {
                                 #[cfg(feature = "csv")]
                                 FileScanDsl::Csv { options } => FileScanIR::Csv { options },
 
+                                #[cfg(feature = "csv")]
+                                FileScanDsl::FixedWidth { options } => {
+                                    FileScanIR::FixedWidth { options }
+                                },
+
                                 #[cfg(feature = "ipc")]
                                 FileScanDsl::Ipc { options } => FileScanIR::Ipc {
                                     options,
                                     metadata: None,
                                 },
 
+                                #[cfg(feature = "ipc")]
+                                FileScanDsl::IpcStream { options } => {
+                                    FileScanIR::IpcStream { options }
+                                },
+
+                                #[cfg(feature = "orc")]
+                                FileScanDsl::Orc { options } => FileScanIR::Orc {
+                                    options,
+                                    metadata: None,
+                                },
+
                                 #[cfg(feature = "parquet")]
                                 FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                     options,
@@ -167,6 +183,9 @@ impl OptimizationRule for ExpandDatasets {
                                 #[cfg(feature = "json")]
                                 FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                                #[cfg(feature = "json")]
+                                FileScanDsl::Json { options } => FileScanIR::Json { options },
+
                                 #[cfg(feature = "python")]
                                 FileScanDsl::PythonDataset { dataset_object } => {
                                     FileScanIR::PythonDataset {
@@ -371,6 +371,14 @@ impl PredicatePushDown<'_> {
                     FileScanIR::Parquet { .. } => {},
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => {},
+                    #[cfg(feature = "ipc")]
+                    FileScanIR::IpcStream { .. } => {},
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => {},
+                    #[cfg(feature = "csv")]
+                    FileScanIR::FixedWidth { .. } => {},
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { .. } => {},
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
@@ -452,10 +452,18 @@ impl ProjectionPushDown {
                     FileScanIR::Anonymous { function, .. } => function.allows_projection_pushdown(),
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { .. } => true,
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => true,
+                    #[cfg(feature = "ipc")]
+                    FileScanIR::IpcStream { .. } => true,
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { .. } => true,
+                    #[cfg(feature = "csv")]
+                    FileScanIR::FixedWidth { .. } => true,
                     #[cfg(feature = "parquet")]
                     FileScanIR::Parquet { .. } => true,
                     // MultiScan will handle it if the PythonDataset cannot do projections.
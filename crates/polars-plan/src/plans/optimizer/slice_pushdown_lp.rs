@@ -230,12 +230,24 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "ipc")]
+                FileScanIR::IpcStream { .. } => true,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
+                #[cfg(feature = "csv")]
+                FileScanIR::FixedWidth { .. } => true,
+
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { .. } => true,
 
+                #[cfg(feature = "json")]
+                FileScanIR::Json { .. } => true,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset { .. } => true,
 
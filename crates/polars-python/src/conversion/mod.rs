@@ -880,9 +880,13 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}}, got {v}",
                 )));
             },
         };
@@ -1337,6 +1341,19 @@ impl<'py> FromPyObject<'py> for Wrap<CastColumnsPolicy> {
             Ok(())
         })?;
 
+        let decimal_upcast = match &*ob
+            .getattr(intern!(py, "decimal_cast"))?
+            .extract::<PyBackedStr>()?
+        {
+            "upcast" => true,
+            "forbid" => false,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown option for decimal_cast: {v}"
+                )));
+            },
+        };
+
         let missing_struct_fields = match &*ob
             .getattr(intern!(py, "missing_struct_fields"))?
             .extract::<PyBackedStr>()?
@@ -1370,6 +1387,7 @@ impl<'py> FromPyObject<'py> for Wrap<CastColumnsPolicy> {
             datetime_nanoseconds_downcast,
             datetime_microseconds_downcast: false,
             datetime_convert_timezone,
+            decimal_upcast,
             missing_struct_fields,
             extra_struct_fields,
         }));
@@ -1459,6 +1477,35 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(crate) fn parse_external_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<ExternalCompression> {
+    let parsed = match compression {
+        "uncompressed" => ExternalCompression::Uncompressed,
+        "gzip" => ExternalCompression::gzip(
+            compression_level
+                .map(|lvl| {
+                    u32::try_from(lvl).map_err(|_| {
+                        PyValueError::new_err(format!(
+                            "gzip compression level must be between 0 and 9, got {lvl}"
+                        ))
+                    })
+                })
+                .transpose()?,
+        )
+        .map_err(PyPolarsErr::from)?,
+        "zstd" => ExternalCompression::zstd(compression_level).map_err(PyPolarsErr::from)?,
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
@@ -41,6 +41,7 @@ impl PyScanOptions<'_> {
             hive_partitioning: Option<bool>,
             hive_schema: Option<Wrap<Schema>>,
             try_parse_hive_dates: bool,
+            path_template: Option<Wrap<PlSmallStr>>,
             rechunk: bool,
             cache: bool,
             storage_options: Option<Vec<(String, String)>>,
@@ -60,6 +61,7 @@ impl PyScanOptions<'_> {
             hive_partitioning,
             hive_schema,
             try_parse_hive_dates,
+            path_template,
             rechunk,
             cache,
             storage_options,
@@ -110,6 +112,8 @@ impl PyScanOptions<'_> {
             hive_start_idx: 0,
             schema: hive_schema,
             try_parse_dates: try_parse_hive_dates,
+            path_template: path_template.map(|x| x.0),
+            partition_values: None,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -133,4 +137,17 @@ impl PyScanOptions<'_> {
 
         Ok(unified_scan_args)
     }
+
+    /// `use_field_ids` of the `ScanCastOptions`, which is passed to the Parquet reader rather
+    /// than being part of the [`CastColumnsPolicy`].
+    #[cfg(feature = "parquet")]
+    pub fn extract_use_field_ids(&self) -> PyResult<bool> {
+        let cast_options = self.0.getattr("cast_options")?;
+
+        if cast_options.is_none() {
+            return Ok(false);
+        }
+
+        cast_options.getattr("use_field_ids")?.extract()
+    }
 }
@@ -322,6 +322,8 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            use_field_ids: scan_options.extract_use_field_ids()?,
+            field_overwrites: Vec::new(),
         };
 
         let sources = sources.0;
@@ -342,8 +344,8 @@ impl PyLazyFrame {
     #[staticmethod]
     #[pyo3(signature = (
         source, sources, n_rows, cache, rechunk, row_index, cloud_options,credential_provider,
-        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
-        include_file_paths
+        hive_partitioning, hive_schema, try_parse_hive_dates, path_template, retries,
+        file_cache_ttl, include_file_paths
     ))]
     fn new_from_ipc(
         source: Option<PyObject>,
@@ -357,6 +359,7 @@ impl PyLazyFrame {
         hive_partitioning: Option<bool>,
         hive_schema: Option<Wrap<Schema>>,
         try_parse_hive_dates: bool,
+        path_template: Option<String>,
         retries: usize,
         file_cache_ttl: Option<u64>,
         include_file_paths: Option<String>,
@@ -373,6 +376,8 @@ impl PyLazyFrame {
             hive_start_idx: 0,
             schema: hive_schema.map(|x| Arc::new(x.0)),
             try_parse_dates: try_parse_hive_dates,
+            path_template: path_template.map(|x| x.into()),
+            partition_values: None,
         };
 
         let mut args = ScanArgsIpc {
@@ -761,7 +766,10 @@ impl PyLazyFrame {
                     partition.variant,
                     options,
                     cloud_options,
-                    sink_options.0,
+                    SinkOptions {
+                        write_mode: partition.write_mode,
+                        ..sink_options.0
+                    },
                     partition.per_partition_sort_by,
                     partition.finish_callback,
                 ),
@@ -825,7 +833,10 @@ impl PyLazyFrame {
                     partition.variant,
                     options,
                     cloud_options,
-                    sink_options.0,
+                    SinkOptions {
+                        write_mode: partition.write_mode,
+                        ..sink_options.0
+                    },
                     partition.per_partition_sort_by,
                     partition.finish_callback,
                 ),
@@ -839,7 +850,8 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, compression, compression_level, encoding, cloud_options, credential_provider,
+        retries, sink_options
     ))]
     fn sink_csv(
         &self,
@@ -859,6 +871,9 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        compression: &str,
+        compression_level: Option<i32>,
+        encoding: Wrap<CsvEncoding>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -886,6 +901,8 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            compression: parse_external_compression(compression, compression_level)?,
+            encoding: encoding.0,
         };
 
         #[cfg(feature = "cloud")]
@@ -919,7 +936,10 @@ impl PyLazyFrame {
                     partition.variant,
                     options,
                     cloud_options,
-                    sink_options.0,
+                    SinkOptions {
+                        write_mode: partition.write_mode,
+                        ..sink_options.0
+                    },
                     partition.per_partition_sort_by,
                     partition.finish_callback,
                 ),
@@ -931,17 +951,24 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "json")]
-    #[pyo3(signature = (target, cloud_options, credential_provider, retries, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
+    ))]
     fn sink_json(
         &self,
         py: Python<'_>,
         target: SinkTarget,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions {
+            compression: parse_external_compression(compression, compression_level)?,
+        };
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -970,7 +997,10 @@ impl PyLazyFrame {
                     partition.variant,
                     options,
                     cloud_options,
-                    sink_options.0,
+                    SinkOptions {
+                        write_mode: partition.write_mode,
+                        ..sink_options.0
+                    },
                     partition.per_partition_sort_by,
                     partition.finish_callback,
                 ),
@@ -2,8 +2,8 @@ use std::sync::{Arc, Mutex};
 
 use polars::prelude::sync_on_close::SyncOnCloseType;
 use polars::prelude::{
-    PartitionTargetCallbackResult, PartitionVariant, PlPath, SinkFinishCallback, SinkOptions,
-    SortColumn, SpecialEq,
+    PartitionTargetCallbackResult, PartitionVariant, PartitionWriteMode, PlPath,
+    SinkFinishCallback, SinkOptions, SortColumn, SpecialEq,
 };
 use polars_utils::IdxSize;
 use polars_utils::plpath::PlPathRef;
@@ -31,6 +31,7 @@ pub struct PyPartitioning {
     pub variant: PartitionVariant,
     pub per_partition_sort_by: Option<Vec<SortColumn>>,
     pub finish_callback: Option<SinkFinishCallback>,
+    pub write_mode: Option<PartitionWriteMode>,
 }
 
 fn parse_per_partition_sort_by(sort_by: Option<Vec<PyExpr>>) -> Option<Vec<SortColumn>> {
@@ -50,13 +51,14 @@ fn parse_per_partition_sort_by(sort_by: Option<Vec<PyExpr>>) -> Option<Vec<SortC
 #[pymethods]
 impl PyPartitioning {
     #[staticmethod]
-    #[pyo3(signature = (base_path, file_path_cb, max_size, per_partition_sort_by, finish_callback))]
+    #[pyo3(signature = (base_path, file_path_cb, max_size, per_partition_sort_by, finish_callback, write_mode=None))]
     pub fn new_max_size(
         base_path: Wrap<PlPath>,
         file_path_cb: Option<PyObject>,
         max_size: IdxSize,
         per_partition_sort_by: Option<Vec<PyExpr>>,
         finish_callback: Option<PyObject>,
+        write_mode: Option<Wrap<PartitionWriteMode>>,
     ) -> PyPartitioning {
         let file_path_cb = file_path_cb.map(|f| PythonObject(f.into_any()));
         let finish_callback =
@@ -68,11 +70,12 @@ impl PyPartitioning {
             variant: PartitionVariant::MaxSize(max_size),
             per_partition_sort_by: parse_per_partition_sort_by(per_partition_sort_by),
             finish_callback,
+            write_mode: write_mode.map(|w| w.0),
         }
     }
 
     #[staticmethod]
-    #[pyo3(signature = (base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback))]
+    #[pyo3(signature = (base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback, write_mode=None))]
     pub fn new_by_key(
         base_path: Wrap<PlPath>,
         file_path_cb: Option<PyObject>,
@@ -80,6 +83,7 @@ impl PyPartitioning {
         include_key: bool,
         per_partition_sort_by: Option<Vec<PyExpr>>,
         finish_callback: Option<PyObject>,
+        write_mode: Option<Wrap<PartitionWriteMode>>,
     ) -> PyPartitioning {
         let file_path_cb = file_path_cb.map(|f| PythonObject(f.into_any()));
         let finish_callback =
@@ -94,11 +98,12 @@ impl PyPartitioning {
             },
             per_partition_sort_by: parse_per_partition_sort_by(per_partition_sort_by),
             finish_callback,
+            write_mode: write_mode.map(|w| w.0),
         }
     }
 
     #[staticmethod]
-    #[pyo3(signature = (base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback))]
+    #[pyo3(signature = (base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback, write_mode=None))]
     pub fn new_parted(
         base_path: Wrap<PlPath>,
         file_path_cb: Option<PyObject>,
@@ -106,6 +111,7 @@ impl PyPartitioning {
         include_key: bool,
         per_partition_sort_by: Option<Vec<PyExpr>>,
         finish_callback: Option<PyObject>,
+        write_mode: Option<Wrap<PartitionWriteMode>>,
     ) -> PyPartitioning {
         let file_path_cb = file_path_cb.map(|f| PythonObject(f.into_any()));
         let finish_callback =
@@ -120,6 +126,7 @@ impl PyPartitioning {
             },
             per_partition_sort_by: parse_per_partition_sort_by(per_partition_sort_by),
             finish_callback,
+            write_mode: write_mode.map(|w| w.0),
         }
     }
 }
@@ -214,6 +221,23 @@ impl<'py> FromPyObject<'py> for Wrap<SyncOnCloseType> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<PartitionWriteMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "error" => PartitionWriteMode::Error,
+            "append" => PartitionWriteMode::Append,
+            "overwrite_all" => PartitionWriteMode::OverwriteAll,
+            "overwrite_touched" => PartitionWriteMode::OverwriteTouched,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`write_mode` must be one of {{'error', 'append', 'overwrite_all', 'overwrite_touched'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
@@ -244,6 +268,8 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
             sync_on_close,
             maintain_order,
             mkdir,
+            write_mode: None,
+            upload: None,
         }))
     }
 }
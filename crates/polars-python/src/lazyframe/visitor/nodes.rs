@@ -40,6 +40,14 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "ipc")]
+        FileScanIR::IpcStream { .. } => Err(PyNotImplementedError::new_err("ipc stream scan")),
+        #[cfg(feature = "orc")]
+        FileScanIR::Orc { .. } => Err(PyNotImplementedError::new_err("orc scan")),
+        #[cfg(feature = "csv")]
+        FileScanIR::FixedWidth { .. } => Err(PyNotImplementedError::new_err("fixed width scan")),
+        #[cfg(feature = "json")]
+        FileScanIR::Json { .. } => Err(PyNotImplementedError::new_err("json scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -149,6 +157,14 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+
+            Some(DeletionFilesList::DeltaDeletionVector(_)) => {
+                return Err(PyNotImplementedError::new_err("delta deletion vectors"));
+            },
+
+            Some(DeletionFilesList::IcebergDeletes(_)) => {
+                return Err(PyNotImplementedError::new_err("iceberg deletes"));
+            },
         })
     }
 }
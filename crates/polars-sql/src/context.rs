@@ -57,6 +57,7 @@ impl SelectModifiers {
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) table_functions: bool,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
@@ -69,6 +70,7 @@ impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            table_functions: true,
             table_map: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
@@ -177,6 +179,14 @@ impl SQLContext {
         self
     }
 
+    /// Allow or disallow table functions such as `read_parquet` in the `FROM` clause. These read
+    /// arbitrary files, so they should be disabled when executing untrusted queries. Enabled by
+    /// default.
+    pub fn with_table_functions(mut self, enabled: bool) -> Self {
+        self.table_functions = enabled;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -1227,6 +1237,10 @@ impl SQLContext {
         args: &[FunctionArg],
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.first().unwrap().value.as_str();
+        polars_ensure!(
+            self.table_functions,
+            SQLInterface: "table functions are disabled in this context, cannot call '{}'", tbl_fn
+        );
         let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
         let (tbl_name, lf) = read_fn.execute(args)?;
         #[allow(clippy::useless_asref)]
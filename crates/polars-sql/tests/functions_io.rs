@@ -29,6 +29,18 @@ fn read_csv_tbl_func() {
     assert_eq!(df_2.width(), 4);
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_disabled() {
+    let mut context = SQLContext::new().with_table_functions(false);
+    let sql = r#"SELECT * FROM read_csv('../../examples/datasets/foods1.csv')"#;
+    let err = context.execute(sql).err().unwrap();
+    assert!(
+        err.to_string().contains("table functions are disabled"),
+        "{err}"
+    );
+}
+
 #[test]
 #[cfg(feature = "csv")]
 fn read_csv_tbl_func_inline() {
@@ -9,7 +9,7 @@ pub use skeleton::{run_query, visualize_physical_plan};
 mod execute;
 pub(crate) mod expression;
 mod graph;
-pub use skeleton::{QueryResult, StreamingQuery};
+pub use skeleton::{BatchReceiver, QueryResult, StreamingQuery};
 mod morsel;
 mod nodes;
 mod physical_plan;
@@ -0,0 +1,75 @@
+use super::compute_node_prelude::*;
+
+/// A sink that passes the morsels it receives in order to a bounded channel.
+///
+/// The query is suspended whenever the channel is full, so only a bounded amount of the result is
+/// held in memory. If the receiving side of the channel is dropped the sink stops and tells the
+/// sources to stop producing morsels.
+pub struct BatchSinkNode {
+    sender: async_channel::Sender<DataFrame>,
+    is_finished: bool,
+}
+
+impl BatchSinkNode {
+    pub fn new(sender: async_channel::Sender<DataFrame>) -> Self {
+        Self {
+            sender,
+            is_finished: false,
+        }
+    }
+}
+
+impl ComputeNode for BatchSinkNode {
+    fn name(&self) -> &str {
+        "batch-sink"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(send.is_empty());
+        assert!(recv.len() == 1);
+
+        if self.is_finished || recv[0] == PortState::Done {
+            recv[0] = PortState::Done;
+            // Closing the channel lets the receiver know that no more batches follow.
+            self.sender.close();
+        } else {
+            recv[0] = PortState::Ready;
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.is_empty());
+        let mut recv = recv_ports[0].take().unwrap().serial();
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                let (df, _seq, source_token, consume_token) = morsel.into_inner();
+                if df.height() == 0 {
+                    continue;
+                }
+
+                if self.sender.send(df).await.is_err() {
+                    source_token.stop();
+                    self.is_finished = true;
+                    break;
+                }
+                drop(consume_token);
+            }
+
+            Ok(())
+        }));
+    }
+}
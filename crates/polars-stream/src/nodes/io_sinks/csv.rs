@@ -6,6 +6,7 @@ use polars_error::PolarsResult;
 use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::{CsvWriter, CsvWriterOptions};
+use polars_io::utils::compression::CompressedWriter;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -87,6 +88,7 @@ impl SinkNode for CsvSinkNode {
                         let mut buffer = Vec::with_capacity(allocation_size);
                         let mut writer = CsvWriter::new(&mut buffer)
                             .include_bom(false) // Handled once in the IO task.
+                            .with_encoding(options.encoding)
                             .include_header(false) // Handled once in the IO task.
                             .with_separator(options.serialize_options.separator)
                             .with_line_terminator(options.serialize_options.line_terminator.clone())
@@ -132,10 +134,16 @@ impl SinkNode for CsvSinkNode {
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
 
+            // A single encoder is used for the whole file, which compresses better than
+            // compressing every morsel separately.
+            let mut encoder = CompressedWriter::new(Vec::new(), options.compression)?;
+
             // Write the header
             if options.include_header || options.include_bom {
-                let mut writer = CsvWriter::new(&mut *file)
+                let mut header = Vec::new();
+                let mut writer = CsvWriter::new(&mut header)
                     .include_bom(options.include_bom)
+                    .with_encoding(options.encoding)
                     .include_header(options.include_header)
                     .with_separator(options.serialize_options.separator)
                     .with_line_terminator(options.serialize_options.line_terminator.clone())
@@ -151,16 +159,21 @@ impl SinkNode for CsvSinkNode {
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
+
+                let header = encoder.compress_chunk(header)?;
+                std::io::Write::write_all(&mut *file, &header)?;
             }
 
             let mut file = file.try_into_async_writeable()?;
 
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    file.write_all(&encoder.compress_chunk(buffer)?).await?;
                 }
             }
 
+            file.write_all(&encoder.finish()?).await?;
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
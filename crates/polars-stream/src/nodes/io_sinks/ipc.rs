@@ -12,7 +12,9 @@ use polars_core::utils::arrow::io::ipc::write::{
 use polars_error::PolarsResult;
 use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
+use polars_io::ipc::statistics::column_statistics;
 use polars_io::ipc::{IpcWriter, IpcWriterOptions};
+use polars_io::predicates::ColumnStatistics;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -81,7 +83,12 @@ impl SinkNode for IpcSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<(Vec<EncodedData>, EncodedData)>();
+        let (mut io_tx, mut io_rx) = connector::<(
+            Vec<EncodedData>,
+            EncodedData,
+            usize,
+            Vec<Option<ColumnStatistics>>,
+        )>();
 
         let options = WriteOptions {
             compression: self.write_options.compression.map(Into::into),
@@ -122,6 +129,12 @@ impl SinkNode for IpcSinkNode {
                             let mut nodes = Vec::new();
                             let mut offset = 0;
 
+                            let statistics = if write_options.record_batch_statistics {
+                                column_statistics(&column)?
+                            } else {
+                                None
+                            };
+
                             // We want to rechunk for two reasons:
                             // 1. the IPC writer expects aligned column chunks
                             // 2. the IPC writer turns chunks / record batches into chunks in the file,
@@ -153,6 +166,7 @@ impl SinkNode for IpcSinkNode {
                                     arrow_data,
                                     nodes,
                                     offset,
+                                    statistics,
                                 ),
                             );
                             if lin_tx.insert(msg).await.is_err() {
@@ -182,6 +196,7 @@ impl SinkNode for IpcSinkNode {
                 arrow_data: Vec<u8>,
                 nodes: Vec<arrow::io::ipc::format::ipc::FieldNode>,
                 offset: i64,
+                statistics: Option<ColumnStatistics>,
             }
             struct Current {
                 seq: usize,
@@ -202,7 +217,7 @@ impl SinkNode for IpcSinkNode {
             // Linearize from all the Encoder tasks.
             while let Some(Priority(
                 Reverse(seq),
-                (i, array, variadic_buffer_counts, buffers, arrow_data, nodes, offset),
+                (i, array, variadic_buffer_counts, buffers, arrow_data, nodes, offset, statistics),
             )) = lin_rx.get().await
             {
                 if current.num_columns_seen == 0 {
@@ -220,6 +235,7 @@ impl SinkNode for IpcSinkNode {
                     arrow_data,
                     nodes,
                     offset,
+                    statistics,
                 });
                 current.num_columns_seen += 1;
 
@@ -231,6 +247,7 @@ impl SinkNode for IpcSinkNode {
                     let mut arrow_data = Vec::new();
                     let mut nodes = Vec::new();
                     let mut offset = 0;
+                    let mut statistics = Vec::new();
 
                     for (i, column) in current.columns.iter_mut().enumerate() {
                         let column = column.take().unwrap();
@@ -258,6 +275,7 @@ impl SinkNode for IpcSinkNode {
                         }));
                         arrow_data.extend(column.arrow_data);
                         nodes.extend(column.nodes);
+                        statistics.push(column.statistics);
 
                         offset += column.offset;
                     }
@@ -279,6 +297,8 @@ impl SinkNode for IpcSinkNode {
                         .send((
                             std::mem::take(&mut current.encoded_dictionaries),
                             encoded_data,
+                            current.height,
+                            statistics,
                         ))
                         .await
                         .is_err()
@@ -308,10 +328,16 @@ impl SinkNode for IpcSinkNode {
             let mut writer = IpcWriter::new(writer)
                 .with_compression(write_options.compression)
                 .with_compat_level(write_options.compat_level)
+                .with_format(write_options.format)
+                .with_record_batch_statistics(write_options.record_batch_statistics)
                 .with_parallel(false)
                 .batched(&input_schema)?;
 
-            while let Ok((dicts, record_batch)) = io_rx.recv().await {
+            while let Ok((dicts, record_batch, height, statistics)) = io_rx.recv().await {
+                if let Some(builder) = writer.statistics_mut() {
+                    builder.push(height, statistics);
+                }
+
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 writer.write_encoded(dicts.as_slice(), &record_batch)?;
@@ -2,7 +2,8 @@ use std::cmp::Reverse;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
+use polars_io::utils::compression::CompressedWriter;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -17,17 +18,20 @@ use crate::nodes::{JoinHandle, TaskPriority};
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 }
 impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
         }
     }
@@ -98,6 +102,7 @@ impl SinkNode for NDJsonSinkNode {
         // Task that will actually do write to the target file.
         let sink_options = self.sink_options.clone();
         let target = self.target.clone();
+        let compression = self.write_options.compression;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             use tokio::io::AsyncWriteExt;
 
@@ -106,12 +111,18 @@ impl SinkNode for NDJsonSinkNode {
                 .await?
                 .try_into_async_writeable()?;
 
+            // A single encoder is used for the whole file, which compresses better than
+            // compressing every morsel separately.
+            let mut encoder = CompressedWriter::new(Vec::new(), compression)?;
+
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    file.write_all(&encoder.compress_chunk(buffer)?).await?;
                 }
             }
 
+            file.write_all(&encoder.finish()?).await?;
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
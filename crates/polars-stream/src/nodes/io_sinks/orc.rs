@@ -0,0 +1,124 @@
+use std::io::BufWriter;
+
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::OrcWriterOptions;
+use polars_plan::dsl::{SinkOptions, SinkTarget};
+
+use super::{SinkInputPort, SinkNode};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::{Receiver, connector};
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+
+pub struct OrcSinkNode {
+    target: SinkTarget,
+
+    input_schema: SchemaRef,
+    write_options: OrcWriterOptions,
+    sink_options: SinkOptions,
+    cloud_options: Option<CloudOptions>,
+}
+
+impl OrcSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        sink_options: SinkOptions,
+        write_options: OrcWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> Self {
+        Self {
+            target,
+
+            input_schema,
+            write_options,
+            sink_options,
+            cloud_options,
+        }
+    }
+}
+
+impl SinkNode for OrcSinkNode {
+    fn name(&self) -> &str {
+        "orc-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        false
+    }
+    fn do_maintain_order(&self) -> bool {
+        self.sink_options.maintain_order
+    }
+
+    fn spawn_sink(
+        &mut self,
+        mut recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        _state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        // Receive task -> IO task
+        let (mut io_tx, mut io_rx) = connector::<DataFrame>();
+
+        // Receive task.
+        //
+        // The stripes are encoded by the batched writer, which buffers until a full stripe is
+        // available, so the morsels are passed through in order.
+        join_handles.push(spawn(TaskPriority::High, async move {
+            while let Ok((outcome, rx)) = recv_port_rx.recv().await {
+                let mut rx = rx.serial();
+                while let Ok(morsel) = rx.recv().await {
+                    let (df, _, _, consume_token) = morsel.into_inner();
+                    if io_tx.send(df).await.is_err() {
+                        return Ok(());
+                    }
+                    drop(consume_token);
+                }
+
+                outcome.stopped();
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // IO task.
+        //
+        // Task that will actually do write to the target file. It is important that this is only
+        // spawned once.
+        let target = self.target.clone();
+        let sink_options = self.sink_options.clone();
+        let cloud_options = self.cloud_options.clone();
+        let write_options = self.write_options;
+        let input_schema = self.input_schema.clone();
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            let mut file = target
+                .open_into_writeable_async(&sink_options, cloud_options.as_ref())
+                .await?;
+
+            let writer = BufWriter::new(&mut *file);
+            let mut writer = write_options.to_writer(writer).batched(&input_schema)?;
+
+            while let Ok(df) = io_rx.recv().await {
+                // @TODO: At the moment this is a sync write, this is not ideal because we can only
+                // have so many blocking threads in the tokio threadpool.
+                writer.write_batch(&df)?;
+            }
+
+            writer.finish()?;
+            drop(writer);
+
+            file.sync_on_close(sink_options.sync_on_close)?;
+            file.close()?;
+
+            PolarsResult::Ok(())
+        });
+        join_handles.push(spawn(TaskPriority::Low, async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }));
+    }
+}
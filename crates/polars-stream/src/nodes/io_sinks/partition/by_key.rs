@@ -9,6 +9,7 @@ use polars_core::prelude::{Column, PlHashSet, PlIndexMap, row_encode};
 use polars_core::schema::SchemaRef;
 use polars_core::utils::arrow::buffer::Buffer;
 use polars_error::PolarsResult;
+use polars_io::partition::PartitionCommit;
 use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
@@ -19,7 +20,9 @@ use crate::async_executor::{AbortOnDropHandle, spawn};
 use crate::execute::StreamingExecutionState;
 use crate::morsel::SourceToken;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{
+    SinkSender, begin_partitions, finish_partitions, open_new_sink,
+};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode, parallelize_receive_task};
 use crate::nodes::{JoinHandle, Morsel, MorselSeq, TaskPriority};
@@ -47,6 +50,7 @@ pub struct PartitionByKeySinkNode {
     per_partition_sort_by: Option<PerPartitionSortBy>,
     written_partitions: Arc<OnceLock<DataFrame>>,
     finish_callback: Option<SinkFinishCallback>,
+    commit: Option<PartitionCommit>,
 }
 
 impl PartitionByKeySinkNode {
@@ -62,6 +66,7 @@ impl PartitionByKeySinkNode {
         include_key: bool,
         per_partition_sort_by: Option<PerPartitionSortBy>,
         finish_callback: Option<SinkFinishCallback>,
+        commit: Option<PartitionCommit>,
     ) -> Self {
         assert!(!key_cols.is_empty());
 
@@ -101,6 +106,7 @@ impl PartitionByKeySinkNode {
             per_partition_sort_by,
             written_partitions: Arc::new(OnceLock::new()),
             finish_callback,
+            commit,
         }
     }
 }
@@ -119,11 +125,9 @@ impl SinkNode for PartitionByKeySinkNode {
     }
 
     fn finish(&self) -> PolarsResult<()> {
-        if let Some(finish_callback) = &self.finish_callback {
-            let df = self.written_partitions.get().unwrap();
-            finish_callback.call(df.clone())?;
-        }
-        Ok(())
+        finish_partitions(self.commit.as_ref(), self.finish_callback.as_ref(), || {
+            self.written_partitions.get().unwrap().clone()
+        })
     }
 
     fn spawn_sink(
@@ -132,6 +136,10 @@ impl SinkNode for PartitionByKeySinkNode {
         state: &StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<polars_error::PolarsResult<()>>>,
     ) {
+        if !begin_partitions(self.commit.as_ref(), join_handles) {
+            return;
+        }
+
         let (pass_rxs, mut io_rx) = parallelize_receive_task::<Linearized>(
             join_handles,
             recv_port_rx,
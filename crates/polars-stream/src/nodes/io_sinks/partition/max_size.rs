@@ -7,6 +7,7 @@ use polars_core::config;
 use polars_core::prelude::Column;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
+use polars_io::partition::PartitionCommit;
 use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
@@ -18,7 +19,9 @@ use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{
+    SinkSender, begin_partitions, finish_partitions, open_new_sink,
+};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode};
 use crate::nodes::{JoinHandle, Morsel, TaskPriority};
@@ -46,6 +49,7 @@ pub struct MaxSizePartitionSinkNode {
     per_partition_sort_by: Option<PerPartitionSortBy>,
     partition_metrics: Arc<Mutex<Vec<Vec<WriteMetrics>>>>,
     finish_callback: Option<SinkFinishCallback>,
+    commit: Option<PartitionCommit>,
 }
 
 const DEFAULT_RETIRE_TASKS: usize = 1;
@@ -62,6 +66,7 @@ impl MaxSizePartitionSinkNode {
 
         per_partition_sort_by: Option<PerPartitionSortBy>,
         finish_callback: Option<SinkFinishCallback>,
+        commit: Option<PartitionCommit>,
     ) -> Self {
         assert!(max_size > 0);
         let num_retire_tasks =
@@ -83,6 +88,7 @@ impl MaxSizePartitionSinkNode {
             per_partition_sort_by,
             partition_metrics: Arc::new(Mutex::new(Vec::with_capacity(num_retire_tasks))),
             finish_callback,
+            commit,
         }
     }
 }
@@ -116,6 +122,10 @@ impl SinkNode for MaxSizePartitionSinkNode {
         state: &StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
+        if !begin_partitions(self.commit.as_ref(), join_handles) {
+            return;
+        }
+
         // Main Task -> Retire Tasks
         let (mut retire_tx, retire_rxs) = distributor_channel(self.num_retire_tasks, 1);
 
@@ -289,16 +299,14 @@ impl SinkNode for MaxSizePartitionSinkNode {
     }
 
     fn finish(&self) -> PolarsResult<()> {
-        if let Some(finish_callback) = &self.finish_callback {
+        finish_partitions(self.commit.as_ref(), self.finish_callback.as_ref(), || {
             let mut partition_metrics = self.partition_metrics.lock().unwrap();
             let partition_metrics =
                 std::mem::take::<Vec<Vec<WriteMetrics>>>(partition_metrics.as_mut())
                     .into_iter()
                     .flatten()
                     .collect();
-            let df = WriteMetrics::collapse_to_df(partition_metrics, &self.input_schema, None);
-            finish_callback.call(df)?;
-        }
-        Ok(())
+            WriteMetrics::collapse_to_df(partition_metrics, &self.input_schema, None)
+        })
     }
 }
@@ -2,14 +2,17 @@ use std::sync::Arc;
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use polars_core::prelude::{Column, DataType, SortMultipleOptions};
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{Column, SortMultipleOptions};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
+use polars_io::hive::hive_partition_dir_name;
+use polars_io::partition::PartitionCommit;
 use polars_plan::dsl::{
     FileType, PartitionTargetCallback, PartitionTargetCallbackResult, PartitionTargetContext,
-    SinkOptions, SinkTarget,
+    SinkFinishCallback, SinkOptions, SinkTarget,
 };
 use polars_utils::format_pl_smallstr;
 use polars_utils::plpath::PlPathRef;
@@ -22,7 +25,7 @@ use crate::execute::StreamingExecutionState;
 use crate::expression::StreamExpr;
 use crate::morsel::{MorselSeq, SourceToken};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
-use crate::nodes::{Morsel, TaskPriority};
+use crate::nodes::{JoinHandle, Morsel, TaskPriority};
 
 pub mod by_key;
 pub mod max_size;
@@ -60,10 +63,11 @@ pub fn get_create_new_fn(
             Ok(sink)
         }) as _,
         #[cfg(feature = "json")]
-        FileType::Json(_ndjson_writer_options) => Arc::new(move |_input_schema, target| {
+        FileType::Json(ndjson_writer_options) => Arc::new(move |_input_schema, target| {
             let sink = Box::new(super::json::NDJsonSinkNode::new(
                 target,
                 sink_options.clone(),
+                ndjson_writer_options,
                 cloud_options.clone(),
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
@@ -93,11 +97,23 @@ pub fn get_create_new_fn(
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
         }) as _,
+        #[cfg(feature = "orc")]
+        FileType::Orc(orc_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::orc::OrcSinkNode::new(
+                input_schema,
+                target,
+                sink_options.clone(),
+                orc_writer_options,
+                cloud_options.clone(),
+            )) as Box<dyn SinkNode + Send + Sync>;
+            Ok(sink)
+        }) as _,
         #[cfg(not(any(
             feature = "csv",
             feature = "parquet",
             feature = "json",
-            feature = "ipc"
+            feature = "ipc",
+            feature = "orc"
         )))]
         _ => {
             panic!("activate source feature")
@@ -105,6 +121,42 @@ pub fn get_create_new_fn(
     }
 }
 
+/// Check the target directory and create the staging directory of the commit, if any, once the
+/// sink starts executing. On failure the error is reported through a task and `false` is
+/// returned, in which case the sink spawns nothing else.
+fn begin_partitions(
+    commit: Option<&PartitionCommit>,
+    join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+) -> bool {
+    let Err(err) = commit.map_or(Ok(()), PartitionCommit::begin) else {
+        return true;
+    };
+    join_handles.push(spawn(TaskPriority::High, async move { Err(err) }));
+    false
+}
+
+/// Commit the staged files, if any, and pass the metrics of the written files to the finish
+/// callback.
+fn finish_partitions(
+    commit: Option<&PartitionCommit>,
+    finish_callback: Option<&SinkFinishCallback>,
+    metrics: impl FnOnce() -> DataFrame,
+) -> PolarsResult<()> {
+    if commit.is_none() && finish_callback.is_none() {
+        return Ok(());
+    }
+
+    let mut df = metrics();
+    if let Some(commit) = commit {
+        // The committed metrics refer to the files at their final location.
+        df = commit.commit(Some(df))?.unwrap();
+    }
+    if let Some(finish_callback) = finish_callback {
+        finish_callback.call(df)?;
+    }
+    Ok(())
+}
+
 enum SinkSender {
     Connector(connector::Sender<Morsel>),
     Distributor(distributor_channel::Sender<Morsel>),
@@ -134,15 +186,7 @@ fn default_by_key_file_path_cb(
 
     let mut file_path = String::new();
     for c in columns {
-        let name = c.name();
-        let value = c.head(Some(1)).strict_cast(&DataType::String)?;
-        let value = value.str().unwrap();
-        let value = value
-            .get(0)
-            .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-            .as_bytes();
-        let value = percent_encoding::percent_encode(value, polars_io::utils::URL_ENCODE_CHAR_SET);
-        write!(&mut file_path, "{name}={value}").unwrap();
+        file_path.push_str(&hive_partition_dir_name(c)?);
         file_path.push(separator);
     }
     write!(&mut file_path, "{in_part_idx}.{ext}").unwrap();
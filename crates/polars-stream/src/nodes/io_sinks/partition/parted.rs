@@ -8,6 +8,7 @@ use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::{AnyValue, Column, IntoColumn, PlHashSet};
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
+use polars_io::partition::PartitionCommit;
 use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
@@ -18,7 +19,9 @@ use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{
+    SinkSender, begin_partitions, finish_partitions, open_new_sink,
+};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode};
 use crate::nodes::{JoinHandle, Morsel, TaskPriority};
@@ -50,6 +53,7 @@ pub struct PartedPartitionSinkNode {
     per_partition_sort_by: Option<PerPartitionSortBy>,
     partition_metrics: Arc<Mutex<Vec<Vec<WriteMetrics>>>>,
     finish_callback: Option<SinkFinishCallback>,
+    commit: Option<PartitionCommit>,
 }
 
 const DEFAULT_RETIRE_TASKS: usize = 1;
@@ -66,6 +70,7 @@ impl PartedPartitionSinkNode {
         include_key: bool,
         per_partition_sort_by: Option<PerPartitionSortBy>,
         finish_callback: Option<SinkFinishCallback>,
+        commit: Option<PartitionCommit>,
     ) -> Self {
         assert!(!key_cols.is_empty());
 
@@ -105,6 +110,7 @@ impl PartedPartitionSinkNode {
             per_partition_sort_by,
             partition_metrics: Arc::new(Mutex::new(Vec::with_capacity(num_retire_tasks))),
             finish_callback,
+            commit,
         }
     }
 }
@@ -127,6 +133,10 @@ impl SinkNode for PartedPartitionSinkNode {
         state: &StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
+        if !begin_partitions(self.commit.as_ref(), join_handles) {
+            return;
+        }
+
         // Main Task -> Retire Tasks
         let (mut retire_tx, retire_rxs) = distributor_channel(self.num_retire_tasks, 1);
 
@@ -325,20 +335,18 @@ impl SinkNode for PartedPartitionSinkNode {
     }
 
     fn finish(&self) -> PolarsResult<()> {
-        if let Some(finish_callback) = &self.finish_callback {
+        finish_partitions(self.commit.as_ref(), self.finish_callback.as_ref(), || {
             let mut written_partitions = self.partition_metrics.lock().unwrap();
             let written_partitions =
                 std::mem::take::<Vec<Vec<WriteMetrics>>>(written_partitions.as_mut())
                     .into_iter()
                     .flatten()
                     .collect();
-            let df = WriteMetrics::collapse_to_df(
+            WriteMetrics::collapse_to_df(
                 written_partitions,
                 &self.sink_input_schema,
                 Some(&self.input_schema.try_project(self.key_cols.iter()).unwrap()),
-            );
-            finish_callback.call(df)?;
-        }
-        Ok(())
+            )
+        })
     }
 }
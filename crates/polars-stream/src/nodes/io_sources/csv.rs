@@ -7,6 +7,7 @@ use polars_core::schema::{SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_bail, polars_err, polars_warn};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
+use polars_io::metrics::IOMetrics;
 use polars_io::prelude::_csv_read_internal::{
     CountLines, NullValuesCompiled, cast_columns, find_starting_point, prepare_csv_schema,
     read_chunk,
@@ -14,8 +15,9 @@ use polars_io::prelude::_csv_read_internal::{
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    maybe_decode_bytes,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::StreamingDecompressor;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
@@ -76,6 +78,7 @@ pub mod builder {
                 options,
                 verbose,
                 cached_bytes: None,
+                io_metrics: None,
             };
 
             Box::new(reader) as Box<dyn FileReader>
@@ -90,8 +93,7 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
@@ -106,16 +108,22 @@ struct CsvFileReader {
     options: Arc<CsvReadOptions>,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     cached_bytes: Option<MemSlice>,
+    io_metrics: Option<Arc<IOMetrics>>,
     verbose: bool,
 }
 
 #[async_trait]
 impl FileReader for CsvFileReader {
     async fn initialize(&mut self) -> PolarsResult<()> {
-        let memslice = self
-            .scan_source
-            .as_scan_source_ref()
-            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+        let scan_source = self.scan_source.as_scan_source_ref();
+        let run_async = self.scan_source.run_async();
+
+        let memslice = match self.io_metrics.as_deref() {
+            Some(io_metrics) => {
+                scan_source.to_memslice_async_assume_latest_with_metrics(run_async, io_metrics)?
+            },
+            None => scan_source.to_memslice_async_assume_latest(run_async)?,
+        };
 
         // Note: We do not decompress in `initialize()`.
         self.cached_bytes = Some(memslice);
@@ -123,13 +131,17 @@ impl FileReader for CsvFileReader {
         Ok(())
     }
 
+    fn set_io_metrics(&mut self, io_metrics: Arc<IOMetrics>) {
+        self.io_metrics = Some(io_metrics);
+    }
+
     fn begin_read(
         &mut self,
         args: BeginReadArgs,
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
 
-        let memslice = self.get_bytes_maybe_decompress()?;
+        let (memslice, decompressor) = self.get_bytes_maybe_decompress()?;
 
         let BeginReadArgs {
             projected_schema,
@@ -165,19 +177,25 @@ impl FileReader for CsvFileReader {
             _ => {},
         }
 
-        // We need to infer the schema to get the columns of this file.
-        let infer_schema_length = if self.options.has_header {
-            Some(1)
+        let line_counter = CountLines::new(
+            self.options.parse_options.quote_char,
+            self.options.parse_options.eol_char,
+        );
+
+        // The decompressed prefix can end in the middle of a line, which must not be used for
+        // inference.
+        let infer_bytes = if decompressor.is_some() {
+            let (_, last_eol) = line_counter.count(&memslice);
+            memslice.slice(0..last_eol + 1)
         } else {
-            // If there is no header the line length may increase later in the
-            // file (https://github.com/pola-rs/polars/pull/21979).
-            self.options.infer_schema_length
+            memslice.clone()
         };
 
+        // We need to infer the schema to get the columns of this file.
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
-            &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
+            &polars_io::mmap::ReaderBytes::Owned(infer_bytes),
             &self.options.parse_options,
-            infer_schema_length,
+            self.infer_schema_length(),
             self.options.has_header,
             self.options.schema_overwrite.as_deref(),
             self.options.skip_rows,
@@ -265,14 +283,15 @@ impl FileReader for CsvFileReader {
         let (line_batch_tx, line_batch_receivers) =
             distributor_channel(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
 
+        let file_size = self.cached_bytes.as_ref().unwrap().len();
+
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
-                line_counter: CountLines::new(
-                    self.options.parse_options.quote_char,
-                    self.options.parse_options.eol_char,
-                ),
+                memslice,
+                decompressor,
+                file_size,
+                line_counter,
                 line_batch_tx,
                 options: self.options.clone(),
                 file_schema_len: inferred_schema.len(),
@@ -293,8 +312,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -312,19 +329,13 @@ impl FileReader for CsvFileReader {
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
                         let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                            chunk_reader.read_chunk(&bytes, n_lines, (offset, len), row_offset)?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -359,7 +370,7 @@ impl FileReader for CsvFileReader {
                             assert_eq!(slice, SLICE_ENDED);
 
                             let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
+                                v.count_lines(&bytes)?
                             } else {
                                 n_lines
                             };
@@ -407,22 +418,105 @@ impl FileReader for CsvFileReader {
 }
 
 impl CsvFileReader {
+    /// Number of rows used to infer the schema of this file.
+    fn infer_schema_length(&self) -> Option<usize> {
+        if self.options.has_header {
+            Some(1)
+        } else {
+            // If there is no header the line length may increase later in the
+            // file (https://github.com/pola-rs/polars/pull/21979).
+            self.options.infer_schema_length
+        }
+    }
+
+    /// Compressed files are not decompressed upfront. Instead, this returns a decompressed prefix
+    /// that contains enough rows to infer the schema, along with the decompressor for the rest
+    /// of the file. Files that are not UTF-8 encoded are fully decompressed and transcoded.
+    ///
     /// # Panics
     /// Panics if `self.cached_bytes` is None.
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    fn get_bytes_maybe_decompress(
+        &self,
+    ) -> PolarsResult<(MemSlice, Option<StreamingDecompressor>)> {
+        let memslice = self.cached_bytes.clone().unwrap();
+        let options = self.options.as_ref();
+        let encoding = options.parse_options.encoding;
+
+        let Some(mut decompressor) = StreamingDecompressor::try_new(memslice.clone())? else {
+            return Ok((decode_memslice(memslice, encoding)?, None));
+        };
+
+        let n_rows_needed = self.infer_schema_length().map(|n| {
+            n.saturating_add(options.skip_lines)
+                .saturating_add(options.skip_rows)
+                .saturating_add(options.skip_rows_after_header)
+                .saturating_add(usize::from(options.has_header))
+                // The last line of the prefix can be incomplete.
+                .saturating_add(1)
+        });
+
+        let line_counter = CountLines::new(
+            options.parse_options.quote_char,
+            options.parse_options.eol_char,
+        );
+        let alt_count_lines = CountLinesWithComments::opt_new(&options.parse_options);
+
         let mut out = vec![];
-        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
+        let mut prefix_len = 64 * 1024;
+
+        loop {
+            // Inference needs the full file if `n_rows_needed` is None.
+            let has_more =
+                decompressor.fill(&mut out, n_rows_needed.map_or(usize::MAX, |_| prefix_len))?;
+
+            if !has_more || !encoding.detect(&out).is_utf8() {
+                decompressor.fill(&mut out, usize::MAX)?;
+                return Ok((decode_memslice(MemSlice::from_vec(out), encoding)?, None));
+            }
+
+            let n_rows_needed = n_rows_needed.unwrap();
+
+            let n_rows = if let Some(v) = &alt_count_lines {
+                v.count_lines(&out)?
+            } else {
+                line_counter.count(&out).0
+            };
 
-        if !out.is_empty() {
-            self.cached_bytes = Some(MemSlice::from_vec(out));
+            if n_rows >= n_rows_needed {
+                break;
+            }
+
+            prefix_len *= 2;
+        }
+
+        if self.verbose {
+            eprintln!(
+                "[CsvFileReader]: streaming decompression, prefix size: {}",
+                out.len()
+            );
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        Ok((MemSlice::from_vec(out), Some(decompressor)))
+    }
+}
+
+/// Transcodes `memslice` to UTF-8 if it has a different encoding.
+fn decode_memslice(memslice: MemSlice, encoding: CsvEncoding) -> PolarsResult<MemSlice> {
+    let mut out = vec![];
+
+    if maybe_decode_bytes(&memslice, encoding, &mut out)?.as_ptr() == memslice.as_ptr() {
+        Ok(memslice)
+    } else {
+        Ok(MemSlice::from_vec(out))
     }
 }
 
 struct LineBatchSource {
+    /// The file, or a decompressed prefix of it if `decompressor` is set.
     memslice: MemSlice,
+    decompressor: Option<StreamingDecompressor>,
+    /// Size of the file as stored, i.e. before decompression.
+    file_size: usize,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -438,6 +532,8 @@ impl LineBatchSource {
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
             memslice,
+            decompressor,
+            file_size,
             line_counter,
             mut line_batch_tx,
             options,
@@ -468,9 +564,6 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
-
         let i = {
             let parse_options = options.parse_options.as_ref();
 
@@ -484,7 +577,7 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                &memslice,
                 quote_char,
                 eol_char,
                 file_schema_len,
@@ -496,14 +589,17 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
+        let mut pending_bytes = PendingBytes {
+            bytes: memslice.slice(i..memslice.len()),
+            decompressor,
+        };
 
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                std::cmp::min(file_size / (16 * num_pipelines), max_chunk_size)
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -514,23 +610,9 @@ impl LineBatchSource {
             std::cmp::max(chunk_size, min_chunk_size)
         };
 
-        loop {
-            if bytes.is_empty() {
-                break;
-            }
-
-            let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
-            let (count, position) = if count == 0 {
-                (1, bytes.len())
-            } else {
-                let pos = (position + 1).min(bytes.len()); // +1 for '\n'
-                (count, pos)
-            };
-
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
-
-            bytes = &bytes[position..];
-
+        while let Some((count, bytes_this_chunk)) =
+            pending_bytes.next_batch(&line_counter, &mut chunk_size)?
+        {
             let current_row_offset = *current_row_offset_ref;
             *current_row_offset_ref += count;
 
@@ -561,8 +643,6 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
-
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
@@ -583,6 +663,64 @@ impl LineBatchSource {
     }
 }
 
+/// Bytes that have not yet been split into line batches.
+struct PendingBytes {
+    bytes: MemSlice,
+    /// Set if there is more data to decompress after `bytes`.
+    decompressor: Option<StreamingDecompressor>,
+}
+
+impl PendingBytes {
+    /// Splits off the next batch of lines. Returns the number of lines according to CountLines
+    /// and the bytes of the batch, or `None` once all bytes have been consumed.
+    fn next_batch(
+        &mut self,
+        line_counter: &CountLines,
+        chunk_size: &mut usize,
+    ) -> PolarsResult<Option<(usize, MemSlice)>> {
+        loop {
+            if let Some(decompressor) = self.decompressor.as_mut() {
+                if self.bytes.len() < *chunk_size {
+                    let mut out = Vec::with_capacity(*chunk_size);
+                    out.extend_from_slice(&self.bytes);
+
+                    if !decompressor.fill(&mut out, *chunk_size)? {
+                        self.decompressor = None;
+                    }
+
+                    self.bytes = MemSlice::from_vec(out);
+                }
+            }
+
+            let bytes = &self.bytes;
+
+            if bytes.is_empty() {
+                return Ok(None);
+            }
+
+            let (count, position) = line_counter.find_next(bytes, chunk_size);
+
+            let (count, position) = if count == 0 {
+                if self.decompressor.is_some() {
+                    // The line continues past the decompressed bytes.
+                    *chunk_size = std::cmp::max(*chunk_size, bytes.len()) * 2;
+                    continue;
+                }
+
+                (1, bytes.len())
+            } else {
+                let pos = (position + 1).min(bytes.len()); // +1 for '\n'
+                (count, pos)
+            };
+
+            let out = bytes.slice(0..position);
+            self.bytes = bytes.slice(position..bytes.len());
+
+            return Ok(Some((count, out)));
+        }
+    }
+}
+
 #[derive(Default)]
 struct ChunkReader {
     reader_schema: SchemaRef,
@@ -0,0 +1,353 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::fixed_width::{
+    FixedWidthChunk, FixedWidthReadOptions, read_fixed_width_chunk, split_fixed_width_chunks,
+};
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::{
+    FileReaderOutputRecv, FileReaderOutputSend,
+};
+use super::multi_file_reader::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+
+/// Target size in bytes of the chunks that are parsed by a single pipeline.
+const CHUNK_SIZE: usize = 1 << 20;
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::fixed_width::FixedWidthReadOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::FixedWidthFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct FixedWidthReaderBuilder {
+        pub options: Arc<FixedWidthReadOptions>,
+    }
+
+    impl FileReaderBuilder for FixedWidthReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "fixed_width"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            _cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = FixedWidthFileReader {
+                scan_source: source,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct FixedWidthFileReader {
+    scan_source: ScanSource,
+    options: Arc<FixedWidthReadOptions>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    chunks: Arc<[FixedWidthChunk]>,
+    n_rows_in_file: usize,
+}
+
+/// A chunk (or part of a chunk) that should be parsed.
+struct ChunkMessage {
+    bytes: Range<usize>,
+    n_rows: usize,
+    /// Row offset of the first row in `slice`, relative to the start of the file.
+    row_offset: IdxSize,
+    /// Rows of the chunk to keep.
+    slice: Range<usize>,
+    morsel_seq_base: u64,
+}
+
+#[async_trait]
+impl FileReader for FixedWidthFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        let chunks: Arc<[FixedWidthChunk]> =
+            split_fixed_width_chunks(&memslice, &self.options, CHUNK_SIZE).into();
+        let n_rows_in_file = chunks.iter().map(|chunk| chunk.n_rows).sum();
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            chunks,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+        let InitializedState {
+            memslice,
+            chunks,
+            n_rows_in_file: _,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let n_rows_in_file = self._n_rows_in_file()?;
+        let pre_slice = pre_slice.map(|x| x.restrict_to_bounds(n_rows_in_file as usize));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                pre_slice.clone(),
+            ));
+        }
+
+        let schema = Arc::new(self.options.schema()?);
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(schema.clone());
+        }
+
+        let (mut morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        if pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            return Ok((
+                morsel_rx,
+                spawn(TaskPriority::Low, std::future::ready(Ok(()))),
+            ));
+        }
+
+        let projection = projected_schema
+            .iter_names()
+            .filter_map(|name| schema.index_of(name))
+            .collect::<Arc<[usize]>>();
+
+        let max_morsel_size = get_ideal_morsel_size();
+        let messages = plan_chunks(
+            &chunks,
+            pre_slice.map(Range::<usize>::from),
+            max_morsel_size,
+        );
+
+        if verbose {
+            eprintln!(
+                "[FixedWidthFileReader]: project: {} / {}, chunks: {} / {}",
+                projection.len(),
+                schema.len(),
+                messages.len(),
+                chunks.len(),
+            );
+        }
+
+        let (mut chunk_tx, chunk_rxs) =
+            distributor_channel::<ChunkMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            for message in messages {
+                if chunk_tx.send(message).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        let decoder_handles = chunk_rxs
+            .into_iter()
+            .zip(morsel_senders.drain(..))
+            .map(|(mut rx, mut morsel_tx)| {
+                let memslice = memslice.clone();
+                let options = self.options.clone();
+                let projection = projection.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(ChunkMessage {
+                        bytes,
+                        n_rows,
+                        row_offset,
+                        slice,
+                        morsel_seq_base,
+                    }) = rx.recv().await
+                    {
+                        let mut df = read_fixed_width_chunk(
+                            &memslice[bytes],
+                            &options,
+                            &projection,
+                            n_rows,
+                        )?
+                        .slice(slice.start as i64, slice.len());
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = offset.saturating_add(row_offset);
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel = Morsel::new(
+                                df.slice((i * max_morsel_size) as i64, max_morsel_size),
+                                MorselSeq::new(morsel_seq_base + i as u64),
+                                source_token.clone(),
+                            );
+
+                            if morsel_tx.send_morsel(morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(self.options.schema()?))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        self._n_rows_in_file()
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        self._n_rows_in_file().map(Some)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self._n_rows_in_file()?,
+            pre_slice,
+        ))
+    }
+}
+
+impl FixedWidthFileReader {
+    fn _n_rows_in_file(&self) -> PolarsResult<IdxSize> {
+        let n_rows = self.init_data.as_ref().unwrap().n_rows_in_file;
+        IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "fixed-width file", size = n_rows))
+    }
+}
+
+/// Determine which (parts of) chunks need to be parsed.
+fn plan_chunks(
+    chunks: &[FixedWidthChunk],
+    slice: Option<Range<usize>>,
+    max_morsel_size: usize,
+) -> Vec<ChunkMessage> {
+    let slice = slice.unwrap_or(0..usize::MAX);
+
+    let mut out = Vec::new();
+    let mut morsel_seq: u64 = 0;
+    let mut chunk_start = 0;
+
+    for chunk in chunks {
+        let chunk_range = chunk_start..chunk_start + chunk.n_rows;
+        chunk_start += chunk.n_rows;
+
+        if chunk_range.start >= slice.end {
+            break;
+        }
+
+        let start = chunk_range.start.max(slice.start);
+        let end = chunk_range.end.min(slice.end);
+        if start >= end {
+            continue;
+        }
+
+        out.push(ChunkMessage {
+            bytes: chunk.bytes.clone(),
+            n_rows: chunk.n_rows,
+            row_offset: start as IdxSize,
+            slice: start - chunk_range.start..end - chunk_range.start,
+            morsel_seq_base: morsel_seq,
+        });
+        morsel_seq += (end - start).div_ceil(max_morsel_size) as u64;
+    }
+
+    out
+}
@@ -7,13 +7,16 @@ use arrow::array::TryExtend;
 use async_trait::async_trait;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::DataType;
-use polars_core::schema::{Schema, SchemaExt};
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_core::utils::arrow::io::ipc::read::{
     FileMetadata, ProjectionInfo, get_row_count_from_blocks, prepare_projection, read_file_metadata,
 };
 use polars_error::{ErrString, PolarsError, PolarsResult, polars_err};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
+use polars_io::ipc::statistics::RecordBatchStatistics;
+use polars_io::metrics::IOMetrics;
+use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::{ScanSource, ScanSourceRef};
 use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
@@ -60,7 +63,7 @@ pub mod builder {
         fn reader_capabilities(&self) -> ReaderCapabilities {
             use ReaderCapabilities as RC;
 
-            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE | RC::PARTIAL_FILTER
         }
 
         fn build_file_reader(
@@ -87,6 +90,7 @@ pub mod builder {
                 cloud_options,
                 metadata,
                 verbose,
+                io_metrics: None,
                 init_data: None,
             };
 
@@ -106,6 +110,7 @@ struct IpcFileReader {
     cloud_options: Option<Arc<CloudOptions>>,
     metadata: Option<Arc<FileMetadata>>,
     verbose: bool,
+    io_metrics: Option<Arc<IOMetrics>>,
 
     init_data: Option<InitializedState>,
 }
@@ -199,7 +204,7 @@ impl FileReader for IpcFileReader {
             projected_schema,
             row_index,
             pre_slice: pre_slice_arg,
-            predicate: None,
+            predicate,
             cast_columns_policy: _,
             num_pipelines,
             callbacks:
@@ -208,10 +213,7 @@ impl FileReader for IpcFileReader {
                     n_rows_in_file_tx,
                     row_position_on_end_tx,
                 },
-        } = args
-        else {
-            panic!("unsupported args: {:?}", &args)
-        };
+        } = args;
 
         let file_schema_pl = std::cell::LazyCell::new(|| {
             Arc::new(Schema::from_arrow_schema(file_metadata.schema.as_ref()))
@@ -309,6 +311,40 @@ impl FileReader for IpcFileReader {
         let projection_info: Option<ProjectionInfo> =
             projection_indices.map(|indices| prepare_projection(&file_metadata.schema, indices));
 
+        // Skipping record batches changes the row positions, so this is only done if no slice or
+        // row index has to be applied.
+        let skip_blocks = match &predicate {
+            Some(predicate)
+                if row_index.is_none()
+                    && pre_slice_arg.is_none()
+                    && !projected_schema.is_empty() =>
+            {
+                skip_record_batches(&file_metadata, predicate)?
+            },
+            _ => None,
+        };
+
+        if let Some(io_metrics) = &self.io_metrics {
+            let n_pruned = skip_blocks
+                .as_ref()
+                .map_or(0, |x| x.iter().filter(|&&skip| skip).count());
+            IOMetrics::add(&io_metrics.row_groups_pruned, n_pruned);
+            IOMetrics::add(
+                &io_metrics.row_groups_read,
+                file_metadata.blocks.len() - n_pruned,
+            );
+        }
+
+        if verbose {
+            if let Some(skip_blocks) = &skip_blocks {
+                eprintln!(
+                    "[IpcFileReader]: skipping {} / {} record batches based on statistics",
+                    skip_blocks.iter().filter(|&&skip| skip).count(),
+                    skip_blocks.len()
+                );
+            }
+        }
+
         // Split size for morsels.
         let max_morsel_size = get_max_morsel_size();
 
@@ -372,6 +408,7 @@ impl FileReader for IpcFileReader {
                 let metadata = metadata.clone();
                 let row_index = row_index.clone();
                 let projection_info = projection_info.clone();
+                let skip_blocks = skip_blocks.clone();
                 AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
                     // Amortize allocations.
                     let mut data_scratch = Vec::new();
@@ -417,7 +454,14 @@ impl FileReader for IpcFileReader {
                             // Create the DataFrame with the appropriate schema and append all the record
                             // batches to it. This will perform schema validation as well.
                             let mut df = DataFrame::empty_with_schema(&pl_schema);
-                            df.try_extend(reader.by_ref().take(block_range.len()))?;
+                            if let Some(skip_blocks) = &skip_blocks {
+                                for block in block_range.clone().filter(|&b| !skip_blocks[b]) {
+                                    reader.set_current_block(block);
+                                    df.try_extend(reader.by_ref().take(1))?;
+                                }
+                            } else {
+                                df.try_extend(reader.by_ref().take(block_range.len()))?;
+                            }
 
                             (data_scratch, message_scratch) = reader.take_scratches();
                             df = df.slice(slice.start as i64, slice.len());
@@ -603,6 +647,17 @@ impl FileReader for IpcFileReader {
         ))
     }
 
+    fn set_io_metrics(&mut self, io_metrics: Arc<IOMetrics>) {
+        self.io_metrics = Some(io_metrics);
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        let file_metadata = &self.init_data.as_ref().unwrap().file_metadata;
+        Ok(Arc::new(Schema::from_arrow_schema(
+            file_metadata.schema.as_ref(),
+        )))
+    }
+
     async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
         self._n_rows_in_file()
     }
@@ -645,3 +700,37 @@ impl IpcFileReader {
         ))
     }
 }
+
+/// Determine which record batches can be skipped based on the statistics embedded in the file.
+///
+/// Returns `None` if the file has no statistics or no record batch can be skipped.
+fn skip_record_batches(
+    metadata: &FileMetadata,
+    predicate: &ScanIOPredicate,
+) -> PolarsResult<Option<Arc<[bool]>>> {
+    let Some(sbp) = &predicate.skip_batch_predicate else {
+        return Ok(None);
+    };
+    let Some(custom_metadata) = &metadata.custom_schema_metadata else {
+        return Ok(None);
+    };
+    let Some(statistics) = RecordBatchStatistics::from_custom_metadata(custom_metadata)? else {
+        return Ok(None);
+    };
+
+    if statistics.num_batches() != metadata.blocks.len() {
+        return Ok(None);
+    }
+
+    let skip_blocks = (0..statistics.num_batches())
+        .map(|i| {
+            sbp.can_skip_batch(
+                statistics.batch_len(i)?,
+                &predicate.live_columns,
+                statistics.batch_statistics(i)?,
+            )
+        })
+        .collect::<PolarsResult<Arc<[bool]>>>()?;
+
+    Ok(skip_blocks.contains(&true).then_some(skip_blocks))
+}
@@ -0,0 +1,296 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::io::ipc::read::{
+    StreamMetadata, StreamReader, StreamState, get_stream_row_count, read_stream_metadata,
+};
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::{
+    FileReaderOutputRecv, FileReaderOutputSend,
+};
+use super::multi_file_reader::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::async_executor::{JoinHandle, TaskPriority, spawn};
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::IpcStreamFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct IpcStreamReaderBuilder {}
+
+    impl FileReaderBuilder for IpcStreamReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "ipc_stream"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            // The stream has no footer, so a negative slice would require reading it twice.
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            _cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = IpcStreamFileReader {
+                scan_source: source,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct IpcStreamFileReader {
+    scan_source: ScanSource,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<StreamMetadata>,
+    /// Offset of the first message after the schema.
+    messages_offset: usize,
+    n_rows_in_file: Option<IdxSize>,
+}
+
+#[async_trait]
+impl FileReader for IpcStreamFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        let mut cursor = Cursor::new(memslice.as_ref());
+        let metadata = Arc::new(read_stream_metadata(&mut cursor)?);
+        let messages_offset = cursor.position() as usize;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata,
+            messages_offset,
+            n_rows_in_file: None,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let slice_range = match pre_slice.clone() {
+            None => None,
+            Some(Slice::Positive { offset, len }) => Some(offset..offset.saturating_add(len)),
+            Some(Slice::Negative { .. }) => unreachable!(),
+        };
+
+        // Counting rows requires a pass over the message headers, only do so when requested.
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(self._n_rows_in_file()?);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                self._n_rows_in_file()?,
+                pre_slice.clone(),
+            ));
+        }
+
+        let InitializedState {
+            memslice,
+            metadata,
+            messages_offset,
+            n_rows_in_file: _,
+        } = self.init_data.clone().unwrap();
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(&metadata.schema)));
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        if slice_range.as_ref().is_some_and(|x| x.is_empty()) {
+            return Ok((
+                morsel_rx,
+                spawn(TaskPriority::Low, std::future::ready(Ok(()))),
+            ));
+        }
+
+        // Columns are decoded in the order of the projected schema. Projected columns that are
+        // missing from the file are skipped, they are handled by the multi-file reader. If nothing
+        // is decoded we still decode the first column to learn the height of every batch.
+        let mut projection = projected_schema
+            .iter_names()
+            .filter_map(|name| metadata.schema.index_of(name))
+            .collect::<Vec<_>>();
+        let project_nothing = projection.is_empty();
+        if project_nothing && !metadata.schema.is_empty() {
+            projection.push(0);
+        }
+        let projection = Some(projection).filter(|x| !x.is_empty());
+
+        if verbose {
+            eprintln!(
+                "[IpcStreamFileReader]: project: {} / {}, pre_slice: {:?}",
+                projected_schema.len(),
+                metadata.schema.len(),
+                pre_slice,
+            );
+        }
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        let handle = spawn(TaskPriority::Low, async move {
+            let slice = slice_range.unwrap_or(0..usize::MAX);
+            let reader = StreamReader::new(
+                Cursor::new(&memslice[messages_offset..]),
+                metadata.as_ref().clone(),
+                projection,
+            );
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            let mut row_offset: usize = 0;
+            let mut morsel_seq: u64 = 0;
+
+            for state in reader {
+                let StreamState::Some(batch) = state? else {
+                    break;
+                };
+
+                let batch_rows = row_offset..row_offset + batch.height();
+                row_offset = batch_rows.end;
+
+                let rows = batch_rows.start.max(slice.start)..batch_rows.end.min(slice.end);
+
+                if rows.is_empty() {
+                    if batch_rows.end >= slice.end {
+                        break;
+                    }
+                    continue;
+                }
+
+                let mut df = if project_nothing {
+                    DataFrame::empty_with_height(rows.len())
+                } else {
+                    DataFrame::from(batch).slice((rows.start - batch_rows.start) as i64, rows.len())
+                };
+
+                if let Some(RowIndex { name, offset }) = &row_index {
+                    let offset = offset.saturating_add(rows.start as IdxSize);
+                    df = df.with_row_index(name.clone(), Some(offset))?;
+                }
+
+                for i in 0..df.height().div_ceil(max_morsel_size) {
+                    let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                    let morsel =
+                        Morsel::new(morsel_df, MorselSeq::new(morsel_seq), source_token.clone());
+                    morsel_seq += 1;
+
+                    if morsel_sender.send_morsel(morsel).await.is_err() {
+                        return Ok(());
+                    }
+                }
+
+                if rows.end >= slice.end {
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, handle))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        self._n_rows_in_file()
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self._n_rows_in_file()?,
+            pre_slice,
+        ))
+    }
+}
+
+impl IpcStreamFileReader {
+    fn _n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        let InitializedState {
+            memslice,
+            metadata: _,
+            messages_offset: _,
+            n_rows_in_file,
+        } = self.init_data.as_mut().unwrap();
+
+        if n_rows_in_file.is_none() {
+            let n_rows = get_stream_row_count(&mut Cursor::new(memslice.as_ref()))?;
+
+            let n_rows = IdxSize::try_from(n_rows)
+                .map_err(|_| polars_err!(bigidx, ctx = "ipc stream", size = n_rows))?;
+
+            *n_rows_in_file = Some(n_rows);
+        }
+
+        Ok(n_rows_in_file.unwrap())
+    }
+}
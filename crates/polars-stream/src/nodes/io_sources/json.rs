@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::json::JsonScanOptions;
+use polars_io::json::record_path::{JsonRecords, RecordReader, read_records};
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::{
+    FileReaderOutputRecv, FileReaderOutputSend,
+};
+use super::multi_file_reader::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::json::JsonScanOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::JsonFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct JsonReaderBuilder {
+        pub options: Arc<JsonScanOptions>,
+    }
+
+    impl FileReaderBuilder for JsonReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "json"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            _cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = JsonFileReader {
+                scan_source: source,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+                memslice: None,
+                n_rows_in_file: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct JsonFileReader {
+    scan_source: ScanSource,
+    options: Arc<JsonScanOptions>,
+    verbose: bool,
+
+    /// The possibly compressed document.
+    memslice: Option<MemSlice>,
+    n_rows_in_file: Option<IdxSize>,
+}
+
+/// A batch of consecutive records that should be parsed into a single morsel.
+struct BatchMessage {
+    records: JsonRecords,
+    /// Position of the first record of the batch in the file.
+    row_offset: usize,
+    morsel_seq: MorselSeq,
+}
+
+#[async_trait]
+impl FileReader for JsonFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.memslice.is_some() {
+            return Ok(());
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        self.memslice = Some(memslice);
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let memslice = self.memslice.clone().unwrap();
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let record_range = match pre_slice {
+            None => 0..usize::MAX,
+            Some(Slice::Positive { offset, len }) => offset..offset.saturating_add(len),
+            Some(Slice::Negative { .. }) => panic!("unsupported args: negative pre-slice"),
+        };
+
+        // The schema is resolved during planning. Missing fields are parsed as null, so the
+        // projected schema can be used as-is.
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(projected_schema.clone());
+        }
+
+        let (mut morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        if verbose {
+            eprintln!(
+                "[JsonFileReader]: project: {}, record_range: {:?}",
+                projected_schema.len(),
+                &record_range,
+            );
+        }
+
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<BatchMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let record_path = self.options.record_path.clone();
+        let needs_n_rows_in_file = n_rows_in_file_tx.is_some();
+
+        // The records are read sequentially, only the parsing of the records is parallel.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let mut reader = RecordReader::from_memslice(memslice, &record_path)?;
+            let mut position = reader.skip(record_range.start)?;
+            let mut morsel_seq = MorselSeq::default();
+
+            while position < record_range.end {
+                let mut records = JsonRecords::default();
+                let n = max_morsel_size.min(record_range.end - position);
+                let n = reader.read(n, &mut records)?;
+                if n == 0 {
+                    break;
+                }
+
+                let message = BatchMessage {
+                    records,
+                    row_offset: position,
+                    morsel_seq,
+                };
+                position += n;
+                morsel_seq = morsel_seq.successor();
+
+                if batch_tx.send(message).await.is_err() {
+                    break;
+                }
+            }
+
+            let n_rows_in_file = if needs_n_rows_in_file {
+                Some(position + reader.skip(usize::MAX)?)
+            } else {
+                None
+            };
+
+            PolarsResult::Ok((position, n_rows_in_file))
+        }));
+
+        let decoder_handles = batch_rxs
+            .into_iter()
+            .zip(morsel_senders.drain(..))
+            .map(|(mut rx, mut morsel_tx)| {
+                let projected_schema = projected_schema.clone();
+                let ignore_errors = self.options.ignore_errors;
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(BatchMessage {
+                        records,
+                        row_offset,
+                        morsel_seq,
+                    }) = rx.recv().await
+                    {
+                        let mut df = read_records(&records, &projected_schema, ignore_errors)?;
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = offset.saturating_add(row_offset as IdxSize);
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        let morsel = Morsel::new(df, morsel_seq, source_token.clone());
+
+                        if morsel_tx.send_morsel(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                let (row_position_on_end, n_rows_in_file) = walker_handle.await?;
+
+                if verbose {
+                    eprintln!(
+                        "[JsonFileReader]: row_position_on_end: {row_position_on_end}, \
+                        n_rows_in_file: {n_rows_in_file:?}"
+                    );
+                }
+
+                if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                    _ = row_position_on_end_tx.try_send(to_idx_size(row_position_on_end)?);
+                }
+
+                if let (Some(mut n_rows_in_file_tx), Some(n_rows_in_file)) =
+                    (n_rows_in_file_tx, n_rows_in_file)
+                {
+                    _ = n_rows_in_file_tx.try_send(to_idx_size(n_rows_in_file)?);
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        if let Some(n_rows_in_file) = self.n_rows_in_file {
+            return Ok(n_rows_in_file);
+        }
+
+        let n_rows_in_file = to_idx_size(self.count_records(usize::MAX)?)?;
+        self.n_rows_in_file = Some(n_rows_in_file);
+        Ok(n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        match pre_slice {
+            Some(Slice::Positive { offset, len }) if self.n_rows_in_file.is_none() => {
+                to_idx_size(self.count_records(offset.saturating_add(len))?)
+            },
+            _ => Ok(calc_row_position_after_slice(
+                self.n_rows_in_file().await?,
+                pre_slice,
+            )),
+        }
+    }
+}
+
+impl JsonFileReader {
+    /// Counts the records of the file, up to `limit`, without parsing them.
+    fn count_records(&self, limit: usize) -> PolarsResult<usize> {
+        let memslice = self.memslice.clone().unwrap();
+        RecordReader::from_memslice(memslice, &self.options.record_path)?.skip(limit)
+    }
+}
+
+fn to_idx_size(n_rows: usize) -> PolarsResult<IdxSize> {
+    IdxSize::try_from(n_rows).map_err(|_| polars_err!(bigidx, ctx = "json file", size = n_rows))
+}
@@ -3,9 +3,17 @@ pub mod multi_file_reader;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "csv")]
+pub mod fixed_width;
 #[cfg(feature = "ipc")]
 pub mod ipc;
+#[cfg(feature = "ipc")]
+pub mod ipc_stream;
+#[cfg(feature = "json")]
+pub mod json;
 #[cfg(feature = "json")]
 pub mod ndjson;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
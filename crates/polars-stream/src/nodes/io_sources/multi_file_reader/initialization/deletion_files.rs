@@ -3,12 +3,17 @@ use std::sync::Arc;
 use arrow::bitmap::bitmask::BitMask;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{BooleanChunked, ChunkAgg, DataType, PlIndexMap};
+use polars_core::prelude::row_encode::encode_rows_unordered;
+use polars_core::prelude::{
+    BooleanChunked, ChunkAgg, ChunkCompareEq, Column, DataType, PlHashSet, PlIndexMap, SchemaExt,
+};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_error::{PolarsResult, feature_gated};
 use polars_io::cloud::CloudOptions;
-use polars_plan::dsl::deletion::DeletionFilesList;
+#[cfg(feature = "delta")]
+use polars_plan::dsl::deletion::DeltaDeletionVector;
+use polars_plan::dsl::deletion::{DeletionFilesList, IcebergDeleteFiles};
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -34,6 +39,17 @@ pub enum DeletionFilesProvider {
         reader_builder: ParquetReaderBuilder,
         projected_schema: SchemaRef,
     },
+
+    #[cfg(feature = "delta")]
+    DeltaDeletionVector {
+        vectors: Arc<PlIndexMap<usize, DeltaDeletionVector>>,
+    },
+
+    #[cfg(feature = "parquet")]
+    IcebergDeletes {
+        deletes: Arc<PlIndexMap<usize, IcebergDeleteFiles>>,
+        position_delete_reader_builder: ParquetReaderBuilder,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -47,23 +63,20 @@ impl DeletionFilesProvider {
                 "parquet",
                 Self::IcebergPositionDelete {
                     paths,
-                    reader_builder: ParquetReaderBuilder {
-                        first_metadata: None,
-                        options: Arc::new(polars_io::prelude::ParquetOptions {
-                            schema: Some(Arc::new(Schema::from_iter([
-                                (PlSmallStr::from_static("file_path"), DataType::String),
-                                (PlSmallStr::from_static("pos"), DataType::Int64),
-                            ]))),
-
-                            parallel: polars_io::prelude::ParallelStrategy::Auto,
-                            low_memory: false,
-                            use_statistics: false,
-                        }),
-                    },
-                    projected_schema: Arc::new(Schema::from_iter([
-                        (PlSmallStr::from_static("file_path"), DataType::String),
-                        (PlSmallStr::from_static("pos"), DataType::Int64),
-                    ])),
+                    reader_builder: parquet_reader_builder(Some(iceberg_position_delete_schema())),
+                    projected_schema: iceberg_position_delete_schema(),
+                }
+            ),
+            DeletionFilesList::DeltaDeletionVector(vectors) => {
+                feature_gated!("delta", Self::DeltaDeletionVector { vectors })
+            },
+            DeletionFilesList::IcebergDeletes(deletes) => feature_gated!(
+                "parquet",
+                Self::IcebergDeletes {
+                    deletes,
+                    position_delete_reader_builder: parquet_reader_builder(Some(
+                        iceberg_position_delete_schema()
+                    )),
                 }
             ),
         }
@@ -72,6 +85,7 @@ impl DeletionFilesProvider {
     pub fn spawn_row_deletions_init(
         &self,
         scan_source_idx: usize,
+        scan_source: &ScanSource,
         cloud_options: Option<Arc<CloudOptions>>,
         num_pipelines: usize,
         verbose: bool,
@@ -95,18 +109,11 @@ impl DeletionFilesProvider {
                     )
                 }
 
-                // We create the readers and immediately spawn off tasks to initialize all of them.
-                let file_readers = paths
+                // We immediately spawn off tasks to load all of the deletion files.
+                let file_loads = paths
                     .iter()
                     .enumerate()
                     .map(|(deletion_file_idx, path)| {
-                        let source = ScanSource::Path(PlPath::new(path));
-                        let mut reader = reader_builder.build_file_reader(
-                            source.clone(),
-                            cloud_options.clone(),
-                            deletion_file_idx,
-                        );
-
                         if verbose {
                             eprintln!(
                                 "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
@@ -115,18 +122,16 @@ impl DeletionFilesProvider {
                             )
                         }
 
-                        AbortOnDropHandle::new(async_executor::spawn(
-                            TaskPriority::Low,
-                            async move {
-                                reader.initialize().await?;
-                                PolarsResult::Ok(reader)
-                            },
-                        ))
+                        spawn_load_parquet_file(
+                            reader_builder,
+                            ScanSource::Path(PlPath::new(path)),
+                            projected_schema.clone(),
+                            cloud_options.clone(),
+                            num_pipelines,
+                        )
                     })
                     .collect::<Vec<_>>();
 
-                let projected_schema = projected_schema.clone();
-
                 // We choose to load deletion files immediately during the initialization phase -
                 // the main driver loop of the multi file may need to serially `.await` on this
                 // between initializing readers when there is a slice.
@@ -135,104 +140,256 @@ impl DeletionFilesProvider {
                 // should be fine as the size of the data should not be too big.
                 let handle =
                     AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                        let handles = file_readers
-                            .into_iter()
-                            .map(|init_fut| {
-                                let begin_read_args = BeginReadArgs {
-                                    projected_schema: projected_schema.clone(),
-                                    row_index: None,
-                                    pre_slice: None,
-                                    predicate: None,
-                                    cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
-                                    num_pipelines,
-                                    callbacks: FileReaderCallbacks {
-                                        file_schema_tx: None,
-                                        n_rows_in_file_tx: None,
-                                        row_position_on_end_tx: None,
-                                    },
-                                };
-
-                                AbortOnDropHandle::new(async_executor::spawn(
-                                    TaskPriority::Low,
-                                    async move {
-                                        let mut reader = init_fut.await?;
-
-                                        let (mut rx, handle) =
-                                            reader.begin_read(begin_read_args)?;
-
-                                        let mut dfs = vec![];
-
-                                        while let Ok(morsel) = rx.recv().await {
-                                            dfs.push(morsel.into_df());
-                                        }
-
-                                        handle.await?;
-
-                                        let df = accumulate_dataframes_vertical_unchecked(dfs);
-
-                                        // Some quick testing on AWS Athena showed that it doesn't
-                                        // write deletion files that reference multiple distinct
-                                        // file paths, so we don't handle that for now.
-                                        assert!(
-                                            df.column("file_path")?.n_unique()? <= 1,
-                                            "assertion failed: iceberg position delete file: \
-                                            n_unique(data_file_paths) <= 1. \
-                                            This is a bug, please open an issue"
-                                        );
-
-                                        let positions_col = df.column("pos")?.clone();
-                                        let max_idx = usize::try_from(
-                                            positions_col
-                                                .as_materialized_series_maintain_scalar()
-                                                .i64()
-                                                .unwrap()
-                                                .max()
-                                                .unwrap_or(0),
-                                        )
-                                        .unwrap();
-
-                                        PolarsResult::Ok((positions_col, max_idx))
-                                    },
-                                ))
-                            })
-                            .collect::<Vec<_>>();
-
-                        let mut position_columns = Vec::with_capacity(handles.len());
-                        let mut filter_mask_len: usize = 0;
-
-                        for handle in handles {
-                            let (positions_col, max_idx) = handle.await?;
-                            filter_mask_len = filter_mask_len.max(max_idx.saturating_add(1));
-                            position_columns.push(positions_col);
+                        let mut position_columns = Vec::with_capacity(file_loads.len());
+
+                        for handle in file_loads {
+                            let df = handle.await?;
+
+                            // Some quick testing on AWS Athena showed that it doesn't
+                            // write deletion files that reference multiple distinct
+                            // file paths, so we don't handle that for now.
+                            assert!(
+                                df.column("file_path")?.n_unique()? <= 1,
+                                "assertion failed: iceberg position delete file: \
+                                n_unique(data_file_paths) <= 1. \
+                                This is a bug, please open an issue"
+                            );
+
+                            position_columns.push(df.column("pos")?.clone());
+                        }
+
+                        let mask = iceberg_position_deletes_to_mask(&position_columns, 0)?;
+                        let mask = ExternalFilterMask::IcebergPositionDelete { mask };
+
+                        if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+                            let max_index = mask.len().checked_sub(1);
+
+                            eprintln!(
+                                "[DeletionFilesProvider[Iceberg]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}, \
+                                max_index: {max_index:?}",
+                            )
+                        }
+
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
+
+            #[cfg(feature = "parquet")]
+            Self::IcebergDeletes {
+                deletes,
+                position_delete_reader_builder,
+            } => {
+                let delete_files = deletes.get(&scan_source_idx)?;
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {}, \
+                        {} position delete files, {} equality delete files",
+                        scan_source_idx,
+                        delete_files.position_deletes.len(),
+                        delete_files.equality_deletes.len(),
+                    )
+                }
+
+                let position_delete_loads = delete_files
+                    .position_deletes
+                    .iter()
+                    .map(|path| {
+                        spawn_load_parquet_file(
+                            position_delete_reader_builder,
+                            ScanSource::Path(PlPath::new(path)),
+                            iceberg_position_delete_schema(),
+                            cloud_options.clone(),
+                            num_pipelines,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let equality_deletes = delete_files.equality_deletes.clone();
+                let scan_source = scan_source.clone();
+                let data_file_path = delete_files.data_file_path.clone();
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let mut position_columns = Vec::with_capacity(position_delete_loads.len());
+
+                        for handle in position_delete_loads {
+                            let df = handle.await?;
+                            // A position delete file can reference multiple data files.
+                            let mask = df
+                                .column("file_path")?
+                                .as_materialized_series()
+                                .equal(data_file_path.as_str())?;
+
+                            position_columns.push(df.column("pos")?.filter(&mask)?);
                         }
 
-                        let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
+                        let mut mask_len = 0;
+                        let mut equality_deleted_positions = vec![];
+
+                        if !equality_deletes.is_empty() {
+                            // The columns of the delete files and the data file are resolved by
+                            // their field IDs, as they can have been renamed since the files were
+                            // written.
+                            let equality_delete_loads = equality_deletes
+                                .iter()
+                                .map(|delete| {
+                                    let load = spawn_load_parquet_file(
+                                        &field_id_reader_builder(
+                                            delete.schema.clone(),
+                                            &delete.equality_ids,
+                                        )?,
+                                        ScanSource::Path(PlPath::new(&delete.path)),
+                                        delete.schema.clone(),
+                                        cloud_options.clone(),
+                                        num_pipelines,
+                                    );
+
+                                    Ok((delete.schema.clone(), load))
+                                })
+                                .collect::<PolarsResult<Vec<_>>>()?;
+
+                            // Equality deletes are resolved to row positions by loading the
+                            // equality columns of the data file.
+                            let mut data_schema = Schema::default();
+                            let mut data_field_ids = vec![];
+
+                            for delete in equality_deletes.iter() {
+                                for (field, id) in
+                                    delete.schema.iter_fields().zip(delete.equality_ids.iter())
+                                {
+                                    if !data_schema.contains(&field.name) {
+                                        data_schema.insert(field.name, field.dtype);
+                                        data_field_ids.push(*id);
+                                    }
+                                }
+                            }
 
-                        for c in position_columns {
-                            for idx in c.as_materialized_series_maintain_scalar().i64().unwrap() {
-                                let idx = usize::try_from(idx.unwrap()).unwrap();
-                                filter_mask.set(idx, false);
+                            let data_schema = Arc::new(data_schema);
+                            let data_df = spawn_load_parquet_file(
+                                &field_id_reader_builder(data_schema.clone(), &data_field_ids)?,
+                                scan_source,
+                                data_schema,
+                                cloud_options,
+                                num_pipelines,
+                            )
+                            .await?;
+                            mask_len = data_df.height();
+
+                            for (schema, handle) in equality_delete_loads {
+                                let delete_df = handle.await?;
+                                let names = schema.iter_names_cloned().collect::<Vec<_>>();
+
+                                let delete_rows = encode_rows_unordered(
+                                    delete_df.select_columns(names.iter().cloned())?.as_slice(),
+                                )?;
+                                let delete_rows = delete_rows
+                                    .downcast_iter()
+                                    .flat_map(|arr| arr.values_iter())
+                                    .collect::<PlHashSet<_>>();
+
+                                let data_rows = encode_rows_unordered(
+                                    data_df.select_columns(names.iter().cloned())?.as_slice(),
+                                )?;
+
+                                equality_deleted_positions.extend(
+                                    data_rows
+                                        .downcast_iter()
+                                        .flat_map(|arr| arr.values_iter())
+                                        .enumerate()
+                                        .filter(|(_, row)| delete_rows.contains(row))
+                                        .map(|(i, _)| i),
+                                );
                             }
                         }
 
-                        let bitmap = filter_mask.freeze();
+                        let mut mask =
+                            iceberg_position_deletes_to_mask(&position_columns, mask_len)?;
 
-                        // Also trigger the bitcount to reduce blocking later down.
-                        bitmap.unset_bits();
-                        debug_assert!(bitmap.lazy_unset_bits().is_some());
+                        if !equality_deleted_positions.is_empty() {
+                            let equality_mask =
+                                deleted_positions_to_mask(mask.len(), equality_deleted_positions);
+                            mask = &mask & &equality_mask;
+                        }
 
-                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
                         let mask = ExternalFilterMask::IcebergPositionDelete { mask };
 
                         if verbose {
                             let num_deleted_rows = mask.num_deleted_rows();
-                            let max_index = mask.len().checked_sub(1);
 
                             eprintln!(
                                 "[DeletionFilesProvider[Iceberg]]: \
                                 scan_source_idx: {scan_source_idx}, \
-                                num_deleted_rows: {num_deleted_rows}, \
-                                max_index: {max_index:?}",
+                                num_deleted_rows: {num_deleted_rows}",
+                            )
+                        }
+
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
+
+            #[cfg(feature = "delta")]
+            Self::DeltaDeletionVector { vectors } => {
+                let deletion_vector = vectors.get(&scan_source_idx)?.clone();
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Delta]]: scan_source_idx: {scan_source_idx}, \
+                        deletion_vector: {deletion_vector:?}"
+                    )
+                }
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let positions = match deletion_vector {
+                            DeltaDeletionVector::Inline {
+                                data,
+                                size_in_bytes,
+                            } => polars_io::delta::decode_inline_deletion_vector(
+                                &data,
+                                size_in_bytes,
+                            )?,
+                            DeltaDeletionVector::File {
+                                path,
+                                offset,
+                                size_in_bytes,
+                            } => polars_io::pl_async::get_runtime()
+                                .spawn(async move {
+                                    polars_io::delta::read_deletion_vector_file(
+                                        &path,
+                                        offset,
+                                        size_in_bytes,
+                                        cloud_options.as_deref(),
+                                    )
+                                    .await
+                                })
+                                .await
+                                .map_err(polars_error::to_compute_err)??,
+                        };
+
+                        let filter_mask_len = positions.iter().max().map_or(0, |idx| idx + 1);
+                        let mask = deleted_positions_to_mask(
+                            usize::try_from(filter_mask_len).unwrap(),
+                            positions
+                                .into_iter()
+                                .map(|idx| usize::try_from(idx).unwrap()),
+                        );
+                        let mask = ExternalFilterMask::DeltaDeletionVector { mask };
+
+                        if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+
+                            eprintln!(
+                                "[DeletionFilesProvider[Delta]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}",
                             )
                         }
 
@@ -245,6 +402,180 @@ impl DeletionFilesProvider {
     }
 }
 
+#[cfg(feature = "parquet")]
+fn iceberg_position_delete_schema() -> SchemaRef {
+    Arc::new(Schema::from_iter([
+        (PlSmallStr::from_static("file_path"), DataType::String),
+        (PlSmallStr::from_static("pos"), DataType::Int64),
+    ]))
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_reader_builder(schema: Option<SchemaRef>) -> ParquetReaderBuilder {
+    ParquetReaderBuilder {
+        first_metadata: None,
+        options: Arc::new(polars_io::prelude::ParquetOptions {
+            schema,
+            parallel: polars_io::prelude::ParallelStrategy::Auto,
+            low_memory: false,
+            use_statistics: false,
+            use_field_ids: false,
+            field_overwrites: Vec::new(),
+        }),
+        field_id_reference: None,
+    }
+}
+
+/// Builds a reader for the columns of `schema`, which are matched to the columns of the file by
+/// their `field_ids`.
+#[cfg(feature = "parquet")]
+fn field_id_reader_builder(
+    schema: SchemaRef,
+    field_ids: &[i32],
+) -> PolarsResult<ParquetReaderBuilder> {
+    use polars_io::prelude::{ChildFieldOverwrites, ParquetFieldOverwrites};
+
+    let field_overwrites = schema
+        .iter_names()
+        .zip(field_ids)
+        .map(|(name, id)| ParquetFieldOverwrites {
+            name: Some(name.clone()),
+            children: ChildFieldOverwrites::None,
+            required: None,
+            field_id: Some(*id),
+            metadata: None,
+        })
+        .collect();
+
+    let mut options = Arc::unwrap_or_clone(parquet_reader_builder(Some(schema)).options);
+    options.use_field_ids = true;
+    options.field_overwrites = field_overwrites;
+
+    ParquetReaderBuilder::new(Arc::new(options), None)
+}
+
+/// Spawns a task that loads the columns of `projected_schema` from a Parquet file. Columns that
+/// are not in the file are loaded as nulls.
+#[cfg(feature = "parquet")]
+fn spawn_load_parquet_file(
+    reader_builder: &ParquetReaderBuilder,
+    source: ScanSource,
+    projected_schema: SchemaRef,
+    cloud_options: Option<Arc<CloudOptions>>,
+    num_pipelines: usize,
+) -> AbortOnDropHandle<PolarsResult<DataFrame>> {
+    let mut reader = reader_builder.build_file_reader(source, cloud_options, 0);
+
+    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+        reader.initialize().await?;
+
+        let file_schema = reader.file_schema().await?;
+        let read_schema = projected_schema
+            .iter()
+            .filter(|(name, _)| file_schema.contains(name))
+            .map(|(name, dtype)| (name.clone(), dtype.clone()))
+            .collect::<Schema>();
+
+        let df = if read_schema.is_empty() {
+            DataFrame::empty_with_height(reader.n_rows_in_file().await? as usize)
+        } else {
+            let (mut rx, handle) = reader.begin_read(BeginReadArgs {
+                projected_schema: Arc::new(read_schema),
+                row_index: None,
+                pre_slice: None,
+                predicate: None,
+                cast_columns_policy: CastColumnsPolicy {
+                    integer_upcast: true,
+                    float_upcast: true,
+                    ..CastColumnsPolicy::ERROR_ON_MISMATCH
+                },
+                num_pipelines,
+                callbacks: FileReaderCallbacks {
+                    file_schema_tx: None,
+                    n_rows_in_file_tx: None,
+                    row_position_on_end_tx: None,
+                },
+            })?;
+
+            let mut dfs = vec![];
+
+            while let Ok(morsel) = rx.recv().await {
+                dfs.push(morsel.into_df());
+            }
+
+            handle.await?;
+
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+
+        let height = df.height();
+        let mut columns = df.take_columns().into_iter();
+
+        projected_schema
+            .iter()
+            .map(|(name, dtype)| {
+                Ok(if file_schema.contains(name) {
+                    columns.next().unwrap()
+                } else {
+                    Column::full_null(name.clone(), height, dtype)
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+            .and_then(|columns| DataFrame::new_with_height(height, columns))
+    }))
+}
+
+/// Builds a filter mask from the `pos` columns of Iceberg position delete files. The mask is at
+/// least `min_len` rows long.
+#[cfg(feature = "parquet")]
+fn iceberg_position_deletes_to_mask(
+    position_columns: &[Column],
+    min_len: usize,
+) -> PolarsResult<BooleanChunked> {
+    let position_columns = position_columns
+        .iter()
+        .map(|c| c.as_materialized_series_maintain_scalar())
+        .collect::<Vec<_>>();
+
+    let mut len = min_len;
+
+    for s in &position_columns {
+        if let Some(max_idx) = s.i64()?.max() {
+            len = len.max(usize::try_from(max_idx).unwrap().saturating_add(1));
+        }
+    }
+
+    Ok(deleted_positions_to_mask(
+        len,
+        position_columns.iter().flat_map(|s| {
+            s.i64()
+                .unwrap()
+                .into_iter()
+                .map(|idx| usize::try_from(idx.unwrap()).unwrap())
+        }),
+    ))
+}
+
+/// Builds a filter mask of `len` rows where the rows at `deleted_positions` are unset.
+fn deleted_positions_to_mask(
+    len: usize,
+    deleted_positions: impl IntoIterator<Item = usize>,
+) -> BooleanChunked {
+    let mut filter_mask = MutableBitmap::from_len_set(len);
+
+    for idx in deleted_positions {
+        filter_mask.set(idx, false);
+    }
+
+    let bitmap = filter_mask.freeze();
+
+    // Also trigger the bitcount to reduce blocking later down.
+    bitmap.unset_bits();
+    debug_assert!(bitmap.lazy_unset_bits().is_some());
+
+    BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap)
+}
+
 pub enum RowDeletionsInit {
     Initializing(AbortOnDropHandle<PolarsResult<ExternalFilterMask>>),
 
@@ -267,7 +598,11 @@ impl RowDeletionsInit {
 #[derive(Debug, Clone)]
 pub enum ExternalFilterMask {
     /// Note: Iceberg positional deletes can have a mask length shorter than the actual data.
+    ///
+    /// This is also used for Iceberg equality deletes, which are resolved to row positions.
     IcebergPositionDelete { mask: BooleanChunked },
+    /// Note: The mask ends at the last deleted row, so it can also be shorter than the data.
+    DeltaDeletionVector { mask: BooleanChunked },
 }
 
 impl ExternalFilterMask {
@@ -275,6 +610,7 @@ impl ExternalFilterMask {
         use ExternalFilterMask::*;
         match self {
             IcebergPositionDelete { .. } => "IcebergPositionDelete",
+            DeltaDeletionVector { .. } => "DeltaDeletionVector",
         }
     }
 
@@ -293,7 +629,7 @@ impl ExternalFilterMask {
 
     pub fn filter_df(&self, df: &mut DataFrame) -> PolarsResult<()> {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => {
                 if !mask.is_empty() {
                     *df = if mask.len() < df.height() {
                         accumulate_dataframes_vertical_unchecked([
@@ -322,12 +658,21 @@ impl ExternalFilterMask {
 
                 Self::IcebergPositionDelete { mask }
             },
+            Self::DeltaDeletionVector { mask } => {
+                assert_ne!(offset, usize::MAX);
+                let offset = offset.min(mask.len());
+                let len = len.min(mask.len() - offset);
+
+                let mask = mask.slice(i64::try_from(offset).unwrap(), len);
+
+                Self::DeltaDeletionVector { mask }
+            },
         }
     }
 
     pub fn num_deleted_rows(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => mask
                 .rechunk()
                 .downcast_get(0)
                 .unwrap()
@@ -384,7 +729,7 @@ impl ExternalFilterMask {
 
     fn get_mask(&self) -> Bitmap {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => {
                 mask.rechunk().downcast_get(0).unwrap().values().clone()
             },
         }
@@ -392,7 +737,7 @@ impl ExternalFilterMask {
 
     pub fn len(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask.len(),
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => mask.len(),
         }
     }
 }
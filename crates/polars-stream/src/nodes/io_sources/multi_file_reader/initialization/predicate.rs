@@ -1,5 +1,6 @@
 use arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
+use polars_io::metrics::IOMetrics;
 use polars_io::predicates::ScanIOPredicate;
 
 use super::MultiScanTaskInitializer;
@@ -29,6 +30,8 @@ impl MultiScanTaskInitializer {
                     // TODO: Optimize to avoid doing this
                     let mask = !&mask;
 
+                    IOMetrics::add(&self.config.io_metrics.files_pruned, mask.set_bits());
+
                     if self.config.verbose {
                         eprintln!(
                             "[MultiScan]: Predicate pushdown allows skipping {} / {} files",
@@ -106,20 +106,17 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
             let cloud_options = config.cloud_options.clone();
             let file_reader_builder = config.file_reader_builder.clone();
             let deletion_files_provider = deletion_files_provider.clone();
+            let io_metrics = config.io_metrics.clone();
 
             AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                let mut reader =
-                    sources
-                        .get(scan_source_idx)
-                        .unwrap()
-                        .into_owned()
-                        .map(|source| {
-                            file_reader_builder.build_file_reader(
-                                source,
-                                cloud_options.clone(),
-                                scan_source_idx,
-                            )
-                        })?;
+                let scan_source = sources.get(scan_source_idx).unwrap().into_owned()?;
+                let mut reader = file_reader_builder.build_file_reader(
+                    scan_source.clone(),
+                    cloud_options.clone(),
+                    scan_source_idx,
+                );
+                // Files are counted as read by the pipeline, which may open them again.
+                reader.set_io_metrics(io_metrics);
 
                 if verbose {
                     eprintln!("resolve_negative_slice(): init scan source {scan_source_idx}");
@@ -127,6 +124,7 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
 
                 let row_deletions = deletion_files_provider.spawn_row_deletions_init(
                     scan_source_idx,
+                    &scan_source,
                     cloud_options,
                     num_pipelines,
                     verbose,
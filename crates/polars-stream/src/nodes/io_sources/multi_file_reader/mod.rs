@@ -14,6 +14,7 @@ use initialization::MultiScanTaskInitializer;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
+use polars_io::metrics::IOMetrics;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::{RowIndex, pl_async};
 use polars_plan::dsl::deletion::DeletionFilesList;
@@ -64,6 +65,9 @@ pub struct MultiFileReaderConfig {
     pub n_readers_pre_init: AtomicUsize,
     pub max_concurrent_scans: AtomicUsize,
 
+    /// I/O counters of this scan, reported in query profiles.
+    pub io_metrics: Arc<IOMetrics>,
+
     pub verbose: bool,
 }
 
@@ -112,6 +116,9 @@ enum MultiScanState {
 pub struct MultiFileReader {
     name: PlSmallStr,
     state: MultiScanState,
+    io_metrics: Arc<IOMetrics>,
+    /// Description of the scanned sources, used when reporting `io_metrics`.
+    sources_description: PlSmallStr,
     verbose: bool,
 }
 
@@ -119,10 +126,21 @@ impl MultiFileReader {
     pub fn new(config: Arc<MultiFileReaderConfig>) -> Self {
         let name = format_pl_smallstr!("multi-scan[{}]", config.file_reader_builder.reader_name());
         let verbose = config.verbose;
+        let io_metrics = config.io_metrics.clone();
+
+        let sources_description = match config.sources.first_path() {
+            Some(path) if config.sources.len() > 1 => {
+                format_pl_smallstr!("{} (+{} more)", path.display(), config.sources.len() - 1)
+            },
+            Some(path) => format_pl_smallstr!("{}", path.display()),
+            None => format_pl_smallstr!("{} in-memory sources", config.sources.len()),
+        };
 
         MultiFileReader {
             name,
             state: MultiScanState::Uninitialized { config },
+            io_metrics,
+            sources_description,
             verbose,
         }
     }
@@ -133,6 +151,10 @@ impl ComputeNode for MultiFileReader {
         &self.name
     }
 
+    fn io_metrics(&self) -> Option<(PlSmallStr, Arc<IOMetrics>)> {
+        Some((self.sources_description.clone(), self.io_metrics.clone()))
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [crate::graph::PortState],
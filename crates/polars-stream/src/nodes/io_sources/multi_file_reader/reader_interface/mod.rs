@@ -4,11 +4,14 @@ pub mod builder;
 pub mod capabilities;
 pub mod output;
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use output::FileReaderOutputRecv;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
+use polars_io::metrics::IOMetrics;
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::CastColumnsPolicy;
 use polars_utils::IdxSize;
@@ -25,6 +28,9 @@ pub trait FileReader: Send + Sync {
     /// This must be called before calling any other functions of the FileReader.
     async fn initialize(&mut self) -> PolarsResult<()>;
 
+    /// Set the counters this reader records its I/O in. Called before `initialize()`.
+    fn set_io_metrics(&mut self, _io_metrics: Arc<IOMetrics>) {}
+
     /// Begin reading the file into morsels.
     fn begin_read(
         &mut self,
@@ -9,6 +9,7 @@ use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
+use polars_io::metrics::IOMetrics;
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSource};
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -197,6 +198,7 @@ impl MultiScanTaskInitializer {
                 .map(move |scan_source_idx| {
                     let cloud_options = config.cloud_options.clone();
                     let file_reader_builder = config.file_reader_builder.clone();
+                    let io_metrics = config.io_metrics.clone();
                     let sources = config.sources.clone();
                     let deletion_files_provider = deletion_files_provider.clone();
                     let initialized_row_deletions = initialized_row_deletions.clone();
@@ -211,6 +213,7 @@ impl MultiScanTaskInitializer {
                             }
 
                             let scan_source = scan_source?;
+                            IOMetrics::add(&io_metrics.files_read, 1);
 
                             if let Some((reader, n_rows_in_file)) = maybe_initialized {
                                 return PolarsResult::Ok((
@@ -225,6 +228,7 @@ impl MultiScanTaskInitializer {
                                 cloud_options.clone(),
                                 scan_source_idx,
                             );
+                            reader.set_io_metrics(io_metrics);
 
                             reader.initialize().await?;
                             let opt_n_rows = reader
@@ -242,6 +246,7 @@ impl MultiScanTaskInitializer {
                             .or_else(|| {
                                 deletion_files_provider.spawn_row_deletions_init(
                                     scan_source_idx,
+                                    &scan_source,
                                     cloud_options,
                                     num_pipelines,
                                     verbose,
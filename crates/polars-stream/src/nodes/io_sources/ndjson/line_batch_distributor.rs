@@ -1,6 +1,7 @@
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_io::prelude::json_lines;
+use polars_io::utils::compression::StreamingDecompressor;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -8,7 +9,10 @@ use super::line_batch_processor::LineBatch;
 use crate::async_primitives::distributor_channel;
 
 pub(super) struct LineBatchDistributor {
+    /// The file, or the compressed file if `decompressor` is set.
     pub(super) global_bytes: MemSlice,
+    /// Decompresses the file incrementally. Not supported with `reverse`.
+    pub(super) decompressor: Option<StreamingDecompressor>,
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
@@ -18,17 +22,20 @@ pub(super) struct LineBatchDistributor {
 impl LineBatchDistributor {
     /// Returns the number of rows skipped (i.e. were not sent to LineBatchProcessors).
     pub(super) async fn run(self) -> PolarsResult<usize> {
+        if self.decompressor.is_some() {
+            return self.run_streaming().await;
+        }
+
         let LineBatchDistributor {
             global_bytes: global_bytes_mem_slice,
+            decompressor: _,
             chunk_size,
             n_rows_to_skip,
             reverse,
             mut line_batch_distribute_tx,
         } = self;
 
-        // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-        let global_bytes: &'static [u8] =
-            unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
+        let global_bytes: &[u8] = global_bytes_mem_slice.as_ref();
         let n_chunks = global_bytes.len().div_ceil(chunk_size);
         let verbose = config::verbose();
 
@@ -53,7 +60,7 @@ impl LineBatchDistributor {
         // The logic below processes in fixed chunks with remainder handling so that in the future
         // we can handle receiving data in a batched manner.
 
-        let mut prev_remainder: &'static [u8] = &[];
+        let mut prev_remainder: &[u8] = &[];
 
         let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
 
@@ -103,10 +110,12 @@ impl LineBatchDistributor {
                 prev_remainder = &[];
                 row_skipper.skip_rows(&mut full_chunk);
 
+                let offset = full_chunk.as_ptr() as usize - global_bytes.as_ptr() as usize;
+
                 if !full_chunk.is_empty()
                     && line_batch_distribute_tx
                         .send(LineBatch {
-                            bytes: full_chunk,
+                            bytes: global_bytes_mem_slice.slice(offset..offset + full_chunk.len()),
                             chunk_idx,
                         })
                         .await
@@ -136,6 +145,79 @@ impl LineBatchDistributor {
 
         Ok(n_rows_skipped)
     }
+
+    /// Decompresses the file in chunks of `chunk_size`, so that only the chunks that are being
+    /// processed are held in memory.
+    async fn run_streaming(self) -> PolarsResult<usize> {
+        let LineBatchDistributor {
+            global_bytes: _,
+            decompressor,
+            chunk_size,
+            n_rows_to_skip,
+            reverse,
+            mut line_batch_distribute_tx,
+        } = self;
+
+        assert!(!reverse);
+        let mut decompressor = decompressor.unwrap();
+
+        if config::verbose() {
+            eprintln!(
+                "[NDJSON LineBatchDistributor]: streaming decompression, chunk_size: {chunk_size}, \
+                n_rows_to_skip: {n_rows_to_skip}"
+            )
+        }
+
+        let mut row_skipper = RowSkipper {
+            remaining_rows_to_skip: n_rows_to_skip,
+            reverse,
+        };
+
+        let mut remainder: Vec<u8> = vec![];
+        let mut has_more = true;
+        let mut chunk_idx: usize = 0;
+
+        while has_more {
+            let mut buf = Vec::with_capacity(remainder.len() + chunk_size);
+            buf.extend_from_slice(&remainder);
+            has_more = decompressor.fill(&mut buf, remainder.len() + chunk_size)?;
+            let buf = MemSlice::from_vec(buf);
+
+            let n_full = if has_more {
+                buf.iter().rposition(|&c| c == b'\n').map_or(0, |i| i + 1)
+            } else {
+                // Last chunk, send everything.
+                buf.len()
+            };
+
+            let mut full_chunk = &buf[..n_full];
+            row_skipper.skip_rows(&mut full_chunk);
+
+            if !full_chunk.is_empty() {
+                let offset = full_chunk.as_ptr() as usize - buf.as_ptr() as usize;
+
+                if line_batch_distribute_tx
+                    .send(LineBatch {
+                        bytes: buf.slice(offset..offset + full_chunk.len()),
+                        chunk_idx,
+                    })
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+
+                chunk_idx += 1;
+            }
+
+            // Copied out so that it does not keep this chunk alive.
+            remainder = buf[n_full..].to_vec();
+        }
+
+        let n_rows_skipped = n_rows_to_skip - row_skipper.remaining_rows_to_skip;
+
+        Ok(n_rows_skipped)
+    }
 }
 
 struct RowSkipper {
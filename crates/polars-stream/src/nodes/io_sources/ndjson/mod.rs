@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{StreamingDecompressor, maybe_decompress_bytes};
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -79,9 +79,11 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
-        // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
-        // Ideally we have a streaming download/decompression.
-        let global_bytes = self.get_bytes_maybe_decompress()?;
+        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
+
+        // TODO: This currently downloads everything upfront in a blocking manner.
+        // Ideally we have a streaming download.
+        let (global_bytes, decompressor) = self.get_bytes_maybe_decompress(is_negative_slice)?;
 
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
@@ -96,8 +98,6 @@ impl FileReader for NDJsonFileReader {
             _ = tx.try_send(schema.clone())
         }
 
-        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
-
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -133,7 +133,9 @@ impl FileReader for NDJsonFileReader {
 
         let chunk_size: usize = {
             let n_bytes_to_split = if let Some(x) = global_slice.as_ref() {
-                if needs_total_row_count {
+                // Note: The size of compressed files is used as-is, line lengths cannot be
+                // estimated from compressed bytes.
+                if needs_total_row_count || decompressor.is_some() {
                     global_bytes.len()
                 } else {
                     // There may be early stopping, try to heuristically use a smaller chunk size to stop faster.
@@ -284,7 +286,7 @@ impl FileReader for NDJsonFileReader {
         let schema = Arc::new(schema);
         let chunk_reader = Arc::new(self.try_init_chunk_reader(&schema)?);
 
-        if !is_negative_slice {
+        if !is_negative_slice && decompressor.is_none() {
             get_memory_prefetch_func(verbose)(global_bytes.as_ref());
         }
 
@@ -307,7 +309,6 @@ impl FileReader for NDJsonFileReader {
             .enumerate()
             .rev()
             .map(|(worker_idx, line_batch_rx)| {
-                let global_bytes = global_bytes.clone();
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
@@ -317,7 +318,6 @@ impl FileReader for NDJsonFileReader {
                     LineBatchProcessor {
                         worker_idx,
 
-                        global_bytes,
                         chunk_reader,
 
                         line_batch_rx,
@@ -345,6 +345,7 @@ impl FileReader for NDJsonFileReader {
             TaskPriority::Low,
             line_batch_distributor::LineBatchDistributor {
                 global_bytes,
+                decompressor,
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
@@ -438,28 +439,41 @@ impl NDJsonFileReader {
         ChunkReader::try_new(&self.options, schema)
     }
 
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
-        if self.cached_bytes.is_none() {
-            let run_async = self.scan_source.run_async();
-            let source = self
-                .scan_source
-                .as_scan_source_ref()
-                .to_memslice_async_assume_latest(run_async)?;
+    /// Compressed files are decompressed incrementally while reading, unless `full` is set, in
+    /// which case the decompressed file is cached.
+    fn get_bytes_maybe_decompress(
+        &mut self,
+        full: bool,
+    ) -> PolarsResult<(MemSlice, Option<StreamingDecompressor>)> {
+        if let Some(memslice) = &self.cached_bytes {
+            return Ok((memslice.clone(), None));
+        }
 
-            let memslice = {
-                let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
+        let run_async = self.scan_source.run_async();
+        let source = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(run_async)?;
 
-                if out.is_empty() {
-                    source
-                } else {
-                    MemSlice::from_vec(out)
-                }
-            };
-
-            self.cached_bytes = Some(memslice);
+        if !full {
+            if let Some(decompressor) = StreamingDecompressor::try_new(source.clone())? {
+                return Ok((source, Some(decompressor)));
+            }
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        let memslice = {
+            let mut out = vec![];
+            maybe_decompress_bytes(&source, &mut out)?;
+
+            if out.is_empty() {
+                source
+            } else {
+                MemSlice::from_vec(out)
+            }
+        };
+
+        self.cached_bytes = Some(memslice.clone());
+
+        Ok((memslice, None))
     }
 }
@@ -0,0 +1,386 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::metrics::IOMetrics;
+use polars_io::orc::{OrcMetadataRef, read_orc_metadata, read_orc_stripe};
+use polars_io::predicates::ScanIOPredicate;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::{
+    FileReaderOutputRecv, FileReaderOutputSend,
+};
+use super::multi_file_reader::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::orc::OrcMetadataRef;
+    use polars_plan::dsl::ScanSource;
+
+    use super::OrcFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct OrcReaderBuilder {
+        pub first_metadata: Option<OrcMetadataRef>,
+    }
+
+    impl FileReaderBuilder for OrcReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "orc"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE | RC::PARTIAL_FILTER
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = OrcFileReader {
+                scan_source: source,
+                cloud_options,
+                metadata: if scan_source_idx == 0 {
+                    self.first_metadata.clone()
+                } else {
+                    None
+                },
+                io_metrics: None,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct OrcFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    metadata: Option<OrcMetadataRef>,
+    io_metrics: Option<Arc<IOMetrics>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: OrcMetadataRef,
+}
+
+/// A stripe (or part of a stripe) that should be decoded.
+struct StripeMessage {
+    stripe_idx: usize,
+    /// Row offset of the first row in `slice`, relative to the start of the file.
+    row_offset: IdxSize,
+    /// Rows of the stripe to keep.
+    slice: Range<usize>,
+    morsel_seq_base: u64,
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution does not download the entire ORC file.
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(addr.to_str())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let metadata = match self.metadata.clone() {
+            Some(v) => v,
+            None => Arc::new(read_orc_metadata(memslice.as_ref())?),
+        };
+
+        self.init_data = Some(InitializedState { memslice, metadata });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+        let InitializedState { memslice, metadata } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice,
+            predicate,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        let n_rows_in_file = self._n_rows_in_file()?;
+        let pre_slice = pre_slice.map(|x| x.restrict_to_bounds(n_rows_in_file as usize));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(
+                metadata.schema().as_ref(),
+            )));
+        }
+
+        let (mut morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        if pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            return Ok((
+                morsel_rx,
+                spawn(TaskPriority::Low, std::future::ready(Ok(()))),
+            ));
+        }
+
+        let projection = projected_schema
+            .iter_names()
+            .filter_map(|name| metadata.schema().index_of(name))
+            .collect::<Arc<[usize]>>();
+
+        let max_morsel_size = get_ideal_morsel_size();
+        let (stripes, n_pruned) = plan_stripes(
+            &metadata,
+            pre_slice.map(Range::<usize>::from),
+            predicate.as_ref(),
+            max_morsel_size,
+        )?;
+
+        if let Some(io_metrics) = &self.io_metrics {
+            IOMetrics::add(&io_metrics.row_groups_read, stripes.len());
+            IOMetrics::add(&io_metrics.row_groups_pruned, n_pruned);
+        }
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: project: {} / {}, stripes: {} / {}",
+                projection.len(),
+                metadata.schema().len(),
+                stripes.len(),
+                metadata.num_stripes(),
+            );
+        }
+
+        let (mut stripe_tx, stripe_rxs) =
+            distributor_channel::<StripeMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            for message in stripes {
+                if stripe_tx.send(message).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        let decoder_handles = stripe_rxs
+            .into_iter()
+            .zip(morsel_senders.drain(..))
+            .map(|(mut rx, mut morsel_tx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let projection = projection.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(StripeMessage {
+                        stripe_idx,
+                        row_offset,
+                        slice,
+                        morsel_seq_base,
+                    }) = rx.recv().await
+                    {
+                        let mut df = if projection.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            read_orc_stripe(memslice.as_ref(), &metadata, stripe_idx, &projection)?
+                                .slice(slice.start as i64, slice.len())
+                        };
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = offset.saturating_add(row_offset);
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel = Morsel::new(
+                                df.slice((i * max_morsel_size) as i64, max_morsel_size),
+                                MorselSeq::new(morsel_seq_base + i as u64),
+                                source_token.clone(),
+                            );
+
+                            if morsel_tx.send_morsel(morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    fn set_io_metrics(&mut self, io_metrics: Arc<IOMetrics>) {
+        self.io_metrics = Some(io_metrics);
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        let metadata = &self.init_data.as_ref().unwrap().metadata;
+        Ok(Arc::new(Schema::from_arrow_schema(
+            metadata.schema().as_ref(),
+        )))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        self._n_rows_in_file()
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        self._n_rows_in_file().map(Some)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self._n_rows_in_file()?,
+            pre_slice,
+        ))
+    }
+}
+
+impl OrcFileReader {
+    fn _n_rows_in_file(&self) -> PolarsResult<IdxSize> {
+        let n_rows = self.init_data.as_ref().unwrap().metadata.num_rows();
+        IdxSize::try_from(n_rows).map_err(|_| polars_err!(bigidx, ctx = "orc file", size = n_rows))
+    }
+}
+
+/// Determine which (parts of) stripes need to be decoded.
+///
+/// Stripes outside of the slice are skipped, as are stripes whose statistics show that no row can
+/// match the predicate. Returns the stripes to decode and the number of stripes skipped because
+/// of the predicate.
+fn plan_stripes(
+    metadata: &OrcMetadataRef,
+    slice: Option<Range<usize>>,
+    predicate: Option<&ScanIOPredicate>,
+    max_morsel_size: usize,
+) -> PolarsResult<(Vec<StripeMessage>, usize)> {
+    let slice = slice.unwrap_or(0..usize::MAX);
+    let skip_batch_predicate = predicate.and_then(|p| {
+        p.skip_batch_predicate
+            .as_ref()
+            .map(|sbp| (sbp, p.live_columns.as_ref()))
+    });
+
+    let mut out = Vec::new();
+    let mut n_pruned = 0;
+    let mut morsel_seq: u64 = 0;
+    let mut stripe_start = 0;
+
+    for stripe_idx in 0..metadata.num_stripes() {
+        let n_rows = metadata.stripe_num_rows(stripe_idx);
+        let stripe_range = stripe_start..stripe_start + n_rows;
+        stripe_start += n_rows;
+
+        if stripe_range.start >= slice.end {
+            break;
+        }
+
+        let start = stripe_range.start.max(slice.start);
+        let end = stripe_range.end.min(slice.end);
+        if start >= end {
+            continue;
+        }
+
+        if let Some((sbp, live_columns)) = skip_batch_predicate {
+            if let Some(stats) = metadata.stripe_statistics(stripe_idx) {
+                if sbp.can_skip_batch(n_rows as IdxSize, live_columns, stats)? {
+                    n_pruned += 1;
+                    continue;
+                }
+            }
+        }
+
+        out.push(StripeMessage {
+            stripe_idx,
+            row_offset: start as IdxSize,
+            slice: start - stripe_range.start..end - stripe_range.start,
+            morsel_seq_base: morsel_seq,
+        });
+        morsel_seq += (end - start).div_ceil(max_morsel_size) as u64;
+    }
+
+    Ok((out, n_pruned))
+}
@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use polars_core::config;
+use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::{FileMetadata, ParallelStrategy, ParquetOptions};
 use polars_io::utils::byte_source::DynByteSourceBuilder;
+use polars_parquet::write::SchemaDescriptor;
 use polars_plan::dsl::ScanSource;
 
 use super::{FileReader, ParquetFileReader};
@@ -14,6 +16,31 @@ use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities:
 pub struct ParquetReaderBuilder {
     pub first_metadata: Option<Arc<FileMetadata>>,
     pub options: Arc<ParquetOptions>,
+    /// The schema whose field names are applied to the fields with the same field ID in every
+    /// file. Only set if `use_field_ids` is enabled.
+    pub field_id_reference: Option<Arc<SchemaDescriptor>>,
+}
+
+impl ParquetReaderBuilder {
+    pub fn new(
+        options: Arc<ParquetOptions>,
+        first_metadata: Option<Arc<FileMetadata>>,
+    ) -> PolarsResult<Self> {
+        // Without the field IDs of the schema, the schema is assumed to be that of the first file.
+        let field_id_reference = match options.field_id_reference()? {
+            Some(reference) => Some(Arc::new(reference)),
+            None if options.use_field_ids => first_metadata
+                .as_ref()
+                .map(|metadata| Arc::new(metadata.schema().clone())),
+            None => None,
+        };
+
+        Ok(Self {
+            first_metadata,
+            options,
+            field_id_reference,
+        })
+    }
 }
 
 #[cfg(feature = "parquet")]
@@ -64,7 +91,9 @@ impl FileReaderBuilder for ParquetReaderBuilder {
             } else {
                 None
             },
+            field_id_reference: self.field_id_reference.clone(),
             byte_source_builder,
+            io_metrics: None,
             verbose,
 
             init_data: None,
@@ -11,6 +11,7 @@ use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_core::utils::arrow::datatypes::ArrowSchemaRef;
 use polars_error::{PolarsResult, polars_ensure};
 use polars_io::RowIndex;
+use polars_io::metrics::IOMetrics;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::_internal::{PrefilterMaskSetting, collect_statistics_with_live_columns};
 use polars_io::prelude::{FileMetadata, ParallelStrategy};
@@ -206,6 +207,7 @@ impl ParquetReadImpl {
 
         let row_index = self.row_index.clone();
         let live_filter_columns_cast = self.live_filter_columns_cast.take();
+        let io_metrics = self.io_metrics.clone();
 
         let prefetch_task = AbortOnDropHandle(io_runtime.spawn(async move {
             polars_ensure!(
@@ -274,6 +276,15 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            if let Some(io_metrics) = &io_metrics {
+                let n_pruned = row_group_mask.as_ref().map_or(0, |x| x.set_bits());
+                IOMetrics::add(&io_metrics.row_groups_pruned, n_pruned);
+                IOMetrics::add(
+                    &io_metrics.row_groups_read,
+                    row_group_slice.len() - n_pruned,
+                );
+            }
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection,
                 predicate,
@@ -6,11 +6,13 @@ use polars_core::prelude::ArrowSchema;
 use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_err};
 use polars_io::cloud::CloudOptions;
+use polars_io::metrics::IOMetrics;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, ParquetOptions};
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder, MemSliceByteSource};
 use polars_io::{RowIndex, pl_async};
-use polars_parquet::read::schema::infer_schema_with_options;
+use polars_parquet::read::schema::{apply_parquet_field_names, infer_schema_with_options};
+use polars_parquet::write::SchemaDescriptor;
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -40,7 +42,10 @@ pub struct ParquetFileReader {
     config: Arc<ParquetOptions>,
     /// Set by the builder if we have metadata left over from DSL conversion.
     metadata: Option<Arc<FileMetadata>>,
+    /// See [`builder::ParquetReaderBuilder::field_id_reference`].
+    field_id_reference: Option<Arc<SchemaDescriptor>>,
     byte_source_builder: DynByteSourceBuilder,
+    io_metrics: Option<Arc<IOMetrics>>,
     verbose: bool,
 
     /// Set during initialize()
@@ -68,7 +73,7 @@ impl FileReader for ParquetFileReader {
         let byte_source_builder = self.byte_source_builder.clone();
         let cloud_options = self.cloud_options.clone();
 
-        let byte_source = pl_async::get_runtime()
+        let mut byte_source = pl_async::get_runtime()
             .spawn(async move {
                 scan_source
                     .as_scan_source_ref()
@@ -78,6 +83,10 @@ impl FileReader for ParquetFileReader {
             .await
             .unwrap()?;
 
+        if let Some(io_metrics) = self.io_metrics.clone() {
+            byte_source = byte_source.with_io_metrics(io_metrics);
+        }
+
         let mut byte_source = Arc::new(byte_source);
 
         let file_metadata = if let Some(v) = self.metadata.clone() {
@@ -104,7 +113,47 @@ impl FileReader for ParquetFileReader {
             )?)
         };
 
-        let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
+        let mut fields_renamed = false;
+
+        let file_metadata = match &self.field_id_reference {
+            Some(reference) => {
+                let mut renamed_metadata = FileMetadata::clone(&file_metadata);
+
+                if renamed_metadata.rename_fields_by_id(reference) {
+                    if verbose {
+                        eprintln!(
+                            "[ParquetFileReader]: renamed fields by field ID: {:?} -> {:?}",
+                            file_metadata
+                                .schema()
+                                .fields()
+                                .iter()
+                                .map(|x| x.name())
+                                .collect::<Vec<_>>(),
+                            renamed_metadata
+                                .schema()
+                                .fields()
+                                .iter()
+                                .map(|x| x.name())
+                                .collect::<Vec<_>>(),
+                        )
+                    }
+
+                    fields_renamed = true;
+                    Arc::new(renamed_metadata)
+                } else {
+                    file_metadata
+                }
+            },
+            _ => file_metadata,
+        };
+
+        let mut file_schema = infer_schema_with_options(&file_metadata, &None)?;
+
+        if fields_renamed {
+            apply_parquet_field_names(&mut file_schema, file_metadata.schema().fields());
+        }
+
+        let file_schema = Arc::new(file_schema);
 
         self.init_data = Some(InitializedState {
             file_metadata,
@@ -116,6 +165,10 @@ impl FileReader for ParquetFileReader {
         Ok(())
     }
 
+    fn set_io_metrics(&mut self, io_metrics: Arc<IOMetrics>) {
+        self.io_metrics = Some(io_metrics);
+    }
+
     fn begin_read(
         &mut self,
         args: BeginReadArgs,
@@ -273,6 +326,7 @@ impl FileReader for ParquetFileReader {
             memory_prefetch_func,
             row_index,
             live_filter_columns_cast,
+            io_metrics: self.io_metrics.clone(),
         }
         .run();
 
@@ -350,6 +404,7 @@ struct ParquetReadImpl {
     memory_prefetch_func: fn(&[u8]) -> (),
     row_index: Option<RowIndex>,
     live_filter_columns_cast: Option<(CastColumns, SchemaRef)>,
+    io_metrics: Option<Arc<IOMetrics>>,
 }
 
 #[derive(Debug)]
@@ -1,3 +1,4 @@
+pub mod batch_sink;
 pub mod filter;
 pub mod group_by;
 pub mod in_memory_map;
@@ -34,7 +35,11 @@ mod compute_node_prelude {
     pub use crate::pipe::{RecvPort, SendPort};
 }
 
+use std::sync::Arc;
+
 use compute_node_prelude::*;
+use polars_io::metrics::IOMetrics;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::execute::StreamingExecutionState;
 
@@ -82,4 +87,9 @@ pub trait ComputeNode: Send {
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
         Ok(None)
     }
+
+    /// The I/O counters of scan nodes, along with a description of the scanned sources.
+    fn io_metrics(&self) -> Option<(PlSmallStr, Arc<IOMetrics>)> {
+        None
+    }
 }
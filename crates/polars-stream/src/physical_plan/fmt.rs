@@ -245,6 +245,7 @@ fn visualize_plan_rec(
             from_ref(input),
         ),
         PhysNodeKind::InMemorySink { input } => ("in-memory-sink".to_string(), from_ref(input)),
+        PhysNodeKind::BatchSink { input, .. } => ("batch-sink".to_string(), from_ref(input)),
         PhysNodeKind::FileSink {
             input, file_type, ..
         } => match file_type {
@@ -256,6 +257,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "orc")]
+            FileType::Orc(_) => ("orc-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -280,6 +283,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "orc")]
+                FileType::Orc(_) => (format!("{variant}[orc]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
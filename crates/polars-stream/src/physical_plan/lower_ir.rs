@@ -487,7 +487,39 @@ pub fn lower_ir(
                 unreachable!();
             };
 
-            if scan_sources.is_empty()
+            if let FileScanIR::Anonymous { .. } = &*scan_type {
+                // There is no streaming reader for anonymous scans, they have no sources and are
+                // executed by the in-memory engine instead.
+                let mut lp_arena = Arena::default();
+                let scan_lp_node = lp_arena.add(v.clone());
+                let executor = Mutex::new(create_physical_plan(
+                    scan_lp_node,
+                    &mut lp_arena,
+                    expr_arena,
+                    None,
+                )?);
+
+                let format_str = ctx.prepare_visualization.then(|| {
+                    let mut buffer = String::new();
+                    write_ir_non_recursive(&mut buffer, v, expr_arena, &Schema::default(), 0)
+                        .unwrap();
+                    buffer
+                });
+                let empty_input = phys_sm.insert(PhysNode::new(
+                    Arc::new(Schema::default()),
+                    PhysNodeKind::InMemorySource {
+                        df: Arc::new(DataFrame::empty()),
+                    },
+                ));
+                PhysNodeKind::InMemoryMap {
+                    input: PhysStream::first(empty_input),
+                    map: Arc::new(move |_| {
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    }),
+                    format_str,
+                }
+            } else if scan_sources.is_empty()
                 || unified_scan_args
                     .pre_slice
                     .as_ref()
@@ -509,10 +541,10 @@ pub fn lower_ir(
                         options,
                         metadata: first_metadata,
                     } => Arc::new(
-                        crate::nodes::io_sources::parquet::builder::ParquetReaderBuilder {
-                            options: Arc::new(options.clone()),
-                            first_metadata: first_metadata.clone(),
-                        },
+                        crate::nodes::io_sources::parquet::builder::ParquetReaderBuilder::new(
+                            Arc::new(options.clone()),
+                            first_metadata.clone(),
+                        )?,
                     ) as Arc<dyn FileReaderBuilder>,
 
                     #[cfg(feature = "ipc")]
@@ -523,16 +555,46 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "ipc")]
+                    FileScanIR::IpcStream {
+                        options: polars_io::ipc::IpcScanOptions {},
+                    } => Arc::new(
+                        crate::nodes::io_sources::ipc_stream::builder::IpcStreamReaderBuilder {},
+                    ) as Arc<dyn FileReaderBuilder>,
+
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc {
+                        options: polars_io::orc::OrcScanOptions {},
+                        metadata: first_metadata,
+                    } => Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder {
+                        first_metadata: first_metadata.clone(),
+                    }) as Arc<dyn FileReaderBuilder>,
+
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
                     },
 
+                    #[cfg(feature = "csv")]
+                    FileScanIR::FixedWidth { options } => Arc::new(
+                        crate::nodes::io_sources::fixed_width::builder::FixedWidthReaderBuilder {
+                            options: Arc::new(options.clone()),
+                        },
+                    )
+                        as Arc<dyn FileReaderBuilder>,
+
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
                     },
 
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { options } => {
+                        Arc::new(crate::nodes::io_sources::json::builder::JsonReaderBuilder {
+                            options: Arc::new(options.clone()),
+                        }) as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object: _,
@@ -554,7 +616,7 @@ pub fn lower_ir(
                         )
                     },
 
-                    FileScanIR::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScanIR::Anonymous { .. } => unreachable!(),
                 };
 
                 {
@@ -63,6 +63,10 @@ impl PhysNode {
     pub fn kind(&self) -> &PhysNodeKind {
         &self.kind
     }
+
+    pub fn kind_mut(&mut self) -> &mut PhysNodeKind {
+        &mut self.kind
+    }
 }
 
 /// A handle representing a physical stream of data with a fixed schema in the
@@ -139,6 +143,12 @@ pub enum PhysNodeKind {
         input: PhysStream,
     },
 
+    /// Sends the morsels in order over a bounded channel instead of collecting them.
+    BatchSink {
+        input: PhysStream,
+        sender: async_channel::Sender<DataFrame>,
+    },
+
     FileSink {
         target: SinkTarget,
         sink_options: SinkOptions,
@@ -317,6 +327,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::Filter { input, .. }
             | PhysNodeKind::SimpleProjection { input, .. }
             | PhysNodeKind::InMemorySink { input }
+            | PhysNodeKind::BatchSink { input, .. }
             | PhysNodeKind::FileSink { input, .. }
             | PhysNodeKind::PartitionSink { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
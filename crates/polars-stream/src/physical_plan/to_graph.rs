@@ -10,6 +10,7 @@ use polars_expr::groups::new_hash_grouper;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
 use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
+use polars_io::partition::PartitionCommit;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
 use polars_plan::dsl::{JoinOptionsIR, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
@@ -241,6 +242,14 @@ fn to_graph_rec<'a>(
             )
         },
 
+        BatchSink { input, sender } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::batch_sink::BatchSinkNode::new(sender.clone()),
+                [(input_key, input.port)],
+            )
+        },
+
         FileSink {
             target,
             sink_options,
@@ -265,10 +274,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -296,11 +306,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "orc")]
+                FileType::Orc(orc_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::orc::OrcSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        *orc_writer_options,
+                        cloud_options.clone(),
+                    )),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "orc"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -322,14 +344,34 @@ fn to_graph_rec<'a>(
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
             let input_key = to_graph_rec(input.node, ctx)?;
 
-            let base_path = base_path.clone();
+            // With a write mode, the files are written to a staging directory and moved into
+            // place when the sink finishes.
+            let commit = sink_options
+                .write_mode
+                .map(|mode| {
+                    PartitionCommit::try_new(
+                        base_path.as_ref().as_ref(),
+                        mode,
+                        cloud_options.as_ref(),
+                    )
+                })
+                .transpose()?;
+            let mut file_sink_options = sink_options.clone();
+            let base_path = match &commit {
+                None => base_path.clone(),
+                Some(commit) => {
+                    // The partition directories don't exist yet in the staging directory.
+                    file_sink_options.mkdir = true;
+                    Arc::new(commit.staging_path())
+                },
+            };
             let file_path_cb = file_path_cb.clone();
-            let ext = PlSmallStr::from_static(file_type.extension());
+            let ext = file_type.extension();
             let create_new = nodes::io_sinks::partition::get_create_new_fn(
                 file_type.clone(),
-                sink_options.clone(),
+                file_sink_options,
                 cloud_options.clone(),
-                finish_callback.is_some(),
+                finish_callback.is_some() || commit.is_some(),
             );
 
             let per_partition_sort_by = match per_partition_sort_by.as_ref() {
@@ -367,6 +409,7 @@ fn to_graph_rec<'a>(
                         sink_options.clone(),
                         per_partition_sort_by,
                         finish_callback.clone(),
+                        commit,
                     ),
                 ),
                 PartitionVariantIR::Parted {
@@ -384,6 +427,7 @@ fn to_graph_rec<'a>(
                         *include_key,
                         per_partition_sort_by,
                         finish_callback.clone(),
+                        commit,
                     ),
                 ),
                 PartitionVariantIR::ByKey {
@@ -401,6 +445,7 @@ fn to_graph_rec<'a>(
                         *include_key,
                         per_partition_sort_by,
                         finish_callback.clone(),
+                        commit,
                     ),
                 ),
             };
@@ -580,6 +625,7 @@ fn to_graph_rec<'a>(
                         num_pipelines: AtomicUsize::new(0),
                         n_readers_pre_init: AtomicUsize::new(0),
                         max_concurrent_scans: AtomicUsize::new(0),
+                        io_metrics: Default::default(),
                         verbose,
                     },
                 )),
@@ -1033,6 +1079,7 @@ fn to_graph_rec<'a>(
                         num_pipelines: AtomicUsize::new(0),
                         n_readers_pre_init: AtomicUsize::new(0),
                         max_concurrent_scans: AtomicUsize::new(0),
+                        io_metrics: Default::default(),
                         verbose,
                     },
                 )),
@@ -4,6 +4,7 @@ use std::cmp::Reverse;
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr, get_expr_depth_limit};
+use polars_io::metrics::IOMetrics;
 use polars_plan::plans::{Context, IR, IRPlan};
 use polars_plan::prelude::AExpr;
 use polars_plan::prelude::expr_ir::ExprIR;
@@ -64,6 +65,32 @@ impl StreamingQuery {
         node: Node,
         ir_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Self> {
+        Self::build_impl(node, ir_arena, expr_arena, None)
+    }
+
+    /// Builds a query whose in-memory sink is replaced by a sink that hands out the result in
+    /// batches as it is computed.
+    ///
+    /// At most `capacity` batches are buffered; the execution waits until the [`BatchReceiver`]
+    /// takes a batch. The query must be executed on a different thread than the one receiving
+    /// the batches, and stops early if the [`BatchReceiver`] is dropped.
+    pub fn build_batched(
+        node: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        capacity: usize,
+    ) -> PolarsResult<(Self, BatchReceiver)> {
+        let (sender, receiver) = async_channel::bounded(capacity.max(1));
+        let query = Self::build_impl(node, ir_arena, expr_arena, Some(sender))?;
+        Ok((query, BatchReceiver(receiver)))
+    }
+
+    fn build_impl(
+        node: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        batch_sender: Option<async_channel::Sender<DataFrame>>,
     ) -> PolarsResult<Self> {
         if let Ok(visual_path) = std::env::var("POLARS_VISUALIZE_IR") {
             let plan = IRPlan {
@@ -85,6 +112,13 @@ impl StreamingQuery {
             &mut phys_sm,
             ctx,
         )?;
+        if let Some(sender) = batch_sender {
+            let kind = phys_sm[root_phys_node].kind_mut();
+            let PhysNodeKind::InMemorySink { input } = *kind else {
+                polars_bail!(InvalidOperation: "only queries with an in-memory sink can be received in batches");
+            };
+            *kind = PhysNodeKind::BatchSink { input, sender };
+        }
         if let Ok(visual_path) = std::env::var("POLARS_VISUALIZE_PHYSICAL_PLAN") {
             let visualization =
                 crate::physical_plan::visualize_plan(root_phys_node, &phys_sm, expr_arena);
@@ -107,6 +141,25 @@ impl StreamingQuery {
         Ok(out)
     }
 
+    /// The I/O counters of the scans in this query as `(node name, sources, counters)`.
+    ///
+    /// The counters are updated while the query executes, so they can be read after
+    /// [`StreamingQuery::execute`] has finished.
+    pub fn io_metrics(&self) -> Vec<(PlSmallStr, PlSmallStr, Arc<IOMetrics>)> {
+        self.graph
+            .nodes
+            .values()
+            .filter_map(|node| {
+                let (sources, io_metrics) = node.compute.io_metrics()?;
+                Some((
+                    PlSmallStr::from_str(node.compute.name()),
+                    sources,
+                    io_metrics,
+                ))
+            })
+            .collect()
+    }
+
     pub fn execute(self) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
@@ -155,6 +208,16 @@ impl StreamingQuery {
     }
 }
 
+/// Receives the batches of a query built with [`StreamingQuery::build_batched`].
+pub struct BatchReceiver(async_channel::Receiver<DataFrame>);
+
+impl BatchReceiver {
+    /// Blocks until the next batch is available. Returns `None` once the query has finished.
+    pub fn recv(&self) -> Option<DataFrame> {
+        self.0.recv_blocking().ok()
+    }
+}
+
 pub enum QueryResult {
     Single(DataFrame),
     /// Collected to multiple in-memory sinks
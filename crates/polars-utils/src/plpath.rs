@@ -79,7 +79,11 @@ impl PlCloudPathRef<'_> {
     }
 
     pub fn strip_scheme(&self) -> &str {
-        &self.uri[self.scheme.as_str().len() + "://".len()..]
+        // A path offset by `PlPathRef::offset_bytes` no longer starts with the scheme.
+        self.uri
+            .strip_prefix(self.scheme.as_str())
+            .and_then(|uri| uri.strip_prefix("://"))
+            .unwrap_or(self.uri)
     }
 }
 
@@ -140,6 +144,7 @@ impl_scheme! {
     Http = "http",
     Https = "https",
     Hf = "hf",
+    Memory = "memory",
 }
 
 impl fmt::Display for CloudScheme {
@@ -149,7 +154,7 @@ impl fmt::Display for CloudScheme {
 }
 
 crate::regex_cache::cached_regex! {
-    static CLOUD_SCHEME_REGEX = r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?|hf)$";
+    static CLOUD_SCHEME_REGEX = r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?|hf|memory)$";
 }
 
 impl<'a> PlPathRef<'a> {
@@ -467,4 +472,21 @@ mod tests {
         assert_plpath_join!("/an/even/longer" + "path/wow" => "/an/even/longer/path/wow");
         assert_plpath_join!("/an/even/longer" + "/path/wow" => "/path/wow", "/an/even/longer/path/wow");
     }
+
+    #[test]
+    fn plpath_offset_bytes_components() {
+        let path = PlPath::new("s3://bucket/table/s3=a/b=1/0.parquet");
+        let path = path.as_ref();
+
+        assert_eq!(
+            path.get_normal_components().collect::<Vec<_>>(),
+            ["bucket", "table", "s3=a", "b=1", "0.parquet"]
+        );
+        assert_eq!(
+            path.offset_bytes("s3://bucket/table/".len())
+                .get_normal_components()
+                .collect::<Vec<_>>(),
+            ["s3=a", "b=1", "0.parquet"]
+        );
+    }
 }
@@ -178,6 +178,8 @@
 //!     - `regex` - Use regexes in [column selection]
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to Polars.
+//! * `flight` - Serve the results of lazy queries with an Arrow Flight server.
+//!     - `flight_sql` - Let Flight servers opt in to serving SQL queries over their frames.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
@@ -203,6 +205,10 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `orc` - Read and write Apache ORC format
+//!     - `delta` - Read Delta Lake tables
+//!     - `iceberg` - Read Iceberg tables
+//!     - `sqlite` - Read and write tables of SQLite databases
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -421,6 +427,8 @@ pub use polars_core::{
     apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
     testing,
 };
+#[cfg(feature = "flight")]
+pub use polars_flight as flight;
 #[cfg(feature = "polars-io")]
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
@@ -0,0 +1,224 @@
+use std::sync::{Arc, Mutex};
+
+use polars::io::catalog::unity::client::CatalogClientBuilder;
+use polars::io::catalog::unity::models::TableInfo;
+use polars::prelude::*;
+
+use super::mock_catalog::serve_mock_catalog;
+
+/// Serves a Unity catalog of tables in `main.default`, which rejects table definitions with the
+/// comment `rejected`. Returns the workspace URL and the log of handled requests.
+fn serve_mock_unity_catalog() -> (String, Arc<Mutex<Vec<String>>>) {
+    let tables = Mutex::new(PlHashMap::<String, Vec<(String, String)>>::new());
+    let requests = Arc::new(Mutex::new(vec![]));
+
+    let uri = serve_mock_catalog({
+        let requests = requests.clone();
+        move |method, target, _, body| {
+            requests.lock().unwrap().push(format!("{method} {target}"));
+            let mut tables = tables.lock().unwrap();
+            let path = target.split_once('?').map_or(target, |(path, _)| path);
+            let table_name = path.strip_prefix("/api/2.1/unity-catalog/tables/main.default.");
+
+            if matches!(method, "POST" | "PATCH") && body.contains(r#""comment":"rejected""#) {
+                return (400, r#"{"error_code":"INVALID_PARAMETER_VALUE"}"#.into());
+            }
+
+            match (method, path, table_name) {
+                ("GET", _, Some(name)) => match tables.get(name) {
+                    Some(fields) => (200, to_json_object(fields)),
+                    None => (404, r#"{"error_code":"TABLE_DOES_NOT_EXIST"}"#.into()),
+                },
+                ("PATCH", _, Some(name)) => match tables.get_mut(name) {
+                    Some(fields) => {
+                        for (key, value) in json_object_fields(body) {
+                            match fields.iter_mut().find(|(k, _)| *k == key) {
+                                Some((_, v)) => *v = value,
+                                None => fields.push((key, value)),
+                            }
+                        }
+                        (200, to_json_object(fields))
+                    },
+                    None => (404, r#"{"error_code":"TABLE_DOES_NOT_EXIST"}"#.into()),
+                },
+                ("DELETE", _, Some(name)) => {
+                    tables.remove(name);
+                    (200, "{}".into())
+                },
+                ("POST", "/api/2.1/unity-catalog/tables", _) => {
+                    let mut fields = json_object_fields(body);
+                    let name = fields.iter().find(|(k, _)| k == "name").unwrap().1.clone();
+                    let name = name.trim_matches('"').to_string();
+                    fields.push(("table_id".into(), format!(r#""{name}-id""#)));
+                    for key in ["created_at", "created_by", "updated_at", "updated_by"] {
+                        fields.push((key.into(), "null".into()));
+                    }
+                    let created = to_json_object(&fields);
+                    tables.insert(name, fields);
+                    (200, created)
+                },
+                (
+                    "POST",
+                    "/api/2.1/unity-catalog/temporary-table-credentials"
+                    | "/api/2.1/unity-catalog/temporary-path-credentials",
+                    _,
+                ) => (200, r#"{"expiration_time":0}"#.into()),
+                _ => (404, "{}".into()),
+            }
+        }
+    });
+
+    (uri, requests)
+}
+
+/// Splits a JSON object into its top-level keys and raw JSON values.
+fn json_object_fields(object: &str) -> Vec<(String, String)> {
+    let inner = &object.trim()[1..object.trim().len() - 1];
+    let mut fields = vec![];
+    let (mut depth, mut in_string, mut escaped, mut start) = (0, false, false, 0);
+
+    for (i, c) in inner.char_indices().chain([(inner.len(), ',')]) {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {},
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                if let Some((key, value)) = inner[start..i].split_once(':') {
+                    fields.push((key.trim().trim_matches('"').into(), value.trim().into()));
+                }
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+
+    fields
+}
+
+fn to_json_object(fields: &[(String, String)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!(r#""{key}":{value}"#))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(","))
+}
+
+#[test]
+fn test_sink_catalog_table() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_sink_catalog_table");
+    let _ = std::fs::remove_dir_all(&table_dir);
+    let storage_location = table_dir.to_str().unwrap().to_string();
+
+    let (uri, requests) = serve_mock_unity_catalog();
+    let client = CatalogClientBuilder::new()
+        .with_workspace_url(uri)
+        .build()?;
+
+    let df = df!(
+        "part" => ["a", "b", "a"],
+        "id" => [0i64, 1, 2],
+        "value" => ["x", "y", "z"],
+    )?;
+    let sink = |df: &DataFrame, args: SinkArgsCatalog| {
+        df.clone()
+            .lazy()
+            .sink_catalog_table(&client, "main", "default", "t", args)
+    };
+    let scan = |table_info: &TableInfo| {
+        LazyFrame::scan_catalog_table(table_info, None)?
+            .sort(["id"], Default::default())
+            .collect()
+    };
+
+    assert!(sink(&df, Default::default()).is_err());
+
+    let table_info = sink(
+        &df,
+        SinkArgsCatalog {
+            storage_location: Some(storage_location.clone()),
+            partition_by: Some(vec!["part".into()]),
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(table_info.storage_location, Some(storage_location.clone()));
+    let columns = table_info.columns.as_deref().unwrap();
+    assert_eq!(
+        columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.partition_index))
+            .collect::<Vec<_>>(),
+        [("id", None), ("value", None), ("part", Some(0))]
+    );
+    assert!(table_dir.join("part=a").is_dir());
+    assert_eq!(scan(&table_info)?, df.select(["id", "value", "part"])?);
+
+    // Appending adds files next to the existing ones.
+    let table_info = sink(&df, Default::default())?;
+    assert_eq!(
+        scan(&table_info)?.column("id")?.i64()?.to_vec(),
+        [0, 0, 1, 1, 2, 2].map(Some)
+    );
+
+    let df = df!("id" => [3i64, 4])?;
+    assert!(sink(&df, Default::default()).is_err());
+
+    // A definition that the catalog rejects fails before any data is moved into the table.
+    assert!(
+        sink(
+            &df,
+            SinkArgsCatalog {
+                partition_by: Some(vec![]),
+                write_mode: Some(PartitionWriteMode::OverwriteAll),
+                comment: Some("rejected".into()),
+                ..Default::default()
+            },
+        )
+        .is_err()
+    );
+    assert_eq!(scan(&table_info)?.height(), 6);
+    assert!(!table_dir.join("_temporary").exists());
+
+    // Overwriting an external table updates its definition with the new schema.
+    let table_info = sink(
+        &df,
+        SinkArgsCatalog {
+            partition_by: Some(vec![]),
+            write_mode: Some(PartitionWriteMode::OverwriteAll),
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(table_info.table_id, "t-id");
+    assert_eq!(table_info.columns.as_deref().unwrap().len(), 1);
+    assert!(!table_dir.join("part=a").exists());
+    assert_eq!(scan(&table_info)?, df);
+
+    let requests = requests.lock().unwrap();
+    assert!(requests.iter().any(|r| r.starts_with(
+        "POST /api/2.1/unity-catalog/temporary-table-credentials?table_id=t-id&operation=READ_WRITE"
+    )));
+    assert!(
+        requests
+            .iter()
+            .any(|r| r == "POST /api/2.1/unity-catalog/temporary-path-credentials")
+    );
+    // The table is registered once and updated in place, it is never deleted.
+    let count = |f: &dyn Fn(&str) -> bool| requests.iter().filter(|r| f(r)).count();
+    assert_eq!(count(&|r| r == "POST /api/2.1/unity-catalog/tables"), 1);
+    assert_eq!(
+        count(&|r| r == "PATCH /api/2.1/unity-catalog/tables/main.default.t"),
+        2
+    );
+    assert_eq!(count(&|r| r.starts_with("DELETE ")), 0);
+
+    std::fs::remove_dir_all(&table_dir)?;
+    Ok(())
+}
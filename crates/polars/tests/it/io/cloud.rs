@@ -0,0 +1,30 @@
+use polars::prelude::*;
+
+#[test]
+fn test_sink_parquet_upload_options() -> PolarsResult<()> {
+    let uri = "memory://test_sink_parquet_upload_options/data.parquet";
+    let df = df!("a" => (0..10_000i64).collect::<Vec<_>>())?;
+
+    // Small parts so that the file is written with a multipart upload.
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(PlPath::new(uri)),
+            Default::default(),
+            None,
+            SinkOptions {
+                upload: Some(cloud::CloudUploadOptions {
+                    chunk_size: Some(1024),
+                    max_concurrency: 2,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )?
+        .collect()?;
+
+    let out = LazyFrame::scan_parquet(PlPath::new(uri), Default::default())?.collect()?;
+    assert_eq!(out, df);
+
+    Ok(())
+}
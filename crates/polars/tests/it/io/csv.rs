@@ -1405,3 +1405,245 @@ fn test_read_io_reader() {
         .head(Some(df.height()));
     assert_eq!(&df, &expected);
 }
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "compress", feature = "decompress"))]
+fn test_sink_csv_compressed() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000i32).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("s{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for (compression, name) in [
+        (ExternalCompression::gzip(None)?, "gzip"),
+        (ExternalCompression::zstd(Some(9))?, "zstd"),
+    ] {
+        for engine in [Engine::InMemory, Engine::Streaming] {
+            let path = std::env::temp_dir().join(format!(
+                "polars_test_sink_csv_compressed_{name}_{engine:?}.csv"
+            ));
+
+            // Written in several morsels.
+            let inputs = (0..4)
+                .map(|i| df.slice(i * 250, 250).lazy())
+                .collect::<Vec<_>>();
+            concat(inputs, Default::default())?
+                .sink_csv(
+                    SinkTarget::Path(PlPath::Local(path.clone().into())),
+                    CsvWriterOptions {
+                        compression,
+                        ..Default::default()
+                    },
+                    None,
+                    Default::default(),
+                )?
+                .collect_with_engine(engine)?;
+
+            let bytes = std::fs::read(&path)?;
+            assert!(polars::io::utils::compression::SupportedCompression::check(&bytes).is_some());
+
+            // The file is a single gzip member or zstd frame.
+            let magic: &[u8] = match name {
+                "gzip" => &[0x1f, 0x8b, 0x08],
+                _ => &[0x28, 0xb5, 0x2f, 0xfd],
+            };
+            assert_eq!(
+                bytes.windows(magic.len()).filter(|w| *w == magic).count(),
+                1
+            );
+
+            let out = CsvReadOptions::default()
+                .try_into_reader_with_file_path(Some(path))?
+                .finish()?;
+            assert!(out.equals(&df));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "compress", feature = "decompress"))]
+fn test_sink_csv_partitioned_compressed() -> PolarsResult<()> {
+    let base_path = std::env::temp_dir().join("polars_test_sink_csv_partitioned_compressed");
+    let _ = std::fs::remove_dir_all(&base_path);
+
+    let df = df!["a" => (0..100i32).collect::<Vec<_>>()]?;
+
+    df.clone()
+        .lazy()
+        .sink_csv_partitioned(
+            Arc::new(PlPath::Local(base_path.clone().into())),
+            None,
+            PartitionVariant::MaxSize(30),
+            CsvWriterOptions {
+                compression: ExternalCompression::gzip(Some(1))?,
+                ..Default::default()
+            },
+            None,
+            SinkOptions {
+                mkdir: true,
+                ..Default::default()
+            },
+            None,
+            None,
+        )?
+        .collect_with_engine(Engine::Streaming)?;
+
+    let mut names = std::fs::read_dir(&base_path)?
+        .map(|entry| Ok(entry?.file_name().into_string().unwrap()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    names.sort();
+    assert_eq!(names, ["0.csv.gz", "1.csv.gz", "2.csv.gz", "3.csv.gz"]);
+
+    let out = LazyCsvReader::new(PlPath::Local(base_path.join("*.csv.gz").into()))
+        .finish()?
+        .collect()?;
+    assert!(out.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "compress", feature = "decompress"))]
+fn test_scan_csv_compressed_streaming() -> PolarsResult<()> {
+    let n = 20_000;
+    let df = df![
+        "a" => (0..n).collect::<Vec<i32>>(),
+        // Quoted newlines must not be split across the decompressed chunks.
+        "b" => (0..n).map(|i| if i % 7 == 0 { format!("s\n{i}") } else { format!("s{i}") }).collect::<Vec<_>>(),
+    ]?;
+
+    for (compression, name) in [
+        (ExternalCompression::gzip(None)?, "gzip"),
+        (ExternalCompression::zstd(None)?, "zstd"),
+    ] {
+        let path = std::env::temp_dir().join(format!(
+            "polars_test_scan_csv_compressed_streaming_{name}.csv"
+        ));
+
+        df.clone()
+            .lazy()
+            .sink_csv(
+                SinkTarget::Path(PlPath::Local(path.clone().into())),
+                CsvWriterOptions {
+                    compression,
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let scan = || LazyCsvReader::new(PlPath::Local(path.clone().into())).finish();
+
+        let out = scan()?.collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals(&df));
+
+        let out = scan()?.limit(3).collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals(&df.head(Some(3))));
+
+        let out = scan()?
+            .slice(15_000, 10)
+            .with_row_index("index", None)
+            .collect_with_engine(Engine::Streaming)?;
+        assert!(out.drop("index")?.equals(&df.slice(15_000, 10)));
+
+        let out = scan()?
+            .select([len()])
+            .collect_with_engine(Engine::Streaming)?;
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(n as IdxSize));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_csv_encodings() -> PolarsResult<()> {
+    let df = df![
+        "name" => ["Zoë", "Ærø", "€ 5"],
+        "n" => [1i64, 2, 3],
+    ]?;
+
+    for encoding in [
+        CsvEncoding::Windows1252,
+        CsvEncoding::Utf16Le,
+        CsvEncoding::Utf16Be,
+    ] {
+        let mut buf = vec![];
+        CsvWriter::new(&mut buf)
+            .include_bom(true)
+            .with_encoding(encoding)
+            .finish(&mut df.clone())?;
+        assert!(std::str::from_utf8(&buf).is_err());
+
+        let out = CsvReadOptions::default()
+            .map_parse_options(|opts| opts.with_encoding(encoding))
+            .into_reader_with_file_handle(Cursor::new(buf.clone()))
+            .finish()?;
+        assert!(out.equals(&df));
+
+        // UTF-16 is detected from the BOM.
+        if encoding != CsvEncoding::Windows1252 {
+            let out = CsvReadOptions::default()
+                .into_reader_with_file_handle(Cursor::new(buf))
+                .finish()?;
+            assert!(out.equals(&df));
+        }
+    }
+
+    let mut buf = vec![];
+    let result = CsvWriter::new(&mut buf)
+        .with_encoding(CsvEncoding::Latin1)
+        .finish(&mut df.clone());
+    assert!(result.is_err());
+
+    let out = CsvReadOptions::default()
+        .map_parse_options(|opts| opts.with_encoding(CsvEncoding::Latin1))
+        .into_reader_with_file_handle(Cursor::new(b"a\nZo\xeb\n".to_vec()))
+        .finish()?;
+    assert_eq!(out.column("a")?.str()?.get(0), Some("Zoë"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_csv_encodings() -> PolarsResult<()> {
+    let df = df![
+        "name" => (0..1000).map(|i| format!("Zoë {i} €")).collect::<Vec<_>>(),
+        "n" => (0..1000i64).collect::<Vec<_>>(),
+    ]?;
+
+    for encoding in [CsvEncoding::Windows1252, CsvEncoding::Utf16Le] {
+        let path = std::env::temp_dir().join(format!("polars_test_scan_csv_{encoding:?}.csv"));
+
+        df.clone()
+            .lazy()
+            .sink_csv(
+                SinkTarget::Path(PlPath::Local(path.clone().into())),
+                CsvWriterOptions {
+                    include_bom: true,
+                    encoding,
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let scan = || {
+            LazyCsvReader::new(PlPath::Local(path.clone().into()))
+                .with_encoding(encoding)
+                .finish()
+        };
+
+        let out = scan()?.collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals(&df));
+
+        let out = scan()?.select([len()]).collect()?;
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,443 @@
+use std::path::Path;
+
+use polars::io::catalog::unity::models::{DataSourceFormat, TableInfo, TableType};
+use polars::prelude::*;
+
+const SCHEMA_STRING: &str = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"value\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}},{\"name\":\"part\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}}]}"#;
+
+fn z85_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] =
+        b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+    let mut out = String::new();
+    for chunk in bytes.chunks(4) {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(word);
+        let mut encoded = [0; 5];
+        for c in encoded.iter_mut().rev() {
+            *c = ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        out.push_str(std::str::from_utf8(&encoded).unwrap());
+    }
+    out
+}
+
+/// Serializes a deletion vector with a single array container.
+fn serialize_deletion_vector(deleted: &[u16]) -> Vec<u8> {
+    let mut bytes = vec![];
+    bytes.extend_from_slice(&1681511377u32.to_le_bytes());
+    bytes.extend_from_slice(&1u64.to_le_bytes());
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    bytes.extend_from_slice(&12346u32.to_le_bytes());
+    bytes.extend_from_slice(&1u32.to_le_bytes());
+    bytes.extend_from_slice(&0u16.to_le_bytes());
+    bytes.extend_from_slice(&(deleted.len() as u16 - 1).to_le_bytes());
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    for i in deleted {
+        bytes.extend_from_slice(&i.to_le_bytes());
+    }
+    bytes
+}
+
+/// Descriptor of a deletion vector that is stored inline in the log.
+fn inline_deletion_vector(deleted: &[u16]) -> String {
+    let bytes = serialize_deletion_vector(deleted);
+    format!(
+        r#"{{"storageType":"i","pathOrInlineDv":"{}","sizeInBytes":{},"cardinality":{}}}"#,
+        z85_encode(&bytes),
+        bytes.len(),
+        deleted.len()
+    )
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Writes a deletion vector file to the table root and returns its descriptor. The file name is
+/// derived from `uuid`.
+fn deletion_vector_file(table_dir: &Path, uuid: [u8; 16], deleted: &[u16]) -> PolarsResult<String> {
+    let bytes = serialize_deletion_vector(deleted);
+    let hex = uuid.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    let name = format!(
+        "deletion_vector_{}-{}-{}-{}-{}.bin",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    );
+
+    // A format version byte, followed by the vector framed by its size and checksum.
+    let mut file = vec![1u8];
+    file.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    file.extend_from_slice(&bytes);
+    file.extend_from_slice(&crc32(&bytes).to_be_bytes());
+    std::fs::write(table_dir.join(name), file)?;
+
+    Ok(format!(
+        r#"{{"storageType":"u","pathOrInlineDv":"{}","offset":1,"sizeInBytes":{},"cardinality":{}}}"#,
+        z85_encode(&uuid),
+        bytes.len(),
+        deleted.len()
+    ))
+}
+
+fn write_data_file(table_dir: &Path, path: &str, mut df: DataFrame) -> PolarsResult<()> {
+    let path = table_dir.join(path);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    ParquetWriter::new(std::fs::File::create(path)?).finish(&mut df)?;
+    Ok(())
+}
+
+fn write_commit(table_dir: &Path, version: i64, actions: &[String]) -> PolarsResult<()> {
+    let path = table_dir
+        .join("_delta_log")
+        .join(format!("{version:020}.json"));
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(path, actions.join("\n"))?;
+    Ok(())
+}
+
+fn protocol_and_metadata() -> Vec<String> {
+    vec![
+        r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["deletionVectors"],"writerFeatures":["deletionVectors"]}}"#.into(),
+        format!(
+            r#"{{"metaData":{{"id":"test","format":{{"provider":"parquet","options":{{}}}},"schemaString":"{SCHEMA_STRING}","partitionColumns":["part"],"configuration":{{}}}}}}"#
+        ),
+    ]
+}
+
+fn add(path: &str, part: &str, deletion_vector: Option<String>) -> String {
+    add_with_partition_value(path, &format!(r#""{part}""#), deletion_vector)
+}
+
+/// `part` is the JSON value of the partition column.
+fn add_with_partition_value(path: &str, part: &str, deletion_vector: Option<String>) -> String {
+    let deletion_vector = deletion_vector
+        .map(|dv| format!(r#","deletionVector":{dv}"#))
+        .unwrap_or_default();
+    format!(
+        r#"{{"add":{{"path":"{path}","partitionValues":{{"part":{part}}},"size":0,"modificationTime":0,"dataChange":true{deletion_vector}}}}}"#
+    )
+}
+
+fn remove(path: &str) -> String {
+    format!(r#"{{"remove":{{"path":"{path}","deletionTimestamp":0,"dataChange":true}}}}"#)
+}
+
+#[test]
+fn test_scan_delta() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_scan_delta");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    write_data_file(
+        &table_dir,
+        "part=a/0.parquet",
+        df!("id" => [0i64, 1, 2, 3], "value" => ["a0", "a1", "a2", "a3"])?,
+    )?;
+    write_data_file(
+        &table_dir,
+        "part=b/1.parquet",
+        df!("id" => [4i64, 5], "value" => ["b4", "b5"])?,
+    )?;
+    write_data_file(
+        &table_dir,
+        "part=b/2.parquet",
+        df!("id" => [6i64], "value" => ["b6"])?,
+    )?;
+
+    write_commit(
+        &table_dir,
+        0,
+        &[
+            protocol_and_metadata(),
+            vec![
+                add("part=a/0.parquet", "a", None),
+                add("part=b/1.parquet", "b", None),
+            ],
+        ]
+        .concat(),
+    )?;
+    // Delete rows 1 and 3 of the first file.
+    write_commit(
+        &table_dir,
+        1,
+        &[
+            remove("part=a/0.parquet"),
+            add(
+                "part=a/0.parquet",
+                "a",
+                Some(inline_deletion_vector(&[1, 3])),
+            ),
+        ],
+    )?;
+    write_commit(
+        &table_dir,
+        2,
+        &[
+            remove("part=b/1.parquet"),
+            add("part=b/2.parquet", "b", None),
+        ],
+    )?;
+
+    let path = PlPath::new(table_dir.to_str().unwrap());
+    let scan = |version| {
+        LazyFrame::scan_delta(
+            path.clone(),
+            ScanArgsDelta {
+                version,
+                ..Default::default()
+            },
+        )?
+        .sort(["id"], Default::default())
+        .collect()
+    };
+
+    let expected = df!(
+        "id" => [0i64, 2, 6],
+        "value" => ["a0", "a2", "b6"],
+        "part" => ["a", "a", "b"],
+    )?;
+    assert!(scan(None)?.equals(&expected));
+    assert!(scan(Some(2))?.equals(&expected));
+
+    let expected = df!(
+        "id" => [0i64, 2, 4, 5],
+        "value" => ["a0", "a2", "b4", "b5"],
+        "part" => ["a", "a", "b", "b"],
+    )?;
+    assert!(scan(Some(1))?.equals(&expected));
+
+    let expected = df!(
+        "id" => [0i64, 1, 2, 3, 4, 5],
+        "value" => ["a0", "a1", "a2", "a3", "b4", "b5"],
+        "part" => ["a", "a", "a", "a", "b", "b"],
+    )?;
+    assert!(scan(Some(0))?.equals(&expected));
+
+    let df = LazyFrame::scan_delta(path.clone(), Default::default())?
+        .filter(col("part").eq(lit("a")))
+        .select([col("id")])
+        .collect()?;
+    assert!(df.equals(&df!("id" => [0i64, 2])?));
+
+    assert!(scan(Some(3)).is_err());
+
+    let table_info = TableInfo {
+        name: "test".into(),
+        table_id: "test".into(),
+        table_type: TableType::External,
+        comment: None,
+        storage_location: Some(table_dir.to_str().unwrap().into()),
+        data_source_format: Some(DataSourceFormat::Delta),
+        columns: None,
+        properties: Default::default(),
+        created_at: None,
+        created_by: None,
+        updated_at: None,
+        updated_by: None,
+    };
+    let df = LazyFrame::scan_catalog_table(&table_info, None)?
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert!(scan(None)?.equals(&df));
+
+    std::fs::remove_dir_all(&table_dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_delta_partition_values_from_log() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_scan_delta_partition_values_from_log");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    // The paths of the data files do not have to encode the partition values.
+    write_data_file(
+        &table_dir,
+        "data/0.parquet",
+        df!("id" => [0i64, 1], "value" => ["a0", "a1"])?,
+    )?;
+    write_data_file(
+        &table_dir,
+        "part=x/1.parquet",
+        df!("id" => [2i64], "value" => ["b2"])?,
+    )?;
+    write_data_file(
+        &table_dir,
+        "2.parquet",
+        df!("id" => [3i64], "value" => ["n3"])?,
+    )?;
+
+    write_commit(
+        &table_dir,
+        0,
+        &[
+            protocol_and_metadata(),
+            vec![
+                add("data/0.parquet", "a", None),
+                add("part=x/1.parquet", "b", None),
+                add_with_partition_value("2.parquet", "null", None),
+            ],
+        ]
+        .concat(),
+    )?;
+
+    let path = PlPath::new(table_dir.to_str().unwrap());
+    let out = LazyFrame::scan_delta(path.clone(), Default::default())?
+        .sort(["id"], Default::default())
+        .collect()?;
+    let expected = df!(
+        "id" => [0i64, 1, 2, 3],
+        "value" => ["a0", "a1", "b2", "n3"],
+        "part" => [Some("a"), Some("a"), Some("b"), None],
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    let out = LazyFrame::scan_delta(path, Default::default())?
+        .filter(col("part").eq(lit("b")))
+        .collect()?;
+    assert!(out.equals(&df!("id" => [2i64], "value" => ["b2"], "part" => ["b"])?));
+
+    std::fs::remove_dir_all(&table_dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_delta_deletion_vector_file() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_scan_delta_deletion_vector_file");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    write_data_file(
+        &table_dir,
+        "part=a/0.parquet",
+        df!("id" => [0i64, 1, 2, 3], "value" => ["a0", "a1", "a2", "a3"])?,
+    )?;
+
+    let deletion_vector = deletion_vector_file(&table_dir, [7; 16], &[0, 2])?;
+    write_commit(
+        &table_dir,
+        0,
+        &[
+            protocol_and_metadata(),
+            vec![add("part=a/0.parquet", "a", Some(deletion_vector))],
+        ]
+        .concat(),
+    )?;
+
+    let out = LazyFrame::scan_delta(PlPath::new(table_dir.to_str().unwrap()), Default::default())?
+        .collect()?;
+    let expected = df!(
+        "id" => [1i64, 3],
+        "value" => ["a1", "a3"],
+        "part" => ["a", "a"],
+    )?;
+    assert!(out.equals(&expected));
+
+    std::fs::remove_dir_all(&table_dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_delta_checkpoint() -> PolarsResult<()> {
+    use std::io::Cursor;
+
+    let table_dir = std::env::temp_dir().join("polars_test_scan_delta_checkpoint");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    write_data_file(
+        &table_dir,
+        "part=a/0.parquet",
+        df!("id" => [0i64, 1, 2], "value" => ["a0", "a1", "a2"])?,
+    )?;
+    write_data_file(
+        &table_dir,
+        "part=b/1.parquet",
+        df!("id" => [3i64], "value" => ["b3"])?,
+    )?;
+    write_data_file(
+        &table_dir,
+        "part=b/2.parquet",
+        df!("id" => [4i64], "value" => ["b4"])?,
+    )?;
+
+    // Checkpoints store maps as lists of key / value structs. The commits before the checkpoint
+    // are not written, so the table can only be read through the checkpoint.
+    let deletion_vector = inline_deletion_vector(&[1]).replace('}', r#","offset":null}"#);
+    let checkpoint = [
+        r#"{"protocol":{"minReaderVersion":3,"readerFeatures":["deletionVectors"]}}"#.to_string(),
+        format!(
+            r#"{{"metaData":{{"schemaString":"{SCHEMA_STRING}","partitionColumns":["part"],"configuration":[{{"key":"delta.enableDeletionVectors","value":"true"}}]}}}}"#
+        ),
+        format!(
+            r#"{{"add":{{"path":"part=a/0.parquet","partitionValues":[{{"key":"part","value":"a"}}],"deletionVector":{deletion_vector}}}}}"#
+        ),
+        r#"{"add":{"path":"part=b/1.parquet","partitionValues":[{"key":"part","value":"b"}]}}"#
+            .to_string(),
+    ]
+    .join("\n");
+    let mut checkpoint = JsonReader::new(Cursor::new(checkpoint))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()?;
+    let checkpoint_path = table_dir
+        .join("_delta_log")
+        .join(format!("{:020}.checkpoint.parquet", 1));
+    std::fs::create_dir_all(checkpoint_path.parent().unwrap())?;
+    ParquetWriter::new(std::fs::File::create(checkpoint_path)?).finish(&mut checkpoint)?;
+
+    write_commit(
+        &table_dir,
+        2,
+        &[
+            remove("part=b/1.parquet"),
+            add("part=b/2.parquet", "b", None),
+        ],
+    )?;
+
+    let path = PlPath::new(table_dir.to_str().unwrap());
+    let scan = |version| {
+        LazyFrame::scan_delta(
+            path.clone(),
+            ScanArgsDelta {
+                version,
+                ..Default::default()
+            },
+        )?
+        .sort(["id"], Default::default())
+        .collect()
+    };
+
+    let expected = df!(
+        "id" => [0i64, 2, 4],
+        "value" => ["a0", "a2", "b4"],
+        "part" => ["a", "a", "b"],
+    )?;
+    assert!(scan(None)?.equals(&expected));
+
+    let expected = df!(
+        "id" => [0i64, 2, 3],
+        "value" => ["a0", "a2", "b3"],
+        "part" => ["a", "a", "b"],
+    )?;
+    assert!(scan(Some(1))?.equals(&expected));
+
+    // The commits that would be needed to reconstruct version 0 are missing.
+    assert!(scan(Some(0)).is_err());
+
+    std::fs::remove_dir_all(&table_dir)?;
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use std::io::Cursor;
+
+use polars::io::RowIndex;
+use polars::io::fixed_width::{FixedWidthColumn, FixedWidthReadOptions};
+use polars::prelude::*;
+
+const STATEMENT: &str = "\
+STATEMENT 2024-01
+# generated by the core banking system
+000001 2024-01-02 DEPOSIT       1500.00
+000002 2024-01-05 WITHDRAWAL     -42.17
+
+000003 2024-01-09 FEE
+000004 2024-01-31 INTEREST         3.51
+";
+
+fn columns() -> Vec<FixedWidthColumn> {
+    vec![
+        FixedWidthColumn::new("id", 0, 6, DataType::Int64),
+        FixedWidthColumn::new("date", 7, 10, DataType::Date),
+        FixedWidthColumn::new("kind", 18, 10, DataType::String),
+        FixedWidthColumn::new("amount", 28, 11, DataType::Float64),
+    ]
+}
+
+#[test]
+fn test_read_fixed_width() -> PolarsResult<()> {
+    let options = FixedWidthReadOptions::new(columns())
+        .with_skip_rows(1)
+        .with_comment_prefix(Some("#"));
+
+    let df = FixedWidthReader::new(Cursor::new(STATEMENT))
+        .with_options(options.clone())
+        .finish()?;
+
+    assert_eq!(df.shape(), (4, 4));
+    assert_eq!(df.column("date")?.dtype(), &DataType::Date);
+    assert_eq!(
+        df.column("kind")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        ["DEPOSIT", "WITHDRAWAL", "FEE", "INTEREST"]
+    );
+    assert_eq!(
+        df.column("amount")?.f64()?.to_vec(),
+        [Some(1500.0), Some(-42.17), None, Some(3.51)]
+    );
+
+    let df = FixedWidthReader::new(Cursor::new(STATEMENT))
+        .with_options(options)
+        .with_projection(Some(vec![3, 0]))
+        .with_n_rows(Some(2))
+        .finish()?;
+    let expected = df!("amount" => [1500.0, -42.17], "id" => [1i64, 2])?;
+    assert!(df.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fixed_width_errors() {
+    // Without a comment prefix the comment line is parsed as a record.
+    let options = FixedWidthReadOptions::new(columns()).with_skip_rows(1);
+    let err = FixedWidthReader::new(Cursor::new(STATEMENT))
+        .with_options(options.clone())
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("in fixed-width column 'id'"));
+
+    let df = FixedWidthReader::new(Cursor::new(STATEMENT))
+        .with_options(options.with_ignore_errors(true))
+        .finish()
+        .unwrap();
+    assert_eq!(df.height(), 5);
+    assert_eq!(df.column("id").unwrap().null_count(), 1);
+
+    let mut columns = columns();
+    columns.push(FixedWidthColumn::new("id", 40, 2, DataType::Int32));
+    let err = FixedWidthReader::new(Cursor::new(STATEMENT))
+        .with_options(FixedWidthReadOptions::new(columns))
+        .finish()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::Duplicate(_)));
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_fixed_width() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_fixed_width.txt");
+    let mut data = String::new();
+    for i in 0..2000 {
+        data.push_str(&format!(
+            "{i:>6}{:<8}{:>9.2}\n",
+            format!("name{}", i % 7),
+            i as f64 / 4.0
+        ));
+    }
+    std::fs::write(&path, data)?;
+    let path = PlPath::Local(path.into());
+
+    let args = ScanArgsFixedWidth {
+        columns: Arc::from([
+            FixedWidthColumn::new("id", 0, 6, DataType::Int32),
+            FixedWidthColumn::new("name", 6, 8, DataType::String),
+            FixedWidthColumn::new("value", 14, 9, DataType::Float64),
+        ]),
+        ..Default::default()
+    };
+
+    let out = LazyFrame::scan_fixed_width(path.clone(), args.clone())?
+        .filter(col("id").gt_eq(lit(1500)))
+        .select([col("name"), col("value")])
+        .limit(3)
+        .collect()?;
+    let expected = df!(
+        "name" => ["name2", "name3", "name4"],
+        "value" => [375.0, 375.25, 375.5],
+    )?;
+    assert!(out.equals(&expected));
+
+    let out = LazyFrame::scan_fixed_width(
+        path.clone(),
+        ScanArgsFixedWidth {
+            n_rows: Some(5),
+            row_index: Some(RowIndex {
+                name: "idx".into(),
+                offset: 10,
+            }),
+            ..args.clone()
+        },
+    )?
+    .with_new_streaming(true)
+    .collect()?;
+    assert_eq!(out.shape(), (5, 4));
+    assert_eq!(out.column("idx")?.idx()?.get(0), Some(10));
+
+    let out = LazyFrame::scan_fixed_width(path, args)?
+        .select([len()])
+        .collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(2000));
+
+    Ok(())
+}
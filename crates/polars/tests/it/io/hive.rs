@@ -0,0 +1,84 @@
+use polars::io::HiveOptions;
+use polars::prelude::*;
+
+#[test]
+fn test_scan_parquet_path_template() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_path_template");
+    let _ = std::fs::remove_dir_all(&root);
+
+    for (dir, source, value) in [
+        ("2024/01/02", "web", 0i64),
+        ("2024/01/02", "app", 1),
+        ("2024/02/01", "web", 2),
+    ] {
+        let dir = root.join(dir);
+        std::fs::create_dir_all(&dir)?;
+        let mut df = df!("value" => [value])?;
+        ParquetWriter::new(std::fs::File::create(
+            dir.join(format!("{source}.parquet")),
+        )?)
+        .finish(&mut df)?;
+    }
+
+    // Not a parquet file, this can only be scanned if it is pruned.
+    std::fs::create_dir_all(root.join("2025/01/01"))?;
+    std::fs::write(root.join("2025/01/01/web.parquet"), b"not parquet")?;
+
+    let scan = |hive_options: HiveOptions| {
+        LazyFrame::scan_parquet(
+            PlPath::new(&format!("{}/**/*.parquet", root.to_str().unwrap())),
+            ScanArgsParquet {
+                hive_options,
+                ..Default::default()
+            },
+        )
+    };
+    let hive_options = HiveOptions {
+        path_template: Some("{year}/{month}/{day}/{source}.parquet".into()),
+        ..HiveOptions::new_enabled()
+    };
+
+    let out = scan(hive_options.clone())?
+        .filter(col("year").eq(lit(2024)))
+        .sort(["value"], Default::default())
+        .collect()?;
+
+    assert_eq!(
+        out,
+        df!(
+            "value" => [0i64, 1, 2],
+            "year" => [2024i64, 2024, 2024],
+            "month" => [1i64, 1, 2],
+            "day" => [2i64, 2, 1],
+            "source" => ["web", "app", "web"],
+        )?
+    );
+
+    let out = scan(HiveOptions {
+        schema: Some(Arc::new(Schema::from_iter([
+            Field::new("year".into(), DataType::Int32),
+            Field::new("month".into(), DataType::String),
+            Field::new("day".into(), DataType::String),
+            Field::new("source".into(), DataType::String),
+        ]))),
+        ..hive_options.clone()
+    })?
+    .filter(col("source").eq(lit("app")).and(col("year").lt(lit(2025))))
+    .collect()?;
+
+    assert_eq!(out.column("year")?.dtype(), &DataType::Int32);
+    assert_eq!(out.column("month")?.str()?.get(0), Some("01"));
+    assert_eq!(out.column("value")?.i64()?.get(0), Some(1));
+
+    assert!(
+        scan(HiveOptions {
+            path_template: Some("{year}/{month}{day}/{source}.parquet".into()),
+            ..HiveOptions::new_enabled()
+        })?
+        .collect()
+        .is_err()
+    );
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
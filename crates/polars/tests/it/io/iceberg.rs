@@ -0,0 +1,602 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use polars::io::RowIndex;
+use polars::io::avro::AvroWriter;
+use polars::io::catalog::iceberg::client::IcebergCatalogClientBuilder;
+use polars::io::catalog::iceberg::models::TableIdentifier;
+use polars::io::catalog::unity::models::{DataSourceFormat, TableInfo, TableType};
+use polars::prelude::*;
+
+use super::mock_catalog::serve_mock_catalog;
+
+const SCHEMA: &str = r#"{"type":"struct","schema-id":0,"fields":[{"id":1,"name":"id","required":true,"type":"long"},{"id":2,"name":"value","required":false,"type":"string"},{"id":3,"name":"part","required":false,"type":"string"}]}"#;
+
+fn path_str(path: &Path) -> String {
+    path.to_str().unwrap().to_string()
+}
+
+fn write_parquet(path: &Path, mut df: DataFrame) -> PolarsResult<()> {
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    ParquetWriter::new(std::fs::File::create(path)?).finish(&mut df)?;
+    Ok(())
+}
+
+fn write_avro(path: &Path, mut df: DataFrame) -> PolarsResult<()> {
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    AvroWriter::new(std::fs::File::create(path)?).finish(&mut df)?;
+    Ok(())
+}
+
+struct Entry<'a> {
+    content: i32,
+    path: &'a Path,
+    part: &'a str,
+    record_count: i64,
+    equality_ids: Option<Vec<i32>>,
+}
+
+/// Writes a manifest whose entries inherit the sequence number of the manifest.
+fn write_manifest(path: &Path, entries: &[Entry]) -> PolarsResult<()> {
+    let n = entries.len();
+    let partition = StructChunked::from_columns(
+        "partition".into(),
+        n,
+        &[Column::new(
+            "part".into(),
+            entries.iter().map(|e| e.part).collect::<Vec<_>>(),
+        )],
+    )?;
+    let equality_ids = entries
+        .iter()
+        .map(|e| {
+            e.equality_ids
+                .as_ref()
+                .map(|ids| Series::new("".into(), ids))
+        })
+        .collect::<ListChunked>()
+        .with_name("equality_ids".into())
+        .cast(&DataType::List(Box::new(DataType::Int32)))?;
+
+    let data_file = StructChunked::from_columns(
+        "data_file".into(),
+        n,
+        &[
+            Column::new(
+                "content".into(),
+                entries.iter().map(|e| e.content).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "file_path".into(),
+                entries.iter().map(|e| path_str(e.path)).collect::<Vec<_>>(),
+            ),
+            Column::new("file_format".into(), vec!["PARQUET"; n]),
+            partition.into_column(),
+            Column::new(
+                "record_count".into(),
+                entries.iter().map(|e| e.record_count).collect::<Vec<_>>(),
+            ),
+            equality_ids.into_column(),
+        ],
+    )?;
+
+    write_avro(
+        path,
+        DataFrame::new(vec![
+            Column::new("status".into(), vec![1i32; n]),
+            Column::new("sequence_number".into(), vec![None::<i64>; n]),
+            data_file.into_column(),
+        ])?,
+    )
+}
+
+fn write_manifest_list(path: &Path, manifests: &[(&Path, i64)]) -> PolarsResult<()> {
+    write_avro(
+        path,
+        df!(
+            "manifest_path" => manifests.iter().map(|(p, _)| path_str(p)).collect::<Vec<_>>(),
+            "partition_spec_id" => vec![0i32; manifests.len()],
+            "sequence_number" => manifests.iter().map(|(_, seq)| *seq).collect::<Vec<_>>(),
+        )?,
+    )
+}
+
+fn write_metadata(
+    path: &Path,
+    current_snapshot_id: i64,
+    snapshots: &[(i64, &Path)],
+) -> PolarsResult<()> {
+    write_metadata_with_schema(path, SCHEMA, 3, current_snapshot_id, snapshots)
+}
+
+fn write_metadata_with_schema(
+    path: &Path,
+    schema: &str,
+    last_column_id: i32,
+    current_snapshot_id: i64,
+    snapshots: &[(i64, &Path)],
+) -> PolarsResult<()> {
+    let snapshots = snapshots
+        .iter()
+        .map(|(id, manifest_list)| {
+            format!(
+                r#"{{"snapshot-id":{id},"sequence-number":{id},"timestamp-ms":0,"schema-id":0,"manifest-list":"{}"}}"#,
+                path_str(manifest_list)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(
+        path,
+        format!(
+            r#"{{"format-version":2,"table-uuid":"test","location":"","last-updated-ms":0,"last-column-id":{last_column_id},"schemas":[{schema}],"current-schema-id":0,"partition-specs":[{{"spec-id":0,"fields":[{{"source-id":3,"field-id":1000,"name":"part","transform":"identity"}}]}}],"default-spec-id":0,"current-snapshot-id":{current_snapshot_id},"snapshots":[{snapshots}]}}"#
+        ),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_iceberg_rest_catalog() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_scan_iceberg_rest_catalog");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    let a0 = table_dir.join("data/part=a/0.parquet");
+    let manifest = table_dir.join("metadata/m0.avro");
+    let manifest_list = table_dir.join("metadata/snap-1.avro");
+    let metadata = table_dir.join("metadata/v1.metadata.json");
+
+    write_parquet(
+        &a0,
+        df!("id" => [0i64, 1], "value" => ["a0", "a1"], "part" => ["a"; 2])?,
+    )?;
+    write_manifest(
+        &manifest,
+        &[Entry {
+            content: 0,
+            path: &a0,
+            part: "a",
+            record_count: 2,
+            equality_ids: None,
+        }],
+    )?;
+    write_manifest_list(&manifest_list, &[(&manifest, 1)])?;
+    write_metadata(&metadata, 1, &[(1, &manifest_list)])?;
+
+    let load_table_result = format!(
+        r#"{{"metadata-location":"{}","metadata":{{"format-version":2,"table-uuid":"test","location":"{}","current-snapshot-id":1}},"config":{{}},"storage-credentials":[{{"prefix":"s3://other","config":{{"s3.access-key-id":"key"}}}}]}}"#,
+        path_str(&metadata),
+        path_str(&table_dir),
+    );
+
+    let requests = Arc::new(Mutex::new(vec![]));
+    let uri = serve_mock_catalog({
+        let requests = requests.clone();
+        move |method, target, headers, _| {
+            let header = |name: &str| {
+                headers
+                    .iter()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v.clone())
+            };
+            requests.lock().unwrap().push((
+                format!("{method} {target}"),
+                header("authorization"),
+                header("x-iceberg-access-delegation"),
+            ));
+
+            match (method, target) {
+                ("GET", "/v1/config?warehouse=wh") => (
+                    200,
+                    r#"{"defaults":{},"overrides":{"prefix":"wh-prefix"}}"#.into(),
+                ),
+                ("GET", "/v1/wh-prefix/namespaces") => (
+                    200,
+                    r#"{"namespaces":[["db"]],"next-page-token":"p2"}"#.into(),
+                ),
+                ("GET", "/v1/wh-prefix/namespaces?pageToken=p2") => {
+                    (200, r#"{"namespaces":[["db","nested"]]}"#.into())
+                },
+                ("GET", "/v1/wh-prefix/namespaces/db%1Fnested") => (
+                    200,
+                    r#"{"namespace":["db","nested"],"properties":{"owner":"polars"}}"#.into(),
+                ),
+                ("GET", "/v1/wh-prefix/namespaces/db/tables") => (
+                    200,
+                    r#"{"identifiers":[{"namespace":["db"],"name":"t"}]}"#.into(),
+                ),
+                ("GET", "/v1/wh-prefix/namespaces/db/tables/t") => (200, load_table_result.clone()),
+                _ => (
+                    404,
+                    r#"{"error":{"message":"not found","type":"NoSuchTableException","code":404}}"#
+                        .into(),
+                ),
+            }
+        }
+    });
+
+    let runtime = polars::io::pl_async::get_runtime();
+    let client = runtime.block_on(
+        IcebergCatalogClientBuilder::new()
+            .with_uri(format!("{uri}/"))
+            .with_warehouse("wh")
+            .with_bearer_token("token")
+            .build(),
+    )?;
+
+    assert_eq!(
+        runtime.block_on(client.list_namespaces(None))?,
+        [vec!["db".to_string()], vec!["db".into(), "nested".into()]]
+    );
+    let namespace = runtime.block_on(client.get_namespace(&["db", "nested"]))?;
+    assert_eq!(namespace.properties["owner"], "polars");
+    assert_eq!(
+        runtime.block_on(client.list_tables(&["db"]))?,
+        [TableIdentifier {
+            namespace: vec!["db".into()],
+            name: "t".into(),
+        }]
+    );
+
+    let table = runtime.block_on(client.load_table(&["db"], "t"))?;
+    assert_eq!(table.scan_location(), path_str(&metadata));
+    assert_eq!(table.metadata.current_snapshot_id, Some(1));
+
+    let out = LazyFrame::scan_iceberg_catalog_table(&client, &["db"], "t", Default::default())?
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out,
+        df!("id" => [0i64, 1], "value" => ["a0", "a1"], "part" => ["a"; 2])?
+    );
+
+    let err = runtime
+        .block_on(client.load_table(&["db"], "missing"))
+        .unwrap_err();
+    assert!(err.to_string().contains("NoSuchTableException"));
+
+    let requests = requests.lock().unwrap();
+    assert!(
+        requests
+            .iter()
+            .all(|(_, auth, _)| auth.as_deref() == Some("Bearer token"))
+    );
+    assert!(
+        requests
+            .iter()
+            .filter(|(request, _, _)| request.contains("/tables/"))
+            .all(|(_, _, delegation)| delegation.as_deref() == Some("vended-credentials"))
+    );
+
+    std::fs::remove_dir_all(&table_dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "aws")]
+fn test_iceberg_rest_catalog_vended_credentials() -> PolarsResult<()> {
+    use polars::io::catalog::iceberg::models::{LoadTableResult, StorageCredential, TableMetadata};
+    use polars::io::cloud::CloudOptions;
+
+    let table = LoadTableResult {
+        metadata_location: Some("s3://bucket/t/metadata/v1.metadata.json".into()),
+        metadata: TableMetadata {
+            format_version: 2,
+            table_uuid: None,
+            location: "s3://bucket/t".into(),
+            current_snapshot_id: None,
+            properties: Default::default(),
+        },
+        config: PlHashMap::from_iter([("client.region".into(), "eu-west-1".into())]),
+        storage_credentials: vec![
+            StorageCredential {
+                prefix: "s3://bucket".into(),
+                config: PlHashMap::from_iter([("s3.access-key-id".into(), "bucket".into())]),
+            },
+            StorageCredential {
+                prefix: "s3://bucket/t".into(),
+                config: PlHashMap::from_iter([("s3.access-key-id".into(), "table".into())]),
+            },
+            StorageCredential {
+                prefix: "s3://other".into(),
+                config: PlHashMap::from_iter([("s3.access-key-id".into(), "other".into())]),
+            },
+        ],
+    };
+
+    let expected = CloudOptions::from_untyped_config(
+        table.scan_location(),
+        [("aws_region", "eu-west-1"), ("aws_access_key_id", "table")],
+    )?;
+    assert_eq!(table.cloud_options(None)?, expected);
+
+    // Vended keys overwrite the configured ones, other keys are kept.
+    let cloud_options = CloudOptions::from_untyped_config(
+        table.scan_location(),
+        [
+            ("aws_access_key_id", "configured"),
+            ("aws_secret_access_key", "secret"),
+        ],
+    )?;
+    let expected = CloudOptions::from_untyped_config(
+        table.scan_location(),
+        [
+            ("aws_secret_access_key", "secret"),
+            ("aws_region", "eu-west-1"),
+            ("aws_access_key_id", "table"),
+        ],
+    )?;
+    assert_eq!(table.cloud_options(Some(cloud_options))?, expected);
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_iceberg() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_scan_iceberg");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    let data = table_dir.join("data");
+    let metadata = table_dir.join("metadata");
+
+    let a0 = data.join("part=a/0.parquet");
+    let b1 = data.join("part=b/1.parquet");
+    let b2 = data.join("part=b/2.parquet");
+    let c3 = data.join("part=c/3.parquet");
+    let position_deletes = data.join("part=a/position-deletes.parquet");
+    let equality_deletes = data.join("part=b/equality-deletes.parquet");
+
+    write_parquet(
+        &a0,
+        df!("id" => [0i64, 1, 2, 3], "value" => ["a0", "a1", "a2", "a3"], "part" => ["a"; 4])?,
+    )?;
+    write_parquet(
+        &b1,
+        df!("id" => [4i64, 5], "value" => ["b4", "b5"], "part" => ["b"; 2])?,
+    )?;
+    // Added in the same snapshot as the equality delete, so the delete does not apply to it.
+    write_parquet(
+        &b2,
+        df!("id" => [5i64, 6], "value" => ["b5", "b6"], "part" => ["b"; 2])?,
+    )?;
+    write_parquet(
+        &position_deletes,
+        df!("file_path" => [path_str(&a0)], "pos" => [1i64])?,
+    )?;
+    write_parquet(&equality_deletes, df!("id" => [5i64])?)?;
+
+    let data_entry = |path, part, record_count| Entry {
+        content: 0,
+        path,
+        part,
+        record_count,
+        equality_ids: None,
+    };
+
+    let m1 = metadata.join("m1.avro");
+    let m2 = metadata.join("m2.avro");
+    let m3 = metadata.join("m3.avro");
+    let m4 = metadata.join("m4.avro");
+    write_manifest(&m1, &[data_entry(&a0, "a", 4), data_entry(&b1, "b", 2)])?;
+    write_manifest(&m2, &[data_entry(&b2, "b", 2)])?;
+    write_manifest(
+        &m3,
+        &[
+            Entry {
+                content: 1,
+                path: &position_deletes,
+                part: "a",
+                record_count: 1,
+                equality_ids: None,
+            },
+            Entry {
+                content: 2,
+                path: &equality_deletes,
+                part: "b",
+                record_count: 1,
+                equality_ids: Some(vec![1]),
+            },
+        ],
+    )?;
+    // This data file does not exist, it must be pruned by the partition filter.
+    write_manifest(&m4, &[data_entry(&c3, "c", 1)])?;
+
+    let snap1 = metadata.join("snap-1.avro");
+    let snap2 = metadata.join("snap-2.avro");
+    let snap3 = metadata.join("snap-3.avro");
+    write_manifest_list(&snap1, &[(&m1, 1)])?;
+    write_manifest_list(&snap2, &[(&m1, 1), (&m2, 2), (&m3, 2)])?;
+    write_manifest_list(&snap3, &[(&m1, 1), (&m2, 2), (&m3, 2), (&m4, 3)])?;
+
+    write_metadata(&metadata.join("v1.metadata.json"), 1, &[(1, &snap1)])?;
+    write_metadata(
+        &metadata.join("v2.metadata.json"),
+        3,
+        &[(1, &snap1), (2, &snap2), (3, &snap3)],
+    )?;
+
+    let table_path = PlPath::new(table_dir.to_str().unwrap());
+    let scan_filtered = |args: ScanArgsIceberg, filter: Expr| -> PolarsResult<DataFrame> {
+        LazyFrame::scan_iceberg(table_path.clone(), args)?
+            .filter(filter)
+            .sort(["id"], Default::default())
+            .collect()
+    };
+    let scan = |args: ScanArgsIceberg| -> PolarsResult<DataFrame> {
+        LazyFrame::scan_iceberg(table_path.clone(), args)?
+            .sort(["id"], Default::default())
+            .collect()
+    };
+
+    let out = scan(ScanArgsIceberg {
+        snapshot_id: Some(2),
+        ..Default::default()
+    })?;
+    assert_eq!(
+        out,
+        df!(
+            "id" => [0i64, 2, 3, 4, 5, 6],
+            "value" => ["a0", "a2", "a3", "b4", "b5", "b6"],
+            "part" => ["a", "a", "a", "b", "b", "b"],
+        )?
+    );
+
+    let out = scan(ScanArgsIceberg {
+        snapshot_id: Some(1),
+        ..Default::default()
+    })?;
+    assert_eq!(
+        out.column("id")?.i64()?.to_vec(),
+        [0, 1, 2, 3, 4, 5].map(Some)
+    );
+
+    // The current snapshot of the latest metadata file.
+    let out = scan_filtered(
+        Default::default(),
+        col("part").neq(lit("c")).and(col("id").gt(lit(2i64))),
+    )?;
+    assert_eq!(out.column("id")?.i64()?.to_vec(), [3, 4, 5, 6].map(Some));
+
+    // The row index and slice are applied by the scan, before the filter.
+    let out = scan_filtered(
+        ScanArgsIceberg {
+            snapshot_id: Some(2),
+            n_rows: Some(5),
+            row_index: Some(RowIndex {
+                name: "index".into(),
+                offset: 0,
+            }),
+            ..Default::default()
+        },
+        col("part").eq(lit("b")),
+    )?;
+    assert_eq!(out.column("index")?.idx()?.to_vec(), [3, 4].map(Some));
+    assert_eq!(out.column("id")?.i64()?.to_vec(), [4, 5].map(Some));
+
+    assert!(
+        scan(ScanArgsIceberg {
+            snapshot_id: Some(4),
+            ..Default::default()
+        })
+        .is_err()
+    );
+
+    // The table properties point at an older metadata file.
+    let table_info = TableInfo {
+        name: "test".into(),
+        table_id: "test".into(),
+        table_type: TableType::Managed,
+        comment: None,
+        storage_location: Some(path_str(&table_dir)),
+        data_source_format: Some(DataSourceFormat::Iceberg),
+        columns: None,
+        properties: PlHashMap::from_iter([(
+            "metadata_location".into(),
+            path_str(&metadata.join("v1.metadata.json")),
+        )]),
+        created_at: None,
+        created_by: None,
+        updated_at: None,
+        updated_by: None,
+    };
+    let out = LazyFrame::scan_catalog_table(&table_info, None)?
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("id")?.i64()?.to_vec(),
+        [0, 1, 2, 3, 4, 5].map(Some)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_iceberg_equality_delete_renamed_column() -> PolarsResult<()> {
+    let table_dir = std::env::temp_dir().join("polars_test_scan_iceberg_renamed_column");
+    let _ = std::fs::remove_dir_all(&table_dir);
+
+    let data = table_dir.join("data");
+    let metadata = table_dir.join("metadata");
+
+    let field_id = |name: &str, field_id| ParquetFieldOverwrites {
+        name: Some(name.into()),
+        children: ChildFieldOverwrites::None,
+        required: None,
+        field_id: Some(field_id),
+        metadata: None,
+    };
+
+    // Written before `id` (field 1) was renamed to `key`, and a new `id` column (field 4) was
+    // added.
+    let a0 = data.join("part=a/0.parquet");
+    std::fs::create_dir_all(a0.parent().unwrap())?;
+    ParquetWriter::new(std::fs::File::create(&a0)?)
+        .with_field_overwrites(vec![
+            field_id("id", 1),
+            field_id("value", 2),
+            field_id("part", 3),
+        ])
+        .finish(&mut df!(
+            "id" => [0i64, 1, 2],
+            "value" => ["a0", "a1", "a2"],
+            "part" => ["a"; 3],
+        )?)?;
+
+    // Deletes on field 1, which is now named `key`.
+    let equality_deletes = data.join("part=a/equality-deletes.parquet");
+    ParquetWriter::new(std::fs::File::create(&equality_deletes)?)
+        .with_field_overwrites(vec![field_id("key", 1)])
+        .finish(&mut df!("key" => [1i64])?)?;
+
+    let m1 = metadata.join("m1.avro");
+    let m2 = metadata.join("m2.avro");
+    write_manifest(
+        &m1,
+        &[Entry {
+            content: 0,
+            path: &a0,
+            part: "a",
+            record_count: 3,
+            equality_ids: None,
+        }],
+    )?;
+    write_manifest(
+        &m2,
+        &[Entry {
+            content: 2,
+            path: &equality_deletes,
+            part: "a",
+            record_count: 1,
+            equality_ids: Some(vec![1]),
+        }],
+    )?;
+
+    let snap = metadata.join("snap-1.avro");
+    write_manifest_list(&snap, &[(&m1, 1), (&m2, 2)])?;
+
+    let schema = r#"{"type":"struct","schema-id":0,"fields":[{"id":1,"name":"key","required":true,"type":"long"},{"id":2,"name":"value","required":false,"type":"string"},{"id":3,"name":"part","required":false,"type":"string"},{"id":4,"name":"id","required":false,"type":"long"}]}"#;
+    write_metadata_with_schema(
+        &metadata.join("v1.metadata.json"),
+        schema,
+        4,
+        1,
+        &[(1, &snap)],
+    )?;
+
+    let out =
+        LazyFrame::scan_iceberg(PlPath::new(table_dir.to_str().unwrap()), Default::default())?
+            .sort(["key"], Default::default())
+            .collect()?;
+    assert_eq!(
+        out,
+        df!(
+            "key" => [0i64, 2],
+            "value" => ["a0", "a2"],
+            "part" => ["a"; 2],
+            "id" => [None::<i64>; 2],
+        )?
+    );
+
+    Ok(())
+}
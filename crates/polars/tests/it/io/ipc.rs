@@ -151,3 +151,407 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_sink_scan_ipc_stream() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000i32).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("s{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let path =
+            std::env::temp_dir().join(format!("polars_test_sink_ipc_stream_{engine:?}.arrows"));
+
+        df.clone()
+            .lazy()
+            .sink_ipc(
+                SinkTarget::Path(PlPath::Local(path.clone().into())),
+                IpcWriterOptions {
+                    format: IpcFormat::Stream,
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            )?
+            .collect_with_engine(engine)?;
+
+        // The stream format has no footer, so the file reader must reject it.
+        assert!(
+            IpcReader::new(std::fs::File::open(&path)?)
+                .finish()
+                .is_err()
+        );
+
+        let path = PlPath::Local(path.into());
+        let out = LazyFrame::scan_ipc_stream(path.clone(), Default::default())?.collect()?;
+        assert!(out.equals(&df));
+
+        let out = LazyFrame::scan_ipc_stream(path.clone(), Default::default())?
+            .select([col("b")])
+            .slice(10, 3)
+            .with_row_index("idx", None)
+            .collect()?;
+        assert_eq!(
+            out,
+            df!["idx" => [0 as IdxSize, 1, 2], "b" => ["s10", "s11", "s12"]]?
+        );
+
+        let out = LazyFrame::scan_ipc_stream(
+            path.clone(),
+            ScanArgsIpc {
+                n_rows: Some(5),
+                row_index: Some(polars::io::RowIndex {
+                    name: "idx".into(),
+                    offset: 100,
+                }),
+                ..Default::default()
+            },
+        )?
+        .collect()?;
+        assert_eq!(
+            out.column("idx")?.idx()?.cont_slice()?,
+            &[100, 101, 102, 103, 104]
+        );
+
+        let out = LazyFrame::scan_ipc_stream(path, Default::default())?
+            .select([len()])
+            .collect()?;
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "ipc_streaming"))]
+fn test_scan_ipc_stream_missing_columns_insert() -> PolarsResult<()> {
+    let mut paths = vec![];
+    for (i, mut df) in [
+        df!["a" => [1i32, 2], "b" => ["x", "y"]]?,
+        df!["a" => [3i32]]?,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let path = std::env::temp_dir().join(format!("polars_test_ipc_stream_missing_{i}.arrows"));
+        IpcStreamWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        paths.push(PlPath::Local(path.into()));
+    }
+
+    let scan = |missing_columns_policy| {
+        DslBuilder::scan_ipc_stream(
+            ScanSources::Paths(paths.clone().into()),
+            Default::default(),
+            UnifiedScanArgs {
+                missing_columns_policy,
+                ..Default::default()
+            },
+        )
+        .map(|builder| LazyFrame::from(builder.build()))
+    };
+
+    let out = scan(MissingColumnsPolicy::Insert)?
+        .select([col("b"), col("a")])
+        .collect()?;
+    assert_eq!(
+        out,
+        df!["b" => [Some("x"), Some("y"), None], "a" => [1i32, 2, 3]]?
+    );
+
+    let out = scan(MissingColumnsPolicy::Insert)?
+        .select([col("b")])
+        .collect()?;
+    assert_eq!(out, df!["b" => [Some("x"), Some("y"), None]]?);
+
+    assert!(
+        scan(MissingColumnsPolicy::Raise)?
+            .select([col("b")])
+            .collect()
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "ipc_streaming"))]
+fn test_sink_ipc_stream_dyn_target() -> PolarsResult<()> {
+    use std::sync::Mutex;
+
+    use polars::io::utils::file::DynWriteable;
+    use polars::io::utils::sync_on_close::SyncOnCloseType;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl DynWriteable for SharedBuffer {
+        fn as_dyn_write(&self) -> &(dyn std::io::Write + Send + 'static) {
+            self as _
+        }
+        fn as_mut_dyn_write(&mut self) -> &mut (dyn std::io::Write + Send + 'static) {
+            self as _
+        }
+        fn close(self: Box<Self>) -> std::io::Result<()> {
+            Ok(())
+        }
+        fn sync_on_close(&mut self, _sync_on_close: SyncOnCloseType) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let df = create_df();
+    let buffer = SharedBuffer::default();
+    let target = SinkTarget::Dyn(SpecialEq::new(Arc::new(Mutex::new(Some(
+        Box::new(buffer.clone()) as Box<dyn DynWriteable>,
+    )))));
+
+    df.clone()
+        .lazy()
+        .sink_ipc(
+            target,
+            IpcWriterOptions {
+                format: IpcFormat::Stream,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect_with_engine(Engine::Streaming)?;
+
+    let bytes = std::mem::take(&mut *buffer.0.lock().unwrap());
+    let out = IpcStreamReader::new(Cursor::new(bytes)).finish()?;
+    assert!(out.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_ipc_record_batch_statistics() -> PolarsResult<()> {
+    use polars::io::ipc::statistics::RecordBatchStatistics;
+    use polars_core::utils::arrow::io::ipc::read::read_file_metadata;
+
+    let df = df![
+        "a" => (0..1000i32).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| (i % 7 != 0).then(|| format!("s{i:04}"))).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| vec![i]).map(|v| Series::new("".into(), v)).collect::<Vec<_>>(),
+    ]?;
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let path = std::env::temp_dir().join(format!(
+            "polars_test_ipc_record_batch_statistics_{engine:?}.arrow"
+        ));
+
+        // Write 4 record batches of 250 rows.
+        let chunked = (0..4)
+            .map(|i| df.slice(i * 250, 250))
+            .reduce(|mut acc, x| {
+                acc.vstack_mut_owned(x).unwrap();
+                acc
+            })
+            .unwrap();
+        chunked
+            .lazy()
+            .sink_ipc(
+                SinkTarget::Path(PlPath::Local(path.clone().into())),
+                IpcWriterOptions {
+                    chunk_size: 250,
+                    record_batch_statistics: true,
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            )?
+            .collect_with_engine(engine)?;
+
+        let metadata = read_file_metadata(&mut std::fs::File::open(&path)?)?;
+        let statistics =
+            RecordBatchStatistics::from_custom_metadata(&metadata.custom_schema_metadata.unwrap())?
+                .unwrap();
+        assert_eq!(statistics.num_batches(), 4);
+        assert_eq!(statistics.batch_len(1)?, 250);
+
+        let batch = statistics.batch_statistics(1)?;
+        assert_eq!(
+            batch.keys().map(|k| k.as_str()).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(batch["a"].min, AnyValue::Int32(250));
+        assert_eq!(batch["a"].max, AnyValue::Int32(499));
+        assert_eq!(batch["a"].null_count, Some(0));
+        assert_eq!(batch["b"].min, AnyValue::StringOwned("s0250".into()));
+        assert_eq!(batch["b"].null_count, Some(36));
+
+        let path = PlPath::Local(path.into());
+        for (predicate, n_pruned) in [
+            (col("a").gt_eq(lit(600)), 2),
+            (col("a").lt(lit(10)).or(col("a").gt(lit(990))), 2),
+            (col("b").eq(lit("s0300")), 3),
+            (col("a").gt(lit(5000)), 4),
+        ] {
+            let (out, _, io_metrics) = LazyFrame::scan_ipc(path.clone(), Default::default())?
+                .filter(predicate.clone())
+                .profile_with_io_metrics()?;
+            let expected = df.clone().lazy().filter(predicate).collect()?;
+            assert!(out.equals_missing(&expected));
+
+            let get = |name: &str| -> PolarsResult<u64> {
+                Ok(io_metrics.column(name)?.u64()?.get(0).unwrap())
+            };
+            assert_eq!(get("row_groups_pruned")?, n_pruned);
+            assert_eq!(get("row_groups_read")?, 4 - n_pruned);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ipc_record_batch_statistics_stream_format() {
+    let mut df = create_df();
+    let result = IpcWriter::new(Cursor::new(Vec::new()))
+        .with_format(IpcFormat::Stream)
+        .with_record_batch_statistics(true)
+        .finish(&mut df);
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "lazy")]
+fn sink_ipc_partitioned_by_a(
+    lf: LazyFrame,
+    base_path: &std::path::Path,
+    write_mode: PartitionWriteMode,
+) -> PolarsResult<()> {
+    lf.sink_ipc_partitioned(
+        Arc::new(PlPath::Local(base_path.into())),
+        None,
+        PartitionVariant::ByKey {
+            key_exprs: vec![col("a")],
+            include_key: false,
+        },
+        Default::default(),
+        None,
+        SinkOptions {
+            write_mode: Some(write_mode),
+            ..Default::default()
+        },
+        None,
+        None,
+    )?
+    .collect_with_engine(Engine::Streaming)?;
+    Ok(())
+}
+
+#[cfg(feature = "lazy")]
+fn read_partitioned_by_a(base_path: &std::path::Path) -> PolarsResult<DataFrame> {
+    LazyFrame::scan_ipc(PlPath::Local(base_path.into()), Default::default())?
+        .select([col("a"), col("b")])
+        .sort(["a", "b"], Default::default())
+        .collect()
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_sink_ipc_partitioned_write_modes() -> PolarsResult<()> {
+    let base_path = std::env::temp_dir().join("polars_test_sink_ipc_partitioned_write_modes");
+    let _ = std::fs::remove_dir_all(&base_path);
+
+    let df = df!["a" => [1i64, 1, 2], "b" => [1i32, 2, 3]]?;
+    sink_ipc_partitioned_by_a(df.clone().lazy(), &base_path, PartitionWriteMode::Error)?;
+    assert_eq!(read_partitioned_by_a(&base_path)?, df);
+    assert!(!base_path.join("_temporary").exists());
+
+    let manifest = std::fs::read_to_string(base_path.join("_SUCCESS"))?;
+    assert!(manifest.contains(r#""write_mode": "error""#));
+    assert!(manifest.contains(r#""path": "a=1/0.ipc""#));
+    assert!(manifest.contains(r#""path": "a=2/0.ipc""#));
+
+    // The target now contains data.
+    let err = sink_ipc_partitioned_by_a(df.clone().lazy(), &base_path, PartitionWriteMode::Error)
+        .unwrap_err();
+    assert!(err.to_string().contains("already contains data"));
+
+    let new = df!["a" => [2i64, 3], "b" => [4i32, 5]]?;
+    sink_ipc_partitioned_by_a(new.clone().lazy(), &base_path, PartitionWriteMode::Append)?;
+    assert_eq!(
+        read_partitioned_by_a(&base_path)?,
+        df!["a" => [1i64, 1, 2, 2, 3], "b" => [1i32, 2, 3, 4, 5]]?
+    );
+    assert_eq!(std::fs::read_dir(base_path.join("a=2"))?.count(), 2);
+
+    let new = df!["a" => [2i64], "b" => [6i32]]?;
+    sink_ipc_partitioned_by_a(
+        new.clone().lazy(),
+        &base_path,
+        PartitionWriteMode::OverwriteTouched,
+    )?;
+    assert_eq!(
+        read_partitioned_by_a(&base_path)?,
+        df!["a" => [1i64, 1, 2, 3], "b" => [1i32, 2, 6, 5]]?
+    );
+
+    sink_ipc_partitioned_by_a(
+        new.clone().lazy(),
+        &base_path,
+        PartitionWriteMode::OverwriteAll,
+    )?;
+    assert_eq!(read_partitioned_by_a(&base_path)?, new);
+    assert!(!base_path.join("a=1").exists());
+    assert!(!base_path.join("_temporary").exists());
+
+    // Write modes only apply to partitioned sinks.
+    let err = new
+        .lazy()
+        .sink_ipc(
+            SinkTarget::Path(PlPath::Local(base_path.join("single.ipc").into())),
+            Default::default(),
+            None,
+            SinkOptions {
+                write_mode: Some(PartitionWriteMode::Append),
+                ..Default::default()
+            },
+        )?
+        .collect_with_engine(Engine::Streaming)
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("only supported by partitioned sinks")
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_sink_ipc_partitioned_failed_write() -> PolarsResult<()> {
+    let base_path = std::env::temp_dir().join("polars_test_sink_ipc_partitioned_failed_write");
+    let _ = std::fs::remove_dir_all(&base_path);
+
+    let df = df!["a" => [1i64, 2], "b" => [1i32, 2]]?;
+    sink_ipc_partitioned_by_a(df.clone().lazy(), &base_path, PartitionWriteMode::Error)?;
+
+    let failing = df!["a" => [3i64, 4], "b" => ["5", "x"]]?
+        .lazy()
+        .with_column(col("b").strict_cast(DataType::Int32));
+    assert!(
+        sink_ipc_partitioned_by_a(failing, &base_path, PartitionWriteMode::OverwriteAll).is_err()
+    );
+
+    // Neither the existing data nor the staged files were touched.
+    assert_eq!(read_partitioned_by_a(&base_path)?, df);
+    assert!(!base_path.join("_temporary").exists());
+
+    Ok(())
+}
@@ -185,3 +185,245 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "compress", feature = "decompress"))]
+fn test_sink_ndjson_compressed() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_sink_ndjson_compressed.jsonl.zst");
+
+    let df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    // Written in several morsels.
+    let inputs = (0..4)
+        .map(|i| df.slice(i * 250, 250).lazy())
+        .collect::<Vec<_>>();
+    concat(inputs, Default::default())?
+        .sink_json(
+            SinkTarget::Path(PlPath::Local(path.clone().into())),
+            JsonWriterOptions {
+                compression: ExternalCompression::zstd(None)?,
+            },
+            None,
+            Default::default(),
+        )?
+        .collect_with_engine(Engine::Streaming)?;
+
+    let bytes = std::fs::read(&path)?;
+    // The file is a single zstd frame.
+    let magic: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+    assert_eq!(
+        bytes.windows(magic.len()).filter(|w| *w == magic).count(),
+        1
+    );
+
+    let mut decompressed = vec![];
+    let bytes = polars::io::utils::compression::maybe_decompress_bytes(&bytes, &mut decompressed)?;
+    assert_ne!(bytes.len(), std::fs::metadata(&path)?.len() as usize);
+
+    let out = JsonReader::new(Cursor::new(bytes))
+        .with_json_format(JsonFormat::JsonLines)
+        .finish()?;
+    assert!(out.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "compress", feature = "decompress"))]
+fn test_scan_ndjson_compressed_streaming() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_ndjson_compressed_streaming.jsonl.gz");
+
+    let n = 20_000;
+    let df = df![
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| format!("s{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    df.clone()
+        .lazy()
+        .sink_json(
+            SinkTarget::Path(PlPath::Local(path.clone().into())),
+            JsonWriterOptions {
+                compression: ExternalCompression::gzip(None)?,
+            },
+            None,
+            Default::default(),
+        )?
+        .collect_with_engine(Engine::Streaming)?;
+
+    let scan = || LazyJsonLineReader::new(PlPath::Local(path.clone().into())).finish();
+
+    let out = scan()?.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df));
+
+    let out = scan()?
+        .slice(15_000, 10)
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df.slice(15_000, 10)));
+
+    // Negative slices decompress the whole file.
+    let out = scan()?.tail(3).collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df.tail(Some(3))));
+
+    let out = scan()?
+        .select([len()])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(n as IdxSize));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_json_record_path() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_json_record_path.json");
+    std::fs::write(
+        &path,
+        r#"{
+    "meta": {"items": [{"a": -1}]},
+    "data": {
+        "items": [
+            {"a": 1, "b": "x", "c": {"d": [1, 2]}},
+            {"a": 2, "b": "y\nz"},
+            {"b": "w", "a": 3}
+        ]
+    }
+}"#,
+    )?;
+
+    let scan = || {
+        LazyJsonReader::new(PlPath::Local(path.clone().into()))
+            .with_record_path(Some("$.data.items[*]".into()))
+            .finish()
+    };
+
+    let out = scan()?.select([col("b"), col("a")]).collect()?;
+    let expected = df![
+        "b" => ["x", "y\nz", "w"],
+        "a" => [1i64, 2, 3],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = scan()?.collect()?;
+    assert_eq!(out.get_column_names(), ["a", "b", "c"]);
+    assert_eq!(out.column("c")?.null_count(), 2);
+
+    let out = LazyJsonReader::new(PlPath::Local(path.clone().into()))
+        .with_record_path(Some("$.data.items".into()))
+        .with_row_index(Some(polars::io::RowIndex {
+            name: "idx".into(),
+            offset: 10,
+        }))
+        .with_n_rows(Some(2))
+        .finish()?
+        .select([col("idx"), col("a")])
+        .collect()?;
+    let expected = df![
+        "idx" => [10 as IdxSize, 11],
+        "a" => [1i64, 2],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = scan()?.select([len()]).collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(3));
+
+    let out = scan()?
+        .with_row_index("idx", None)
+        .tail(1)
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("idx")?.idx()?.get(0), Some(2));
+    assert_eq!(out.column("b")?.str()?.get(0), Some("w"));
+
+    // The rest of the path after the streamed part is evaluated as a JSONPath on every element.
+    let out = LazyJsonReader::new(PlPath::Local(path.clone().into()))
+        .with_record_path(Some("$.data.items[?(@.a > 1)]".into()))
+        .finish()?
+        .select([col("a")])
+        .collect()?;
+    assert!(out.equals(&df!["a" => [2i64, 3]]?));
+
+    let out = scan()?
+        .filter(col("a").gt(lit(1)))
+        .select([col("b")])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("b")?.str()?.get(1), Some("w"));
+
+    let err = LazyJsonReader::new(PlPath::Local(path.clone().into()))
+        .with_record_path(Some("data.items".into()))
+        .finish()
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("invalid JSON record path"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "compress", feature = "decompress"))]
+fn test_scan_json_compressed_streaming() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_json_compressed_streaming.json.gz");
+
+    let n = 50_000;
+    let items = (0..n)
+        .map(|i| format!("{{\"a\": {i}, \"b\": \"s{i}\"}}"))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    let doc = format!("{{\"meta\": {{}}, \"data\": {{\"items\": [{items}]}}}}");
+    std::fs::write(
+        &path,
+        ExternalCompression::gzip(None)?.maybe_compress(doc.into_bytes())?,
+    )?;
+
+    let scan = || {
+        LazyJsonReader::new(PlPath::Local(path.clone().into()))
+            .with_record_path(Some("$.data.items[*]".into()))
+            .finish()
+    };
+
+    let out = scan()?
+        .with_row_index("idx", None)
+        .slice(30_000, 3)
+        .collect_with_engine(Engine::Streaming)?;
+    let expected = df![
+        "idx" => [30_000 as IdxSize, 30_001, 30_002],
+        "a" => [30_000i64, 30_001, 30_002],
+        "b" => ["s30000", "s30001", "s30002"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = scan()?.tail(1).collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(n - 1));
+
+    let out = scan()?
+        .select([len()])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(n as IdxSize));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_json_array() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_json_array.json");
+    std::fs::write(&path, "[{\"a\": 1}, {\"a\": null}, {\"a\": 3}]")?;
+
+    let out = LazyJsonReader::new(PlPath::Local(path.clone().into()))
+        .finish()?
+        .tail(2)
+        .collect()?;
+    assert_eq!(out.column("a")?.i64()?.to_vec(), [None, Some(3)]);
+
+    std::fs::write(&path, "[{\"a\": 1}, 2]")?;
+    assert!(
+        LazyJsonReader::new(PlPath::Local(path.clone().into()))
+            .finish()?
+            .collect()
+            .is_err()
+    );
+
+    Ok(())
+}
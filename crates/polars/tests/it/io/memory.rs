@@ -0,0 +1,194 @@
+use polars::prelude::*;
+
+fn sink_parquet(df: &DataFrame, uri: &str) -> PolarsResult<()> {
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(PlPath::new(uri)),
+            Default::default(),
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+    Ok(())
+}
+
+#[test]
+fn test_memory_sink_and_scan() -> PolarsResult<()> {
+    let df = df!("a" => [1i64, 2, 3], "b" => ["x", "y", "z"])?;
+
+    sink_parquet(&df, "memory://test_memory_sink_and_scan/data.parquet")?;
+
+    let out = LazyFrame::scan_parquet(
+        PlPath::new("memory://test_memory_sink_and_scan/data.parquet"),
+        Default::default(),
+    )?
+    .collect()?;
+    assert_eq!(out, df);
+
+    // Buckets are separate stores.
+    assert!(
+        LazyFrame::scan_parquet(
+            PlPath::new("memory://test_memory_sink_and_scan_other/data.parquet"),
+            Default::default(),
+        )?
+        .collect()
+        .is_err()
+    );
+
+    // Removing a bucket drops its data.
+    assert!(polars::io::cloud::remove_memory_store(
+        "test_memory_sink_and_scan"
+    ));
+    assert!(!polars::io::cloud::remove_memory_store(
+        "test_memory_sink_and_scan"
+    ));
+    assert!(
+        LazyFrame::scan_parquet(
+            PlPath::new("memory://test_memory_sink_and_scan/data.parquet"),
+            Default::default(),
+        )?
+        .collect()
+        .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_memory_scan_glob() -> PolarsResult<()> {
+    for i in 0..3i64 {
+        df!("a" => [i])?
+            .lazy()
+            .sink_csv(
+                SinkTarget::Path(PlPath::new(&format!(
+                    "memory://test_memory_scan_glob/dir/{i}.csv"
+                ))),
+                Default::default(),
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+    }
+    sink_parquet(
+        &df!("a" => [10i64])?,
+        "memory://test_memory_scan_glob/dir/other.parquet",
+    )?;
+
+    let out = LazyCsvReader::new(PlPath::new("memory://test_memory_scan_glob/dir/*.csv"))
+        .finish()?
+        .sort(["a"], Default::default())
+        .collect()?;
+    assert_eq!(out, df!("a" => [0i64, 1, 2])?);
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_sink_partitioned_hive() -> PolarsResult<()> {
+    let df = df!(
+        "part" => ["a", "b", "a", "c"],
+        "value" => [0i64, 1, 2, 3],
+    )?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet_partitioned(
+            Arc::new(PlPath::new(
+                "memory://test_memory_sink_partitioned_hive/table",
+            )),
+            None,
+            PartitionVariant::ByKey {
+                key_exprs: vec![col("part")],
+                include_key: false,
+            },
+            Default::default(),
+            None,
+            Default::default(),
+            None,
+            None,
+        )?
+        .collect()?;
+
+    let out = LazyFrame::scan_parquet(
+        PlPath::new("memory://test_memory_sink_partitioned_hive/table/**/*.parquet"),
+        Default::default(),
+    )?
+    .sort(["value"], Default::default())
+    .collect()?;
+    assert_eq!(out, df.select(["value", "part"])?);
+
+    let out = LazyFrame::scan_parquet(
+        PlPath::new("memory://test_memory_sink_partitioned_hive/table/part=a/"),
+        Default::default(),
+    )?
+    .sort(["value"], Default::default())
+    .collect()?;
+    assert_eq!(out, df!("value" => [0i64, 2])?);
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_sink_partitioned_write_mode() -> PolarsResult<()> {
+    let root = "memory://test_memory_sink_partitioned_write_mode/table";
+    let sink = |df: &DataFrame, write_mode| {
+        df.clone()
+            .lazy()
+            .sink_parquet_partitioned(
+                Arc::new(PlPath::new(root)),
+                None,
+                PartitionVariant::ByKey {
+                    key_exprs: vec![col("part")],
+                    include_key: false,
+                },
+                Default::default(),
+                None,
+                SinkOptions {
+                    write_mode: Some(write_mode),
+                    ..Default::default()
+                },
+                None,
+                None,
+            )?
+            .collect()
+    };
+    let scan = || {
+        LazyFrame::scan_parquet(PlPath::new(&format!("{root}/")), Default::default())?
+            .sort(["value"], Default::default())
+            .collect()
+    };
+
+    let df = df!("part" => ["a", "b", "a"], "value" => [0i64, 1, 2])?;
+    sink(&df, PartitionWriteMode::Error)?;
+    assert_eq!(scan()?, df.select(["value", "part"])?);
+    assert!(sink(&df, PartitionWriteMode::Error).is_err());
+
+    sink(&df, PartitionWriteMode::Append)?;
+    assert_eq!(
+        scan()?.column("value")?.i64()?.to_vec(),
+        [0, 0, 1, 1, 2, 2].map(Some)
+    );
+
+    // Only the files of partition `b` are replaced.
+    let df = df!("part" => ["b"], "value" => [3i64])?;
+    sink(&df, PartitionWriteMode::OverwriteTouched)?;
+    assert_eq!(
+        scan()?.column("value")?.i64()?.to_vec(),
+        [0, 0, 2, 2, 3].map(Some)
+    );
+
+    sink(&df, PartitionWriteMode::OverwriteAll)?;
+    assert_eq!(scan()?, df.select(["value", "part"])?);
+
+    // Nothing is left behind in the staging directory.
+    let out = LazyFrame::scan_parquet(
+        PlPath::new(&format!("{root}/_temporary/**/*.parquet")),
+        Default::default(),
+    )?
+    .collect();
+    assert!(out.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+/// Serves HTTP requests with `handler`, which is called with the method, the request target and
+/// the headers and body. Returns the base URI of the server.
+pub(crate) fn serve_mock_catalog(
+    handler: impl Fn(&str, &str, &[(String, String)], &str) -> (u16, String) + Send + 'static,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let uri = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut request_line = request_line.split_whitespace();
+            let method = request_line.next().unwrap().to_string();
+            let target = request_line.next().unwrap().to_string();
+
+            let mut headers = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let Some((k, v)) = line.trim_end().split_once(':') else {
+                    break;
+                };
+                headers.push((k.trim().to_ascii_lowercase(), v.trim().to_string()));
+            }
+
+            let content_length = headers
+                .iter()
+                .find(|(k, _)| k == "content-length")
+                .map_or(0, |(_, v)| v.parse().unwrap());
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let body = String::from_utf8(body).unwrap();
+
+            let (status, body) = handler(&method, &target, &headers, &body);
+            write!(
+                stream,
+                "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    uri
+}
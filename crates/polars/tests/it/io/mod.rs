@@ -1,8 +1,11 @@
 mod csv;
+mod fixed_width;
 
 #[cfg(feature = "json")]
 mod json;
 
+#[cfg(feature = "parquet")]
+mod hive;
 #[cfg(feature = "parquet")]
 mod parquet;
 
@@ -14,6 +17,27 @@ mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
 
+#[cfg(feature = "orc")]
+mod orc;
+
+#[cfg(feature = "delta")]
+mod delta;
+#[cfg(feature = "iceberg")]
+mod iceberg;
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+#[cfg(all(feature = "catalog", feature = "parquet"))]
+mod catalog;
+#[cfg(feature = "catalog")]
+mod mock_catalog;
+
+#[cfg(all(feature = "cloud", feature = "parquet"))]
+mod cloud;
+#[cfg(all(feature = "cloud", feature = "parquet"))]
+mod memory;
+
 use polars::prelude::*;
 
 pub(crate) fn create_df() -> DataFrame {
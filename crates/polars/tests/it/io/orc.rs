@@ -0,0 +1,185 @@
+use std::io::Cursor;
+
+use polars::io::RowIndex;
+use polars::prelude::*;
+
+use super::create_df;
+
+#[test]
+fn write_and_read_orc() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = create_df();
+
+    OrcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("orc writer");
+    buf.set_position(0);
+
+    let df_read = OrcReader::new(buf).finish().unwrap();
+    assert!(df.equals(&df_read));
+}
+
+#[test]
+fn test_orc_compressions_and_stripes() -> PolarsResult<()> {
+    let mut df = df![
+        "int" => (0..1000i64).map(|i| (i % 7 != 0).then_some(i * 31 - 500)).collect::<Vec<_>>(),
+        "str" => (0..1000).map(|i| format!("value-{}", i % 13)).collect::<Vec<_>>(),
+        "bool" => (0..1000).map(|i| i % 3 == 0).collect::<Vec<_>>(),
+        "float" => (0..1000).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+    ]?;
+
+    for compression in [
+        OrcCompression::Uncompressed,
+        OrcCompression::Zlib(None),
+        OrcCompression::Snappy,
+        OrcCompression::Lz4,
+        OrcCompression::Zstd(Some(3)),
+    ] {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        OrcWriter::new(&mut buf)
+            .with_compression(compression)
+            .with_stripe_size(Some(128))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = OrcReader::new(buf).finish()?;
+        assert!(df.equals_missing(&df_read), "{compression:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_read_orc_with_columns_and_n_rows() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4], "c" => [3, 4, 5])?;
+
+    OrcWriter::new(&mut buf)
+        .with_stripe_size(Some(2))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let df_read = OrcReader::new(buf)
+        .with_columns(Some(vec!["c".to_string(), "a".to_string()]))
+        .with_n_rows(Some(2))
+        .finish()?;
+
+    let expected = df!("c" => [3, 4], "a" => [1, 2])?;
+    assert!(df_read.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_and_sink_orc() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_and_sink_orc.orc");
+    let path = PlPath::Local(path.into());
+
+    let df = df![
+        "a" => (0..100i32).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    df.clone()
+        .lazy()
+        .sink_orc(
+            SinkTarget::Path(path.clone()),
+            OrcWriterOptions {
+                stripe_size: 10,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let out = LazyFrame::scan_orc(path.clone(), Default::default())?
+        .filter(col("a").gt_eq(lit(45)))
+        .select([col("b")])
+        .limit(10)
+        .collect()?;
+    let expected = df.slice(45, 10).select(["b"])?;
+    assert!(out.equals(&expected));
+
+    let out = LazyFrame::scan_orc(
+        path,
+        ScanArgsOrc {
+            n_rows: Some(5),
+            row_index: Some(RowIndex {
+                name: "idx".into(),
+                offset: 10,
+            }),
+            ..Default::default()
+        },
+    )?
+    .collect()?;
+    assert_eq!(out.shape(), (5, 3));
+    assert_eq!(
+        out.column("idx")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (10..15).collect::<Vec<IdxSize>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_orc_skips_stripes_by_statistics() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_orc_skips_stripes_by_statistics.orc");
+    let path = PlPath::Local(path.into());
+
+    df!("a" => (0..100i64).collect::<Vec<_>>())?
+        .lazy()
+        .sink_orc(
+            SinkTarget::Path(path.clone()),
+            OrcWriterOptions {
+                stripe_size: 10,
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let (out, _, io_metrics) = LazyFrame::scan_orc(path, Default::default())?
+        .filter(col("a").gt_eq(lit(95i64)))
+        .profile_with_io_metrics()?;
+    assert_eq!(out, df!("a" => (95..100i64).collect::<Vec<_>>())?);
+
+    let get =
+        |name: &str| -> PolarsResult<u64> { Ok(io_metrics.column(name)?.u64()?.get(0).unwrap()) };
+    assert_eq!(get("row_groups_read")?, 1);
+    assert_eq!(get("row_groups_pruned")?, 9);
+
+    Ok(())
+}
+
+#[test]
+fn test_read_corrupted_orc_does_not_panic() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df![
+        "int" => [Some(1i64), None, Some(-3)],
+        "str" => ["a", "bb", "ccc"],
+        "list" => [Series::new("".into(), [1i32]), Series::new("".into(), [2i32, 3]), Series::new("".into(), [4i32])],
+    ]?;
+    OrcWriter::new(&mut buf)
+        .with_compression(OrcCompression::Uncompressed)
+        .finish(&mut df)?;
+    let bytes = buf.into_inner();
+
+    // Reading may fail on every corrupted byte, but it has to return an error instead of
+    // panicking.
+    for i in 0..bytes.len() {
+        for corrupt in [0x00, 0xFF, bytes[i].wrapping_add(1)] {
+            let mut bytes = bytes.clone();
+            bytes[i] = corrupt;
+            let _ = OrcReader::new(Cursor::new(bytes)).finish();
+        }
+    }
+
+    Ok(())
+}
@@ -206,3 +206,416 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_scan_parquet_glob_braces_and_exclusions() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_glob_braces_and_exclusions");
+    let _ = std::fs::remove_dir_all(&root);
+
+    for (dir, file, value) in [
+        ("2022/a", "part-0", 0i64),
+        ("2023/a", "part-0", 1),
+        ("2023/_tmp", "part-1", 2),
+        ("2024/b", "part-0", 3),
+        ("2024/b", "other", 4),
+    ] {
+        let dir = root.join(dir);
+        std::fs::create_dir_all(&dir)?;
+        let mut df = df!("value" => [value])?;
+        ParquetWriter::new(std::fs::File::create(dir.join(format!("{file}.parquet")))?)
+            .finish(&mut df)?;
+    }
+
+    let root = root.to_str().unwrap();
+    let scan = |paths: &[String]| {
+        LazyFrame::scan_parquet_files(
+            paths.iter().map(|p| PlPath::new(p)).collect(),
+            Default::default(),
+        )?
+        .sort(["value"], Default::default())
+        .collect()
+    };
+
+    let out = scan(&[format!("{root}/{{2023,2024}}/*/part-?.parquet")])?;
+    assert_eq!(out, df!("value" => [1i64, 2, 3])?);
+
+    let out = scan(&[
+        format!("{root}/{{2023,2024}}/*/part-?.parquet"),
+        "!**/_tmp/**".into(),
+    ])?;
+    assert_eq!(out, df!("value" => [1i64, 3])?);
+
+    let out = scan(&[
+        format!("{root}/202[!3]/**/*.parquet"),
+        format!("!{root}/*/b"),
+    ])?;
+    assert_eq!(out, df!("value" => [0i64])?);
+
+    // Files matched by overlapping alternatives are read once.
+    let out = scan(&[format!("{root}/{{2023,*}}/a/*.parquet")])?;
+    assert_eq!(out, df!("value" => [0i64, 1])?);
+
+    // Excluding without anything to include is an error rather than an empty result.
+    assert!(scan(&["!**/_tmp/**".into()]).is_err());
+
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cloud")]
+fn test_scan_parquet_glob_braces_and_exclusions_cloud() -> PolarsResult<()> {
+    let root = "memory://test_scan_parquet_glob_braces_and_exclusions_cloud";
+
+    for (dir, value) in [
+        ("2022/a", 0i64),
+        ("2023/a", 1),
+        ("2023/_tmp", 2),
+        ("2024/b", 3),
+    ] {
+        df!("value" => [value])?
+            .lazy()
+            .sink_parquet(
+                SinkTarget::Path(PlPath::new(&format!("{root}/{dir}/part-0.parquet"))),
+                Default::default(),
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+    }
+
+    let out = LazyFrame::scan_parquet_files(
+        [
+            PlPath::new(&format!("{root}/{{2023,2024}}/*/part-[0-9].parquet")),
+            PlPath::new("!**/_tmp/**"),
+        ]
+        .into(),
+        Default::default(),
+    )?
+    .sort(["value"], Default::default())
+    .collect()?;
+    assert_eq!(out, df!("value" => [1i64, 3])?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-datetime")]
+fn test_sink_parquet_partitioned_hive_key_round_trip() -> PolarsResult<()> {
+    let root =
+        std::env::temp_dir().join("polars_test_sink_parquet_partitioned_hive_key_round_trip");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let df = df!(
+        "s" => [Some("a/b"), Some("c=d"), Some("50%"), None, Some("*?[")],
+        "date" => [19000i32, 19001, 19002, 19003, 19004],
+        "ms" => [1i64, 2, 3, 4, 1_000],
+        "ns" => [1i64, 2, 3, 4, 1_000_000_001],
+        "value" => [0i64, 1, 2, 3, 4],
+    )?
+    .lazy()
+    .with_columns([
+        col("date").cast(DataType::Date),
+        col("ms").cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+        col("ns").cast(DataType::Datetime(TimeUnit::Nanoseconds, None)),
+    ])
+    .collect()?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet_partitioned(
+            Arc::new(PlPath::Local(root.as_path().into())),
+            None,
+            PartitionVariant::ByKey {
+                key_exprs: vec![col("s"), col("date"), col("ms"), col("ns")],
+                include_key: false,
+            },
+            Default::default(),
+            None,
+            SinkOptions {
+                mkdir: true,
+                ..Default::default()
+            },
+            None,
+            None,
+        )?
+        .collect()?;
+
+    assert!(root.join("s=a%2Fb").is_dir());
+    assert!(root.join("s=c%3Dd").is_dir());
+    assert!(root.join("s=50%25").is_dir());
+    assert!(root.join("s=__HIVE_DEFAULT_PARTITION__").is_dir());
+    assert!(root.join("s=%2A%3F%5B").is_dir());
+
+    // The types of the keys are inferred from the directory names.
+    let out = LazyFrame::scan_parquet(PlPath::Local(root.as_path().into()), Default::default())?
+        .sort(["value"], Default::default())
+        .collect()?;
+    assert_eq!(out, df.select(["value", "s", "date", "ms", "ns"])?);
+
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cloud")]
+fn test_sink_parquet_partitioned_hive_escaped_keys_cloud() -> PolarsResult<()> {
+    let df = df!(
+        "a" => [Some("x/y"), Some("50%"), None],
+        "b" => [1i64, 2, 3],
+        "value" => [0i64, 1, 2],
+    )?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet_partitioned(
+            Arc::new(PlPath::new(
+                "memory://test_sink_parquet_partitioned_hive_escaped_keys_cloud/table",
+            )),
+            None,
+            PartitionVariant::ByKey {
+                key_exprs: vec![col("a"), col("b")],
+                include_key: false,
+            },
+            Default::default(),
+            None,
+            Default::default(),
+            None,
+            None,
+        )?
+        .collect()?;
+
+    // Scanning the directory, so all of the partition keys come after the base path.
+    let out = LazyFrame::scan_parquet(
+        PlPath::new("memory://test_sink_parquet_partitioned_hive_escaped_keys_cloud/table/"),
+        Default::default(),
+    )?
+    .sort(["value"], Default::default())
+    .collect()?;
+    assert_eq!(out, df.select(["value", "a", "b"])?);
+
+    Ok(())
+}
+
+#[cfg(feature = "dtype-struct")]
+fn field_id(name: &str, field_id: i32, children: ChildFieldOverwrites) -> ParquetFieldOverwrites {
+    ParquetFieldOverwrites {
+        name: Some(name.into()),
+        children,
+        required: None,
+        field_id: Some(field_id),
+        metadata: None,
+    }
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_scan_parquet_rename_by_field_id() -> PolarsResult<()> {
+    use ChildFieldOverwrites as C;
+
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_rename_by_field_id");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+
+    let mut new = df!(
+        "id" => [1i64],
+        "x" => [1.0f64],
+        "y" => [2.0f64],
+        "name" => ["a"],
+    )?
+    .lazy()
+    .select([
+        col("id"),
+        as_struct(vec![col("x"), col("y")]).alias("point"),
+        col("name"),
+    ])
+    .collect()?;
+
+    // Written before `id` and `point.x` were renamed and `dropped` was removed, with the columns
+    // and struct fields in a different order.
+    let mut old = df!(
+        "name" => ["b"],
+        "y" => [4.0f32],
+        "x_old" => [3.0f32],
+        "dropped" => [0i64],
+        "key" => [2i32],
+    )?
+    .lazy()
+    .select([
+        col("name"),
+        as_struct(vec![col("y"), col("x_old")]).alias("p"),
+        col("dropped"),
+        col("key"),
+    ])
+    .collect()?;
+
+    ParquetWriter::new(std::fs::File::create(root.join("0.parquet"))?)
+        .with_field_overwrites(vec![
+            field_id("id", 1, C::None),
+            field_id(
+                "point",
+                2,
+                C::Struct(vec![field_id("x", 3, C::None), field_id("y", 4, C::None)]),
+            ),
+            field_id("name", 5, C::None),
+        ])
+        .finish(&mut new)?;
+
+    ParquetWriter::new(std::fs::File::create(root.join("1.parquet"))?)
+        .with_field_overwrites(vec![
+            field_id("name", 5, C::None),
+            field_id(
+                "p",
+                2,
+                C::Struct(vec![
+                    field_id("y", 4, C::None),
+                    field_id("x_old", 3, C::None),
+                ]),
+            ),
+            field_id("dropped", 6, C::None),
+            field_id("key", 1, C::None),
+        ])
+        .finish(&mut old)?;
+
+    let scan = |use_field_ids| {
+        LazyFrame::scan_parquet(
+            PlPath::Local(root.as_path().into()),
+            ScanArgsParquet {
+                use_field_ids,
+                cast_columns_policy: CastColumnsPolicy {
+                    integer_upcast: true,
+                    float_upcast: true,
+                    ..CastColumnsPolicy::ERROR_ON_MISMATCH
+                },
+                extra_columns_policy: ExtraColumnsPolicy::Ignore,
+                ..Default::default()
+            },
+        )?
+        .collect()
+    };
+
+    let expected = df!(
+        "id" => [1i64, 2],
+        "x" => [1.0f64, 3.0],
+        "y" => [2.0f64, 4.0],
+        "name" => ["a", "b"],
+    )?
+    .lazy()
+    .select([
+        col("id"),
+        as_struct(vec![col("x"), col("y")]).alias("point"),
+        col("name"),
+    ])
+    .collect()?;
+
+    assert_eq!(scan(true)?, expected);
+    // Without field IDs the renamed columns are not found.
+    assert!(scan(false).is_err());
+
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_scan_parquet_rename_by_field_id_of_schema() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_rename_by_field_id_of_schema");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+
+    // The first file was written before `a` was renamed to `b` and a new `a` was added.
+    ParquetWriter::new(std::fs::File::create(root.join("0.parquet"))?)
+        .with_field_overwrites(vec![field_id("a", 1, ChildFieldOverwrites::None)])
+        .finish(&mut df!("a" => [1i64])?)?;
+    ParquetWriter::new(std::fs::File::create(root.join("1.parquet"))?)
+        .with_field_overwrites(vec![
+            field_id("b", 1, ChildFieldOverwrites::None),
+            field_id("a", 2, ChildFieldOverwrites::None),
+        ])
+        .finish(&mut df!("b" => [2i64], "a" => [3i64])?)?;
+
+    let scan = |field_overwrites| {
+        LazyFrame::scan_parquet(
+            PlPath::Local(root.as_path().into()),
+            ScanArgsParquet {
+                schema: Some(Arc::new(Schema::from_iter([
+                    Field::new("b".into(), DataType::Int64),
+                    Field::new("a".into(), DataType::Int64),
+                ]))),
+                allow_missing_columns: true,
+                use_field_ids: true,
+                field_overwrites,
+                ..Default::default()
+            },
+        )?
+        .collect()
+    };
+
+    // The field IDs are resolved against those of the schema rather than the first file.
+    assert_eq!(
+        scan(vec![
+            field_id("b", 1, ChildFieldOverwrites::None),
+            field_id("a", 2, ChildFieldOverwrites::None),
+        ])?,
+        df!("b" => [1i64, 2], "a" => [None, Some(3i64)])?
+    );
+
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_scan_parquet_decimal_upcast() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_decimal_upcast");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+
+    for (file, precision, scale, value) in [
+        ("0.parquet", 10, 3, 1_500i64),
+        ("1.parquet", 5, 2, 225),
+        ("2.parquet", 10, 1, 10),
+    ] {
+        let mut df = df!("v" => [value])?
+            .lazy()
+            .select([col("v")
+                .cast(DataType::Int128)
+                .cast(DataType::Decimal(Some(precision), Some(scale)))])
+            .collect()?;
+        ParquetWriter::new(std::fs::File::create(root.join(file))?).finish(&mut df)?;
+    }
+
+    let scan = |paths: &[&str], decimal_upcast| {
+        LazyFrame::scan_parquet_files(
+            paths
+                .iter()
+                .map(|p| PlPath::Local(root.join(p).as_path().into()))
+                .collect(),
+            ScanArgsParquet {
+                cast_columns_policy: CastColumnsPolicy {
+                    decimal_upcast,
+                    ..CastColumnsPolicy::ERROR_ON_MISMATCH
+                },
+                ..Default::default()
+            },
+        )?
+        .collect()
+    };
+
+    let out = scan(&["0.parquet", "1.parquet"], true)?;
+    assert_eq!(
+        out.column("v")?.dtype(),
+        &DataType::Decimal(Some(10), Some(3))
+    );
+    assert_eq!(
+        out.column("v")?.cast(&DataType::String)?,
+        Column::new("v".into(), ["1500.000", "225.000"])
+    );
+
+    assert!(scan(&["0.parquet", "1.parquet"], false).is_err());
+    // Decimal(5, 2) has too few integer digits for the values of Decimal(10, 1).
+    assert!(scan(&["1.parquet", "2.parquet"], true).is_err());
+
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
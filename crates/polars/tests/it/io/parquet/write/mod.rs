@@ -297,3 +297,44 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+fn test_write_partitioned_dataset_overwrite_all() -> PolarsResult<()> {
+    use polars::io::parquet::write::ParquetWriteOptions;
+    use polars::io::partition::{
+        PartitionWriteMode, PartitionedWriteOptions, write_partitioned_dataset_with_options,
+    };
+    use polars_utils::plpath::PlPath;
+
+    let base_path = std::env::temp_dir().join("polars_test_write_partitioned_dataset_overwrite");
+    let _ = std::fs::remove_dir_all(&base_path);
+    let addr = PlPath::Local(base_path.clone().into());
+
+    for (mut df, expected_dirs) in [
+        (df!("a" => [1, 1, 2], "b" => [1, 2, 3])?, ["a=1", "a=2"]),
+        (df!("a" => [3, 4], "b" => [4, 5])?, ["a=3", "a=4"]),
+    ] {
+        write_partitioned_dataset_with_options(
+            &mut df,
+            addr.as_ref(),
+            vec!["a".into()],
+            &ParquetWriteOptions::default(),
+            None,
+            &PartitionedWriteOptions {
+                chunk_size: usize::MAX,
+                write_mode: Some(PartitionWriteMode::OverwriteAll),
+            },
+        )?;
+
+        let mut dirs = std::fs::read_dir(&base_path)?
+            .map(|e| Ok(e?.file_name().into_string().unwrap()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        dirs.sort();
+        assert_eq!(dirs, ["_SUCCESS", expected_dirs[0], expected_dirs[1]]);
+    }
+
+    let manifest = std::fs::read_to_string(base_path.join("_SUCCESS"))?;
+    assert!(manifest.contains(r#""path": "a=3/00000000.parquet", "num_rows": 1"#));
+
+    Ok(())
+}
@@ -0,0 +1,346 @@
+use std::path::PathBuf;
+
+use polars::io::RowIndex;
+use polars::prelude::*;
+
+fn test_db(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("polars_test_{name}.db"));
+    _ = std::fs::remove_file(&path);
+    path
+}
+
+fn create_reference_db(name: &str) -> PathBuf {
+    let path = test_db(name);
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        r#"
+        CREATE TABLE species (id INTEGER, name TEXT, weight REAL, extinct BOOLEAN, notes);
+        INSERT INTO species VALUES (1, 'oak', 12.5, 0, 'tree');
+        INSERT INTO species VALUES (2, 'elm', NULL, 0, 42);
+        INSERT INTO species VALUES (3, 'dodo', 20.0, 1, NULL);
+        INSERT INTO species VALUES (4, NULL, 3.25, 1, 'it''s');
+        INSERT INTO species VALUES (5, 'ash', 7.0, NULL, 'tree');
+        "#,
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn test_scan_sqlite_table() -> PolarsResult<()> {
+    let path = create_reference_db("scan_sqlite_table");
+
+    let lf = LazyFrame::scan_sqlite(&path, "species", Default::default())?;
+    let schema = lf.clone().collect_schema()?;
+    assert_eq!(
+        schema.as_ref(),
+        &Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("name".into(), DataType::String),
+            Field::new("weight".into(), DataType::Float64),
+            Field::new("extinct".into(), DataType::Boolean),
+            // No declared type, inferred from the values.
+            Field::new("notes".into(), DataType::String),
+        ])
+    );
+
+    let expected = df!(
+        "id" => [1i64, 2, 3, 4, 5],
+        "name" => [Some("oak"), Some("elm"), Some("dodo"), None, Some("ash")],
+        "weight" => [Some(12.5), None, Some(20.0), Some(3.25), Some(7.0)],
+        "extinct" => [Some(false), Some(false), Some(true), Some(true), None],
+        "notes" => [Some("tree"), Some("42"), None, Some("it's"), Some("tree")],
+    )?;
+    assert!(lf.clone().collect()?.equals_missing(&expected));
+
+    let out = lf
+        .clone()
+        .select([col("name"), col("id")])
+        .limit(2)
+        .collect()?;
+    assert!(out.equals_missing(&expected.select(["name", "id"])?.head(Some(2))));
+
+    let out = lf.clone().select([len()]).collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(5));
+
+    let out = LazyFrame::scan_sqlite(
+        &path,
+        "species",
+        ScanArgsSqlite {
+            n_rows: Some(3),
+            row_index: Some(RowIndex {
+                name: "idx".into(),
+                offset: 10,
+            }),
+            ..Default::default()
+        },
+    )?
+    .select([col("idx"), col("name")])
+    .collect()?;
+    let expected = df!(
+        "idx" => [10 as IdxSize, 11, 12],
+        "name" => ["oak", "elm", "dodo"],
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_sqlite_predicate_pushdown() -> PolarsResult<()> {
+    let path = create_reference_db("scan_sqlite_predicate_pushdown");
+    let lf = LazyFrame::scan_sqlite(&path, "species", Default::default())?;
+    let all = lf.clone().collect()?;
+
+    let predicates = vec![
+        col("id").gt(lit(1)).and(col("weight").lt_eq(lit(12.5))),
+        col("name").eq(lit("oak")).or(col("name").is_null()),
+        col("notes").is_not_null().and(col("id").neq(lit(4))),
+        col("name").eq_missing(lit(NULL)),
+        // Not translated to SQL and applied after reading.
+        (col("id") + col("weight")).gt(lit(10)),
+        col("extinct").and(col("id").gt(lit(3))),
+        lit(false),
+    ];
+    #[cfg(feature = "is_in")]
+    let predicates = {
+        let ids = || lit(Series::new("".into(), [2i64, 5])).implode();
+        [
+            predicates,
+            vec![
+                col("id").is_in(ids(), false),
+                col("id").is_in(ids(), false).not(),
+            ],
+        ]
+        .concat()
+    };
+
+    for predicate in predicates {
+        let expected = all
+            .clone()
+            .lazy()
+            .filter(predicate.clone())
+            .select([col("name")])
+            .collect()?;
+        let out = lf
+            .clone()
+            .filter(predicate.clone())
+            .select([col("name")])
+            .collect()?;
+        assert!(out.equals_missing(&expected), "{predicate:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_sqlite_predicate_pushdown_collation_and_affinity() -> PolarsResult<()> {
+    let path = test_db("scan_sqlite_predicate_pushdown_collation");
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        r#"
+        CREATE TABLE codes (code TEXT COLLATE NOCASE, num TEXT, n INTEGER);
+        INSERT INTO codes VALUES ('a', '10', 1);
+        INSERT INTO codes VALUES ('A', '9', 2);
+        INSERT INTO codes VALUES ('b', '010', 3);
+        "#,
+    )
+    .unwrap();
+
+    // `num` has TEXT affinity, but is read as integers.
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("code".into(), DataType::String),
+        Field::new("num".into(), DataType::Int64),
+        Field::new("n".into(), DataType::Int64),
+    ]));
+    let lf = LazyFrame::scan_sqlite(
+        &path,
+        "codes",
+        ScanArgsSqlite {
+            schema: Some(schema),
+            ..Default::default()
+        },
+    )?;
+    let all = lf.clone().collect()?;
+
+    for (predicate, n) in [
+        (col("code").eq(lit("a")), vec![1i64]),
+        (col("code").lt(lit("b")), vec![1, 2]),
+        (col("num").eq(lit(10)), vec![1, 3]),
+        (col("num").gt(lit(9)), vec![1, 3]),
+        (col("code").eq(lit("A")).and(col("n").gt(lit(1))), vec![2]),
+    ] {
+        let expected = all.clone().lazy().filter(predicate.clone()).collect()?;
+        assert_eq!(
+            expected.column("n")?.i64()?.to_vec(),
+            n.into_iter().map(Some).collect::<Vec<_>>()
+        );
+        let out = lf.clone().filter(predicate.clone()).collect()?;
+        assert!(out.equals_missing(&expected), "{predicate:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_sqlite_query() -> PolarsResult<()> {
+    let path = create_reference_db("scan_sqlite_query");
+
+    let lf = LazyFrame::scan_sqlite(
+        &path,
+        "SELECT id, id * 2 AS double, upper(name) AS name FROM species WHERE id < 5;",
+        Default::default(),
+    )?;
+    assert_eq!(
+        lf.clone().collect_schema()?.as_ref(),
+        &Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("double".into(), DataType::Int64),
+            Field::new("name".into(), DataType::String),
+        ])
+    );
+
+    let out = lf
+        .filter(col("double").gt(lit(2)))
+        .select([col("name"), col("double")])
+        .collect()?;
+    let expected = df!(
+        "name" => [Some("ELM"), Some("DODO"), None],
+        "double" => [4i64, 6, 8],
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    let mut reader = SqliteReader::new(&path, SqliteSource::new("species"))
+        .with_columns(Some(["name".into()].into()))
+        .with_filter(Some("\"id\" > 2".into()))
+        .with_n_rows(Some(4));
+    assert_eq!(
+        reader.sql()?,
+        r#"SELECT "name" FROM (SELECT * FROM "species" LIMIT 4) WHERE "id" > 2"#
+    );
+    let expected = df!("name" => [Some("dodo"), None])?;
+    assert!(reader.finish()?.equals_missing(&expected));
+
+    let mut heights = vec![];
+    SqliteReader::new(&path, SqliteSource::new("species")).read_batches(2, |df| {
+        heights.push(df.height());
+        Ok(())
+    })?;
+    assert_eq!(heights, [2, 2, 1]);
+
+    Ok(())
+}
+
+#[test]
+fn test_sink_sqlite() -> PolarsResult<()> {
+    let path = test_db("sink_sqlite");
+
+    let df = df!(
+        "int" => [Some(1i32), None, Some(3)],
+        "float" => [Some(1.5f32), Some(-2.0), None],
+        "str" => [Some("a"), None, Some("c\"d")],
+        "bool" => [Some(true), Some(false), None],
+        "bin" => [Some(b"\x00\x01".as_slice()), None, Some(b"x".as_slice())],
+    )?;
+
+    df.clone()
+        .lazy()
+        .sink_sqlite(&path, "my table", SqliteIfExists::Fail)?;
+    let read = || -> PolarsResult<DataFrame> {
+        LazyFrame::scan_sqlite(&path, "my table", Default::default())?.collect()
+    };
+    let expected = df!(
+        "int" => [Some(1i64), None, Some(3)],
+        "float" => [Some(1.5f64), Some(-2.0), None],
+        "str" => [Some("a"), None, Some("c\"d")],
+        "bool" => [Some(true), Some(false), None],
+        "bin" => [Some(b"\x00\x01".as_slice()), None, Some(b"x".as_slice())],
+    )?;
+    assert!(read()?.equals_missing(&expected));
+
+    let err = df
+        .clone()
+        .lazy()
+        .sink_sqlite(&path, "my table", SqliteIfExists::Fail)
+        .unwrap_err();
+    assert!(err.to_string().contains("already exists"));
+
+    df.clone()
+        .lazy()
+        .sink_sqlite(&path, "my table", SqliteIfExists::Append)?;
+    assert!(read()?.equals_missing(&expected.vstack(&expected)?));
+
+    df.clone()
+        .lazy()
+        .slice(0, 1)
+        .sink_sqlite(&path, "my table", SqliteIfExists::Replace)?;
+    assert!(read()?.equals_missing(&expected.head(Some(1))));
+
+    // Copy a table of the database.
+    LazyFrame::scan_sqlite(&path, "my table", Default::default())?.sink_sqlite(
+        &path,
+        "copy",
+        SqliteIfExists::Fail,
+    )?;
+    let copy = LazyFrame::scan_sqlite(&path, "copy", Default::default())?.collect()?;
+    assert!(copy.equals_missing(&expected.head(Some(1))));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+fn test_sink_sqlite_temporal_roundtrip() -> PolarsResult<()> {
+    let path = test_db("sink_sqlite_temporal");
+
+    let df = df!(
+        "date" => [Some(19_000i32), None, Some(-1)],
+        "datetime" => [Some(1_700_000_000_123_456i64), Some(-1), None],
+        "time" => [Some(45_296_500_000_000i64), None, Some(0)],
+    )?
+    .lazy()
+    .with_columns([
+        col("date").cast(DataType::Date),
+        col("datetime").cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+        col("time").cast(DataType::Time),
+    ])
+    .collect()?;
+
+    df.clone()
+        .lazy()
+        .sink_sqlite(&path, "events", SqliteIfExists::Fail)?;
+
+    let lf = LazyFrame::scan_sqlite(&path, "events", Default::default())?;
+    assert_eq!(lf.clone().collect_schema()?, df.schema().clone());
+    assert!(lf.collect()?.equals_missing(&df));
+
+    // Values written by other tools, as text with a UTC offset or as UNIX timestamps.
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        r#"
+        DELETE FROM events;
+        INSERT INTO events VALUES ('2024-01-31', '2024-01-31T12:00:00+01:00', '08:15');
+        INSERT INTO events VALUES (86400, 60, NULL);
+        "#,
+    )
+    .unwrap();
+    let out = LazyFrame::scan_sqlite(&path, "events", Default::default())?
+        .select([
+            col("date").cast(DataType::String),
+            col("datetime").cast(DataType::String),
+            col("time").cast(DataType::String),
+        ])
+        .collect()?;
+    let expected = df!(
+        "date" => ["2024-01-31", "1970-01-02"],
+        "datetime" => ["2024-01-31 11:00:00.000000", "1970-01-01 00:01:00.000000"],
+        "time" => [Some("08:15:00"), None],
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
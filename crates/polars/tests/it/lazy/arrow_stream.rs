@@ -0,0 +1,86 @@
+use arrow::array::StructArray;
+use arrow::ffi::{ArrowArrayStream, ArrowArrayStreamReader};
+
+use super::*;
+
+fn read_stream(stream: ArrowArrayStream) -> PolarsResult<Vec<DataFrame>> {
+    let mut reader = unsafe { ArrowArrayStreamReader::try_new(Box::new(stream))? };
+    let mut batches = vec![];
+    while let Some(array) = unsafe { reader.next() } {
+        let array = array?;
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        let columns = array
+            .fields()
+            .iter()
+            .zip(array.values())
+            .map(|(field, values)| {
+                Ok(Series::from_arrow(field.name.clone(), values.clone())?.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        batches.push(DataFrame::new(columns)?);
+    }
+    Ok(batches)
+}
+
+#[test]
+fn test_lazy_into_arrow_c_stream() -> PolarsResult<()> {
+    let n = 250_000;
+    let df = df!(
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| (i % 3 != 0).then(|| format!("v{i}"))).collect::<Vec<_>>(),
+    )?;
+    let lf = df
+        .lazy()
+        .filter(col("a").gt(lit(10)))
+        .with_column((col("a") * lit(2)).alias("c"));
+
+    let batches = read_stream(lf.clone().into_arrow_c_stream()?)?;
+    // The result is produced in multiple batches instead of being collected at once.
+    assert!(batches.len() > 1);
+
+    let mut out = batches[0].clone();
+    for batch in &batches[1..] {
+        out.vstack_mut(batch)?;
+    }
+    assert!(out.equals_missing(&lf.collect()?));
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_into_arrow_c_stream_release_early() -> PolarsResult<()> {
+    let df = df!("a" => (0..250_000).collect::<Vec<i64>>())?;
+    let stream = df.lazy().into_arrow_c_stream()?;
+
+    let mut reader = unsafe { ArrowArrayStreamReader::try_new(Box::new(stream))? };
+    let first = unsafe { reader.next() }.unwrap()?;
+    assert!(first.len() > 0);
+    // Releasing the stream stops the query.
+    drop(reader);
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_into_arrow_c_stream_error() -> PolarsResult<()> {
+    let df = df!("a" => ["1", "2", "x"])?;
+    let stream = df
+        .lazy()
+        .select([col("a").strict_cast(DataType::Int64)])
+        .into_arrow_c_stream()?;
+
+    let err = read_stream(stream).unwrap_err();
+    assert!(err.to_string().contains("conversion"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_into_arrow_c_stream_no_columns() -> PolarsResult<()> {
+    let lf = df!("a" => [1i64, 2, 3])?.lazy().select(Vec::<Expr>::new());
+
+    let err = lf.into_arrow_c_stream().unwrap_err();
+    assert!(err.to_string().contains("without columns"), "{err}");
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn test_lazy_collect_batches() -> PolarsResult<()> {
+    let n = 250_000;
+    let lf = df!("a" => (0..n).collect::<Vec<i64>>())?
+        .lazy()
+        .with_column((col("a") % lit(7)).alias("b"));
+
+    let batches = lf.clone().collect_batches()?;
+    assert_eq!(batches.schema(), &lf.clone().collect_schema()?);
+
+    let batches = batches.collect::<PolarsResult<Vec<_>>>()?;
+    assert!(batches.len() > 1);
+    assert!(batches.iter().all(|df| df.height() < n as usize));
+
+    let mut out = batches[0].clone();
+    for batch in &batches[1..] {
+        out.vstack_mut(batch)?;
+    }
+    assert!(out.equals(&lf.collect()?));
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_collect_batches_drop_early() -> PolarsResult<()> {
+    let mut batches = df!("a" => (0..250_000).collect::<Vec<i64>>())?
+        .lazy()
+        .collect_batches()?;
+    assert!(batches.next().unwrap()?.height() > 0);
+    // Dropping the iterator stops the query.
+    drop(batches);
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_collect_batches_error() -> PolarsResult<()> {
+    let batches = df!("a" => ["1", "2", "x"])?
+        .lazy()
+        .select([col("a").strict_cast(DataType::Int64)])
+        .collect_batches()?;
+
+    let err = batches.collect::<PolarsResult<Vec<_>>>().unwrap_err();
+    assert!(err.to_string().contains("conversion"), "{err}");
+
+    Ok(())
+}
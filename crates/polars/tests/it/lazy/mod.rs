@@ -1,4 +1,8 @@
 mod aggregation;
+#[cfg(feature = "new_streaming")]
+mod arrow_stream;
+#[cfg(feature = "new_streaming")]
+mod batches;
 #[cfg(feature = "cse")]
 mod cse;
 mod cwc;
@@ -10,6 +14,8 @@ mod functions;
 mod group_by;
 mod group_by_dynamic;
 mod predicate_queries;
+#[cfg(feature = "cloud")]
+mod profile;
 mod projection_queries;
 mod queries;
 mod schema;
@@ -0,0 +1,95 @@
+use polars::prelude::*;
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_profile_io_metrics() -> PolarsResult<()> {
+    let root = "memory://test_profile_io_metrics";
+
+    for year in [2023, 2024] {
+        df!("value" => (0..100i64).collect::<Vec<_>>())?
+            .lazy()
+            .sink_parquet(
+                SinkTarget::Path(PlPath::new(&format!("{root}/year={year}/0.parquet"))),
+                ParquetWriteOptions {
+                    row_group_size: Some(10),
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+    }
+
+    let (out, _, io_metrics) = LazyFrame::scan_parquet(
+        PlPath::new(&format!("{root}/**/*.parquet")),
+        Default::default(),
+    )?
+    .filter(col("year").eq(lit(2024)).and(col("value").gt_eq(lit(95))))
+    .profile_with_io_metrics()?;
+    assert_eq!(out.height(), 5);
+    assert_eq!(io_metrics.height(), 1);
+
+    let get =
+        |name: &str| -> PolarsResult<u64> { Ok(io_metrics.column(name)?.u64()?.get(0).unwrap()) };
+    assert_eq!(get("files_read")?, 1);
+    assert_eq!(get("files_pruned")?, 1);
+    assert_eq!(get("row_groups_read")?, 1);
+    assert_eq!(get("row_groups_pruned")?, 9);
+    assert!(get("range_requests")? > 0);
+    assert!(get("bytes_requested")? >= get("bytes_used")?);
+    assert!(get("bytes_used")? > 0);
+
+    // The files opened to resolve a negative slice are only counted when they are read.
+    for (len, files_read) in [(5, 1), (105, 2)] {
+        let (out, _, io_metrics) = LazyFrame::scan_parquet(
+            PlPath::new(&format!("{root}/**/*.parquet")),
+            Default::default(),
+        )?
+        .slice(-(len as i64), len)
+        .profile_with_io_metrics()?;
+        assert_eq!(out.height(), len as usize);
+        assert_eq!(
+            io_metrics.column("files_read")?.u64()?.get(0),
+            Some(files_read)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_profile_io_metrics_file_cache_hits() -> PolarsResult<()> {
+    let uri = "memory://test_profile_io_metrics_file_cache_hits/data.csv";
+
+    df!("a" => [1i64, 2, 3])?
+        .lazy()
+        .sink_csv(
+            SinkTarget::Path(PlPath::new(uri)),
+            Default::default(),
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let profile = || -> PolarsResult<(u64, u64)> {
+        let (_, _, io_metrics) = LazyCsvReader::new(PlPath::new(uri))
+            .finish()?
+            .profile_with_io_metrics()?;
+        Ok((
+            io_metrics.column("file_cache_hits")?.u64()?.get(0).unwrap(),
+            io_metrics
+                .column("file_cache_misses")?
+                .u64()?
+                .get(0)
+                .unwrap(),
+        ))
+    };
+
+    // The file is downloaded into the cache when the schema is inferred, so reading it during
+    // execution is a cache hit.
+    assert_eq!(profile()?, (1, 0));
+    assert_eq!(profile()?, (1, 0));
+
+    Ok(())
+}
@@ -105,10 +105,13 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8", "utf8-lossy", "latin1", "windows-1252", "utf16-le", "utf16-be"
+]
 DeletionFiles: TypeAlias = tuple[
     Literal["iceberg-position-delete"], dict[int, list[str]]
 ]
+FileCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
@@ -128,6 +131,9 @@ ParallelStrategy: TypeAlias = Literal[
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+PartitionWriteMode: TypeAlias = Literal[
+    "error", "append", "overwrite_all", "overwrite_touched"
+]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
@@ -384,6 +390,7 @@ __all__ = [
     "EpochTimeUnit",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
+    "FileCompression",
     "FileSource",
     "FillNullStrategy",
     "FloatFmt",
@@ -414,6 +421,7 @@ __all__ = [
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
+    "PartitionWriteMode",
     "PartitioningScheme",
     "PivotAgg",
     "PolarsDataType",
@@ -136,6 +136,7 @@ DataSourceFormat = Literal[
     "PARQUET",
     "ORC",
     "TEXT",
+    "ICEBERG",
     "UNITY_CATALOG",
     "DELTASHARING",
     "DATABRICKS_FORMAT",
@@ -42,6 +42,16 @@ if TYPE_CHECKING:
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
 
+# Encodings that are decoded by the CSV reader, other encodings are decoded with Python.
+_NATIVE_ENCODINGS = {
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "windows-1252",
+    "utf16-le",
+    "utf16-be",
+}
+
 
 @deprecate_renamed_parameter("dtypes", "schema_overrides", version="0.20.31")
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -181,11 +191,12 @@ def read_csv(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', 'windows-1252', 'windows-1252-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. Files starting with a UTF-16 byte order mark are read
+        as UTF-16 when using `utf8`. When using other encodings than the
+        listed ones, the input is first decoded in memory with python.
+        Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -460,7 +471,7 @@ def read_csv(
 
     # TODO: scan_csv doesn't support a "dtype slice" (i.e. list[DataType])
     schema_overrides_is_list = isinstance(schema_overrides, Sequence)
-    encoding_supported_in_lazy = encoding in {"utf8", "utf8-lossy"}
+    encoding_supported_in_lazy = encoding in _NATIVE_ENCODINGS
 
     new_streaming = (
         os.getenv("POLARS_FORCE_NEW_STREAMING") == "1"
@@ -541,7 +552,7 @@ def read_csv(
     else:
         with prepare_file_arg(
             source,
-            encoding=encoding,
+            encoding=None if encoding_supported_in_lazy else encoding,
             use_pyarrow=False,
             raise_if_empty=raise_if_empty,
             storage_options=storage_options,
@@ -565,7 +576,7 @@ def read_csv(
                 infer_schema_length=infer_schema_length,
                 batch_size=batch_size,
                 n_rows=n_rows,
-                encoding=encoding if encoding == "utf8-lossy" else "utf8",
+                encoding=encoding if encoding_supported_in_lazy else "utf8",
                 low_memory=low_memory,
                 rechunk=rechunk,
                 skip_rows_after_header=skip_rows_after_header,
@@ -850,11 +861,10 @@ def read_csv_batched(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. Files starting with a UTF-16 byte order mark are read
+        as UTF-16 when using `utf8`. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1036,7 +1046,7 @@ def read_csv_batched(
         infer_schema_length=infer_schema_length,
         batch_size=batch_size,
         n_rows=n_rows,
-        encoding=encoding if encoding == "utf8-lossy" else "utf8",
+        encoding=encoding if encoding in _NATIVE_ENCODINGS else "utf8",
         low_memory=low_memory,
         rechunk=rechunk,
         skip_rows_after_header=skip_rows_after_header,
@@ -1181,9 +1191,11 @@ def scan_csv(
         Set `infer_schema=False` to read all columns as `pl.String`.
     n_rows
         Stop reading from CSV file after reading `n_rows`.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. Defaults to "utf8".
+        characters. Files starting with a UTF-16 byte order mark are read
+        as UTF-16 when using `utf8`. Other encodings are transcoded to utf8
+        in memory before parsing. Defaults to "utf8".
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -388,6 +388,7 @@ def scan_ipc(
     hive_partitioning: bool | None = None,
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
+    path_template: str | None = None,
     include_file_paths: str | None = None,
 ) -> LazyFrame:
     """
@@ -464,6 +465,17 @@ def scan_ipc(
             at any point without it being considered a breaking change.
     try_parse_hive_dates
         Whether to try parsing hive values as date/datetime types.
+    path_template
+        Template to extract the partition columns from paths that are not laid out
+        as `key=value` directories, e.g. `"{year}/{month}/{day}/{source}.arrow"`.
+        The template is matched against the trailing segments of each path. A
+        `{name}` placeholder matches a non-empty part of a segment, `*` matches any
+        part of a segment and all other characters must match literally. The
+        extracted columns prune files in the same way as hive partitions.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
     """
@@ -509,6 +521,7 @@ def scan_ipc(
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
         try_parse_hive_dates=try_parse_hive_dates,
+        path_template=path_template,
         include_file_paths=include_file_paths,
     )
     return wrap_ldf(pylf)
@@ -58,6 +58,7 @@ def read_parquet(
     schema: SchemaDict | None = None,
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
+    path_template: str | None = None,
     rechunk: bool = False,
     low_memory: bool = False,
     storage_options: dict[str, Any] | None = None,
@@ -130,6 +131,17 @@ def read_parquet(
             at any point without it being considered a breaking change.
     try_parse_hive_dates
         Whether to try parsing hive values as date/datetime types.
+    path_template
+        Template to extract the partition columns from paths that are not laid out
+        as `key=value` directories, e.g. `"{year}/{month}/{day}/{source}.parquet"`.
+        The template is matched against the trailing segments of each path. A
+        `{name}` placeholder matches a non-empty part of a segment, `*` matches any
+        part of a segment and all other characters must match literally. The
+        extracted columns prune files in the same way as hive partitions.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     rechunk
         Make sure that all columns are contiguous in memory by
         aggregating the chunks into a single array.
@@ -262,6 +274,7 @@ def read_parquet(
         schema=schema,
         hive_schema=hive_schema,
         try_parse_hive_dates=try_parse_hive_dates,
+        path_template=path_template,
         rechunk=rechunk,
         low_memory=low_memory,
         cache=False,
@@ -406,6 +419,7 @@ def scan_parquet(
     schema: SchemaDict | None = None,
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
+    path_template: str | None = None,
     rechunk: bool = False,
     low_memory: bool = False,
     cache: bool = True,
@@ -489,6 +503,17 @@ def scan_parquet(
             at any point without it being considered a breaking change.
     try_parse_hive_dates
         Whether to try parsing hive values as date/datetime types.
+    path_template
+        Template to extract the partition columns from paths that are not laid out
+        as `key=value` directories, e.g. `"{year}/{month}/{day}/{source}.parquet"`.
+        The template is matched against the trailing segments of each path. A
+        `{name}` placeholder matches a non-empty part of a segment, `*` matches any
+        part of a segment and all other characters must match literally. The
+        extracted columns prune files in the same way as hive partitions.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     rechunk
         In case of reading multiple files via a glob pattern rechunk the final DataFrame
         into contiguous memory chunks.
@@ -638,6 +663,7 @@ def scan_parquet(
             hive_partitioning=hive_partitioning,
             hive_schema=hive_schema,
             try_parse_hive_dates=try_parse_hive_dates,
+            path_template=path_template,
             rechunk=rechunk,
             cache=cache,
             storage_options=(
@@ -16,6 +16,8 @@ if TYPE_CHECKING:
 
     from typing import IO, Any, Callable
 
+    from polars._typing import PartitionWriteMode
+
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyPartitioning
 
@@ -231,6 +233,20 @@ class PartitionMaxSize(PartitioningScheme):
 
         For parquet files, the callback is given a dataframe with metrics about all
         files written files.
+    write_mode : {'error', 'append', 'overwrite_all', 'overwrite_touched'}, optional
+        How to handle the data that already exists in `base_path`. With a write mode
+        the files are first written to a `_temporary` directory and only moved into
+        `base_path` once the query succeeds, after which a `_SUCCESS` manifest lists
+        the written files. On cloud storage the files are moved by copying them, so
+        the move is not atomic.
+
+        * `'error'`: raise an error if `base_path` contains any data.
+        * `'append'`: keep the existing files.
+        * `'overwrite_all'`: replace all existing data.
+        * `'overwrite_touched'`: replace the files of the partitions that are
+          written to, and keep the other partitions.
+
+        By default the files are written directly into `base_path`.
 
     Examples
     --------
@@ -256,6 +272,7 @@ class PartitionMaxSize(PartitioningScheme):
         max_size: int,
         per_partition_sort_by: str | Expr | Iterable[str | Expr] | None = None,
         finish_callback: Callable[[DataFrame], None] | None = None,
+        write_mode: PartitionWriteMode | None = None,
     ) -> None:
         issue_unstable_warning("partitioning strategies are considered unstable.")
         super().__init__(
@@ -267,6 +284,7 @@ class PartitionMaxSize(PartitioningScheme):
                     per_partition_sort_by
                 ),
                 finish_callback=_prepare_finish_callback(finish_callback),
+                write_mode=write_mode,
             )
         )
 
@@ -339,6 +357,20 @@ class PartitionByKey(PartitioningScheme):
 
         For parquet files, the callback is given a dataframe with metrics about all
         files written files.
+    write_mode : {'error', 'append', 'overwrite_all', 'overwrite_touched'}, optional
+        How to handle the data that already exists in `base_path`. With a write mode
+        the files are first written to a `_temporary` directory and only moved into
+        `base_path` once the query succeeds, after which a `_SUCCESS` manifest lists
+        the written files. On cloud storage the files are moved by copying them, so
+        the move is not atomic.
+
+        * `'error'`: raise an error if `base_path` contains any data.
+        * `'append'`: keep the existing files.
+        * `'overwrite_all'`: replace all existing data.
+        * `'overwrite_touched'`: replace the files of the partitions that are
+          written to, and keep the other partitions.
+
+        By default the files are written directly into `base_path`.
 
     Examples
     --------
@@ -384,6 +416,7 @@ class PartitionByKey(PartitioningScheme):
         include_key: bool = True,
         per_partition_sort_by: str | Expr | Iterable[str | Expr] | None = None,
         finish_callback: Callable[[DataFrame], None] | None = None,
+        write_mode: PartitionWriteMode | None = None,
     ) -> None:
         issue_unstable_warning("partitioning strategies are considered unstable.")
 
@@ -398,6 +431,7 @@ class PartitionByKey(PartitioningScheme):
                     per_partition_sort_by
                 ),
                 finish_callback=_prepare_finish_callback(finish_callback),
+                write_mode=write_mode,
             )
         )
 
@@ -446,6 +480,20 @@ class PartitionParted(PartitioningScheme):
 
         For parquet files, the callback is given a dataframe with metrics about all
         files written files.
+    write_mode : {'error', 'append', 'overwrite_all', 'overwrite_touched'}, optional
+        How to handle the data that already exists in `base_path`. With a write mode
+        the files are first written to a `_temporary` directory and only moved into
+        `base_path` once the query succeeds, after which a `_SUCCESS` manifest lists
+        the written files. On cloud storage the files are moved by copying them, so
+        the move is not atomic.
+
+        * `'error'`: raise an error if `base_path` contains any data.
+        * `'append'`: keep the existing files.
+        * `'overwrite_all'`: replace all existing data.
+        * `'overwrite_touched'`: replace the files of the partitions that are
+          written to, and keep the other partitions.
+
+        By default the files are written directly into `base_path`.
 
     Examples
     --------
@@ -473,6 +521,7 @@ class PartitionParted(PartitioningScheme):
         include_key: bool = True,
         per_partition_sort_by: str | Expr | Iterable[str | Expr] | None = None,
         finish_callback: Callable[[DataFrame], None] | None = None,
+        write_mode: PartitionWriteMode | None = None,
     ) -> None:
         issue_unstable_warning("partitioning strategies are considered unstable.")
 
@@ -487,5 +536,6 @@ class PartitionParted(PartitioningScheme):
                     per_partition_sort_by
                 ),
                 finish_callback=_prepare_finish_callback(finish_callback),
+                write_mode=write_mode,
             )
         )
@@ -35,6 +35,7 @@ class ScanOptions:
     hive_partitioning: bool | None = None
     hive_schema: SchemaDict | None = None
     try_parse_hive_dates: bool = True
+    path_template: str | None = None
 
     rechunk: bool = False
     cache: bool = True
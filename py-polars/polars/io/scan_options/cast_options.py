@@ -29,6 +29,8 @@ class ScanCastOptions:
         datetime_cast: Literal["forbid"]
         | DatetimeCastOption
         | Collection[DatetimeCastOption] = "forbid",
+        decimal_cast: Literal["upcast", "forbid"] = "forbid",
+        use_field_ids: bool = False,
         missing_struct_fields: Literal["insert", "raise"] = "raise",
         extra_struct_fields: Literal["ignore", "raise"] = "raise",
         _internal_call: bool = False,
@@ -64,6 +66,19 @@ class ScanCastOptions:
             * `convert-timezone`: Allow casting to a different timezone.
             * `forbid`: Raises an error if dtypes do not match.
 
+        decimal_cast
+            Configuration for casting from decimal types:
+
+            * `upcast`: Allow casting to decimal types with at least as many integer \
+            and fractional digits.
+            * `forbid`: Raises an error if dtypes do not match.
+
+        use_field_ids
+            Match the columns and struct fields of every file to those of the first
+            file by their Parquet field IDs instead of by name, so that columns that
+            were renamed in between are read under their name in the first file.
+            Only applies to Parquet scans.
+
         missing_struct_fields
             Configuration for behavior when struct fields defined in the schema
             are missing from the data:
@@ -85,6 +100,8 @@ class ScanCastOptions:
         self.integer_cast = integer_cast
         self.float_cast = float_cast
         self.datetime_cast = datetime_cast
+        self.decimal_cast = decimal_cast
+        self.use_field_ids = use_field_ids
         self.missing_struct_fields = missing_struct_fields
         self.extra_struct_fields = extra_struct_fields
 
@@ -108,6 +125,7 @@ class ScanCastOptions:
                 integer_cast="upcast",
                 float_cast=["upcast", "downcast"],
                 datetime_cast=["nanosecond-downcast", "convert-timezone"],
+                decimal_cast="upcast",
                 missing_struct_fields="insert",
                 extra_struct_fields="ignore",
                 _internal_call=True,
@@ -123,9 +123,11 @@ if TYPE_CHECKING:
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvEncoding,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
+        FileCompression,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
@@ -2633,6 +2635,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
 
+            Uploads can be tuned for every provider with `upload_chunk_size` (bytes
+            per part of a multipart upload), `upload_max_concurrency`,
+            `upload_max_retries` and `upload_retry_backoff_ms`.
+
             If `storage_options` is not provided, Polars will try to infer the
             information from environment variables.
         credential_provider
@@ -2903,6 +2909,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
 
+            Uploads can be tuned for every provider with `upload_chunk_size` (bytes
+            per part of a multipart upload), `upload_max_concurrency`,
+            `upload_max_retries` and `upload_retry_backoff_ms`.
+
             If `storage_options` is not provided, Polars will try to infer the
             information from environment variables.
         credential_provider
@@ -3034,6 +3044,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3066,6 +3079,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3097,6 +3113,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3172,6 +3191,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. Files of partitioned
+            sinks get a `.gz` or `.zst` suffix appended to their extension.
+        compression_level
+            The level of compression to use. Higher compression means smaller
+            files on disk.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}
+            Text encoding of the written file. Characters that cannot be
+            represented in the encoding raise an error. The BOM written with
+            `include_bom` is that of the encoding.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3191,6 +3223,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
 
+            Uploads can be tuned for every provider with `upload_chunk_size` (bytes
+            per part of a multipart upload), `upload_max_concurrency`,
+            `upload_max_retries` and `upload_retry_backoff_ms`.
+
             If `storage_options` is not provided, Polars will try to infer the
             information from environment variables.
         credential_provider
@@ -3297,6 +3333,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
+            encoding=encoding,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3315,6 +3354,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3333,6 +3374,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3350,6 +3393,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: FileCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3371,6 +3416,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. Files of partitioned
+            sinks get a `.gz` or `.zst` suffix appended to their extension.
+        compression_level
+            The level of compression to use. Higher compression means smaller
+            files on disk.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3390,6 +3444,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
             `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
 
+            Uploads can be tuned for every provider with `upload_chunk_size` (bytes
+            per part of a multipart upload), `upload_max_concurrency`,
+            `upload_max_retries` and `upload_retry_backoff_ms`.
+
             If `storage_options` is not provided, Polars will try to infer the
             information from environment variables.
         credential_provider
@@ -3476,6 +3534,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         ldf = self._ldf.sink_json(
             target=target,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -25,7 +25,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import CsvQuoteStyle, TimeUnit
+    from polars._typing import CsvEncoding, CsvQuoteStyle, TimeUnit
     from tests.unit.conftest import MemoryUsage
 
 
@@ -533,6 +533,44 @@ def test_read_csv_encoding_lossy(tmp_path: Path) -> None:
         )
 
 
+@pytest.mark.parametrize(
+    ("encoding", "python_encoding"),
+    [
+        ("latin1", "latin-1"),
+        ("windows-1252", "cp1252"),
+        ("utf16-le", "utf-16-le"),
+        ("utf16-be", "utf-16-be"),
+    ],
+)
+def test_csv_native_encodings(
+    encoding: CsvEncoding, python_encoding: str, tmp_path: Path
+) -> None:
+    df = pl.DataFrame({"name": ["Zoë", "Ærø"], "n": [1, 2]})
+    if encoding == "windows-1252":
+        df = df.with_columns(pl.col("name") + " €")
+
+    path = tmp_path / "encoded.csv"
+    df.lazy().sink_csv(path, encoding=encoding)
+    assert path.read_bytes() == df.write_csv().encode(python_encoding)
+
+    assert_frame_equal(pl.read_csv(path, encoding=encoding), df)
+    assert_frame_equal(pl.scan_csv(path, encoding=encoding).collect(), df)
+
+
+def test_csv_utf16_bom_detection() -> None:
+    df = pl.DataFrame({"name": ["Zoë", "😀"], "n": [1, 2]})
+
+    for python_encoding in ["utf-16-le", "utf-16-be"]:
+        bom = "\ufeff".encode(python_encoding)
+        data = bom + df.write_csv().encode(python_encoding)
+        assert_frame_equal(pl.read_csv(data), df)
+
+
+def test_sink_csv_encoding_unrepresentable(tmp_path: Path) -> None:
+    with pytest.raises(ComputeError, match="cannot be encoded as latin-1"):
+        pl.LazyFrame({"a": ["€"]}).sink_csv(tmp_path / "out.csv", encoding="latin1")
+
+
 @pytest.mark.may_fail_auto_streaming  # read->scan_csv dispatch
 def test_column_rename_and_schema_overrides() -> None:
     csv = textwrap.dedent(
@@ -1046,3 +1046,28 @@ print("OK", end="")
     )
 
     assert out == b"OK"
+
+
+@pytest.mark.write_disk
+def test_scan_path_template(tmp_path: Path) -> None:
+    for year, source in [(2023, "web"), (2024, "web"), (2024, "app")]:
+        (tmp_path / str(year)).mkdir(exist_ok=True)
+        df = pl.DataFrame({"x": [year]})
+        df.write_parquet(tmp_path / str(year) / f"{source}.parquet")
+        df.write_ipc(tmp_path / str(year) / f"{source}.arrow")
+
+    expect = pl.DataFrame(
+        {"x": [2024], "year": [2024], "source": ["app"]},
+        schema_overrides={"x": pl.Int64},
+    )
+
+    lf = pl.scan_parquet(
+        tmp_path / "**/*.parquet", path_template="{year}/{source}.parquet"
+    )
+    assert lf.collect_schema().names() == ["x", "year", "source"]
+    out = lf.filter(pl.col("year") == 2024, pl.col("source") == "app").collect()
+    assert_frame_equal(out, expect, check_dtypes=False)
+
+    lf = pl.scan_ipc(tmp_path / "**/*.arrow", path_template="{year}/{source}.arrow")
+    out = lf.filter(pl.col("year") == 2024, pl.col("source") == "app").collect()
+    assert_frame_equal(out, expect, check_dtypes=False)
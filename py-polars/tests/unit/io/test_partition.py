@@ -537,3 +537,35 @@ def test_finish_callback_nested_23306() -> None:
     )
 
     lf.sink_parquet(partitioning, mkdir=True)
+
+
+@pytest.mark.write_disk
+def test_partition_by_key_write_mode(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 1, 2], "b": [1, 2, 3]})
+
+    df.lazy().sink_parquet(
+        PartitionByKey(tmp_path, by="a", include_key=False, write_mode="error"),
+        engine="streaming",
+    )
+    assert (tmp_path / "_SUCCESS").exists()
+    assert not (tmp_path / "_temporary").exists()
+
+    with pytest.raises(pl.exceptions.ComputeError, match="already contains data"):
+        df.lazy().sink_parquet(
+            PartitionByKey(tmp_path, by="a", include_key=False, write_mode="error"),
+            engine="streaming",
+        )
+
+    new = pl.DataFrame({"a": [3], "b": [4]})
+    new.lazy().sink_parquet(
+        PartitionByKey(tmp_path, by="a", include_key=False, write_mode="overwrite_all"),
+        engine="streaming",
+    )
+    assert_frame_equal(
+        pl.scan_parquet(tmp_path).select("a", "b").collect(),
+        new,
+        check_dtypes=False,
+    )
+
+    with pytest.raises(ValueError, match="`write_mode` must be one of"):
+        PartitionByKey(tmp_path, by="a", write_mode="replace")  # type: ignore[arg-type]
@@ -2,12 +2,14 @@ from __future__ import annotations
 
 import io
 from datetime import datetime
+from decimal import Decimal as D
 from typing import IO, Any, Callable
 from zoneinfo import ZoneInfo
 
 import pytest
 
 import polars as pl
+from polars.io.parquet import ParquetFieldOverwrites
 from polars.testing import assert_frame_equal
 
 
@@ -62,6 +64,14 @@ from polars.testing import assert_frame_equal
                 datetime_cast=["nanosecond-downcast", "convert-timezone"]
             ),
         ),
+        (
+            (
+                pl.lit(D("1.5"), dtype=pl.Decimal(10, 2)),
+                pl.lit(D("2.25"), dtype=pl.Decimal(5, 2)),
+            ),
+            pl.Series([D("1.5"), D("2.25")], dtype=pl.Decimal(10, 2)),
+            pl.ScanCastOptions(decimal_cast="upcast"),
+        ),
         (
             (  # We also test nested primitive upcast policy with this one
                 pl.lit(
@@ -220,6 +230,35 @@ def test_scan_cast_options_extra_struct_fields() -> None:
     )
 
 
+def test_scan_cast_options_use_field_ids() -> None:
+    files: list[IO[bytes]] = [io.BytesIO(), io.BytesIO()]
+
+    pl.LazyFrame({"a": [1]}).sink_parquet(
+        files[0], field_overwrites={"a": ParquetFieldOverwrites(field_id=1)}
+    )
+    pl.LazyFrame({"renamed": [2]}).sink_parquet(
+        files[1], field_overwrites={"renamed": ParquetFieldOverwrites(field_id=1)}
+    )
+
+    for f in files:
+        f.seek(0)
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        pl.scan_parquet(files, extra_columns="ignore").collect()
+
+    for f in files:
+        f.seek(0)
+
+    assert_frame_equal(
+        pl.scan_parquet(
+            files,
+            cast_options=pl.ScanCastOptions(use_field_ids=True),
+            extra_columns="ignore",
+        ).collect(),
+        pl.DataFrame({"a": [1, 2]}),
+    )
+
+
 def test_cast_options_ignore_extra_columns() -> None:
     files: list[IO[bytes]] = [io.BytesIO(), io.BytesIO()]
 
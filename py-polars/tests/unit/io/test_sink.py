@@ -1,3 +1,5 @@
+from __future__ import annotations
+
 import io
 from pathlib import Path
 from typing import Any
@@ -106,3 +108,58 @@ def test_sink_to_file(tmp_path: Path, sink: Any, scan: Any) -> None:
             scan(f).collect(),
             df,
         )
+
+
+@pytest.mark.parametrize(
+    ("scan", "sink"),
+    [
+        (pl.scan_csv, pl.LazyFrame.sink_csv),
+        (pl.scan_ndjson, pl.LazyFrame.sink_ndjson),
+    ],
+)
+@pytest.mark.parametrize(
+    ("compression", "compression_level", "magic"),
+    [
+        ("gzip", None, b"\x1f\x8b"),
+        ("gzip", 9, b"\x1f\x8b"),
+        ("zstd", 19, b"\x28\xb5\x2f\xfd"),
+    ],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_sink_compressed(
+    tmp_path: Path,
+    scan: Any,
+    sink: Any,
+    compression: str,
+    compression_level: int | None,
+    magic: bytes,
+    engine: EngineType,
+) -> None:
+    df = pl.DataFrame({"a": range(1000), "b": [f"s{i}" for i in range(1000)]})
+
+    f = tmp_path / "file"
+    sink(
+        df.lazy(),
+        f,
+        compression=compression,
+        compression_level=compression_level,
+        engine=engine,
+    )
+
+    assert f.read_bytes().startswith(magic)
+    assert_frame_equal(scan(f).collect(), df)
+
+
+@pytest.mark.parametrize(
+    "sink",
+    [pl.LazyFrame.sink_csv, pl.LazyFrame.sink_ndjson],
+)
+def test_sink_compression_level_out_of_range(sink: Any) -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        sink(
+            pl.LazyFrame({"a": [1]}),
+            io.BytesIO(),
+            compression="gzip",
+            compression_level=10,
+        )